@@ -0,0 +1,716 @@
+## Admin login
+
+admin-login-title = Sign in
+admin-login-welcome = Welcome
+admin-login-name = Username
+admin-login-password = Password
+admin-login-captcha = Captcha
+admin-login-submit = Sign in
+admin-login-required = Please sign in first
+
+## Login messages
+
+auth-name-required = The username is required
+auth-password-required = The password is required
+auth-captcha-required = The captcha is required
+auth-captcha-length = The captcha length is invalid
+auth-captcha-error = The captcha is wrong
+auth-logged-in = You are already signed in
+auth-password-error = Wrong username or password
+auth-user-disabled = The account does not exist or is disabled
+auth-login-failed = Failed to sign in
+auth-login-success = Signed in
+
+## Common
+
+admin-title = Nako Blog Admin
+admin-home = Home
+admin-status = Status
+admin-enable = Enabled
+admin-disable = Disabled
+admin-enable-switch = On|Off
+admin-submit = Submit
+admin-reset = Reset
+admin-query = Search
+admin-add = Add
+admin-create = Create
+admin-edit = Edit
+admin-editor = Edit
+admin-delete = Delete
+admin-detail = Details
+admin-view = View
+admin-save = Save
+admin-back = Back
+admin-refresh = Refresh
+admin-action = Actions
+admin-tip = Notice
+admin-confirm = OK
+admin-cancel = Cancel
+admin-yes = Yes
+admin-no = No
+admin-all = All
+admin-on = On
+admin-off = Off
+admin-default = Default
+admin-forever = Never
+admin-success = Success
+admin-failed = Failed
+admin-upload = Upload
+admin-upload-image = Upload image
+admin-select-image = Choose image
+admin-show-image = Show image
+admin-download = Download
+admin-preview = Preview
+admin-merge = Merge
+admin-remove = Remove
+admin-move-up = Move up
+admin-move-down = Move down
+admin-clear = Empty
+admin-approve = Approve
+admin-review = Review
+admin-reply = Reply
+admin-generate = Generate
+admin-restore = Restore
+admin-check = Check
+admin-import = Import
+admin-skip = Skipped
+admin-added = Added
+admin-updated = Updated
+admin-deleted = Deleted
+admin-modified = Modified
+admin-normal = Normal
+admin-image = Image
+admin-attachment = Attachment
+admin-name = Name
+admin-title-label = Title
+admin-content = Content
+admin-link = Link
+admin-keywords = Keywords
+admin-description = Description
+admin-cover = Cover
+admin-cate = Category
+admin-tag = Tags
+admin-slug = Slug
+admin-alias = Slug
+admin-sort = Sort
+admin-order = Order
+admin-user = User
+admin-account = Account
+admin-email = Email
+admin-phone = Phone
+admin-source = Source
+admin-mode = Mode
+admin-options = Options
+admin-art = Articles
+admin-page = Pages
+admin-comment = Comments
+admin-event = Events
+admin-permission = Permissions
+admin-token = Token
+admin-template = Template
+admin-message = Message
+admin-add-time = Added at
+admin-add-ip = Added from IP
+admin-update-time = Modified at
+admin-upload-time = Uploaded at
+admin-upload-ip = Uploaded from IP
+admin-send-time = Sent at
+admin-times = Hits
+admin-edit-info = Edit
+admin-number-hint = Larger numbers come first
+admin-please-select-cate = Choose a category
+admin-input-title = Enter a title
+admin-input-name = Enter a name
+admin-input-content = Enter the content
+admin-input-description = Enter a description
+admin-input-keywords = Enter keywords
+admin-input-sort = Enter the sort order
+admin-input-slug = Enter a slug
+admin-input-account = Enter the account
+admin-input-confirm = Press Enter to confirm
+admin-get-success = Loaded
+admin-add-success = Added
+admin-add-failed = Failed to add
+admin-update-success = Updated
+admin-update-failed = Failed to update
+admin-delete-success = Deleted
+admin-delete-failed = Failed to delete
+admin-save-success = Saved
+admin-save-failed = Failed to save
+admin-upload-success = Uploaded
+admin-upload-failed = Upload failed
+admin-upload-failed-mark = Upload failed!
+admin-remove-success = Removed
+admin-remove-failed = Failed to remove
+admin-restore-success = Restored
+admin-restore-failed = Failed to restore
+admin-action-failed = Action failed
+admin-clear-success = Emptied
+admin-clear-failed = Failed to empty
+admin-batch-delete = Delete selected
+admin-batch-delete-success = Selected items deleted
+admin-batch-update-success = Selected items updated
+admin-nothing-selected = Nothing selected
+admin-id-required = ID is required
+admin-status-required = Status is required
+admin-status-error = Invalid status
+admin-name-required = Name is required
+admin-content-required = Content is required
+admin-sort-required = Sort order is required
+admin-link-required = Link is required
+admin-record-not-found = Record not found
+admin-file-not-found = File not found
+admin-page-not-found = Page not found
+admin-slug-used = The slug { $slug } is already in use
+
+## Menu and console
+
+admin-menu-console = Console
+admin-menu-art = Articles
+admin-menu-art-list = All articles
+admin-menu-cate = Categories
+admin-menu-tag = Tags
+admin-menu-series = Series
+admin-menu-comment = Comments
+admin-menu-comment-rule = Comment rules
+admin-menu-page = Pages
+admin-menu-attach = Attachments
+admin-menu-guestbook = Guestbook
+admin-menu-friendlink = Links
+admin-menu-system = System
+admin-menu-user = Users
+admin-menu-theme = Themes
+admin-menu-template = Templates
+admin-menu-redirect = Redirects
+admin-menu-not-found = 404 log
+admin-menu-import = Import and export
+admin-menu-setting = Settings
+admin-menu-profile = Profile
+admin-menu-password = Change password
+admin-menu-token = Access tokens
+admin-logout = Log out
+admin-logout-success = Logged out
+admin-source-code = Source code
+admin-console-shortcut = Shortcuts
+admin-console-stats = Statistics
+admin-console-art-count = Articles
+admin-console-cate-count = Categories
+admin-console-comment-count = Comments
+admin-console-tag-count = Tags
+admin-console-new-arts = Latest articles
+admin-console-role-name = Role
+admin-console-role-key = Key
+admin-console-role-enable = Enabled
+admin-console-chart-unit = (10k yuan)
+admin-console-chart-month = Month {"{"}value{"}"}
+admin-console-chart-axis = Unit: 10 MWh
+
+## Error pages
+
+admin-error-title = System notice
+admin-error-jump = Go now
+admin-error-retry = Reload
+
+## Articles
+
+admin-art-add = New article
+admin-art-edit = Edit article
+admin-art-detail = Article details
+admin-art-cate = Category
+admin-art-slug-hint = Used in the article URL, generated from the title when empty
+admin-art-user = Author
+admin-art-from = Source
+admin-art-input-from = Enter the source
+admin-art-views = Views
+admin-art-views-short = Views
+admin-art-top = Pinned
+admin-art-top-short = Top
+admin-art-uuid = Article UUID
+admin-art-brief = Summary
+admin-art-input-brief = Enter a summary
+admin-art-input-tags = Enter tags
+admin-art-publish-time = Published at
+admin-art-input-publish-time = Enter the publish time
+admin-art-close-comment = Close comments
+admin-art-confirm-delete = Delete this article?
+admin-art-not-found = Article not found
+admin-art-cate-required = Category is required
+admin-art-required = Article is required
+admin-art-title-required = Title is required
+admin-art-brief-required = Summary is required
+admin-art-publish-time-required = Publish time is required
+admin-art-update-not-found = The article to update does not exist
+admin-art-delete-not-found = The article to delete does not exist
+
+## Attachments
+
+admin-attach-detail = Attachment details
+admin-attach-file-name = File name
+admin-attach-file-name-short = File name
+admin-attach-path = Path
+admin-attach-ext = Extension
+admin-attach-size = Size
+admin-attach-hash = Hash
+admin-attach-type = Type
+admin-attach-confirm-delete = Delete this attachment?
+admin-attach-not-found = Attachment not found
+admin-attach-delete-not-found = The attachment to delete does not exist
+admin-attach-cannot-preview = This attachment cannot be previewed
+
+## Categories
+
+admin-cate-edit = Edit category
+admin-cate-detail = Category details
+admin-cate-name = Category name
+admin-cate-input-name = Enter the category name
+admin-cate-slug = Category slug
+admin-cate-input-slug = Enter the category slug
+admin-cate-parent = Parent category
+admin-cate-parent-id = Parent ID
+admin-cate-top = Top level
+admin-cate-desc = Category description
+admin-cate-list-tpl = List template
+admin-cate-view-tpl = Article template
+admin-cate-delete-prompt = Delete the category. Enter the category ID to move subcategories and articles to, or 0 to keep them
+admin-cate-not-found = Category not found
+admin-cate-required = Category is required
+admin-slug-required = Slug is required
+admin-cate-slug-exists = The category slug already exists
+admin-cate-parent-required = Parent is required
+admin-cate-name-required = Category name is required
+admin-cate-slug-required = Category slug is required
+admin-cate-list-tpl-required = List template is required
+admin-cate-view-tpl-required = Article template is required
+admin-cate-update-not-found = The category to update does not exist
+admin-cate-parent-not-found = Parent category not found
+admin-cate-parent-invalid = The parent cannot be the category itself or one of its subcategories
+admin-cate-delete-not-found = The category to delete does not exist
+admin-cate-not-empty = The category has subcategories or articles, choose a category to move them to
+admin-cate-move-not-found = The target category does not exist
+admin-cate-move-invalid = Cannot move to the category itself or one of its subcategories
+admin-cate-move-children-failed = Failed to move subcategories
+admin-cate-move-arts-failed = Failed to move articles
+
+## Comments
+
+admin-comment-detail = Comment details
+admin-comment-art-id = Article ID
+admin-comment-reply-to = Reply to
+admin-comment-user-email = Email
+admin-comment-notify = Reply notification
+admin-comment-reply-content = Reply
+admin-comment-reply-time = Replied at
+admin-comment-reply-ip = Reply IP
+admin-comment-pending = Pending
+admin-comment-approved = Approved
+admin-comment-spam = Spam
+admin-comment-trash = Trash
+admin-comment-approve-selected = Approve selected
+admin-comment-spam-selected = Mark as spam
+admin-comment-trash-selected = Move to trash
+admin-comment-block-ip = Block IP
+admin-comment-block-email = Block email
+admin-comment-owner = User
+admin-comment-owner-email = User email
+admin-comment-add-rule = Add rule
+admin-comment-clear-only = Only spam and trash can be emptied
+admin-comment-confirm-clear = Delete all comments in this list?
+admin-comment-confirm-delete = Delete this comment?
+admin-comment-confirm-batch-delete = Delete the selected comments?
+admin-comment-not-found = Comment not found
+admin-comment-delete-not-found = The comment to delete does not exist
+admin-comment-update-not-found = The comment to update does not exist
+
+## Comment rules
+
+admin-rule-edit = Edit rule
+admin-rule-type = Rule type
+admin-rule-content = Rule
+admin-rule-input-content = Enter the rule
+admin-rule-hint = End an IP with * to match a prefix, use @example.com to match an email domain. Keywords match the name, email and content
+admin-rule-action = Action
+admin-rule-allow = Approve
+admin-rule-block = Mark as spam
+admin-rule-hits = Hits
+admin-rule-confirm-delete = Delete this rule?
+admin-rule-exists = The rule already exists
+admin-rule-not-found = Rule not found
+admin-rule-update-not-found = The rule to update does not exist
+admin-rule-delete-not-found = The rule to delete does not exist
+admin-rule-action-error = Invalid action
+admin-rule-content-required = Rule is required
+admin-rule-ip-error = Invalid IP, end it with * to match a prefix
+admin-rule-email-error = Invalid email, use @example.com to match a domain
+admin-rule-type-error = Invalid rule type
+
+## Friend links
+
+admin-link-edit = Edit link
+admin-link-detail = Link details
+admin-link-url = URL
+admin-link-input-url = Enter the URL
+admin-link-target = Open in
+admin-link-target-blank = New window
+admin-link-target-self = Same window
+admin-link-icon = Icon
+admin-link-input-icon = Enter the icon URL
+admin-link-confirm-delete = Delete this link?
+admin-link-not-found = Link not found
+admin-link-target-required = Open in is required
+admin-link-update-not-found = The link to update does not exist
+admin-link-delete-not-found = The link to delete does not exist
+
+## Guestbook
+
+admin-guestbook-detail = Message details
+admin-guestbook-id = Message ID
+admin-guestbook-content = Message
+admin-guestbook-phone = Phone
+admin-guestbook-email = Email
+admin-guestbook-qq = QQ
+admin-guestbook-weixin = WeChat
+admin-guestbook-time = Sent at
+admin-guestbook-ip = IP
+admin-guestbook-contact = Contacted
+admin-guestbook-contact-switch = Yes|No
+admin-guestbook-approved-switch = Approved|Pending
+admin-guestbook-replied = Replied
+admin-guestbook-not-replied = Not replied
+admin-guestbook-reply = Reply
+admin-guestbook-input-reply = Enter a reply, leave empty to remove it
+admin-guestbook-approved-hint = Approved messages are shown on the public guestbook
+admin-guestbook-confirm-delete = Delete this message?
+admin-guestbook-confirm-batch-delete = Delete the selected messages?
+admin-guestbook-not-found = Message not found
+admin-guestbook-approved-required = Review status is required
+admin-guestbook-reply-not-found = The message to reply to does not exist
+admin-guestbook-reply-failed = Failed to reply
+admin-guestbook-approved-failed = Failed to update the review status
+admin-guestbook-reply-success = Replied
+admin-guestbook-delete-not-found = The message to delete does not exist
+admin-guestbook-update-not-found = The message to update does not exist
+admin-guestbook-review-not-found = The message to review does not exist
+
+## Import and export
+
+admin-import-wxr = Import WordPress data
+admin-import-wxr-file = WXR file
+admin-import-dry-run = Preview only
+admin-import-download-media = Download attachments
+admin-import-start = Start import
+admin-import-report = Import report
+admin-import-markdown = Markdown import and export
+admin-import-zip = Zip file
+admin-import-zip-hint = A zip of md or html files with YAML front matter. Articles are matched by uuid and pages by slug
+admin-import-export-all = Export all
+admin-import-failed = Import failed
+admin-import-read-failed = Failed to read the import file
+admin-import-done = Import finished
+admin-import-no-front-matter = Missing front matter
+admin-import-front-matter-error = Invalid front matter
+admin-import-not-wxr = Not a WordPress export file
+admin-import-parse-failed = Failed to parse the file: { $error }
+admin-import-status-code = Status code { $code }
+admin-import-cate-parent-missing = The parent { $parent } of category { $name } does not exist
+admin-import-cate-failed = Failed to import category { $name }
+admin-import-tag-failed = Failed to import tag { $name }
+admin-import-media-failed = Failed to download attachment { $url }: { $error }
+admin-import-art-no-cate = No category available for article { $title }
+admin-import-art-failed = Failed to import article { $title }
+admin-import-comment-failed = Failed to import comment { $id } of article { $title }
+admin-import-page-exists = Page { $slug } already exists
+admin-import-page-failed = Failed to import page { $title }
+admin-import-file-title-required = { $file }: title is required
+admin-import-file-slug-required = { $file }: page slug is required
+admin-import-file-update-failed = { $file }: failed to update
+admin-import-file-add-failed = { $file }: failed to add
+admin-import-file-cate-missing = { $file }: category { $slug } does not exist, using the default category
+admin-import-file-no-cate = { $file }: no category available
+
+## 404 log
+
+admin-not-found-detail = 404 details
+admin-not-found-referrer = Recent referrers
+admin-not-found-last-time = Last seen
+admin-not-found-first-time = First seen
+admin-not-found-no-referrer = No referrers yet
+admin-not-found-add-redirect = Add redirect
+admin-not-found-confirm-delete = Delete this record?
+admin-not-found-confirm-clear = Delete all records?
+
+## Pages
+
+admin-page-detail = Page details
+admin-page-edit = Edit page
+admin-page-tpl-used = Template
+admin-page-add = New page
+admin-page-slug = Page slug
+admin-page-input-slug = Enter the page slug
+admin-page-title = Page title
+admin-page-input-title = Enter the page title
+admin-page-content = Page content
+admin-page-input-content = Enter the page content
+admin-page-tpl = Page template
+admin-page-confirm-delete = Delete this page?
+admin-page-slug-required = Page slug is required
+admin-page-title-required = Page title is required
+admin-page-slug-exists = The page slug already exists
+admin-page-tpl-required = Page template is required
+admin-page-update-not-found = The page to update does not exist
+admin-page-delete-not-found = The page to delete does not exist
+
+## Profile
+
+admin-profile-avatar = Avatar
+admin-profile-avatar-upload = Upload avatar
+admin-profile-avatar-change = Change avatar
+admin-profile-rotate-left = Rotate left 15°
+admin-profile-rotate-right = Rotate right 15°
+admin-profile-avatar-hint = Tip: use a square image smaller than 5 MB.
+admin-profile-avatar-too-large = The file must be smaller than 5 MB
+admin-profile-nickname = Nickname
+admin-profile-input-nickname = Enter a nickname
+admin-profile-email-hint = Receives comment and guestbook notifications
+admin-profile-sign = Bio
+admin-profile-input-sign = Enter a bio
+admin-profile-old-password = Current password
+admin-profile-input-old-password = Enter the current password
+admin-profile-new-password = New password
+admin-profile-input-new-password = Enter the new password
+admin-profile-confirm-password = Confirm password
+admin-profile-input-confirm-password = Enter the new password again
+admin-account-not-found = Account not found
+admin-account-required = Account is required
+admin-nickname-required = Nickname is required
+admin-email-error = Invalid email
+admin-account-exists = The account already exists
+admin-old-password-required = Current password is required
+admin-new-password-required = New password is required
+admin-confirm-password-required = Password confirmation is required
+admin-confirm-password-mismatch = The passwords do not match
+admin-password-update-failed = Failed to change the password
+admin-old-password-error = The current password is wrong
+admin-password-update-success = Password changed
+admin-avatar-required = Avatar data is required
+admin-avatar-update-failed = Failed to change the avatar
+admin-avatar-update-success = Avatar changed
+
+## Access tokens
+
+admin-token-create = New access token
+admin-token-input-name = Enter a token name
+admin-token-expire-days = Valid for (days)
+admin-token-expire-hint = 0 never expires
+admin-token-mine = My access tokens
+admin-token-last-used = Last used
+admin-token-expire-time = Expires at
+admin-token-save-hint = Save the token now, it is only shown once:
+admin-token-confirm-revoke = Revoke this token?
+admin-token-scopes-required = Permissions are required
+admin-token-expire-too-long = A token can be valid for at most 3650 days
+admin-token-expire-negative = Valid days cannot be negative
+admin-token-revoke-success = Revoked
+admin-token-revoke-failed = Failed to revoke
+
+## Redirects
+
+admin-redirect-edit = Edit redirect
+admin-redirect-source = Source
+admin-redirect-input-source = Enter the source path, e.g. /old-page
+admin-redirect-match = Match
+admin-redirect-target = Target
+admin-redirect-input-target = Enter the target URL
+admin-redirect-code = Status code
+admin-redirect-match-exact = Exact
+admin-redirect-match-prefix = Prefix
+admin-redirect-match-regex = Regex
+admin-redirect-confirm-delete = Delete this redirect?
+admin-redirect-source-required = Source is required
+admin-redirect-source-exists = The source already exists
+admin-redirect-not-found = Redirect not found
+admin-redirect-update-not-found = The redirect to update does not exist
+admin-redirect-delete-not-found = The redirect to delete does not exist
+admin-redirect-code-error = Invalid status code
+admin-redirect-source-slash = The source must start with /
+admin-redirect-match-error = Invalid match type
+admin-redirect-target-required = Target is required
+admin-redirect-regex-error = Invalid regular expression: { $error }
+admin-redirect-hint = For prefix matches the rest of the path is appended to the target, regex targets can use ${"{"}1{"}"} groups. 410 needs no target
+
+## Series
+
+admin-series-add = New series
+admin-series-edit = Edit series
+admin-series-arts = Series articles
+admin-series-name = Series name
+admin-series-input-name = Enter the series name
+admin-series-slug = Series slug
+admin-series-input-slug = Enter the series slug
+admin-series-slug-hint = Used in the series URL /s/slug, generated from the name when empty
+admin-series-input-cover = Enter a cover image URL or upload one
+admin-series-desc = Series description
+admin-series-input-desc = Enter the series description
+admin-series-art-input = Article ID or UUID
+admin-series-art-add = Add to series
+admin-series-published = Published
+admin-series-unpublished = Draft
+admin-series-confirm-remove = Remove this article from the series?
+admin-series-confirm-delete = Delete this series? Its articles are kept
+admin-series-name-required = Series name is required
+admin-series-slug-required = Series slug is required
+admin-series-slug-exists = The series slug already exists
+admin-series-not-found = Series not found
+admin-series-update-not-found = The series to update does not exist
+admin-series-delete-not-found = The series to delete does not exist
+admin-series-art-exists = The article is already in the series
+admin-series-art-not-in = The article is not in the series
+admin-series-arts-changed = The article list has changed, reload and try again
+admin-series-sort-failed = Failed to reorder
+admin-series-sort-success = Reordered
+admin-series-arts-title = Series articles - { $title }
+
+## Settings
+
+admin-setting-name = Site name
+admin-setting-input-name = Enter the site name
+admin-setting-url = Site URL
+admin-setting-url-hint = e.g. https://example.com, used for canonical and share links. The request host is used when empty
+admin-setting-keywords = Site keywords
+admin-setting-input-keywords = Enter the site keywords
+admin-setting-description = Site description
+admin-setting-input-description = Enter the site description
+admin-setting-copyright = Copyright
+admin-setting-input-copyright = Enter the copyright notice
+admin-setting-beian = ICP license
+admin-setting-input-beian = Enter the ICP license number
+admin-setting-lang = Site language
+admin-setting-lang-auto = Browser language
+admin-setting-open = Site status
+admin-setting-closed = Closed for maintenance
+admin-setting-comment-auto-approve = Auto approve comments
+admin-setting-comment-auto-approve-hint = Approve new comments from emails with an approved comment
+admin-setting-comment-close-days = Auto close comments
+admin-setting-comment-close-days-placeholder = Days after publishing
+admin-setting-comment-close-days-hint = Close comments this many days after publishing, 0 keeps them open
+admin-setting-guestbook-public = Public guestbook
+admin-setting-guestbook-public-hint = Show approved messages on the site, contact details stay private
+
+## Tags
+
+admin-tag-edit = Edit tag
+admin-tag-detail = Tag details
+admin-tag-name = Tag name
+admin-tag-input-name = Enter the tag name
+admin-tag-art-count = Articles
+admin-tag-confirm-delete = Delete this tag?
+admin-tag-not-found = Tag not found
+admin-tag-required = Tag is required
+admin-tag-exists = The tag already exists
+admin-tag-name-required = Tag name is required
+admin-tag-slug-required = Tag slug is required
+admin-tag-update-not-found = The tag to update does not exist
+admin-tag-slug-exists = The tag slug already exists
+admin-tag-update-arts-failed = Failed to update article tags
+admin-tag-delete-not-found = The tag to delete does not exist
+admin-tag-delete-arts-failed = Failed to remove the tag from articles
+admin-tag-target-required = Target tag is required
+admin-tag-merge-not-found = The tag to merge does not exist
+admin-tag-target-not-found = Target tag not found
+admin-tag-merge-self = A tag cannot be merged into itself
+admin-tag-merge-failed = Failed to merge
+admin-tag-merge-success = Merged
+admin-tag-merge-to = Merge { $name } into tag
+
+## Templates
+
+admin-template-edit = Edit template
+admin-template-preview-hint = Preview page, defaults to this template
+admin-template-restore-theme = Restore theme file
+admin-template-versions = History
+admin-template-no-versions = No history yet
+admin-template-confirm-restore-theme = Discard your changes and restore the theme template?
+admin-template-confirm-restore = Restore this version?
+admin-template-create = New template
+admin-template-name = Template name
+admin-template-theme = Theme templates
+admin-template-theme-file = Theme file
+admin-template-name-error = Invalid template name
+admin-template-check-success = The template is valid
+admin-template-version-not-found = Version not found
+
+## Themes
+
+admin-theme-preview-end = End preview
+admin-theme-upload = Upload theme
+admin-theme-zip = Theme package
+admin-theme-zip-hint = The zip must contain theme.json and the templates and static folders. A theme with the same name is replaced
+admin-theme-active = Active
+admin-theme-confirm-enable = Activate this theme?
+admin-theme-not-found = Theme not found
+admin-theme-enable-failed = Failed to activate
+admin-theme-enable-success = Activated
+admin-theme-preview-failed = Failed to preview
+admin-theme-read-failed = Failed to read the theme package
+admin-theme-missing-templates = The theme is missing templates: { $names }
+admin-theme-previewing = Previewing theme { $name }, only you can see it.
+admin-theme-name = Name: { $name }
+admin-theme-author = Author: { $name }
+admin-theme-missing = Missing templates: { $names }
+
+## Users
+
+admin-user-edit = Edit user
+admin-user-detail = Account details
+admin-user-reg-time = Registered at
+admin-user-reg-ip = Registered from IP
+admin-user-password = Change password
+admin-user-cannot-update = This account cannot be changed
+admin-user-cannot-update-self = You cannot change your own account here
+admin-user-update-not-found = The account to update does not exist
+admin-user-cannot-delete = This account cannot be deleted
+admin-user-cannot-delete-self = You cannot delete yourself
+admin-user-delete-not-found = The account to delete does not exist
+admin-password-required = Password is required
+
+## Webhooks
+
+admin-webhook-add = New webhook
+admin-webhook-edit = Edit webhook
+admin-webhook-url = URL
+admin-webhook-input-url = Enter the URL that receives events, e.g. https://example.com/hook
+admin-webhook-secret = Secret
+admin-webhook-input-secret = Enter a signing secret
+admin-webhook-secret-hint = X-Nako-Signature is sha256= followed by the HMAC-SHA256 of the body. Requests are not signed when empty
+admin-webhook-events = Events
+admin-webhook-logs = Deliveries
+admin-webhook-send-test = Send test
+admin-webhook-confirm-delete = Delete this webhook and its deliveries?
+admin-webhook-pending = Queued
+admin-webhook-retrying = Retrying
+admin-webhook-resend = Resend
+admin-webhook-response-code = Response code
+admin-webhook-log-detail = Delivery details
+admin-webhook-attempts = Attempts
+admin-webhook-next-time = Next attempt
+admin-webhook-response = Response
+admin-webhook-request = Request
+admin-webhook-url-error = The URL must use http or https
+admin-webhook-events-required = Choose at least one event
+admin-webhook-not-found = Webhook not found
+admin-webhook-update-not-found = The webhook to update does not exist
+admin-webhook-delete-not-found = The webhook to delete does not exist
+admin-webhook-disabled = The webhook is disabled
+admin-webhook-send-failed = Failed to send
+admin-webhook-test-queued = Queued, check the deliveries for the result
+admin-webhook-test = Test
+admin-webhook-log-pending = The delivery is already queued
+admin-webhook-resend-failed = Failed to resend
+admin-webhook-queued = Queued
+admin-webhook-unavailable = The webhook does not exist or is disabled
+admin-webhook-art-published = Article published
+admin-webhook-art-updated = Article updated
+admin-webhook-art-deleted = Article deleted
+admin-webhook-comment-created = New comment
+admin-webhook-comment-approved = Comment approved
+admin-webhook-guestbook-created = New guestbook message
+admin-webhook-user-login = User login
@@ -0,0 +1,111 @@
+## Common
+
+site-closed = The site is under maintenance...
+error-retry = Something went wrong, please try again
+error-not-found = Page not found
+error-gone = This page has been removed
+tips = Notice
+tips-title = Notice
+submit = Submit
+prev-page = Previous
+next-page = Next
+language = Language
+
+## Blog pages
+
+blog-home = Home
+blog-search = Search
+blog-about = About me
+blog-about-text = nako-blog is a blog system written in rust.
+blog-contact = Contact
+blog-contact-text = This blog theme comes from the web, contact me if you need it.
+blog-links = Links
+blog-browse-arts = Browse articles
+blog-leave-message = Leave a message
+blog-hot-arts = Popular articles
+blog-cates = Categories
+blog-tags = Tags
+blog-friendlinks = Friend links
+blog-more-arts = More articles
+blog-all-arts = All articles
+blog-art-list = Articles
+blog-read-more = Read more
+blog-art-from = Web
+blog-uncategorized = Uncategorized
+blog-tag = Tag: { $name }
+blog-tag-label = Tag
+blog-share = Share
+blog-comments-count = { $count ->
+    [one] { $count } reply
+   *[other] { $count } replies
+}
+blog-guestbook = Guestbook
+blog-guestbook-count = { $count ->
+    [one] { $count } message
+   *[other] { $count } messages
+}
+blog-guestbook-empty = No messages yet
+blog-guestbook-reply = Reply from the author
+blog-series = Series
+blog-series-label = Series: { $title }
+blog-series-count = { $count ->
+    [one] { $count } article
+   *[other] { $count } articles
+}
+blog-series-empty = No articles in this series yet
+blog-series-part = Part { $index } of { $total }
+blog-series-prev = Previous
+blog-series-next = Next
+blog-prev-art = Previous
+blog-next-art = Next
+blog-cate-art-nav = In { $name }
+blog-related-arts = Related articles
+blog-comment-user = Guest
+blog-comment-add = Leave a reply
+blog-comment-reply = Reply
+blog-comment-reply-to = Reply to { $name }
+blog-comment-reply-cancel = Cancel reply
+blog-comment-notify = Email me when someone replies
+blog-form-username = Your name
+blog-form-email = Your email
+blog-form-message = Your message
+blog-form-content = Your comment
+
+## Blog messages
+
+blog-art-not-found = The article does not exist
+blog-cate-not-found = The category does not exist
+comment-uuid-required = The article id is missing
+comment-failed = Failed to submit the reply
+comment-success = Reply submitted
+comment-pending = Reply submitted, it will be shown after review
+comment-closed = Comments are closed for this article
+comment-reply-not-found = The comment you are replying to does not exist
+guestbook-failed = Failed to submit the message
+guestbook-success = Message submitted
+form-username-required = Your name is required
+form-email-required = Your email is required
+form-email-invalid = Your email is invalid
+form-content-required = Your message is required
+
+## Mail
+
+mail-comment-subject = New comment on "{ $title }"
+mail-comment-body = { $name } commented on "{ $title }":
+mail-comment-review = The comment will be shown after it is approved in the admin.
+mail-reply-subject = New reply to your comment on "{ $title }"
+mail-reply-body = { $name } replied to your comment on "{ $title }":
+mail-reply-yours = Your comment:
+mail-guestbook-subject = New guestbook message
+mail-guestbook-body = { $name } ({ $email }) left a message:
+mail-view-art = View the article
+mail-view-admin = Open the admin
+mail-test-subject = Test email
+mail-test-body = This is a test email. If you received it, the mail settings for { $host } are correct.
+mail-footer = This email was sent automatically by { $site }, please do not reply.
+
+## Api
+
+api-token-required = The token is required
+api-token-invalid = The token is invalid or expired
+api-token-no-scope = The token does not have the { $scope } scope
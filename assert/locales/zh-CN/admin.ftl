@@ -0,0 +1,716 @@
+## 后台登陆
+
+admin-login-title = 系统登陆
+admin-login-welcome = 欢迎使用
+admin-login-name = 账 户
+admin-login-password = 密 码
+admin-login-captcha = 验证码
+admin-login-submit = 登 入
+admin-login-required = 请先登陆
+
+## 登陆提示
+
+auth-name-required = 账号不能为空
+auth-password-required = 密码不能为空
+auth-captcha-required = 验证码不能为空
+auth-captcha-length = 验证码位数错误
+auth-captcha-error = 验证码错误
+auth-logged-in = 你已经登陆了
+auth-password-error = 账号或者密码错误
+auth-user-disabled = 账号不存在或者已被禁用
+auth-login-failed = 登陆失败
+auth-login-success = 登陆成功
+
+## 通用
+
+admin-title = Nako Blog 后台
+admin-home = 首页
+admin-status = 状态
+admin-enable = 启用
+admin-disable = 禁用
+admin-enable-switch = 启用|禁用
+admin-submit = 提交
+admin-reset = 重置
+admin-query = 查询
+admin-add = 新增
+admin-create = 新建
+admin-edit = 修改
+admin-editor = 编辑
+admin-delete = 删除
+admin-detail = 详情
+admin-view = 查看
+admin-save = 保存
+admin-back = 返回
+admin-refresh = 刷新
+admin-action = 操作
+admin-tip = 提示
+admin-confirm = 确定
+admin-cancel = 取消
+admin-yes = 是
+admin-no = 否
+admin-all = 全部
+admin-on = 开启
+admin-off = 关闭
+admin-default = 默认
+admin-forever = 永久
+admin-success = 成功
+admin-failed = 失败
+admin-upload = 上传
+admin-upload-image = 上传图片
+admin-select-image = 选择图片
+admin-show-image = 展开图片
+admin-download = 下载
+admin-preview = 预览
+admin-merge = 合并
+admin-remove = 移除
+admin-move-up = 上移
+admin-move-down = 下移
+admin-clear = 清空
+admin-approve = 通过
+admin-review = 审核
+admin-reply = 回复
+admin-generate = 生成
+admin-restore = 恢复
+admin-check = 校验
+admin-import = 导入
+admin-skip = 跳过
+admin-added = 添加
+admin-updated = 更新
+admin-deleted = 已删除
+admin-modified = 已修改
+admin-normal = 正常
+admin-image = 图片
+admin-attachment = 附件
+admin-name = 名称
+admin-title-label = 标题
+admin-content = 内容
+admin-link = 链接
+admin-keywords = 关键字
+admin-description = 描述
+admin-cover = 封面
+admin-cate = 分类
+admin-tag = 标签
+admin-slug = 标识
+admin-alias = 别名
+admin-sort = 排序
+admin-order = 顺序
+admin-user = 用户
+admin-account = 账号
+admin-email = 邮箱
+admin-phone = 电话
+admin-source = 来源
+admin-mode = 模式
+admin-options = 选项
+admin-art = 文章
+admin-page = 页面
+admin-comment = 评论
+admin-event = 事件
+admin-permission = 权限
+admin-token = 令牌
+admin-template = 模板
+admin-message = 留言
+admin-add-time = 添加时间
+admin-add-ip = 添加IP
+admin-update-time = 修改时间
+admin-upload-time = 上传时间
+admin-upload-ip = 上传IP
+admin-send-time = 发送时间
+admin-times = 次数
+admin-edit-info = 更改信息
+admin-number-hint = 数值越大越靠前
+admin-please-select-cate = 清选择分类
+admin-input-title = 请输入标题
+admin-input-name = 请输入名称
+admin-input-content = 请输入内容
+admin-input-description = 请输入描述
+admin-input-keywords = 请输入关键字
+admin-input-sort = 请输入排序
+admin-input-slug = 请输入标识
+admin-input-account = 请输入账号
+admin-input-confirm = 输入后回车确认
+admin-get-success = 获取成功
+admin-add-success = 添加成功
+admin-add-failed = 添加失败
+admin-update-success = 更新成功
+admin-update-failed = 更新失败
+admin-delete-success = 删除成功
+admin-delete-failed = 删除失败
+admin-save-success = 保存成功
+admin-save-failed = 保存失败
+admin-upload-success = 上传成功
+admin-upload-failed = 上传失败
+admin-upload-failed-mark = 上传失败！
+admin-remove-success = 移除成功
+admin-remove-failed = 移除失败
+admin-restore-success = 恢复成功
+admin-restore-failed = 恢复失败
+admin-action-failed = 操作失败
+admin-clear-success = 清空成功
+admin-clear-failed = 清空失败
+admin-batch-delete = 批量删除
+admin-batch-delete-success = 批量删除成功
+admin-batch-update-success = 批量更改成功
+admin-nothing-selected = 未选中数据
+admin-id-required = ID不能为空
+admin-status-required = 状态不能为空
+admin-status-error = 状态错误
+admin-name-required = 名称不能为空
+admin-content-required = 内容不能为空
+admin-sort-required = 排序不能为空
+admin-link-required = 链接不能为空
+admin-record-not-found = 记录不存在
+admin-file-not-found = 文件不存在
+admin-page-not-found = 页面不存在
+admin-slug-used = 别名 { $slug } 已被使用
+
+## 菜单及首页
+
+admin-menu-console = 控制台
+admin-menu-art = 文章管理
+admin-menu-art-list = 文章列表
+admin-menu-cate = 分类管理
+admin-menu-tag = 标签管理
+admin-menu-series = 专题管理
+admin-menu-comment = 评论管理
+admin-menu-comment-rule = 评论规则
+admin-menu-page = 页面管理
+admin-menu-attach = 附件管理
+admin-menu-guestbook = 留言管理
+admin-menu-friendlink = 友情链接
+admin-menu-system = 系统管理
+admin-menu-user = 用户管理
+admin-menu-theme = 主题管理
+admin-menu-template = 模板编辑
+admin-menu-redirect = 链接跳转
+admin-menu-not-found = 404 记录
+admin-menu-import = 导入导出
+admin-menu-setting = 网站设置
+admin-menu-profile = 基本资料
+admin-menu-password = 更改密码
+admin-menu-token = 访问令牌
+admin-logout = 注销登录
+admin-logout-success = 注销成功
+admin-source-code = 开源地址
+admin-console-shortcut = 快捷菜单
+admin-console-stats = 数据统计
+admin-console-art-count = 文章数量
+admin-console-cate-count = 分类数量
+admin-console-comment-count = 评论数量
+admin-console-tag-count = 标签数量
+admin-console-new-arts = 最新文章
+admin-console-role-name = 角色名
+admin-console-role-key = Key值
+admin-console-role-enable = 是否可用
+admin-console-chart-unit = 万元
+admin-console-chart-month = {"{"}value{"}"}月
+admin-console-chart-axis = 单位：万千瓦时
+
+## 错误页
+
+admin-error-title = 系统提示
+admin-error-jump = 马上跳转
+admin-error-retry = 刷新重试
+
+## 文章
+
+admin-art-add = 添加文章
+admin-art-edit = 编辑文章
+admin-art-detail = 文章详情
+admin-art-cate = 所属分类
+admin-art-slug-hint = 用于文章链接，为空时根据标题生成
+admin-art-user = 所属账号
+admin-art-from = 文章来源
+admin-art-input-from = 请输入文章来源
+admin-art-views = 阅读量
+admin-art-views-short = 阅读数
+admin-art-top = 置顶
+admin-art-top-short = 顶
+admin-art-uuid = 文章UUID
+admin-art-brief = 简介
+admin-art-input-brief = 请输入简介
+admin-art-input-tags = 请输入标签
+admin-art-publish-time = 发布时间
+admin-art-input-publish-time = 请输入发布时间
+admin-art-close-comment = 关闭评论
+admin-art-confirm-delete = 确定要删除该文章
+admin-art-not-found = 文章不存在
+admin-art-cate-required = 所属分类不能为空
+admin-art-required = 文章不能为空
+admin-art-title-required = 文章标题不能为空
+admin-art-brief-required = 简介不能为空
+admin-art-publish-time-required = 发布时间不能为空
+admin-art-update-not-found = 要更改的文章不存在
+admin-art-delete-not-found = 要删除的文章不存在
+
+## 附件
+
+admin-attach-detail = 附件详情
+admin-attach-file-name = 文件名称
+admin-attach-file-name-short = 文件名
+admin-attach-path = 存储路径
+admin-attach-ext = 后缀
+admin-attach-size = 大小
+admin-attach-hash = HASH值
+admin-attach-type = 附件类型
+admin-attach-confirm-delete = 确定要删除该用户
+admin-attach-not-found = 附件不存在
+admin-attach-delete-not-found = 要删除的附件不存在
+admin-attach-cannot-preview = 附件不能预览
+
+## 分类
+
+admin-cate-edit = 分类编辑
+admin-cate-detail = 分类详情
+admin-cate-name = 分类名称
+admin-cate-input-name = 请输入分类名称
+admin-cate-slug = 分类标识
+admin-cate-input-slug = 请输入分类标识
+admin-cate-parent = 父级分类
+admin-cate-parent-id = 父级ID
+admin-cate-top = 作为顶级分类
+admin-cate-desc = 分类描述
+admin-cate-list-tpl = 列表模板
+admin-cate-view-tpl = 详情模板
+admin-cate-delete-prompt = 删除分类，子分类及文章转移到的分类ID，0 为不转移
+admin-cate-not-found = 分类不存在
+admin-cate-required = 分类不能为空
+admin-slug-required = 标识不能为空
+admin-cate-slug-exists = 分类标识已经存在
+admin-cate-parent-required = 父级不能为空
+admin-cate-name-required = 分类名称不能为空
+admin-cate-slug-required = 分类标识不能为空
+admin-cate-list-tpl-required = 列表模板不能为空
+admin-cate-view-tpl-required = 详情模板不能为空
+admin-cate-update-not-found = 要更改的分类不存在
+admin-cate-parent-not-found = 父级分类不存在
+admin-cate-parent-invalid = 父级分类不能为自身或者下级分类
+admin-cate-delete-not-found = 要删除的分类不存在
+admin-cate-not-empty = 分类下存在子分类或者文章，请选择转移分类
+admin-cate-move-not-found = 转移到的分类不存在
+admin-cate-move-invalid = 不能转移到自身或者下级分类
+admin-cate-move-children-failed = 转移子分类失败
+admin-cate-move-arts-failed = 转移文章失败
+
+## 评论
+
+admin-comment-detail = 评论详情
+admin-comment-art-id = 所属文章ID
+admin-comment-reply-to = 回复评论
+admin-comment-user-email = 账号邮箱
+admin-comment-notify = 回复通知
+admin-comment-reply-content = 回复内容
+admin-comment-reply-time = 回复时间
+admin-comment-reply-ip = 回复IP
+admin-comment-pending = 待审核
+admin-comment-approved = 已通过
+admin-comment-spam = 垃圾评论
+admin-comment-trash = 回收站
+admin-comment-approve-selected = 批量通过
+admin-comment-spam-selected = 标记垃圾
+admin-comment-trash-selected = 移至回收站
+admin-comment-block-ip = 屏蔽IP
+admin-comment-block-email = 屏蔽邮箱
+admin-comment-owner = 所属用户
+admin-comment-owner-email = 用户邮箱
+admin-comment-add-rule = 添加规则
+admin-comment-clear-only = 只能清空垃圾评论或回收站
+admin-comment-confirm-clear = 确定要清空当前列表的全部评论吗?
+admin-comment-confirm-delete = 确定要删除该评论
+admin-comment-confirm-batch-delete = 确定要删除这些回复吗?
+admin-comment-not-found = 评论不存在
+admin-comment-delete-not-found = 要删除的评论不存在
+admin-comment-update-not-found = 要更改的评论不存在
+
+## 评论规则
+
+admin-rule-edit = 编辑规则
+admin-rule-type = 规则类型
+admin-rule-content = 规则内容
+admin-rule-input-content = 请输入规则内容
+admin-rule-hint = IP 可用 * 结尾匹配前缀，邮箱可用 @example.com 匹配域名，关键字匹配用户名、邮箱及内容
+admin-rule-action = 处理方式
+admin-rule-allow = 直接通过
+admin-rule-block = 标记为垃圾评论
+admin-rule-hits = 命中
+admin-rule-confirm-delete = 确定要删除该规则
+admin-rule-exists = 规则已存在
+admin-rule-not-found = 规则不存在
+admin-rule-update-not-found = 要更改的规则不存在
+admin-rule-delete-not-found = 要删除的规则不存在
+admin-rule-action-error = 处理方式错误
+admin-rule-content-required = 规则内容不能为空
+admin-rule-ip-error = IP 格式错误，可用 * 结尾匹配前缀
+admin-rule-email-error = 邮箱格式错误，可用 @example.com 匹配域名
+admin-rule-type-error = 规则类型错误
+
+## 友情链接
+
+admin-link-edit = 链接编辑
+admin-link-detail = 链接详情
+admin-link-url = 链接地址
+admin-link-input-url = 请输入链接地址
+admin-link-target = 跳转方式
+admin-link-target-blank = 跳出页面
+admin-link-target-self = 当前页面
+admin-link-icon = 链接图标
+admin-link-input-icon = 请输入链接图标
+admin-link-confirm-delete = 确定要删除该链接
+admin-link-not-found = 链接不存在
+admin-link-target-required = 跳转方式不能为空
+admin-link-update-not-found = 要更改的链接不存在
+admin-link-delete-not-found = 要删除的链接不存在
+
+## 留言
+
+admin-guestbook-detail = 留言详情
+admin-guestbook-id = 留言ID
+admin-guestbook-content = 留言内容
+admin-guestbook-phone = 联系电话
+admin-guestbook-email = 联系邮箱
+admin-guestbook-qq = 联系QQ
+admin-guestbook-weixin = 联系微信
+admin-guestbook-time = 留言时间
+admin-guestbook-ip = 留言IP
+admin-guestbook-contact = 联系
+admin-guestbook-contact-switch = 已联系|未联系
+admin-guestbook-approved-switch = 通过|待审
+admin-guestbook-replied = 已回复
+admin-guestbook-not-replied = 未回复
+admin-guestbook-reply = 回复留言
+admin-guestbook-input-reply = 请输入回复内容，为空时删除回复
+admin-guestbook-approved-hint = 通过审核的留言在前台留言板显示
+admin-guestbook-confirm-delete = 确定要删除该留言
+admin-guestbook-confirm-batch-delete = 确定要删除这些留言?
+admin-guestbook-not-found = 留言不存在
+admin-guestbook-approved-required = 审核状态不能为空
+admin-guestbook-reply-not-found = 要回复的留言不存在
+admin-guestbook-reply-failed = 回复失败
+admin-guestbook-approved-failed = 更新审核状态失败
+admin-guestbook-reply-success = 回复成功
+admin-guestbook-delete-not-found = 要删除的留言不存在
+admin-guestbook-update-not-found = 要更改的留言不存在
+admin-guestbook-review-not-found = 要审核的留言不存在
+
+## 导入导出
+
+admin-import-wxr = 导入 WordPress 数据
+admin-import-wxr-file = WXR 文件
+admin-import-dry-run = 只预览不导入
+admin-import-download-media = 下载附件
+admin-import-start = 开始导入
+admin-import-report = 导入报告
+admin-import-markdown = Markdown 导入导出
+admin-import-zip = 压缩包
+admin-import-zip-hint = zip 内为带 YAML 头部的 md 或者 html 文件，文章按 uuid 更新，页面按 slug 更新
+admin-import-export-all = 导出全部
+admin-import-failed = 导入失败
+admin-import-read-failed = 导入文件读取失败
+admin-import-done = 导入完成
+admin-import-no-front-matter = 缺少头部信息
+admin-import-front-matter-error = 头部信息格式错误
+admin-import-not-wxr = 不是 WordPress 导出文件
+admin-import-parse-failed = 文件解析失败: { $error }
+admin-import-status-code = 状态码 { $code }
+admin-import-cate-parent-missing = 分类 { $name } 的父级 { $parent } 不存在
+admin-import-cate-failed = 分类 { $name } 导入失败
+admin-import-tag-failed = 标签 { $name } 导入失败
+admin-import-media-failed = 附件 { $url } 下载失败: { $error }
+admin-import-art-no-cate = 文章 { $title } 没有可用分类
+admin-import-art-failed = 文章 { $title } 导入失败
+admin-import-comment-failed = 文章 { $title } 的评论 { $id } 导入失败
+admin-import-page-exists = 页面 { $slug } 已经存在
+admin-import-page-failed = 页面 { $title } 导入失败
+admin-import-file-title-required = { $file }: 标题不能为空
+admin-import-file-slug-required = { $file }: 页面标志不能为空
+admin-import-file-update-failed = { $file }: 更新失败
+admin-import-file-add-failed = { $file }: 添加失败
+admin-import-file-cate-missing = { $file }: 分类 { $slug } 不存在，使用默认分类
+admin-import-file-no-cate = { $file }: 没有可用分类
+
+## 404 记录
+
+admin-not-found-detail = 404 详情
+admin-not-found-referrer = 最近来源
+admin-not-found-last-time = 最后访问
+admin-not-found-first-time = 首次访问
+admin-not-found-no-referrer = 暂无来源
+admin-not-found-add-redirect = 添加跳转
+admin-not-found-confirm-delete = 确定要删除该记录
+admin-not-found-confirm-clear = 确定要清空全部记录
+
+## 页面
+
+admin-page-detail = 页面详情
+admin-page-edit = 编辑页面
+admin-page-tpl-used = 使用模板
+admin-page-add = 添加账号
+admin-page-slug = 页面标识
+admin-page-input-slug = 请输入页面标识
+admin-page-title = 页面标题
+admin-page-input-title = 请输入页面标题
+admin-page-content = 页面内容
+admin-page-input-content = 请输入页面内容
+admin-page-tpl = 页面模板
+admin-page-confirm-delete = 确定要删除该页面
+admin-page-slug-required = 页面标识不能为空
+admin-page-title-required = 页面标题不能为空
+admin-page-slug-exists = 页面标识已经存在
+admin-page-tpl-required = 页面模板不能为空
+admin-page-update-not-found = 要更改的页面不存在
+admin-page-delete-not-found = 要删除的页面不存在
+
+## 个人资料
+
+admin-profile-avatar = 头像
+admin-profile-avatar-upload = 头像上传
+admin-profile-avatar-change = 更改头像
+admin-profile-rotate-left = 左旋15°
+admin-profile-rotate-right = 右旋15°
+admin-profile-avatar-hint = 建议:图片的尺寸宽高比为1:1,大小在5m以内。
+admin-profile-avatar-too-large = 上传文件不得超过5m
+admin-profile-nickname = 昵称
+admin-profile-input-nickname = 请输入昵称
+admin-profile-email-hint = 用于接收评论及留言通知
+admin-profile-sign = 签名
+admin-profile-input-sign = 请输入签名
+admin-profile-old-password = 旧密码
+admin-profile-input-old-password = 请输入旧密码
+admin-profile-new-password = 新密码
+admin-profile-input-new-password = 请输入新密码
+admin-profile-confirm-password = 确认密码
+admin-profile-input-confirm-password = 请输入确认密码
+admin-account-not-found = 账号不存在
+admin-account-required = 账号不能为空
+admin-nickname-required = 昵称不能为空
+admin-email-error = 邮箱格式错误
+admin-account-exists = 账号已经存在
+admin-old-password-required = 旧密码不能为空
+admin-new-password-required = 新密码不能为空
+admin-confirm-password-required = 确认密码不能为空
+admin-confirm-password-mismatch = 确认密码不一致
+admin-password-update-failed = 更改密码失败
+admin-old-password-error = 账号旧密码错误
+admin-password-update-success = 更新密码成功
+admin-avatar-required = 头像数据不能为空
+admin-avatar-update-failed = 更改头像失败
+admin-avatar-update-success = 更改头像成功
+
+## 访问令牌
+
+admin-token-create = 新建访问令牌
+admin-token-input-name = 请输入令牌名称
+admin-token-expire-days = 有效天数
+admin-token-expire-hint = 0 为永久有效
+admin-token-mine = 我的访问令牌
+admin-token-last-used = 最后使用
+admin-token-expire-time = 过期时间
+admin-token-save-hint = 请保存好令牌，令牌只显示一次：
+admin-token-confirm-revoke = 确定要撤销该令牌
+admin-token-scopes-required = 权限不能为空
+admin-token-expire-too-long = 有效天数不能超过 3650 天
+admin-token-expire-negative = 有效天数不能为负数
+admin-token-revoke-success = 撤销成功
+admin-token-revoke-failed = 撤销失败
+
+## 链接跳转
+
+admin-redirect-edit = 更改跳转
+admin-redirect-source = 来源链接
+admin-redirect-input-source = 请输入来源链接，如 /old-page
+admin-redirect-match = 匹配方式
+admin-redirect-target = 目标链接
+admin-redirect-input-target = 请输入目标链接
+admin-redirect-code = 状态码
+admin-redirect-match-exact = 完整匹配
+admin-redirect-match-prefix = 前缀匹配
+admin-redirect-match-regex = 正则匹配
+admin-redirect-confirm-delete = 确定要删除该跳转
+admin-redirect-source-required = 来源链接不能为空
+admin-redirect-source-exists = 来源链接已存在
+admin-redirect-not-found = 跳转不存在
+admin-redirect-update-not-found = 要更改的跳转不存在
+admin-redirect-delete-not-found = 要删除的跳转不存在
+admin-redirect-code-error = 状态码错误
+admin-redirect-source-slash = 来源链接需以 / 开头
+admin-redirect-match-error = 匹配方式错误
+admin-redirect-target-required = 目标链接不能为空
+admin-redirect-regex-error = 正则表达式错误，{ $error }
+admin-redirect-hint = 前缀匹配时剩余部分追加到目标链接，正则可用 ${"{"}1{"}"} 分组，410 不需要目标链接
+
+## 专题
+
+admin-series-add = 添加专题
+admin-series-edit = 修改专题
+admin-series-arts = 专题文章
+admin-series-name = 专题名称
+admin-series-input-name = 请输入专题名称
+admin-series-slug = 专题标识
+admin-series-input-slug = 请输入专题标识
+admin-series-slug-hint = 用于专题链接 /s/标识，为空时根据名称生成
+admin-series-input-cover = 请输入封面图片链接或者上传
+admin-series-desc = 专题描述
+admin-series-input-desc = 请输入专题描述
+admin-series-art-input = 文章 ID 或者 UUID
+admin-series-art-add = 添加到专题
+admin-series-published = 已发布
+admin-series-unpublished = 未发布
+admin-series-confirm-remove = 确定要从专题中移除该文章
+admin-series-confirm-delete = 确定要删除该专题，专题中的文章不会被删除
+admin-series-name-required = 专题名称不能为空
+admin-series-slug-required = 专题标识不能为空
+admin-series-slug-exists = 专题标识已经存在
+admin-series-not-found = 专题不存在
+admin-series-update-not-found = 要更改的专题不存在
+admin-series-delete-not-found = 要删除的专题不存在
+admin-series-art-exists = 文章已在专题中
+admin-series-art-not-in = 文章不在专题中
+admin-series-arts-changed = 文章列表已变动，请刷新后重试
+admin-series-sort-failed = 排序失败
+admin-series-sort-success = 排序成功
+admin-series-arts-title = 专题文章 - { $title }
+
+## 网站设置
+
+admin-setting-name = 网站名称
+admin-setting-input-name = 请输入网站名称
+admin-setting-url = 网站地址
+admin-setting-url-hint = 如 https://example.com，用于生成规范链接及分享地址，为空时使用访问地址
+admin-setting-keywords = 网站关键字
+admin-setting-input-keywords = 请输入网站关键字
+admin-setting-description = 网站描述
+admin-setting-input-description = 请输入网站描述
+admin-setting-copyright = 版权信息
+admin-setting-input-copyright = 请输入版权信息
+admin-setting-beian = 网站备案
+admin-setting-input-beian = 请输入网站备案
+admin-setting-lang = 网站语言
+admin-setting-lang-auto = 按浏览器语言
+admin-setting-open = 网站状态
+admin-setting-closed = 关闭维护
+admin-setting-comment-auto-approve = 评论自动通过
+admin-setting-comment-auto-approve-hint = 邮箱有已通过的评论时，新评论直接通过
+admin-setting-comment-close-days = 自动关闭评论
+admin-setting-comment-close-days-placeholder = 文章发布多少天后关闭评论
+admin-setting-comment-close-days-hint = 文章发布多少天后关闭评论，0 为不关闭
+admin-setting-guestbook-public = 公开留言板
+admin-setting-guestbook-public-hint = 前台显示已审核的留言，联系方式不会公开
+
+## 标签
+
+admin-tag-edit = 标签编辑
+admin-tag-detail = 标签详情
+admin-tag-name = 标签名称
+admin-tag-input-name = 请输入标签名称
+admin-tag-art-count = 文章数
+admin-tag-confirm-delete = 确定要删除该标签
+admin-tag-not-found = 标签不存在
+admin-tag-required = 标签不能为空
+admin-tag-exists = 标签已经存在
+admin-tag-name-required = 标签名称不能为空
+admin-tag-slug-required = 标签标识不能为空
+admin-tag-update-not-found = 要更改的标签不存在
+admin-tag-slug-exists = 标签标识已经存在
+admin-tag-update-arts-failed = 更新文章标签失败
+admin-tag-delete-not-found = 要删除的标签不存在
+admin-tag-delete-arts-failed = 删除文章关联失败
+admin-tag-target-required = 目标标签不能为空
+admin-tag-merge-not-found = 要合并的标签不存在
+admin-tag-target-not-found = 目标标签不存在
+admin-tag-merge-self = 不能合并到自身
+admin-tag-merge-failed = 合并失败
+admin-tag-merge-success = 合并成功
+admin-tag-merge-to = 将 { $name } 合并到标签
+
+## 模板
+
+admin-template-edit = 编辑模板
+admin-template-preview-hint = 预览页面，默认为当前模板
+admin-template-restore-theme = 恢复主题文件
+admin-template-versions = 历史版本
+admin-template-no-versions = 暂无历史版本
+admin-template-confirm-restore-theme = 确定要删除修改，恢复为主题中的模板吗
+admin-template-confirm-restore = 确定要恢复到该版本吗
+admin-template-create = 新建模板
+admin-template-name = 模板名称
+admin-template-theme = 主题模板
+admin-template-theme-file = 主题文件
+admin-template-name-error = 模板名称错误
+admin-template-check-success = 模板校验通过
+admin-template-version-not-found = 版本不存在
+
+## 主题
+
+admin-theme-preview-end = 结束预览
+admin-theme-upload = 上传主题
+admin-theme-zip = 主题包
+admin-theme-zip-hint = zip 内需包含 theme.json、templates 及 static 目录，同名主题会被覆盖
+admin-theme-active = 使用中
+admin-theme-confirm-enable = 确定要启用该主题吗
+admin-theme-not-found = 主题不存在
+admin-theme-enable-failed = 启用失败
+admin-theme-enable-success = 启用成功
+admin-theme-preview-failed = 预览失败
+admin-theme-read-failed = 主题包读取失败
+admin-theme-missing-templates = 主题缺少模板: { $names }
+admin-theme-previewing = 正在预览主题 { $name }，只对当前登陆用户生效。
+admin-theme-name = 名称：{ $name }
+admin-theme-author = 作者：{ $name }
+admin-theme-missing = 缺少模板：{ $names }
+
+## 用户
+
+admin-user-edit = 用户编辑
+admin-user-detail = 账号详情
+admin-user-reg-time = 注册时间
+admin-user-reg-ip = 注册IP
+admin-user-password = 修改密码
+admin-user-cannot-update = 当前账号不能被修改
+admin-user-cannot-update-self = 你不能修改你自己
+admin-user-update-not-found = 要更改的账号不存在
+admin-user-cannot-delete = 当前账号不能被删除
+admin-user-cannot-delete-self = 你不能删除你自己
+admin-user-delete-not-found = 要删除的账号不存在
+admin-password-required = 密码不能为空
+
+## Webhook
+
+admin-webhook-add = 添加 Webhook
+admin-webhook-edit = 更改 Webhook
+admin-webhook-url = 请求链接
+admin-webhook-input-url = 请输入接收事件的链接，如 https://example.com/hook
+admin-webhook-secret = 密钥
+admin-webhook-input-secret = 请输入签名密钥
+admin-webhook-secret-hint = 请求头 X-Nako-Signature 为 sha256= 加请求内容的 HMAC-SHA256 签名，为空时不签名
+admin-webhook-events = 触发事件
+admin-webhook-logs = 投递记录
+admin-webhook-send-test = 发送测试
+admin-webhook-confirm-delete = 确定要删除该 Webhook，投递记录会一起删除
+admin-webhook-pending = 等待发送
+admin-webhook-retrying = 等待重试
+admin-webhook-resend = 重新发送
+admin-webhook-response-code = 响应码
+admin-webhook-log-detail = 投递详情
+admin-webhook-attempts = 发送次数
+admin-webhook-next-time = 下次发送
+admin-webhook-response = 响应内容
+admin-webhook-request = 请求内容
+admin-webhook-url-error = 请求链接需为 http 或 https 链接
+admin-webhook-events-required = 请选择触发事件
+admin-webhook-not-found = Webhook 不存在
+admin-webhook-update-not-found = 要更改的 Webhook 不存在
+admin-webhook-delete-not-found = 要删除的 Webhook 不存在
+admin-webhook-disabled = Webhook 已禁用
+admin-webhook-send-failed = 发送失败
+admin-webhook-test-queued = 已加入发送队列，可在投递记录查看结果
+admin-webhook-test = 测试
+admin-webhook-log-pending = 记录正在等待发送
+admin-webhook-resend-failed = 重新发送失败
+admin-webhook-queued = 已加入发送队列
+admin-webhook-unavailable = webhook 不存在或已禁用
+admin-webhook-art-published = 文章发布
+admin-webhook-art-updated = 文章更新
+admin-webhook-art-deleted = 文章删除
+admin-webhook-comment-created = 新评论
+admin-webhook-comment-approved = 评论审核通过
+admin-webhook-guestbook-created = 新留言
+admin-webhook-user-login = 用户登录
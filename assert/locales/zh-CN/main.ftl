@@ -0,0 +1,102 @@
+## 通用
+
+site-closed = 网站关闭维护中...
+error-retry = 发生了错误，请重试
+error-not-found = 页面不存在
+error-gone = 页面已删除
+tips = 提示
+tips-title = 提示信息
+submit = 提交
+prev-page = 上一页
+next-page = 下一页
+language = 语言
+
+## 前台页面
+
+blog-home = 首页
+blog-search = 搜索
+blog-about = 关于我
+blog-about-text = nako-blog 是使用 rust 开发的博客系统。
+blog-contact = 联系我
+blog-contact-text = 本博客模板来源于网络，如果你有需求可联系我。
+blog-links = 推荐链接
+blog-browse-arts = 浏览文章
+blog-leave-message = 给我留言
+blog-hot-arts = 热门文章
+blog-cates = 文章分类
+blog-tags = 推荐标签
+blog-friendlinks = 友情链接
+blog-more-arts = 查看更多文章
+blog-all-arts = 全部文章
+blog-art-list = 文章列表
+blog-read-more = 阅读更多
+blog-art-from = 网络
+blog-uncategorized = 文章未分类
+blog-tag = 标签: { $name }
+blog-tag-label = 标签
+blog-share = 分享到
+blog-comments-count = { $count } 条回复
+blog-guestbook = 留言板
+blog-guestbook-count = { $count } 条留言
+blog-guestbook-empty = 暂无留言
+blog-guestbook-reply = 博主回复
+blog-series = 专题
+blog-series-label = 专题: { $title }
+blog-series-count = 共 { $count } 篇
+blog-series-empty = 专题暂无文章
+blog-series-part = 第 { $index } 篇，共 { $total } 篇
+blog-series-prev = 上一篇
+blog-series-next = 下一篇
+blog-prev-art = 上一篇
+blog-next-art = 下一篇
+blog-cate-art-nav = 分类: { $name }
+blog-related-arts = 相关文章
+blog-comment-user = 留言用户
+blog-comment-add = 添加回复
+blog-comment-reply = 回复
+blog-comment-reply-to = 回复 { $name }
+blog-comment-reply-cancel = 取消回复
+blog-comment-notify = 有回复时邮件通知我
+blog-form-username = 你的称呼
+blog-form-email = 你的联系邮箱
+blog-form-message = 你的信息
+blog-form-content = 你的留言内容
+
+## 前台提示
+
+blog-art-not-found = 文章不存在
+blog-cate-not-found = 分类不存在
+comment-uuid-required = 文章ID丢失
+comment-failed = 提交回复失败
+comment-success = 提交回复成功
+comment-pending = 提交回复成功，审核通过后显示
+comment-closed = 该文章已关闭评论
+comment-reply-not-found = 回复的评论不存在
+guestbook-failed = 提交留言失败
+guestbook-success = 提交留言成功
+form-username-required = 你的称呼不能为空
+form-email-required = 你的联系邮箱不能为空
+form-email-invalid = 你的联系邮箱格式错误
+form-content-required = 你的留言内容不能为空
+
+## 邮件
+
+mail-comment-subject = 《{ $title }》有新评论
+mail-comment-body = { $name } 评论了文章《{ $title }》：
+mail-comment-review = 评论审核通过后才会显示，请到后台处理。
+mail-reply-subject = 你在《{ $title }》的评论有新回复
+mail-reply-body = { $name } 回复了你在《{ $title }》的评论：
+mail-reply-yours = 你的评论：
+mail-guestbook-subject = 有新留言
+mail-guestbook-body = { $name }（{ $email }）留言：
+mail-view-art = 查看文章
+mail-view-admin = 进入后台
+mail-test-subject = 测试邮件
+mail-test-body = 这是一封测试邮件，收到说明 { $host } 的邮件配置正确。
+mail-footer = 此邮件由 { $site } 自动发送，请勿直接回复。
+
+## 接口
+
+api-token-required = 令牌不能为空
+api-token-invalid = 令牌错误或者已过期
+api-token-no-scope = 令牌没有 { $scope } 权限
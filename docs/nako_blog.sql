@@ -7,6 +7,7 @@ DROP TABLE IF EXISTS `nako_art`;
 CREATE TABLE IF NOT EXISTS `nako_art` (
   `id` int unsigned NOT NULL AUTO_INCREMENT,
   `uuid` char(36) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT 'id',
+  `slug` varchar(150) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci DEFAULT NULL COMMENT '别名',
   `cate_id` int unsigned NOT NULL DEFAULT '0' COMMENT '分类ID',
   `user_id` int unsigned NOT NULL DEFAULT '0' COMMENT '作者',
   `title` varchar(150) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '标题',
@@ -17,13 +18,15 @@ CREATE TABLE IF NOT EXISTS `nako_art` (
   `brief` varchar(150) COLLATE utf8mb4_unicode_ci DEFAULT '' COMMENT '简介',
   `tags` varchar(250) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci DEFAULT NULL COMMENT '标签',
   `from` varchar(200) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci DEFAULT '' COMMENT '来源',
-  `views` bigint unsigned DEFAULT '0' COMMENT '阅读量',
+  `views` bigint DEFAULT '0' COMMENT '阅读量',
   `is_top` tinyint(1) DEFAULT '0' COMMENT '1-置顶',
+  `comment_closed` tinyint(1) NOT NULL DEFAULT '0' COMMENT '1-关闭评论',
   `status` tinyint(1) DEFAULT '1' COMMENT '1-启用，0-禁用',
   `add_time` int NOT NULL DEFAULT '0' COMMENT '添加时间',
   `add_ip` varchar(50) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '添加IP',
   PRIMARY KEY (`id`),
-  KEY `uuid` (`id`) USING BTREE
+  KEY `uuid` (`id`) USING BTREE,
+  UNIQUE KEY `idx-nako_art-slug` (`slug`)
 ) ENGINE=MyISAM AUTO_INCREMENT=8 DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='文章';
 
 -- 正在导出表  nako_blog.nako_art 的数据：5 rows
@@ -36,6 +39,25 @@ REPLACE INTO `nako_art` (`id`, `uuid`, `cate_id`, `user_id`, `title`, `keywords`
 	(6, '0414689e-9f51-4b02-869a-23ad5cd78992', 6, 1, '测试文章', '测试文章', '测试文章', '/upload/images/b91a7d14-e03b-41fa-977a-e965d4a4c23f.png', '<p>测试文章</p>', '测试文章', '测试文章', '网络', 2, 0, 1, 1685352288, '127.0.0.1');
 /*!40000 ALTER TABLE `nako_art` ENABLE KEYS */;
 
+-- 导出  表 nako_blog.nako_art_tag 结构
+DROP TABLE IF EXISTS `nako_art_tag`;
+CREATE TABLE IF NOT EXISTS `nako_art_tag` (
+  `art_id` int unsigned NOT NULL COMMENT '文章ID',
+  `tag_id` int unsigned NOT NULL COMMENT '标签ID',
+  PRIMARY KEY (`art_id`,`tag_id`),
+  KEY `tag_id` (`tag_id`)
+) ENGINE=MyISAM DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='文章标签';
+
+-- 正在导出表  nako_blog.nako_art_tag 的数据：5 rows
+/*!40000 ALTER TABLE `nako_art_tag` DISABLE KEYS */;
+REPLACE INTO `nako_art_tag` (`art_id`, `tag_id`) VALUES
+	(2, 2),
+	(3, 5),
+	(4, 6),
+	(5, 1),
+	(6, 7);
+/*!40000 ALTER TABLE `nako_art_tag` ENABLE KEYS */;
+
 -- 导出  表 nako_blog.nako_attach 结构
 DROP TABLE IF EXISTS `nako_attach`;
 CREATE TABLE IF NOT EXISTS `nako_attach` (
@@ -43,7 +65,7 @@ CREATE TABLE IF NOT EXISTS `nako_attach` (
   `name` mediumtext CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL COMMENT '文件名',
   `path` varchar(250) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '文件路径',
   `ext` varchar(10) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '文件类型',
-  `size` int(10) unsigned zerofill NOT NULL DEFAULT '0000000000' COMMENT '文件大小',
+  `size` bigint NOT NULL DEFAULT '0' COMMENT '文件大小',
   `md5` char(32) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '文件md5',
   `type` tinyint DEFAULT '0' COMMENT '附件类型。1-附件,2-图片',
   `status` tinyint(1) NOT NULL DEFAULT '1' COMMENT '状态',
@@ -90,7 +112,8 @@ CREATE TABLE IF NOT EXISTS `nako_comment` (
   `username` varchar(50) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '账号',
   `email` varchar(150) COLLATE utf8mb4_unicode_ci DEFAULT '' COMMENT '邮箱',
   `content` text CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL COMMENT '评论内容',
-  `status` tinyint(1) DEFAULT '1' COMMENT '1-启用，0-禁用',
+  `status` tinyint(1) DEFAULT '1' COMMENT '0-待审核，1-已通过，2-垃圾评论，3-回收站',
+  `notify` tinyint(1) NOT NULL DEFAULT '0' COMMENT '1-有回复时邮件通知',
   `add_time` int NOT NULL DEFAULT '0' COMMENT '添加时间',
   `add_ip` varchar(50) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '添加IP',
   PRIMARY KEY (`id`)
@@ -107,6 +130,19 @@ REPLACE INTO `nako_comment` (`id`, `art_id`, `reply_id`, `username`, `email`, `c
 	(10, 5, NULL, '测试22', 'ceshi@qq.com', '测试测试22', 1, 1685238182, '127.0.0.1');
 /*!40000 ALTER TABLE `nako_comment` ENABLE KEYS */;
 
+-- 导出  表 nako_blog.nako_comment_rule 结构
+DROP TABLE IF EXISTS `nako_comment_rule`;
+CREATE TABLE IF NOT EXISTS `nako_comment_rule` (
+  `id` int unsigned NOT NULL AUTO_INCREMENT,
+  `rule_type` varchar(10) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT 'ip、email、keyword',
+  `value` varchar(255) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '规则内容',
+  `action` varchar(10) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT 'allow-直接通过，block-垃圾评论',
+  `hits` bigint NOT NULL DEFAULT '0' COMMENT '命中次数',
+  `add_time` int NOT NULL DEFAULT '0' COMMENT '添加时间',
+  PRIMARY KEY (`id`),
+  KEY `idx-nako_comment_rule-rule_type` (`rule_type`)
+) ENGINE=MyISAM DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='评论规则';
+
 -- 导出  表 nako_blog.nako_friendlink 结构
 DROP TABLE IF EXISTS `nako_friendlink`;
 CREATE TABLE IF NOT EXISTS `nako_friendlink` (
@@ -138,6 +174,9 @@ CREATE TABLE IF NOT EXISTS `nako_guestbook` (
   `email` varchar(50) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci DEFAULT '' COMMENT '联系邮箱',
   `qq` varchar(15) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci DEFAULT '' COMMENT '联系qq',
   `weixin` varchar(100) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci DEFAULT '' COMMENT '联系微信',
+  `reply` text CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci COMMENT '回复内容',
+  `reply_time` int NOT NULL DEFAULT '0' COMMENT '回复时间',
+  `approved` tinyint(1) NOT NULL DEFAULT '0' COMMENT '1-前台显示，0-待审核',
   `status` tinyint(1) DEFAULT '1' COMMENT '1-已联系，0-未联系',
   `add_time` int NOT NULL DEFAULT '0' COMMENT '添加时间',
   `add_ip` varchar(50) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '添加IP',
@@ -151,6 +190,48 @@ REPLACE INTO `nako_guestbook` (`id`, `name`, `message`, `phone`, `email`, `qq`,
 	(2, '222', '12312341234', NULL, '123@123.com', NULL, NULL, 0, 1685230678, '127.0.0.1');
 /*!40000 ALTER TABLE `nako_guestbook` ENABLE KEYS */;
 
+-- 导出  表 nako_blog.nako_mail 结构
+DROP TABLE IF EXISTS `nako_mail`;
+CREATE TABLE IF NOT EXISTS `nako_mail` (
+  `id` int unsigned NOT NULL AUTO_INCREMENT,
+  `email` varchar(100) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '收件人',
+  `subject` varchar(255) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '标题',
+  `body` longtext CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL COMMENT '内容',
+  `status` tinyint(1) NOT NULL DEFAULT '0' COMMENT '0-待发送，1-已发送，2-发送失败',
+  `attempts` int NOT NULL DEFAULT '0' COMMENT '发送次数',
+  `error` varchar(255) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '错误信息',
+  `next_time` bigint NOT NULL DEFAULT '0' COMMENT '下次发送时间',
+  `send_time` bigint NOT NULL DEFAULT '0' COMMENT '发送时间',
+  `add_time` bigint NOT NULL DEFAULT '0' COMMENT '添加时间',
+  PRIMARY KEY (`id`),
+  KEY `idx-nako_mail-status` (`status`,`next_time`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='邮件队列';
+
+-- 导出  表 nako_blog.nako_not_found 结构
+DROP TABLE IF EXISTS `nako_not_found`;
+CREATE TABLE IF NOT EXISTS `nako_not_found` (
+  `id` int unsigned NOT NULL AUTO_INCREMENT,
+  `path` varchar(255) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '链接',
+  `hits` bigint NOT NULL DEFAULT '0' COMMENT '访问次数',
+  `referrer` varchar(255) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '最近来源',
+  `last_time` bigint NOT NULL DEFAULT '0' COMMENT '最后访问时间',
+  `add_time` bigint NOT NULL DEFAULT '0' COMMENT '添加时间',
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `idx-nako_not_found-path` (`path`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='404 记录';
+
+-- 导出  表 nako_blog.nako_not_found_referrer 结构
+DROP TABLE IF EXISTS `nako_not_found_referrer`;
+CREATE TABLE IF NOT EXISTS `nako_not_found_referrer` (
+  `id` int unsigned NOT NULL AUTO_INCREMENT,
+  `not_found_id` int unsigned NOT NULL DEFAULT '0' COMMENT '404 记录ID',
+  `referrer` varchar(255) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '来源',
+  `hits` bigint NOT NULL DEFAULT '0' COMMENT '访问次数',
+  `last_time` bigint NOT NULL DEFAULT '0' COMMENT '最后访问时间',
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `idx-nako_not_found_referrer-referrer` (`not_found_id`,`referrer`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='404 来源';
+
 -- 导出  表 nako_blog.nako_page 结构
 DROP TABLE IF EXISTS `nako_page`;
 CREATE TABLE IF NOT EXISTS `nako_page` (
@@ -175,6 +256,48 @@ REPLACE INTO `nako_page` (`id`, `user_id`, `slug`, `title`, `keywords`, `descrip
 	(3, 1, 'contact', '联系我', '', '', '<p>联系我</p>', 'page_contact.html', 1, 1685169754, '127.0.0.1');
 /*!40000 ALTER TABLE `nako_page` ENABLE KEYS */;
 
+-- 导出  表 nako_blog.nako_redirect 结构
+DROP TABLE IF EXISTS `nako_redirect`;
+CREATE TABLE IF NOT EXISTS `nako_redirect` (
+  `id` int unsigned NOT NULL AUTO_INCREMENT,
+  `source` varchar(255) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '旧链接',
+  `match_type` varchar(10) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT 'exact' COMMENT '匹配方式，exact、prefix 或 regex',
+  `target` varchar(255) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '新链接',
+  `code` int NOT NULL DEFAULT '301' COMMENT '状态码',
+  `art_id` int unsigned NOT NULL DEFAULT '0' COMMENT '文章ID',
+  `hits` bigint NOT NULL DEFAULT '0' COMMENT '命中次数',
+  `status` tinyint(1) NOT NULL DEFAULT '1' COMMENT '1-启用，0-禁用',
+  `add_time` bigint NOT NULL DEFAULT '0' COMMENT '添加时间',
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `idx-nako_redirect-source` (`source`),
+  KEY `idx-nako_redirect-art_id` (`art_id`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='链接跳转';
+
+-- 导出  表 nako_blog.nako_series 结构
+DROP TABLE IF EXISTS `nako_series`;
+CREATE TABLE IF NOT EXISTS `nako_series` (
+  `id` int unsigned NOT NULL AUTO_INCREMENT,
+  `title` varchar(100) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '专题名称',
+  `slug` varchar(100) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '专题标识',
+  `desc` text CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci COMMENT '描述',
+  `cover` varchar(150) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '封面',
+  `sort` int NOT NULL DEFAULT '100' COMMENT '排序',
+  `status` tinyint(1) NOT NULL DEFAULT '1' COMMENT '1-启用，0-禁用',
+  `add_time` bigint NOT NULL DEFAULT '0' COMMENT '添加时间',
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `idx-nako_series-slug` (`slug`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='专题';
+
+-- 导出  表 nako_blog.nako_series_art 结构
+DROP TABLE IF EXISTS `nako_series_art`;
+CREATE TABLE IF NOT EXISTS `nako_series_art` (
+  `series_id` int unsigned NOT NULL COMMENT '专题ID',
+  `art_id` int unsigned NOT NULL COMMENT '文章ID',
+  `sort` int NOT NULL DEFAULT '0' COMMENT '专题内顺序',
+  PRIMARY KEY (`series_id`,`art_id`),
+  KEY `idx-nako_series_art-art_id` (`art_id`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='专题文章';
+
 -- 导出  表 nako_blog.nako_setting 结构
 DROP TABLE IF EXISTS `nako_setting`;
 CREATE TABLE IF NOT EXISTS `nako_setting` (
@@ -207,15 +330,44 @@ CREATE TABLE IF NOT EXISTS `nako_tag` (
   `add_time` int NOT NULL DEFAULT '0' COMMENT '添加时间',
   `add_ip` varchar(50) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '添加IP',
   PRIMARY KEY (`id`)
-) ENGINE=MyISAM AUTO_INCREMENT=5 DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='标签';
+) ENGINE=MyISAM AUTO_INCREMENT=8 DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='标签';
 
 -- 正在导出表  nako_blog.nako_tag 的数据：2 rows
 /*!40000 ALTER TABLE `nako_tag` DISABLE KEYS */;
 REPLACE INTO `nako_tag` (`id`, `name`, `desc`, `sort`, `status`, `add_time`, `add_ip`) VALUES
 	(1, '八卦', '八卦八卦', 101, 1, 1655823356, '127.0.0.1'),
-	(2, '安卓', '安卓', 100, 1, 1655911967, '127.0.0.1');
+	(2, '安卓', '安卓', 100, 1, 1655911967, '127.0.0.1'),
+	(5, '推荐', NULL, 100, 1, 1685386512, '127.0.0.1'),
+	(6, '热蒙八卦', NULL, 100, 1, 1685242600, '127.0.0.1'),
+	(7, '测试文章', NULL, 100, 1, 1685352288, '127.0.0.1');
 /*!40000 ALTER TABLE `nako_tag` ENABLE KEYS */;
 
+-- 导出  表 nako_blog.nako_template 结构
+DROP TABLE IF EXISTS `nako_template`;
+CREATE TABLE IF NOT EXISTS `nako_template` (
+  `id` int unsigned NOT NULL AUTO_INCREMENT,
+  `name` varchar(150) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '模板名称',
+  `content` longtext CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL COMMENT '模板内容',
+  `user_id` int unsigned NOT NULL DEFAULT '0' COMMENT '修改用户',
+  `update_time` bigint NOT NULL DEFAULT '0' COMMENT '更新时间',
+  `add_time` bigint NOT NULL DEFAULT '0' COMMENT '添加时间',
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `idx-nako_template-name` (`name`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='后台编辑的模板';
+
+-- 导出  表 nako_blog.nako_template_version 结构
+DROP TABLE IF EXISTS `nako_template_version`;
+CREATE TABLE IF NOT EXISTS `nako_template_version` (
+  `id` int unsigned NOT NULL AUTO_INCREMENT,
+  `name` varchar(150) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '模板名称',
+  `content` longtext CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL COMMENT '模板内容',
+  `user_id` int unsigned NOT NULL DEFAULT '0' COMMENT '修改用户',
+  `add_time` bigint NOT NULL DEFAULT '0' COMMENT '添加时间',
+  `add_ip` varchar(50) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '添加IP',
+  PRIMARY KEY (`id`),
+  KEY `idx-nako_template_version-name` (`name`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='模板历史版本';
+
 -- 导出  表 nako_blog.nako_user 结构
 DROP TABLE IF EXISTS `nako_user`;
 CREATE TABLE IF NOT EXISTS `nako_user` (
@@ -223,6 +375,7 @@ CREATE TABLE IF NOT EXISTS `nako_user` (
   `username` varchar(50) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '账号，大小写字母数字',
   `password` char(62) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '密码',
   `nickname` varchar(100) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '昵称',
+  `email` varchar(100) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '邮箱，接收通知',
   `avatar` varchar(150) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci DEFAULT NULL COMMENT '头像',
   `sign` varchar(200) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci DEFAULT NULL COMMENT '签名',
   `status` tinyint(1) DEFAULT '1' COMMENT '1-启用，0-禁用',
@@ -236,4 +389,54 @@ CREATE TABLE IF NOT EXISTS `nako_user` (
 REPLACE INTO `nako_user` (`id`, `username`, `password`, `nickname`, `avatar`, `sign`, `status`, `add_time`, `add_ip`) VALUES
 	(1, 'admin', '$2b$12$/aXiCMVd11/L5Mt0WonuiOfNLr81HJtNsIzLucYVVm9dNlZbcH7q.', '管理员', '/upload/avatar/356a192b7913b04c54574d18c28d46e6395428ab.jpg', 'signsign', 1, 1684299438, '127.0.0.1'),
 	(10, 'nako', '$2b$12$InjaYkeO1x72hH9WQG8N1uBHkleYyULDmbVgaB1O.edXPrrZorUlO', 'nako-blog', NULL, 'nako-blog 是使用 rust 开发的博客系统', 1, 1684304910, '127.0.0.1');
-/*!40000 ALTER TABLE `nako_user` ENABLE KEYS */;
\ No newline at end of file
+/*!40000 ALTER TABLE `nako_user` ENABLE KEYS */;
+-- 导出  表 nako_blog.nako_user_token 结构
+DROP TABLE IF EXISTS `nako_user_token`;
+CREATE TABLE IF NOT EXISTS `nako_user_token` (
+  `id` int unsigned NOT NULL AUTO_INCREMENT,
+  `user_id` int unsigned NOT NULL DEFAULT '0' COMMENT '用户ID',
+  `name` varchar(100) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '名称',
+  `token` char(64) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '令牌哈希',
+  `token_prefix` varchar(20) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '令牌前缀',
+  `scopes` varchar(200) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '权限',
+  `last_used_time` int DEFAULT NULL COMMENT '最后使用时间',
+  `expire_time` int DEFAULT NULL COMMENT '过期时间',
+  `status` tinyint(1) DEFAULT '1' COMMENT '1-启用，0-禁用',
+  `add_time` int NOT NULL DEFAULT '0' COMMENT '添加时间',
+  `add_ip` varchar(50) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '添加IP',
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `token` (`token`),
+  KEY `user_id` (`user_id`)
+) ENGINE=MyISAM DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='访问令牌';
+
+-- 导出  表 nako_blog.nako_webhook 结构
+DROP TABLE IF EXISTS `nako_webhook`;
+CREATE TABLE IF NOT EXISTS `nako_webhook` (
+  `id` int unsigned NOT NULL AUTO_INCREMENT,
+  `name` varchar(50) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '名称',
+  `url` varchar(255) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '请求链接',
+  `secret` varchar(100) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '签名密钥',
+  `events` varchar(255) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '订阅的事件，逗号分隔',
+  `status` tinyint(1) NOT NULL DEFAULT '1' COMMENT '1-启用，0-禁用',
+  `add_time` bigint NOT NULL DEFAULT '0' COMMENT '添加时间',
+  PRIMARY KEY (`id`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='Webhook';
+
+-- 导出  表 nako_blog.nako_webhook_log 结构
+DROP TABLE IF EXISTS `nako_webhook_log`;
+CREATE TABLE IF NOT EXISTS `nako_webhook_log` (
+  `id` int unsigned NOT NULL AUTO_INCREMENT,
+  `webhook_id` int unsigned NOT NULL DEFAULT '0' COMMENT 'Webhook ID',
+  `event` varchar(50) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '事件',
+  `payload` longtext CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL COMMENT '请求内容',
+  `status` tinyint(1) NOT NULL DEFAULT '0' COMMENT '0-待发送，1-成功，2-失败',
+  `attempts` int NOT NULL DEFAULT '0' COMMENT '发送次数',
+  `response_code` int NOT NULL DEFAULT '0' COMMENT '响应码',
+  `response` varchar(255) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT '' COMMENT '响应内容或错误信息',
+  `next_time` bigint NOT NULL DEFAULT '0' COMMENT '下次发送时间',
+  `send_time` bigint NOT NULL DEFAULT '0' COMMENT '发送时间',
+  `add_time` bigint NOT NULL DEFAULT '0' COMMENT '添加时间',
+  PRIMARY KEY (`id`),
+  KEY `idx-nako_webhook_log-webhook_id` (`webhook_id`),
+  KEY `idx-nako_webhook_log-status` (`status`,`next_time`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='Webhook 投递记录';
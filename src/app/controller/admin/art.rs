@@ -1,7 +1,8 @@
 use actix_web::{web, Error, HttpRequest, HttpResponse, Result};
+use sea_orm::TryIntoModel;
 
 use crate::nako::global::{AppState, Deserialize, Serialize, Session, Validate};
-use crate::nako::{http as nako_http, time, utils};
+use crate::nako::{http as nako_http, i18n, time, utils};
 
 use crate::app::entity::{
     self,
@@ -9,11 +10,11 @@ use crate::app::entity::{
     // cate as cate_entity,
 };
 use crate::app::model::{art, cate, user};
-use crate::app::service::http;
+use crate::app::service::{http, permalink, redirect, related, webhook};
 
 // 首页
 pub async fn index(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let ctx = nako_http::view_data();
 
@@ -54,6 +55,7 @@ pub async fn list(
         uuid: query.uuid.clone(),
         tag: None,
         cate_id: None,
+        cate_ids: None,
         user_id: None,
         is_top: query.is_top,
         status: query.status,
@@ -73,7 +75,10 @@ pub async fn list(
         count: count,
     };
 
-    Ok(nako_http::success_response_json("获取成功", res))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-get-success"),
+        res,
+    ))
 }
 
 // ==========================
@@ -89,10 +94,14 @@ pub async fn detail(
     query: web::Query<DetailQuery>,
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     if query.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "ID不能为空", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
     }
 
     let data = art::ArtModel::find_by_id(db, query.id)
@@ -100,7 +109,11 @@ pub async fn detail(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "文章不存在", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-art-not-found"),
+            "",
+        ));
     }
 
     // 分类
@@ -128,7 +141,7 @@ pub async fn detail(
 // 添加
 pub async fn create(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let cate_list = cate::CateModel::find_all(db).await.unwrap_or_default();
 
@@ -143,6 +156,7 @@ pub async fn create(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
 pub struct CreateForm {
     cate_id: u32,
     title: String,
+    slug: Option<String>,
     status: i32,
 }
 
@@ -156,13 +170,19 @@ pub async fn create_save(
     let db = &state.db;
 
     if params.cate_id == 0 {
-        return Ok(nako_http::error_response_json("所属分类不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-art-cate-required",
+        )));
     }
     if params.title.as_str() == "" {
-        return Ok(nako_http::error_response_json("文章不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-art-required",
+        )));
     }
     if params.status != 0 && params.status != 1 {
-        return Ok(nako_http::error_response_json("状态不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-status-required",
+        )));
     }
 
     let add_time = time::now().timestamp();
@@ -177,27 +197,41 @@ pub async fn create_save(
         .unwrap_or_default()
         .unwrap_or_default();
 
-    let create_data = art::ArtModel::create(
-        db,
-        art_entity::Model {
-            uuid: utils::uuid(),
-            cate_id: params.cate_id,
-            user_id: user_id,
-            title: params.title.clone(),
-            content: "".to_string(),
-            views: Some(0),
-            status: Some(params.status),
-            add_time: Some(add_time),
-            add_ip: Some(ip.clone()),
-            ..entity::default()
-        },
-    )
-    .await;
-    if !create_data.is_ok() {
-        return Ok(nako_http::error_response_json("添加失败"));
+    let mut create_data = art_entity::Model {
+        uuid: utils::uuid(),
+        cate_id: params.cate_id,
+        user_id: user_id,
+        title: params.title.clone(),
+        slug: params.slug.clone(),
+        content: "".to_string(),
+        views: Some(0),
+        status: Some(params.status),
+        add_time: Some(add_time),
+        add_ip: Some(ip.clone()),
+        ..entity::default()
+    };
+
+    // 文章别名
+    if let Err(err) = permalink::fill_slug(db, &mut create_data, None).await {
+        return Ok(nako_http::error_response_json(err.as_str()));
     }
 
-    Ok(nako_http::success_response_json("添加成功", ""))
+    let create_data = art::ArtModel::create(db, create_data).await;
+    let new_info = match create_data.and_then(|v| v.try_into_model()) {
+        Ok(v) => v,
+        Err(_) => return Ok(nako_http::error_response_json(&i18n::t("admin-add-failed"))),
+    };
+
+    permalink::record_create(db, &new_info).await;
+    webhook::fire_art(db, None, &new_info).await;
+
+    // 相关文章
+    related::changed(&mut state.get_ref().clone(), &new_info, Vec::new()).await;
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-add-success"),
+        "",
+    ))
 }
 
 // ==========================
@@ -213,10 +247,14 @@ pub async fn update(
     query: web::Query<UpdateQuery>,
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     if query.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "ID不能为空", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
     }
 
     let info = art::ArtModel::find_by_id(db, query.id)
@@ -224,7 +262,11 @@ pub async fn update(
         .unwrap_or_default()
         .unwrap_or_default();
     if info.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "文章不存在", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-art-not-found"),
+            "",
+        ));
     }
 
     let cate_list = cate::CateModel::find_all(db).await.unwrap_or_default();
@@ -242,6 +284,7 @@ pub struct UpdateForm {
     cate_id: u32,
     cover: String,
     title: String,
+    slug: String,
     keywords: String,
     description: String,
     content: String,
@@ -249,23 +292,25 @@ pub struct UpdateForm {
     tags: String,
     from: String,
     is_top: i32,
+    #[serde(default)]
+    comment_closed: i32,
     status: i32,
     add_time: String,
 }
 
 #[derive(Debug, Validate, Deserialize, Clone)]
 pub struct UpdateValidate {
-    #[validate(required(message = "所属分类不能为空"))]
+    #[validate(required(message = "admin-art-cate-required"))]
     cate_id: Option<u32>,
-    #[validate(required(message = "文章标题不能为空"))]
+    #[validate(required(message = "admin-art-title-required"))]
     title: Option<String>,
-    #[validate(required(message = "内容不能为空"))]
+    #[validate(required(message = "admin-content-required"))]
     content: Option<String>,
-    #[validate(required(message = "简介不能为空"))]
+    #[validate(required(message = "admin-art-brief-required"))]
     brief: Option<String>,
-    #[validate(required(message = "状态不能为空"))]
+    #[validate(required(message = "admin-status-required"))]
     status: Option<i32>,
-    #[validate(required(message = "发布时间不能为空"))]
+    #[validate(required(message = "admin-art-publish-time-required"))]
     add_time: Option<String>,
 }
 
@@ -276,7 +321,9 @@ pub async fn update_save(
     params: web::Form<UpdateForm>,
 ) -> Result<HttpResponse, Error> {
     if query.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
     let vali_data = UpdateValidate {
@@ -291,7 +338,7 @@ pub async fn update_save(
     let vali = vali_data.validate();
     if vali.is_err() {
         return Ok(nako_http::error_response_json(
-            format!("{}", vali.unwrap_err()).as_str(),
+            i18n::validate_message(&vali.unwrap_err()).as_str(),
         ));
     }
 
@@ -302,37 +349,61 @@ pub async fn update_save(
         .unwrap_or_default()
         .unwrap_or_default();
     if info.id == 0 {
-        return Ok(nako_http::error_response_json("要更改的文章不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-art-update-not-found",
+        )));
     }
 
     let add_time = time::parse(params.add_time.as_str()).timestamp();
 
-    // 更新
-    let data = art::ArtModel::update_by_id(
-        db,
-        query.id,
-        art_entity::Model {
-            cate_id: params.cate_id,
-            title: params.title.clone(),
-            keywords: Some(params.keywords.clone()),
-            description: Some(params.description.clone()),
-            cover: Some(params.cover.clone()),
-            content: params.content.clone(),
-            brief: Some(params.brief.clone()),
-            tags: Some(params.tags.clone()),
-            from: Some(params.from.clone()),
-            is_top: Some(params.is_top),
-            status: Some(params.status),
-            add_time: Some(add_time),
-            ..entity::default()
-        },
-    )
-    .await;
-    if data.is_err() {
-        return Ok(nako_http::error_response_json("更新失败"));
+    let mut update_data = art_entity::Model {
+        cate_id: params.cate_id,
+        title: params.title.clone(),
+        slug: Some(params.slug.clone()),
+        keywords: Some(params.keywords.clone()),
+        description: Some(params.description.clone()),
+        cover: Some(params.cover.clone()),
+        content: params.content.clone(),
+        brief: Some(params.brief.clone()),
+        tags: Some(params.tags.clone()),
+        from: Some(params.from.clone()),
+        is_top: Some(params.is_top),
+        comment_closed: params.comment_closed,
+        status: Some(params.status),
+        add_time: Some(add_time),
+        ..entity::default()
+    };
+
+    // 文章别名
+    if let Err(err) = permalink::fill_slug(db, &mut update_data, Some(&info)).await {
+        return Ok(nako_http::error_response_json(err.as_str()));
     }
 
-    Ok(nako_http::success_response_json("更新成功", ""))
+    // 更新前的相关文章
+    let related_ids = related::neighbor_ids(db, &info).await;
+
+    // 更新
+    let new_info = match art::ArtModel::update_by_id(db, query.id, update_data).await {
+        Ok(v) => v,
+        Err(_) => {
+            return Ok(nako_http::error_response_json(&i18n::t(
+                "admin-update-failed",
+            )))
+        }
+    };
+
+    // 链接变动时记录跳转
+    permalink::record_change(db, &info, &new_info).await;
+
+    webhook::fire_art(db, Some(&info), &new_info).await;
+
+    // 相关文章
+    related::changed(&mut state.get_ref().clone(), &new_info, related_ids).await;
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
 }
 
 // ==========================
@@ -350,7 +421,9 @@ pub async fn delete(
     let db = &state.db;
 
     if query.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
     let data = art::ArtModel::find_by_id(db, query.id)
@@ -358,15 +431,33 @@ pub async fn delete(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(nako_http::error_response_json("要删除的文章不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-art-delete-not-found",
+        )));
     }
 
+    // 删除后标签关联也被删除，先查找需清除相关文章缓存的文章
+    let mut related_ids = related::neighbor_ids(db, &data).await;
+    related_ids.push(data.id);
+
     let delete_data = art::ArtModel::delete(db, query.id).await;
     if delete_data.is_err() {
-        return Ok(nako_http::error_response_json("删除失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-delete-failed",
+        )));
     }
 
-    Ok(nako_http::success_response_json("删除成功", ""))
+    // 文章的跳转已一起删除
+    redirect::load(db).await;
+
+    webhook::fire(db, webhook::ART_DELETED, &data).await;
+
+    related::clear(&mut state.get_ref().clone(), related_ids).await;
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-delete-success"),
+        "",
+    ))
 }
 
 // ==========================
@@ -391,11 +482,15 @@ pub async fn update_status(
     let db = &state.db;
 
     if query.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
     if params.status != 0 && params.status != 1 {
-        return Ok(nako_http::error_response_json("状态不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-status-required",
+        )));
     }
 
     let data = art::ArtModel::find_by_id(db, query.id)
@@ -403,7 +498,9 @@ pub async fn update_status(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(nako_http::error_response_json("要更改的文章不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-art-update-not-found",
+        )));
     }
 
     // 更新
@@ -416,9 +513,22 @@ pub async fn update_status(
         },
     )
     .await;
-    if status.is_err() {
-        return Ok(nako_http::error_response_json("更新失败"));
-    }
+    let status = match status {
+        Ok(v) => v,
+        Err(_) => {
+            return Ok(nako_http::error_response_json(&i18n::t(
+                "admin-update-failed",
+            )))
+        }
+    };
+
+    webhook::fire_art(db, Some(&data), &status).await;
+
+    // 相关文章
+    related::changed(&mut state.get_ref().clone(), &status, Vec::new()).await;
 
-    Ok(nako_http::success_response_json("更新成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
 }
@@ -6,7 +6,7 @@ use actix_web::{
 use std::fs;
 
 use crate::nako::global::{AppState, Deserialize, Serialize};
-use crate::nako::http as nako_http;
+use crate::nako::{http as nako_http, i18n};
 
 use crate::app::entity::attach as attach_entity;
 use crate::app::model::attach;
@@ -15,7 +15,7 @@ use crate::nako::app::{attach_path, upload_path};
 
 // 首页
 pub async fn index(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let ctx = nako_http::view_data();
 
@@ -68,7 +68,10 @@ pub async fn list(
         count: count,
     };
 
-    Ok(nako_http::success_response_json("获取成功", res))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-get-success"),
+        res,
+    ))
 }
 
 // ==========================
@@ -84,10 +87,14 @@ pub async fn detail(
     query: web::Query<DetailQuery>,
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     if query.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "ID不能为空", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
     }
 
     let data = attach::AttachModel::find_by_id(db, query.id)
@@ -95,7 +102,11 @@ pub async fn detail(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "附件不存在", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-attach-not-found"),
+            "",
+        ));
     }
 
     let mut ctx = nako_http::view_data();
@@ -119,7 +130,9 @@ pub async fn delete(
     let db = &state.db;
 
     if query.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
     let data = attach::AttachModel::find_by_id(db, query.id)
@@ -127,12 +140,16 @@ pub async fn delete(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(nako_http::error_response_json("要删除的附件不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-attach-delete-not-found",
+        )));
     }
 
     let status = attach::AttachModel::delete(db, query.id).await;
     if status.is_err() {
-        return Ok(nako_http::error_response_json("删除失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-delete-failed",
+        )));
     }
 
     let mut upload_path = upload_path(data.path.clone());
@@ -142,7 +159,10 @@ pub async fn delete(
 
     fs::remove_file(upload_path).unwrap_or_default();
 
-    Ok(nako_http::success_response_json("删除成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-delete-success"),
+        "",
+    ))
 }
 
 // ==========================
@@ -161,7 +181,7 @@ pub async fn download(
     let db = &state.db;
 
     if query.id == 0 {
-        return Ok(nako_http::text("ID不能为空".to_string()));
+        return Ok(nako_http::text(i18n::t("admin-id-required")));
     }
 
     let data = attach::AttachModel::find_by_id(db, query.id)
@@ -169,7 +189,7 @@ pub async fn download(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(nako_http::text("附件不存在".to_string()));
+        return Ok(nako_http::text(i18n::t("admin-attach-not-found")));
     }
 
     let mut filename = upload_path(data.path.clone());
@@ -188,7 +208,7 @@ pub async fn download(
             .into_response(&req));
     }
 
-    return Ok(nako_http::text("文件不存在".to_string()));
+    return Ok(nako_http::text(i18n::t("admin-file-not-found")));
 }
 
 // ==========================
@@ -207,7 +227,7 @@ pub async fn preview(
     let db = &state.db;
 
     if query.id == 0 {
-        return Ok(nako_http::text("ID不能为空".to_string()));
+        return Ok(nako_http::text(i18n::t("admin-id-required")));
     }
 
     let data = attach::AttachModel::find_by_id(db, query.id)
@@ -215,11 +235,11 @@ pub async fn preview(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(nako_http::text("附件不存在".to_string()));
+        return Ok(nako_http::text(i18n::t("admin-attach-not-found")));
     }
 
     if data.r#type != 2 {
-        return Ok(nako_http::text("附件不能预览".to_string()));
+        return Ok(nako_http::text(i18n::t("admin-attach-cannot-preview")));
     }
 
     let upload_path = upload_path(data.path);
@@ -228,5 +248,5 @@ pub async fn preview(
         return Ok(named_file.into_response(&req));
     }
 
-    return Ok(nako_http::text("文件不存在".to_string()));
+    return Ok(nako_http::text(i18n::t("admin-file-not-found")));
 }
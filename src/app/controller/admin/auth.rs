@@ -10,10 +10,11 @@ use captcha::Captcha;
 use crate::nako::{
     auth as nako_auth,
     global::{AppState, Session, Validate},
-    http as nako_http, rsa, utils,
+    http as nako_http, i18n, rsa, utils,
 };
 
 use crate::app::model::user;
+use crate::app::service::webhook;
 
 const AUTH_KEY: &str = "nako:auth_key";
 
@@ -58,7 +59,7 @@ pub async fn login(
         return Ok(nako_http::redirect(redirect_url));
     }
 
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let mut ctx = nako_http::view_data();
 
@@ -88,13 +89,13 @@ pub struct LoginParams {
 
 #[derive(Debug, Validate, Deserialize, Clone)]
 pub struct LoginValidate {
-    #[validate(required(message = "账号不能为空"))]
+    #[validate(required(message = "auth-name-required"))]
     name: Option<String>,
-    #[validate(required(message = "密码不能为空"))]
+    #[validate(required(message = "auth-password-required"))]
     password: Option<String>,
     #[validate(
-        required(message = "验证码不能为空"),
-        length(min = 4, message = "验证码位数错误")
+        required(message = "auth-captcha-required"),
+        length(min = 4, message = "auth-captcha-length")
     )]
     captcha: Option<String>,
 }
@@ -110,7 +111,7 @@ pub async fn login_check(
         .unwrap_or_default()
         .unwrap_or_default();
     if login_id > 0 {
-        return Ok(nako_http::error_response_json("你已经登陆了"));
+        return Ok(nako_http::error_response_json(&i18n::t("auth-logged-in")));
     }
 
     let vali_data = LoginValidate {
@@ -122,7 +123,7 @@ pub async fn login_check(
     let vali = vali_data.validate();
     if vali.is_err() {
         return Ok(nako_http::error_response_json(
-            format!("{}", vali.unwrap_err()).as_str(),
+            i18n::validate_message(&vali.unwrap_err()).as_str(),
         ));
     }
 
@@ -131,7 +132,7 @@ pub async fn login_check(
         .unwrap_or_default()
         .unwrap_or_default();
     if params.captcha.to_uppercase() != auth_captcha.to_uppercase() {
-        return Ok(nako_http::error_response_json("验证码错误"));
+        return Ok(nako_http::error_response_json(&i18n::t("auth-captcha-error")));
     }
 
     let db = &state.db;
@@ -141,10 +142,10 @@ pub async fn login_check(
         .unwrap_or_default();
 
     if user_info.id == 0 {
-        return Ok(nako_http::error_response_json("账号或者密码错误"));
+        return Ok(nako_http::error_response_json(&i18n::t("auth-password-error")));
     }
 
-    let pass = user_info.password.unwrap_or("".to_string());
+    let pass = user_info.password.clone().unwrap_or("".to_string());
 
     // 私钥
     let prikey = session
@@ -160,21 +161,23 @@ pub async fn login_check(
 
     // 验证密码
     if !nako_auth::password_verify(depass.as_str(), pass.as_str()) {
-        return Ok(nako_http::error_response_json("账号或者密码错误"));
+        return Ok(nako_http::error_response_json(&i18n::t("auth-password-error")));
     }
 
     let status = user_info.status.unwrap_or(0);
     if status == 0 {
-        return Ok(nako_http::error_response_json("账号不存在或者已被禁用"));
+        return Ok(nako_http::error_response_json(&i18n::t("auth-user-disabled")));
     }
 
     if session.insert("login_id", user_info.id).is_err() {
-        return Ok(nako_http::error_response_json("登陆失败"));
+        return Ok(nako_http::error_response_json(&i18n::t("auth-login-failed")));
     }
 
     session.remove(AUTH_KEY);
 
-    Ok(nako_http::success_response_json("登陆成功", ""))
+    webhook::fire_login(db, &user_info).await;
+
+    Ok(nako_http::success_response_json(&i18n::t("auth-login-success"), ""))
 }
 
 // 退出
@@ -1,15 +1,15 @@
 use actix_web::{web, Error, HttpRequest, HttpResponse, Result};
 
 use crate::nako::global::{AppState, Deserialize, Serialize, Validate};
-use crate::nako::{app, http as nako_http, time};
+use crate::nako::{app, http as nako_http, i18n, time};
 
 use crate::app::entity::{self, cate as cate_entity};
-use crate::app::model::cate;
-use crate::app::service::http;
+use crate::app::model::{art, cate};
+use crate::app::service::{http, permalink};
 
 // 首页
 pub async fn index(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let ctx = nako_http::view_data();
 
@@ -64,7 +64,10 @@ pub async fn list(
         count: count,
     };
 
-    Ok(nako_http::success_response_json("获取成功", res))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-get-success"),
+        res,
+    ))
 }
 
 // ==========================
@@ -80,10 +83,14 @@ pub async fn detail(
     query: web::Query<DetailQuery>,
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     if query.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "ID不能为空", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
     }
 
     let data = cate::CateModel::find_by_id(db, query.id)
@@ -91,7 +98,11 @@ pub async fn detail(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "分类不存在", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-cate-not-found"),
+            "",
+        ));
     }
 
     let mut ctx = nako_http::view_data();
@@ -104,7 +115,7 @@ pub async fn detail(
 
 // 添加
 pub async fn create(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let ctx = nako_http::view_data();
 
@@ -128,13 +139,19 @@ pub async fn create_save(
     let db = &state.db;
 
     if params.name.as_str() == "" {
-        return Ok(nako_http::error_response_json("分类不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-cate-required",
+        )));
     }
     if params.slug.as_str() == "" {
-        return Ok(nako_http::error_response_json("标识不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-slug-required",
+        )));
     }
     if params.status != 0 && params.status != 1 {
-        return Ok(nako_http::error_response_json("状态不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-status-required",
+        )));
     }
 
     let data = cate::CateModel::find_by_slug(db, params.slug.as_str())
@@ -142,7 +159,9 @@ pub async fn create_save(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id > 0 {
-        return Ok(nako_http::error_response_json("分类标识已经存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-cate-slug-exists",
+        )));
     }
 
     let add_time = time::now().timestamp();
@@ -169,10 +188,15 @@ pub async fn create_save(
     )
     .await;
     if create_data.is_ok() {
-        return Ok(nako_http::success_response_json("添加成功", ""));
+        permalink::load_cates(db).await;
+
+        return Ok(nako_http::success_response_json(
+            &i18n::t("admin-add-success"),
+            "",
+        ));
     }
 
-    Ok(nako_http::error_response_json("添加失败"))
+    Ok(nako_http::error_response_json(&i18n::t("admin-add-failed")))
 }
 
 // ==========================
@@ -188,10 +212,14 @@ pub async fn update(
     query: web::Query<UpdateQuery>,
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     if query.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "ID不能为空", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
     }
 
     let info = cate::CateModel::find_by_id(db, query.id)
@@ -199,10 +227,19 @@ pub async fn update(
         .unwrap_or_default()
         .unwrap_or_default();
     if info.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "分类不存在", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-cate-not-found"),
+            "",
+        ));
     }
 
-    let cate_list = cate::CateModel::find_all(db).await.unwrap_or_default();
+    // 排除自身及下级分类
+    let all_cates = cate::CateModel::find_all(db).await.unwrap_or_default();
+    let cate_list = cate::CateModel::tree_list(&all_cates)
+        .into_iter()
+        .filter(|c| !cate::CateModel::is_cycle(&all_cates, info.id, c.cate.id))
+        .collect::<Vec<cate::CateNode>>();
 
     let list_tpls = app::list_tpls();
     let view_tpls = app::view_tpls();
@@ -231,19 +268,19 @@ pub struct UpdateForm {
 
 #[derive(Debug, Validate, Deserialize, Clone)]
 pub struct UpdateValidate {
-    #[validate(required(message = "父级不能为空"))]
+    #[validate(required(message = "admin-cate-parent-required"))]
     pid: Option<u32>,
-    #[validate(required(message = "分类名称不能为空"))]
+    #[validate(required(message = "admin-cate-name-required"))]
     name: Option<String>,
-    #[validate(required(message = "分类标识不能为空"))]
+    #[validate(required(message = "admin-cate-slug-required"))]
     slug: Option<String>,
-    #[validate(required(message = "排序不能为空"))]
+    #[validate(required(message = "admin-sort-required"))]
     sort: Option<i32>,
-    #[validate(required(message = "列表模板不能为空"))]
+    #[validate(required(message = "admin-cate-list-tpl-required"))]
     list_tpl: Option<String>,
-    #[validate(required(message = "详情模板不能为空"))]
+    #[validate(required(message = "admin-cate-view-tpl-required"))]
     view_tpl: Option<String>,
-    #[validate(required(message = "状态不能为空"))]
+    #[validate(required(message = "admin-status-required"))]
     status: Option<i32>,
 }
 
@@ -254,7 +291,9 @@ pub async fn update_save(
     params: web::Form<UpdateForm>,
 ) -> Result<HttpResponse, Error> {
     if query.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
     let vali_data = UpdateValidate {
@@ -270,7 +309,7 @@ pub async fn update_save(
     let vali = vali_data.validate();
     if vali.is_err() {
         return Ok(nako_http::error_response_json(
-            format!("{}", vali.unwrap_err()).as_str(),
+            i18n::validate_message(&vali.unwrap_err()).as_str(),
         ));
     }
 
@@ -281,7 +320,9 @@ pub async fn update_save(
         .unwrap_or_default()
         .unwrap_or_default();
     if info.id == 0 {
-        return Ok(nako_http::error_response_json("要更改的分类不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-cate-update-not-found",
+        )));
     }
 
     let info_by_name = cate::CateModel::find_by_slug(db, params.slug.as_str())
@@ -290,10 +331,33 @@ pub async fn update_save(
         .unwrap_or_default();
     if info_by_name.id > 0 {
         if info.id != info_by_name.id {
-            return Ok(nako_http::error_response_json("分类标识已经存在"));
+            return Ok(nako_http::error_response_json(&i18n::t(
+                "admin-cate-slug-exists",
+            )));
+        }
+    }
+
+    // 父级不能为自身或者下级分类
+    if params.pid > 0 {
+        let all_cates = cate::CateModel::find_all(db).await.unwrap_or_default();
+        if !all_cates.iter().any(|c| c.id == params.pid) {
+            return Ok(nako_http::error_response_json(&i18n::t(
+                "admin-cate-parent-not-found",
+            )));
+        }
+        if cate::CateModel::is_cycle(&all_cates, info.id, params.pid) {
+            return Ok(nako_http::error_response_json(&i18n::t(
+                "admin-cate-parent-invalid",
+            )));
         }
     }
 
+    // 分类路径变动时需记录文章旧链接的跳转
+    let all_cates = cate::CateModel::find_all(db).await.unwrap_or_default();
+    let mut cate_ids = cate::CateModel::descendant_ids(&all_cates, info.id);
+    cate_ids.push(info.id);
+    let art_urls = permalink::cate_art_urls(db, cate_ids).await;
+
     // 更新
     let data = cate::CateModel::update_by_id(
         db,
@@ -312,10 +376,17 @@ pub async fn update_save(
     )
     .await;
     if data.is_err() {
-        return Ok(nako_http::error_response_json("更新失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-update-failed",
+        )));
     }
 
-    Ok(nako_http::success_response_json("更新成功", ""))
+    permalink::record_cate_change(db, art_urls).await;
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
 }
 
 // ==========================
@@ -323,6 +394,7 @@ pub async fn update_save(
 #[derive(Deserialize)]
 pub struct DeleteForm {
     id: u32,
+    move_to: Option<u32>,
 }
 
 // 删除
@@ -333,7 +405,9 @@ pub async fn delete(
     let db = &state.db;
 
     if query.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
     let data = cate::CateModel::find_by_id(db, query.id)
@@ -341,15 +415,85 @@ pub async fn delete(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(nako_http::error_response_json("要删除的分类不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-cate-delete-not-found",
+        )));
+    }
+
+    let all_cates = cate::CateModel::find_all(db).await.unwrap_or_default();
+    let has_children = all_cates.iter().any(|c| c.pid == data.id);
+
+    let art_count = art::ArtModel::search_count(
+        db,
+        art::ArtWhere {
+            uuid: None,
+            cate_id: Some(data.id),
+            cate_ids: None,
+            user_id: None,
+            title: None,
+            tag: None,
+            is_top: None,
+            status: None,
+        },
+    )
+    .await
+    .unwrap_or(0);
+
+    let mut cate_ids = cate::CateModel::descendant_ids(&all_cates, data.id);
+    cate_ids.push(data.id);
+    let art_urls = permalink::cate_art_urls(db, cate_ids).await;
+
+    // 存在子分类或者文章时需转移
+    if has_children || art_count > 0 {
+        let move_to = query.move_to.unwrap_or_default();
+        if move_to == 0 {
+            return Ok(nako_http::error_response_json(&i18n::t(
+                "admin-cate-not-empty",
+            )));
+        }
+
+        if !all_cates.iter().any(|c| c.id == move_to) {
+            return Ok(nako_http::error_response_json(&i18n::t(
+                "admin-cate-move-not-found",
+            )));
+        }
+        if cate::CateModel::is_cycle(&all_cates, data.id, move_to) {
+            return Ok(nako_http::error_response_json(&i18n::t(
+                "admin-cate-move-invalid",
+            )));
+        }
+
+        if cate::CateModel::update_pid_by_pid(db, data.id, move_to)
+            .await
+            .is_err()
+        {
+            return Ok(nako_http::error_response_json(&i18n::t(
+                "admin-cate-move-children-failed",
+            )));
+        }
+        if art::ArtModel::update_cate_by_cate_id(db, data.id, move_to)
+            .await
+            .is_err()
+        {
+            return Ok(nako_http::error_response_json(&i18n::t(
+                "admin-cate-move-arts-failed",
+            )));
+        }
     }
 
     let delete_data = cate::CateModel::delete(db, query.id).await;
     if delete_data.is_err() {
-        return Ok(nako_http::error_response_json("删除失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-delete-failed",
+        )));
     }
 
-    Ok(nako_http::success_response_json("删除成功", ""))
+    permalink::record_cate_change(db, art_urls).await;
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-delete-success"),
+        "",
+    ))
 }
 
 // ==========================
@@ -374,11 +518,15 @@ pub async fn update_status(
     let db = &state.db;
 
     if query.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
     if params.status != 0 && params.status != 1 {
-        return Ok(nako_http::error_response_json("状态不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-status-required",
+        )));
     }
 
     let data = cate::CateModel::find_by_id(db, query.id)
@@ -386,7 +534,9 @@ pub async fn update_status(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(nako_http::error_response_json("要更改的分类不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-cate-update-not-found",
+        )));
     }
 
     // 更新
@@ -400,8 +550,13 @@ pub async fn update_status(
     )
     .await;
     if status.is_err() {
-        return Ok(nako_http::error_response_json("更新失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-update-failed",
+        )));
     }
 
-    Ok(nako_http::success_response_json("更新成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
 }
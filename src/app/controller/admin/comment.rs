@@ -1,17 +1,48 @@
 use actix_web::{web, Error, HttpResponse, Result};
 
 use crate::nako::global::{AppState, Deserialize, Serialize};
-use crate::nako::http as nako_http;
+use crate::nako::{http as nako_http, i18n};
 
 use crate::app::entity::{self, comment as comment_entity};
 use crate::app::model::comment;
-use crate::app::service::http;
+use crate::app::service::{self, http};
+
+/// 状态名称，名称为翻译的 key
+pub const STATUS_NAMES: [(i32, &str); 4] = [
+    (comment::STATUS_PENDING, "admin-comment-pending"),
+    (comment::STATUS_APPROVED, "admin-comment-approved"),
+    (comment::STATUS_SPAM, "admin-comment-spam"),
+    (comment::STATUS_TRASH, "admin-comment-trash"),
+];
+
+#[derive(Serialize)]
+pub struct StatusTab {
+    status: i32,
+    name: String,
+    count: u64,
+}
 
 // 首页
 pub async fn index(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
-    let mut view = state.view.clone();
+    let db = &state.db;
+    let mut view = state.view.get();
+
+    // 各状态数量
+    let mut tabs: Vec<StatusTab> = Vec::new();
+    for (status, name) in STATUS_NAMES {
+        let count = comment::CommentModel::find_count_by_status(db, status)
+            .await
+            .unwrap_or(0);
 
-    let ctx = nako_http::view_data();
+        tabs.push(StatusTab {
+            status,
+            name: i18n::t(name),
+            count,
+        });
+    }
+
+    let mut ctx = nako_http::view_data();
+    ctx.insert("tabs", &tabs);
 
     Ok(nako_http::view(&mut view, "admin/comment/index.html", &ctx))
 }
@@ -63,12 +94,12 @@ pub async fn list(
         .await
         .unwrap_or(0);
 
-    let res: ListData = ListData {
-        list: list,
-        count: count,
-    };
+    let res: ListData = ListData { list, count };
 
-    Ok(nako_http::success_response_json("获取成功", res))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-get-success"),
+        res,
+    ))
 }
 
 // ==========================
@@ -84,10 +115,14 @@ pub async fn detail(
     query: web::Query<DetailQuery>,
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     if query.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "ID不能为空", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
     }
 
     let data = comment::CommentModel::find_by_id(db, query.id)
@@ -95,7 +130,11 @@ pub async fn detail(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "评论不存在", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-comment-not-found"),
+            "",
+        ));
     }
 
     let mut ctx = nako_http::view_data();
@@ -123,7 +162,9 @@ pub async fn delete(
     let db = &state.db;
 
     if query.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
     let data = comment::CommentModel::find_by_id(db, query.id)
@@ -131,15 +172,22 @@ pub async fn delete(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(nako_http::error_response_json("要删除的评论不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-comment-delete-not-found",
+        )));
     }
 
     let delete_data = comment::CommentModel::delete(db, query.id).await;
     if delete_data.is_err() {
-        return Ok(nako_http::error_response_json("删除失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-delete-failed",
+        )));
     }
 
-    Ok(nako_http::success_response_json("删除成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-delete-success"),
+        "",
+    ))
 }
 
 // ==========================
@@ -157,7 +205,9 @@ pub async fn batch_delete(
     let db = &state.db;
 
     if params.ids.as_str() == "" {
-        return Ok(nako_http::error_response_json("未选中数据"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-nothing-selected",
+        )));
     }
 
     let ids = params.ids.split(",").collect::<Vec<&str>>();
@@ -174,7 +224,10 @@ pub async fn batch_delete(
         }
     }
 
-    Ok(nako_http::success_response_json("批量删除成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-batch-delete-success"),
+        "",
+    ))
 }
 
 // ==========================
@@ -199,11 +252,15 @@ pub async fn update_status(
     let db = &state.db;
 
     if query.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
-    if params.status != 0 && params.status != 1 {
-        return Ok(nako_http::error_response_json("状态不能为空"));
+    if !comment::STATUSES.contains(&params.status) {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-status-error",
+        )));
     }
 
     let data = comment::CommentModel::find_by_id(db, query.id)
@@ -211,7 +268,9 @@ pub async fn update_status(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(nako_http::error_response_json("要更改的评论不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-comment-update-not-found",
+        )));
     }
 
     // 更新
@@ -224,9 +283,120 @@ pub async fn update_status(
         },
     )
     .await;
-    if status.is_err() {
-        return Ok(nako_http::error_response_json("更新失败"));
+    let status = match status {
+        Ok(v) => v,
+        Err(_) => {
+            return Ok(nako_http::error_response_json(&i18n::t(
+                "admin-update-failed",
+            )))
+        }
+    };
+
+    // 审核通过后通知被回复的评论者
+    if data.status != Some(comment::STATUS_APPROVED)
+        && status.status == Some(comment::STATUS_APPROVED)
+    {
+        service::comment::approved(&state, &status).await;
     }
 
-    Ok(nako_http::success_response_json("更新成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
+}
+
+// ==========================
+
+#[derive(Deserialize)]
+pub struct BatchStatusForm {
+    ids: String,
+    status: i32,
+}
+
+// 批量更改状态
+pub async fn batch_status(
+    state: web::Data<AppState>,
+    params: web::Form<BatchStatusForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if params.ids.as_str() == "" {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-nothing-selected",
+        )));
+    }
+
+    if !comment::STATUSES.contains(&params.status) {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-status-error",
+        )));
+    }
+
+    let ids = params.ids.split(",").collect::<Vec<&str>>();
+
+    for id in ids {
+        let update_id = id.parse::<u32>().unwrap_or_default();
+
+        let data = comment::CommentModel::find_by_id(db, update_id)
+            .await
+            .unwrap_or_default()
+            .unwrap_or_default();
+        if data.id == 0 || data.status == Some(params.status) {
+            continue;
+        }
+
+        let status = comment::CommentModel::update_status_by_id(
+            db,
+            update_id,
+            comment_entity::Model {
+                status: Some(params.status),
+                ..entity::default()
+            },
+        )
+        .await;
+
+        if let Ok(status) = status {
+            if status.status == Some(comment::STATUS_APPROVED) {
+                service::comment::approved(&state, &status).await;
+            }
+        }
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-batch-update-success"),
+        "",
+    ))
+}
+
+// ==========================
+
+#[derive(Deserialize)]
+pub struct ClearForm {
+    status: i32,
+}
+
+// 清空垃圾评论或回收站
+pub async fn clear(
+    state: web::Data<AppState>,
+    params: web::Form<ClearForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if params.status != comment::STATUS_SPAM && params.status != comment::STATUS_TRASH {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-comment-clear-only",
+        )));
+    }
+
+    let delete_data = comment::CommentModel::delete_by_status(db, params.status).await;
+    if delete_data.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-clear-failed",
+        )));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-clear-success"),
+        "",
+    ))
 }
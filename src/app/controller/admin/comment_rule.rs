@@ -0,0 +1,322 @@
+use actix_web::{web, Error, HttpResponse, Result};
+
+use crate::nako::global::{AppState, Deserialize, Serialize};
+use crate::nako::{http as nako_http, i18n, time};
+
+use crate::app::entity::{self, comment_rule as comment_rule_entity};
+use crate::app::model::comment_rule;
+use crate::app::service::{self, http};
+
+// 规则类型
+fn rule_types() -> Vec<(&'static str, String)> {
+    vec![
+        (comment_rule::TYPE_IP, "IP".to_string()),
+        (comment_rule::TYPE_EMAIL, i18n::t("admin-email")),
+        (comment_rule::TYPE_KEYWORD, i18n::t("admin-keywords")),
+    ]
+}
+
+// 处理方式
+fn actions() -> Vec<(&'static str, String)> {
+    vec![
+        (comment_rule::ACTION_BLOCK, i18n::t("admin-rule-block")),
+        (comment_rule::ACTION_ALLOW, i18n::t("admin-rule-allow")),
+    ]
+}
+
+// 首页
+pub async fn index(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    let mut view = state.view.get();
+
+    let mut ctx = nako_http::view_data();
+    ctx.insert("rule_types", &rule_types());
+    ctx.insert("actions", &actions());
+
+    Ok(nako_http::view(
+        &mut view,
+        "admin/comment_rule/index.html",
+        &ctx,
+    ))
+}
+
+// ==========================
+
+#[derive(Serialize)]
+pub struct ListData {
+    list: Vec<comment_rule_entity::Model>,
+    count: u64,
+}
+
+#[derive(Deserialize)]
+pub struct ListQuery {
+    page: u64,
+    limit: u64,
+
+    rule_type: Option<String>,
+    value: Option<String>,
+    action: Option<String>,
+}
+
+// 数据列表
+pub async fn list(
+    state: web::Data<AppState>,
+    query: web::Query<ListQuery>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let page: u64 = query.page;
+    let per_page: u64 = query.limit;
+
+    let search_where = comment_rule::CommentRuleWhere {
+        rule_type: query.rule_type.clone(),
+        value: query.value.clone(),
+        action: query.action.clone(),
+    };
+    let search_where = search_where.format();
+
+    let (list, _num_pages) =
+        comment_rule::CommentRuleModel::search_in_page(db, page, per_page, search_where.clone())
+            .await
+            .unwrap_or_default();
+    let count = comment_rule::CommentRuleModel::search_count(db, search_where.clone())
+        .await
+        .unwrap_or(0);
+
+    let res = ListData { list, count };
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-get-success"),
+        res,
+    ))
+}
+
+// ==========================
+
+#[derive(Deserialize)]
+pub struct CreateQuery {
+    rule_type: Option<String>,
+    value: Option<String>,
+}
+
+// 添加，可从评论列表带上 IP 或邮箱
+pub async fn create(
+    state: web::Data<AppState>,
+    query: web::Query<CreateQuery>,
+) -> Result<HttpResponse, Error> {
+    let mut view = state.view.get();
+
+    let mut ctx = nako_http::view_data();
+    ctx.insert("rule_type", &query.rule_type.clone().unwrap_or_default());
+    ctx.insert("value", &query.value.clone().unwrap_or_default());
+    ctx.insert("rule_types", &rule_types());
+    ctx.insert("actions", &actions());
+
+    Ok(nako_http::view(
+        &mut view,
+        "admin/comment_rule/create.html",
+        &ctx,
+    ))
+}
+
+// 表单数据
+#[derive(Deserialize)]
+pub struct SaveForm {
+    rule_type: String,
+    value: String,
+    action: String,
+}
+
+impl SaveForm {
+    // 检测数据
+    async fn check(&self, state: &AppState, id: u32) -> Option<String> {
+        if let Err(err) = service::comment::check_rule(
+            self.rule_type.as_str(),
+            self.value.as_str(),
+            self.action.as_str(),
+        ) {
+            return Some(err);
+        }
+
+        let exists = comment_rule::CommentRuleModel::find_by_value(
+            &state.db,
+            self.rule_type.as_str(),
+            self.value.trim(),
+        )
+        .await
+        .unwrap_or_default();
+        if exists.filter(|v| v.id != id).is_some() {
+            return Some(i18n::t("admin-rule-exists"));
+        }
+
+        None
+    }
+
+    fn model(&self) -> comment_rule_entity::Model {
+        comment_rule_entity::Model {
+            rule_type: self.rule_type.clone(),
+            value: self.value.trim().to_string(),
+            action: self.action.clone(),
+            ..entity::default()
+        }
+    }
+}
+
+// 添加保存
+pub async fn create_save(
+    state: web::Data<AppState>,
+    params: web::Form<SaveForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if let Some(err) = params.check(&state, 0).await {
+        return Ok(nako_http::error_response_json(err.as_str()));
+    }
+
+    let create_data = comment_rule::CommentRuleModel::create(
+        db,
+        comment_rule_entity::Model {
+            add_time: time::now().timestamp(),
+            ..params.model()
+        },
+    )
+    .await;
+    if create_data.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t("admin-add-failed")));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-add-success"),
+        "",
+    ))
+}
+
+// ==========================
+
+#[derive(Deserialize)]
+pub struct UpdateQuery {
+    id: u32,
+}
+
+// 更新
+pub async fn update(
+    state: web::Data<AppState>,
+    query: web::Query<UpdateQuery>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+    let mut view = state.view.get();
+
+    if query.id == 0 {
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
+    }
+
+    let info = comment_rule::CommentRuleModel::find_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if info.id == 0 {
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-rule-not-found"),
+            "",
+        ));
+    }
+
+    let mut ctx = nako_http::view_data();
+    ctx.insert("data", &info);
+    ctx.insert("rule_types", &rule_types());
+    ctx.insert("actions", &actions());
+
+    Ok(nako_http::view(
+        &mut view,
+        "admin/comment_rule/update.html",
+        &ctx,
+    ))
+}
+
+// 更新保存
+pub async fn update_save(
+    state: web::Data<AppState>,
+    query: web::Query<UpdateQuery>,
+    params: web::Form<SaveForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if query.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
+    }
+
+    let info = comment_rule::CommentRuleModel::find_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if info.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-rule-update-not-found",
+        )));
+    }
+
+    if let Some(err) = params.check(&state, info.id).await {
+        return Ok(nako_http::error_response_json(err.as_str()));
+    }
+
+    let data = comment_rule::CommentRuleModel::update_by_id(db, query.id, params.model()).await;
+    if data.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-update-failed",
+        )));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
+}
+
+// ==========================
+
+#[derive(Deserialize)]
+pub struct DeleteForm {
+    id: u32,
+}
+
+// 删除
+pub async fn delete(
+    state: web::Data<AppState>,
+    query: web::Form<DeleteForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if query.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
+    }
+
+    let data = comment_rule::CommentRuleModel::find_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-rule-delete-not-found",
+        )));
+    }
+
+    let delete_data = comment_rule::CommentRuleModel::delete(db, query.id).await;
+    if delete_data.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-delete-failed",
+        )));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-delete-success"),
+        "",
+    ))
+}
@@ -1,21 +1,21 @@
 use actix_web::{http::Method, web, Error, HttpRequest, HttpResponse, Result};
 
 use crate::nako::global::AppState;
-use crate::nako::http as nako_http;
+use crate::nako::{http as nako_http, i18n};
 
 /// 错误页面
 pub async fn index(req: HttpRequest, state: web::Data<AppState>) -> Result<HttpResponse, Error> {
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
-    let error = "页面不存在";
+    let error = i18n::t("admin-page-not-found");
 
     let method = req.method();
     if method == Method::POST {
-        return Ok(nako_http::error_response_json(error));
+        return Ok(nako_http::error_response_json(&error));
     }
 
     let mut ctx = nako_http::view_data();
-    ctx.insert("message", &error.to_string());
+    ctx.insert("message", &error);
 
     Ok(nako_http::view(&mut view, "admin/error/index.html", &ctx))
 }
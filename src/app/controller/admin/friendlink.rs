@@ -2,7 +2,7 @@ use actix_web::{web, Error, HttpRequest, HttpResponse, Result};
 use std::collections::HashMap;
 
 use crate::nako::global::{AppState, Deserialize, Serialize, Validate};
-use crate::nako::{http as nako_http, time};
+use crate::nako::{http as nako_http, i18n, time};
 
 use crate::app::entity::{self, friendlink as friendlink_entity};
 use crate::app::model::friendlink;
@@ -10,7 +10,7 @@ use crate::app::service::http;
 
 // 首页
 pub async fn index(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let ctx = nako_http::view_data();
 
@@ -71,7 +71,10 @@ pub async fn list(
         count: count,
     };
 
-    Ok(nako_http::success_response_json("获取成功", res))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-get-success"),
+        res,
+    ))
 }
 
 // ==========================
@@ -87,10 +90,14 @@ pub async fn detail(
     query: web::Query<DetailQuery>,
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     if query.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "ID不能为空", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
     }
 
     let data = friendlink::FriendlinkModel::find_by_id(db, query.id)
@@ -98,7 +105,11 @@ pub async fn detail(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "链接不存在", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-link-not-found"),
+            "",
+        ));
     }
 
     let mut ctx = nako_http::view_data();
@@ -115,7 +126,7 @@ pub async fn detail(
 
 // 添加
 pub async fn create(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let ctx = nako_http::view_data();
 
@@ -143,13 +154,19 @@ pub async fn create_save(
     let db = &state.db;
 
     if params.title.as_str() == "" {
-        return Ok(nako_http::error_response_json("名称不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-name-required",
+        )));
     }
     if params.url.as_str() == "" {
-        return Ok(nako_http::error_response_json("链接不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-link-required",
+        )));
     }
     if params.status != 0 && params.status != 1 {
-        return Ok(nako_http::error_response_json("状态不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-status-required",
+        )));
     }
 
     let add_time = time::now().timestamp();
@@ -175,10 +192,13 @@ pub async fn create_save(
     )
     .await;
     if create_data.is_ok() {
-        return Ok(nako_http::success_response_json("添加成功", ""));
+        return Ok(nako_http::success_response_json(
+            &i18n::t("admin-add-success"),
+            "",
+        ));
     }
 
-    Ok(nako_http::error_response_json("添加失败"))
+    Ok(nako_http::error_response_json(&i18n::t("admin-add-failed")))
 }
 
 // ==========================
@@ -194,10 +214,14 @@ pub async fn update(
     query: web::Query<UpdateQuery>,
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     if query.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "ID不能为空", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
     }
 
     let info = friendlink::FriendlinkModel::find_by_id(db, query.id)
@@ -205,12 +229,16 @@ pub async fn update(
         .unwrap_or_default()
         .unwrap_or_default();
     if info.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "链接不存在", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-link-not-found"),
+            "",
+        ));
     }
 
     let mut targets = HashMap::new();
-    targets.insert("_blank", "跳出页面");
-    targets.insert("_self", "当前页面");
+    targets.insert("_blank", i18n::t("admin-link-target-blank"));
+    targets.insert("_self", i18n::t("admin-link-target-self"));
 
     let mut ctx = nako_http::view_data();
     ctx.insert("data", &info);
@@ -236,15 +264,15 @@ pub struct UpdateForm {
 
 #[derive(Debug, Validate, Deserialize, Clone)]
 pub struct UpdateValidate {
-    #[validate(required(message = "名称不能为空"))]
+    #[validate(required(message = "admin-name-required"))]
     title: Option<String>,
-    #[validate(required(message = "链接不能为空"))]
+    #[validate(required(message = "admin-link-required"))]
     url: Option<String>,
-    #[validate(required(message = "跳转方式不能为空"))]
+    #[validate(required(message = "admin-link-target-required"))]
     target: Option<String>,
-    #[validate(required(message = "排序不能为空"))]
+    #[validate(required(message = "admin-sort-required"))]
     sort: Option<i32>,
-    #[validate(required(message = "状态不能为空"))]
+    #[validate(required(message = "admin-status-required"))]
     status: Option<i32>,
 }
 
@@ -255,7 +283,9 @@ pub async fn update_save(
     params: web::Form<UpdateForm>,
 ) -> Result<HttpResponse, Error> {
     if query.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
     let vali_data = UpdateValidate {
@@ -269,7 +299,7 @@ pub async fn update_save(
     let vali = vali_data.validate();
     if vali.is_err() {
         return Ok(nako_http::error_response_json(
-            format!("{}", vali.unwrap_err()).as_str(),
+            i18n::validate_message(&vali.unwrap_err()).as_str(),
         ));
     }
 
@@ -280,7 +310,9 @@ pub async fn update_save(
         .unwrap_or_default()
         .unwrap_or_default();
     if info.id == 0 {
-        return Ok(nako_http::error_response_json("要更改的链接不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-link-update-not-found",
+        )));
     }
 
     // 更新
@@ -299,10 +331,15 @@ pub async fn update_save(
     )
     .await;
     if data.is_err() {
-        return Ok(nako_http::error_response_json("更新失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-update-failed",
+        )));
     }
 
-    Ok(nako_http::success_response_json("更新成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
 }
 
 // ==========================
@@ -320,7 +357,9 @@ pub async fn delete(
     let db = &state.db;
 
     if query.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
     let data = friendlink::FriendlinkModel::find_by_id(db, query.id)
@@ -328,15 +367,22 @@ pub async fn delete(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(nako_http::error_response_json("要删除的链接不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-link-delete-not-found",
+        )));
     }
 
     let delete_data = friendlink::FriendlinkModel::delete(db, query.id).await;
     if delete_data.is_err() {
-        return Ok(nako_http::error_response_json("删除失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-delete-failed",
+        )));
     }
 
-    Ok(nako_http::success_response_json("删除成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-delete-success"),
+        "",
+    ))
 }
 
 // ==========================
@@ -361,11 +407,15 @@ pub async fn update_status(
     let db = &state.db;
 
     if query.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
     if params.status != 0 && params.status != 1 {
-        return Ok(nako_http::error_response_json("状态不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-status-required",
+        )));
     }
 
     let data = friendlink::FriendlinkModel::find_by_id(db, query.id)
@@ -373,7 +423,9 @@ pub async fn update_status(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(nako_http::error_response_json("要更改的链接不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-link-update-not-found",
+        )));
     }
 
     // 更新
@@ -387,8 +439,13 @@ pub async fn update_status(
     )
     .await;
     if status.is_err() {
-        return Ok(nako_http::error_response_json("更新失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-update-failed",
+        )));
     }
 
-    Ok(nako_http::success_response_json("更新成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
 }
@@ -1,7 +1,7 @@
 use actix_web::{web, Error, HttpResponse, Result};
 
 use crate::nako::global::{AppState, Deserialize, Serialize};
-use crate::nako::http as nako_http;
+use crate::nako::{http as nako_http, i18n, time};
 
 use crate::app::entity::{self, guestbook as guestbook_entity};
 use crate::app::model::guestbook;
@@ -9,7 +9,7 @@ use crate::app::service::http;
 
 // 首页
 pub async fn index(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let ctx = nako_http::view_data();
 
@@ -40,6 +40,7 @@ pub struct ListQuery {
     qq: Option<String>,
     weixin: Option<String>,
     status: Option<i32>,
+    approved: Option<i32>,
 }
 
 // 数据列表
@@ -60,6 +61,7 @@ pub async fn list(
         qq: query.qq.clone(),
         weixin: query.weixin.clone(),
         status: query.status,
+        approved: query.approved,
     };
     let search_where = search_where.format();
 
@@ -76,7 +78,10 @@ pub async fn list(
         count: count,
     };
 
-    Ok(nako_http::success_response_json("获取成功", res))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-get-success"),
+        res,
+    ))
 }
 
 // ==========================
@@ -92,10 +97,14 @@ pub async fn detail(
     query: web::Query<DetailQuery>,
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     if query.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "ID不能为空", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
     }
 
     let data = guestbook::GuestbookModel::find_by_id(db, query.id)
@@ -103,7 +112,11 @@ pub async fn detail(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "留言不存在", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-guestbook-not-found"),
+            "",
+        ));
     }
 
     let mut ctx = nako_http::view_data();
@@ -118,6 +131,129 @@ pub async fn detail(
 
 // ==========================
 
+#[derive(Deserialize)]
+pub struct ReplyQuery {
+    id: u32,
+}
+
+// 回复
+pub async fn reply(
+    state: web::Data<AppState>,
+    query: web::Query<ReplyQuery>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+    let mut view = state.view.get();
+
+    if query.id == 0 {
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
+    }
+
+    let data = guestbook::GuestbookModel::find_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 {
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-guestbook-not-found"),
+            "",
+        ));
+    }
+
+    let mut ctx = nako_http::view_data();
+    ctx.insert("data", &data);
+
+    Ok(nako_http::view(
+        &mut view,
+        "admin/guestbook/reply.html",
+        &ctx,
+    ))
+}
+
+// 表单数据
+#[derive(Deserialize)]
+pub struct ReplyForm {
+    reply: String,
+    approved: i32,
+}
+
+// 回复保存，回复内容为空时删除回复
+pub async fn reply_save(
+    state: web::Data<AppState>,
+    query: web::Query<ReplyQuery>,
+    params: web::Form<ReplyForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if query.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
+    }
+
+    if params.approved != 0 && params.approved != 1 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-guestbook-approved-required",
+        )));
+    }
+
+    let data = guestbook::GuestbookModel::find_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-guestbook-reply-not-found",
+        )));
+    }
+
+    let reply = params.reply.trim().to_string();
+    let (reply, reply_time) = if reply.is_empty() {
+        (None, 0)
+    } else if data.reply.as_deref() == Some(reply.as_str()) {
+        (Some(reply), data.reply_time)
+    } else {
+        (Some(reply), time::now().timestamp())
+    };
+
+    let update_data = guestbook::GuestbookModel::update_reply_by_id(
+        db,
+        query.id,
+        guestbook_entity::Model {
+            reply,
+            reply_time,
+            ..entity::default()
+        },
+    )
+    .await;
+    if update_data.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-guestbook-reply-failed",
+        )));
+    }
+
+    if data.approved != params.approved {
+        let approved =
+            guestbook::GuestbookModel::update_approved_by_id(db, query.id, params.approved).await;
+        if approved.is_err() {
+            return Ok(nako_http::error_response_json(&i18n::t(
+                "admin-guestbook-approved-failed",
+            )));
+        }
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-guestbook-reply-success"),
+        "",
+    ))
+}
+
+// ==========================
+
 #[derive(Deserialize)]
 pub struct DeleteForm {
     id: u32,
@@ -131,7 +267,9 @@ pub async fn delete(
     let db = &state.db;
 
     if params.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
     let data = guestbook::GuestbookModel::find_by_id(db, params.id)
@@ -139,15 +277,22 @@ pub async fn delete(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(nako_http::error_response_json("要删除的留言不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-guestbook-delete-not-found",
+        )));
     }
 
     let delete_data = guestbook::GuestbookModel::delete(db, params.id).await;
     if delete_data.is_err() {
-        return Ok(nako_http::error_response_json("删除失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-delete-failed",
+        )));
     }
 
-    Ok(nako_http::success_response_json("删除成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-delete-success"),
+        "",
+    ))
 }
 
 // ==========================
@@ -165,7 +310,9 @@ pub async fn batch_delete(
     let db = &state.db;
 
     if params.ids.as_str() == "" {
-        return Ok(nako_http::error_response_json("未选中数据"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-nothing-selected",
+        )));
     }
 
     let ids = params.ids.split(",").collect::<Vec<&str>>();
@@ -182,7 +329,10 @@ pub async fn batch_delete(
         }
     }
 
-    Ok(nako_http::success_response_json("批量删除成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-batch-delete-success"),
+        "",
+    ))
 }
 
 // ==========================
@@ -207,11 +357,15 @@ pub async fn update_status(
     let db = &state.db;
 
     if query.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
     if params.status != 0 && params.status != 1 {
-        return Ok(nako_http::error_response_json("状态不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-status-required",
+        )));
     }
 
     let data = guestbook::GuestbookModel::find_by_id(db, query.id)
@@ -219,7 +373,9 @@ pub async fn update_status(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(nako_http::error_response_json("要更改的留言不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-guestbook-update-not-found",
+        )));
     }
 
     // 更新
@@ -233,8 +389,65 @@ pub async fn update_status(
     )
     .await;
     if status.is_err() {
-        return Ok(nako_http::error_response_json("更新失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-update-failed",
+        )));
     }
 
-    Ok(nako_http::success_response_json("更新成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
+}
+
+// ==========================
+
+// 表单数据
+#[derive(Deserialize)]
+pub struct UpdateApprovedForm {
+    approved: i32,
+}
+
+// 前台显示审核
+pub async fn update_approved(
+    state: web::Data<AppState>,
+    query: web::Query<UpdateStatusQuery>,
+    params: web::Form<UpdateApprovedForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if query.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
+    }
+
+    if params.approved != 0 && params.approved != 1 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-guestbook-approved-required",
+        )));
+    }
+
+    let data = guestbook::GuestbookModel::find_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-guestbook-review-not-found",
+        )));
+    }
+
+    let approved =
+        guestbook::GuestbookModel::update_approved_by_id(db, query.id, params.approved).await;
+    if approved.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-update-failed",
+        )));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
 }
@@ -0,0 +1,147 @@
+use std::io::Read;
+
+use actix_multipart::form::{tempfile::TempFile, text::Text, MultipartForm};
+use actix_web::{
+    http::header::{ContentDisposition, DispositionParam, DispositionType},
+    web, Error, HttpRequest, HttpResponse, Result,
+};
+
+use crate::nako::global::{AppState, Session};
+use crate::nako::{http as nako_http, i18n, time};
+
+use crate::app::service::{markdown, permalink, related, wxr};
+
+// 导入页面
+pub async fn index(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    let mut view = state.view.get();
+
+    let ctx = nako_http::view_data();
+
+    Ok(nako_http::view(&mut view, "admin/import/index.html", &ctx))
+}
+
+#[derive(Debug, MultipartForm)]
+pub struct WxrForm {
+    file: TempFile,
+    dry_run: Option<Text<i32>>,
+    download_media: Option<Text<i32>>,
+}
+
+// 导入 WordPress 数据
+pub async fn wxr(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    session: Session,
+    MultipartForm(mut form): MultipartForm<WxrForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let mut data = String::new();
+    if form.file.file.read_to_string(&mut data).is_err() || data.is_empty() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-import-read-failed",
+        )));
+    }
+
+    let wxr_data = match wxr::parse(data.as_str()) {
+        Ok(v) => v,
+        Err(err) => return Ok(nako_http::error_response_json(err.as_str())),
+    };
+
+    let user_id = session
+        .get::<u32>("login_id")
+        .unwrap_or_default()
+        .unwrap_or_default();
+
+    let add_ip: String = match req.peer_addr() {
+        Some(val) => val.ip().to_string(),
+        None => "0.0.0.0".to_string(),
+    };
+
+    let opts = wxr::ImportOptions {
+        user_id,
+        dry_run: form.dry_run.map(|v| v.0 == 1).unwrap_or_default(),
+        download_media: form.download_media.map(|v| v.0 == 1).unwrap_or_default(),
+        add_ip,
+    };
+
+    let report = wxr::import(db, wxr_data, opts).await;
+
+    // 导入可能新增分类
+    permalink::load_cates(db).await;
+
+    if !report.dry_run {
+        related::clear_all(&mut state.get_ref().clone().redis).await;
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-import-done"),
+        report,
+    ))
+}
+
+// 导出文章及页面
+pub async fn export(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let data = match markdown::export(db).await {
+        Ok(v) => v,
+        Err(err) => return Ok(nako_http::text(err)),
+    };
+
+    let filename = format!("nako-blog-{}.zip", time::now().format("%Y%m%d%H%M%S"));
+
+    Ok(HttpResponse::Ok()
+        .content_type("application/zip")
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(filename)],
+        })
+        .body(data))
+}
+
+#[derive(Debug, MultipartForm)]
+pub struct MarkdownForm {
+    file: TempFile,
+}
+
+// 导入 Markdown 压缩包
+pub async fn markdown(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    session: Session,
+    MultipartForm(mut form): MultipartForm<MarkdownForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let mut data = Vec::new();
+    if form.file.file.read_to_end(&mut data).is_err() || data.is_empty() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-import-read-failed",
+        )));
+    }
+
+    let files = match markdown::read_zip(&data) {
+        Ok(v) => v,
+        Err(err) => return Ok(nako_http::error_response_json(err.as_str())),
+    };
+
+    let user_id = session
+        .get::<u32>("login_id")
+        .unwrap_or_default()
+        .unwrap_or_default();
+
+    let add_ip: String = match req.peer_addr() {
+        Some(val) => val.ip().to_string(),
+        None => "0.0.0.0".to_string(),
+    };
+
+    let report = markdown::import(db, files, user_id, add_ip.as_str()).await;
+
+    related::clear_all(&mut state.get_ref().clone().redis).await;
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-import-done"),
+        report,
+    ))
+}
@@ -8,7 +8,7 @@ use crate::app::model::{art, cate, comment, tag, user};
 // 首页
 pub async fn index(state: web::Data<AppState>, session: Session) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let id = session
         .get::<u32>("login_id")
@@ -28,13 +28,14 @@ pub async fn index(state: web::Data<AppState>, session: Session) -> Result<HttpR
 // 控制台
 pub async fn console(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let search_where = art::ArtWhere {
         title: None,
         uuid: None,
         tag: None,
         cate_id: None,
+        cate_ids: None,
         user_id: None,
         is_top: None,
         status: Some(1),
@@ -3,13 +3,21 @@ pub mod attach;
 pub mod auth;
 pub mod cate;
 pub mod comment;
+pub mod comment_rule;
 pub mod error;
 pub mod friendlink;
 pub mod guestbook;
+pub mod import;
 pub mod index;
+pub mod not_found;
 pub mod page;
 pub mod profile;
+pub mod redirect;
+pub mod series;
 pub mod setting;
 pub mod tag;
+pub mod template;
+pub mod theme;
 pub mod upload;
 pub mod user;
+pub mod webhook;
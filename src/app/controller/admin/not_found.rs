@@ -0,0 +1,172 @@
+use actix_web::{web, Error, HttpResponse, Result};
+
+use crate::nako::global::{AppState, Deserialize, Serialize};
+use crate::nako::{http as nako_http, i18n};
+
+use crate::app::entity::not_found as not_found_entity;
+use crate::app::model::not_found;
+use crate::app::service::http;
+
+// 首页
+pub async fn index(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    let mut view = state.view.get();
+
+    let ctx = nako_http::view_data();
+
+    Ok(nako_http::view(
+        &mut view,
+        "admin/not_found/index.html",
+        &ctx,
+    ))
+}
+
+// ==========================
+
+#[derive(Serialize)]
+pub struct ListData {
+    list: Vec<not_found_entity::Model>,
+    count: u64,
+}
+
+#[derive(Deserialize)]
+pub struct ListQuery {
+    page: u64,
+    limit: u64,
+
+    path: Option<String>,
+    referrer: Option<String>,
+}
+
+// 数据列表，按访问次数排序
+pub async fn list(
+    state: web::Data<AppState>,
+    query: web::Query<ListQuery>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let page: u64 = query.page;
+    let per_page: u64 = query.limit;
+
+    let search_where = not_found::NotFoundWhere {
+        path: query.path.clone(),
+        referrer: query.referrer.clone(),
+    };
+    let search_where = search_where.format();
+
+    let (list, _num_pages) =
+        not_found::NotFoundModel::search_in_page(db, page, per_page, search_where.clone())
+            .await
+            .unwrap_or_default();
+    let count = not_found::NotFoundModel::search_count(db, search_where.clone())
+        .await
+        .unwrap_or(0);
+
+    let res = ListData {
+        list,
+        count,
+    };
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-get-success"),
+        res,
+    ))
+}
+
+// ==========================
+
+#[derive(Deserialize)]
+pub struct DetailQuery {
+    id: u32,
+}
+
+// 详情及来源
+pub async fn detail(
+    state: web::Data<AppState>,
+    query: web::Query<DetailQuery>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+    let mut view = state.view.get();
+
+    if query.id == 0 {
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
+    }
+
+    let data = not_found::NotFoundModel::find_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 {
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-record-not-found"),
+            "",
+        ));
+    }
+
+    let referrers = not_found::NotFoundModel::find_referrers(db, data.id, 50)
+        .await
+        .unwrap_or_default();
+
+    let mut ctx = nako_http::view_data();
+    ctx.insert("data", &data);
+    ctx.insert("referrers", &referrers);
+
+    Ok(nako_http::view(
+        &mut view,
+        "admin/not_found/detail.html",
+        &ctx,
+    ))
+}
+
+// ==========================
+
+#[derive(Deserialize)]
+pub struct DeleteForm {
+    id: u32,
+}
+
+// 删除
+pub async fn delete(
+    state: web::Data<AppState>,
+    query: web::Form<DeleteForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if query.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
+    }
+
+    let delete_data = not_found::NotFoundModel::delete(db, query.id).await;
+    if delete_data.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-delete-failed",
+        )));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-delete-success"),
+        "",
+    ))
+}
+
+// 清空
+pub async fn clear(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if not_found::NotFoundModel::delete_all(db).await.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-clear-failed",
+        )));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-clear-success"),
+        "",
+    ))
+}
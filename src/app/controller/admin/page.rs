@@ -1,7 +1,7 @@
 use actix_web::{web, Error, HttpRequest, HttpResponse, Result};
 
 use crate::nako::global::{AppState, Deserialize, Serialize, Session, Validate};
-use crate::nako::{app, http as nako_http, time};
+use crate::nako::{app, http as nako_http, i18n, time};
 
 use crate::app::entity::{self, page as page_entity};
 use crate::app::model::{page, user};
@@ -9,7 +9,7 @@ use crate::app::service::http;
 
 // 首页
 pub async fn index(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let ctx = nako_http::view_data();
 
@@ -64,7 +64,10 @@ pub async fn list(
         count: count,
     };
 
-    Ok(nako_http::success_response_json("获取成功", res))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-get-success"),
+        res,
+    ))
 }
 
 // ==========================
@@ -80,10 +83,14 @@ pub async fn detail(
     query: web::Query<DetailQuery>,
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     if query.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "ID不能为空", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
     }
 
     let data = page::PageModel::find_by_id(db, query.id)
@@ -91,7 +98,11 @@ pub async fn detail(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "页面不存在", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-page-not-found"),
+            "",
+        ));
     }
 
     // 作者
@@ -111,7 +122,7 @@ pub async fn detail(
 
 // 添加
 pub async fn create(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let ctx = nako_http::view_data();
 
@@ -136,13 +147,19 @@ pub async fn create_save(
     let db = &state.db;
 
     if params.slug.as_str() == "" {
-        return Ok(nako_http::error_response_json("页面标识不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-page-slug-required",
+        )));
     }
     if params.title.as_str() == "" {
-        return Ok(nako_http::error_response_json("页面标题不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-page-title-required",
+        )));
     }
     if params.status != 0 && params.status != 1 {
-        return Ok(nako_http::error_response_json("状态不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-status-required",
+        )));
     }
 
     let add_time = time::now().timestamp();
@@ -162,7 +179,9 @@ pub async fn create_save(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id > 0 {
-        return Ok(nako_http::error_response_json("页面标识已经存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-page-slug-exists",
+        )));
     }
 
     let create_data = page::PageModel::create(
@@ -180,10 +199,13 @@ pub async fn create_save(
     )
     .await;
     if !create_data.is_ok() {
-        return Ok(nako_http::error_response_json("添加失败"));
+        return Ok(nako_http::error_response_json(&i18n::t("admin-add-failed")));
     }
 
-    Ok(nako_http::success_response_json("添加成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-add-success"),
+        "",
+    ))
 }
 
 // ==========================
@@ -199,10 +221,14 @@ pub async fn update(
     query: web::Query<UpdateQuery>,
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     if query.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "ID不能为空", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
     }
 
     let info = page::PageModel::find_by_id(db, query.id)
@@ -210,7 +236,11 @@ pub async fn update(
         .unwrap_or_default()
         .unwrap_or_default();
     if info.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "页面不存在", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-page-not-found"),
+            "",
+        ));
     }
 
     let page_tpls = app::page_tpls();
@@ -236,15 +266,15 @@ pub struct UpdateForm {
 
 #[derive(Debug, Validate, Deserialize, Clone)]
 pub struct UpdateValidate {
-    #[validate(required(message = "页面标识不能为空"))]
+    #[validate(required(message = "admin-page-slug-required"))]
     slug: Option<String>,
-    #[validate(required(message = "页面标题不能为空"))]
+    #[validate(required(message = "admin-page-title-required"))]
     title: Option<String>,
-    #[validate(required(message = "内容不能为空"))]
+    #[validate(required(message = "admin-content-required"))]
     content: Option<String>,
-    #[validate(required(message = "页面模板不能为空"))]
+    #[validate(required(message = "admin-page-tpl-required"))]
     tpl: Option<String>,
-    #[validate(required(message = "状态不能为空"))]
+    #[validate(required(message = "admin-status-required"))]
     status: Option<i32>,
 }
 
@@ -257,7 +287,9 @@ pub async fn update_save(
     let db = &state.db;
 
     if query.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
     let vali_data = UpdateValidate {
@@ -271,7 +303,7 @@ pub async fn update_save(
     let vali = vali_data.validate();
     if vali.is_err() {
         return Ok(nako_http::error_response_json(
-            format!("{}", vali.unwrap_err()).as_str(),
+            i18n::validate_message(&vali.unwrap_err()).as_str(),
         ));
     }
 
@@ -280,7 +312,9 @@ pub async fn update_save(
         .unwrap_or_default()
         .unwrap_or_default();
     if info.id == 0 {
-        return Ok(nako_http::error_response_json("要更改的页面不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-page-update-not-found",
+        )));
     }
 
     let info_by_name = page::PageModel::find_by_slug(db, params.slug.as_str())
@@ -289,7 +323,9 @@ pub async fn update_save(
         .unwrap_or_default();
     if info_by_name.id > 0 {
         if info.id != info_by_name.id {
-            return Ok(nako_http::error_response_json("分类标识已经存在"));
+            return Ok(nako_http::error_response_json(&i18n::t(
+                "admin-cate-slug-exists",
+            )));
         }
     }
 
@@ -310,10 +346,15 @@ pub async fn update_save(
     )
     .await;
     if data.is_err() {
-        return Ok(nako_http::error_response_json("更新失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-update-failed",
+        )));
     }
 
-    Ok(nako_http::success_response_json("更新成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
 }
 
 // ==========================
@@ -331,7 +372,9 @@ pub async fn delete(
     let db = &state.db;
 
     if query.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
     let data = page::PageModel::find_by_id(db, query.id)
@@ -339,15 +382,22 @@ pub async fn delete(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(nako_http::error_response_json("要删除的页面不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-page-delete-not-found",
+        )));
     }
 
     let delete_data = page::PageModel::delete(db, query.id).await;
     if delete_data.is_err() {
-        return Ok(nako_http::error_response_json("删除失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-delete-failed",
+        )));
     }
 
-    Ok(nako_http::success_response_json("删除成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-delete-success"),
+        "",
+    ))
 }
 
 // ==========================
@@ -372,11 +422,15 @@ pub async fn update_status(
     let db = &state.db;
 
     if query.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
     if params.status != 0 && params.status != 1 {
-        return Ok(nako_http::error_response_json("状态不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-status-required",
+        )));
     }
 
     let data = page::PageModel::find_by_id(db, query.id)
@@ -384,7 +438,9 @@ pub async fn update_status(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(nako_http::error_response_json("要更改的页面不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-page-update-not-found",
+        )));
     }
 
     // 更新
@@ -398,8 +454,13 @@ pub async fn update_status(
     )
     .await;
     if status.is_err() {
-        return Ok(nako_http::error_response_json("更新失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-update-failed",
+        )));
     }
 
-    Ok(nako_http::success_response_json("更新成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
 }
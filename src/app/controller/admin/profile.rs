@@ -1,10 +1,10 @@
-use actix_web::{web, Error, HttpResponse, Result};
+use actix_web::{web, Error, HttpRequest, HttpResponse, Result};
 
-use crate::nako::global::{AppState, Deserialize, Session};
-use crate::nako::{auth as nako_auth, http as nako_http};
+use crate::nako::global::{AppState, Deserialize, Serialize, Session};
+use crate::nako::{auth as nako_auth, http as nako_http, i18n, time};
 
-use crate::app::entity::{self, user as user_entity};
-use crate::app::model::user;
+use crate::app::entity::{self, user as user_entity, user_token as user_token_entity};
+use crate::app::model::{user, user_token};
 use crate::app::service::http;
 
 // 更新信息
@@ -13,7 +13,7 @@ pub async fn update_info(
     session: Session,
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let id = session
         .get::<u32>("login_id")
@@ -25,7 +25,11 @@ pub async fn update_info(
         .unwrap_or_default()
         .unwrap_or_default();
     if user_info.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "账号不存在", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-account-not-found"),
+            "",
+        ));
     }
 
     let mut ctx = nako_http::view_data();
@@ -43,6 +47,8 @@ pub async fn update_info(
 pub struct UpdateForm {
     username: String,
     nickname: String,
+    #[serde(default)]
+    email: String,
     sign: String,
 }
 
@@ -55,10 +61,19 @@ pub async fn update_info_save(
     let db = &state.db;
 
     if params.username.as_str() == "" {
-        return Ok(nako_http::error_response_json("账号不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-account-required",
+        )));
     }
     if params.nickname.as_str() == "" {
-        return Ok(nako_http::error_response_json("昵称不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-nickname-required",
+        )));
+    }
+    if !params.email.is_empty() && !validator::validate_email(params.email.as_str()) {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-email-error",
+        )));
     }
 
     let id = session
@@ -71,7 +86,9 @@ pub async fn update_info_save(
         .unwrap_or_default()
         .unwrap_or_default();
     if user_info.id == 0 {
-        return Ok(nako_http::error_response_json("账号不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-account-not-found",
+        )));
     }
 
     let user_info_by_name = user::UserModel::find_user_by_name(db, params.username.as_str())
@@ -80,7 +97,9 @@ pub async fn update_info_save(
         .unwrap_or_default();
     if user_info_by_name.id > 0 {
         if user_info_by_name.id != user_info.id {
-            return Ok(nako_http::error_response_json("账号已经存在"));
+            return Ok(nako_http::error_response_json(&i18n::t(
+                "admin-account-exists",
+            )));
         }
     }
 
@@ -91,6 +110,7 @@ pub async fn update_info_save(
         user_entity::Model {
             username: params.username.clone(),
             nickname: params.nickname.clone(),
+            email: params.email.clone(),
             sign: Some(params.sign.clone()),
             status: Some(1),
             ..entity::default()
@@ -98,17 +118,22 @@ pub async fn update_info_save(
     )
     .await;
     if user_data.is_err() {
-        return Ok(nako_http::error_response_json("更新失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-update-failed",
+        )));
     }
 
-    Ok(nako_http::success_response_json("更新成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
 }
 
 // ==========================
 
 // 更改密码
 pub async fn update_password(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let ctx = nako_http::view_data();
 
@@ -136,16 +161,24 @@ pub async fn update_password_save(
     let db = &state.db;
 
     if params.old_password.as_str() == "" {
-        return Ok(nako_http::error_response_json("旧密码不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-old-password-required",
+        )));
     }
     if params.new_password.as_str() == "" {
-        return Ok(nako_http::error_response_json("新密码不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-new-password-required",
+        )));
     }
     if params.new_password2.as_str() == "" {
-        return Ok(nako_http::error_response_json("确认密码不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-confirm-password-required",
+        )));
     }
     if params.new_password2 != params.new_password {
-        return Ok(nako_http::error_response_json("确认密码不一致"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-confirm-password-mismatch",
+        )));
     }
 
     let mut id: u32 = 0;
@@ -158,18 +191,24 @@ pub async fn update_password_save(
         .unwrap_or_default()
         .unwrap_or_default();
     if user_info.id == 0 {
-        return Ok(nako_http::error_response_json("更改密码失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-password-update-failed",
+        )));
     }
 
     let pass = user_info.password.unwrap_or("".to_string());
 
     if !nako_auth::password_verify(params.old_password.as_str(), pass.as_str()) {
-        return Ok(nako_http::error_response_json("账号旧密码错误"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-old-password-error",
+        )));
     }
 
     let new_hashed_password = nako_auth::password_hash(params.new_password.as_str());
     if new_hashed_password.as_str() == "" {
-        return Ok(nako_http::error_response_json("更改密码失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-password-update-failed",
+        )));
     }
 
     // 更新
@@ -183,17 +222,22 @@ pub async fn update_password_save(
     )
     .await;
     if new_user_data.is_err() {
-        return Ok(nako_http::error_response_json("更改密码失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-password-update-failed",
+        )));
     }
 
-    Ok(nako_http::success_response_json("更新密码成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-password-update-success"),
+        "",
+    ))
 }
 
 // ==========================
 
 // 更改头像
 pub async fn update_avatar(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let ctx = nako_http::view_data();
 
@@ -219,7 +263,9 @@ pub async fn update_avatar_save(
     let db = &state.db;
 
     if params.avatar.as_str() == "" {
-        return Ok(nako_http::error_response_json("头像数据不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-avatar-required",
+        )));
     }
 
     let mut id: u32 = 0;
@@ -232,7 +278,9 @@ pub async fn update_avatar_save(
         .unwrap_or_default()
         .unwrap_or_default();
     if user_info.id == 0 {
-        return Ok(nako_http::error_response_json("更改头像失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-avatar-update-failed",
+        )));
     }
 
     // 更新
@@ -246,8 +294,179 @@ pub async fn update_avatar_save(
     )
     .await;
     if new_user_data.is_err() {
-        return Ok(nako_http::error_response_json("更改头像失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-avatar-update-failed",
+        )));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-avatar-update-success"),
+        "",
+    ))
+}
+
+// ==========================
+
+// 访问令牌
+pub async fn token(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    let mut view = state.view.get();
+
+    let mut ctx = nako_http::view_data();
+    ctx.insert("scopes", &user_token::SCOPES);
+
+    Ok(nako_http::view(&mut view, "admin/profile/token.html", &ctx))
+}
+
+// 访问令牌列表
+pub async fn token_list(
+    state: web::Data<AppState>,
+    session: Session,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let id = session
+        .get::<u32>("login_id")
+        .unwrap_or_default()
+        .unwrap_or_default();
+
+    let list = user_token::UserTokenModel::find_all_by_user_id(db, id)
+        .await
+        .unwrap_or_default();
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-get-success"),
+        list,
+    ))
+}
+
+// 令牌最长有效天数
+const MAX_EXPIRE_DAYS: i64 = 3650;
+
+// 表单数据
+#[derive(Deserialize)]
+pub struct TokenCreateForm {
+    name: String,
+    scopes: String,
+    expire_days: Option<i64>,
+}
+
+#[derive(Serialize)]
+pub struct TokenCreateData {
+    token: String,
+}
+
+// 添加访问令牌
+pub async fn token_create(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    params: web::Form<TokenCreateForm>,
+    session: Session,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if params.name.as_str() == "" {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-name-required",
+        )));
     }
 
-    Ok(nako_http::success_response_json("更改头像成功", ""))
+    let scopes = params.scopes.split(',').map(|v| v.to_string()).collect();
+    let scopes = user_token::UserTokenModel::format_scopes(scopes);
+    if scopes.as_str() == "" {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-token-scopes-required",
+        )));
+    }
+
+    let id = session
+        .get::<u32>("login_id")
+        .unwrap_or_default()
+        .unwrap_or_default();
+
+    if params.expire_days.unwrap_or_default() < 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-token-expire-negative",
+        )));
+    }
+    if params.expire_days.unwrap_or_default() > MAX_EXPIRE_DAYS {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-token-expire-too-long",
+        )));
+    }
+
+    let add_time = time::now().timestamp();
+
+    let expire_time = match params.expire_days {
+        Some(v) if v > 0 => Some(add_time + v * 60 * 60 * 24),
+        _ => None,
+    };
+
+    let mut ip: String = "0.0.0.0".to_string();
+    if let Some(val) = req.peer_addr() {
+        ip = val.ip().to_string();
+    }
+
+    let token = user_token::UserTokenModel::generate();
+
+    let create_data = user_token::UserTokenModel::create(
+        db,
+        user_token_entity::Model {
+            user_id: id,
+            name: params.name.clone(),
+            token: user_token::UserTokenModel::hash(token.as_str()),
+            token_prefix: token.chars().take(12).collect(),
+            scopes,
+            expire_time,
+            status: Some(1),
+            add_time: Some(add_time),
+            add_ip: Some(ip),
+            ..entity::default()
+        },
+    )
+    .await;
+    if create_data.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t("admin-add-failed")));
+    }
+
+    // 令牌只在创建时显示一次
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-add-success"),
+        TokenCreateData { token },
+    ))
+}
+
+#[derive(Deserialize)]
+pub struct TokenDeleteForm {
+    id: u32,
+}
+
+// 撤销访问令牌
+pub async fn token_delete(
+    state: web::Data<AppState>,
+    params: web::Form<TokenDeleteForm>,
+    session: Session,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if params.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
+    }
+
+    let id = session
+        .get::<u32>("login_id")
+        .unwrap_or_default()
+        .unwrap_or_default();
+
+    let delete_data = user_token::UserTokenModel::revoke(db, params.id, id).await;
+    match delete_data {
+        Ok(res) if res.rows_affected > 0 => Ok(nako_http::success_response_json(
+            &i18n::t("admin-token-revoke-success"),
+            "",
+        )),
+        _ => Ok(nako_http::error_response_json(&i18n::t(
+            "admin-token-revoke-failed",
+        ))),
+    }
 }
@@ -0,0 +1,404 @@
+use actix_web::{web, Error, HttpResponse, Result};
+
+use crate::nako::global::{AppState, Deserialize, Serialize};
+use crate::nako::{http as nako_http, i18n, time};
+
+use crate::app::entity::{self, redirect as redirect_entity};
+use crate::app::model::{
+    not_found,
+    redirect::{self, MATCH_EXACT},
+};
+use crate::app::service::{self, http};
+
+// 匹配方式
+fn match_types() -> Vec<(&'static str, String)> {
+    vec![
+        (redirect::MATCH_EXACT, i18n::t("admin-redirect-match-exact")),
+        (
+            redirect::MATCH_PREFIX,
+            i18n::t("admin-redirect-match-prefix"),
+        ),
+        (redirect::MATCH_REGEX, i18n::t("admin-redirect-match-regex")),
+    ]
+}
+
+// 首页
+pub async fn index(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    let mut view = state.view.get();
+
+    let mut ctx = nako_http::view_data();
+    ctx.insert("match_types", &match_types());
+    ctx.insert("codes", &service::redirect::CODES);
+
+    Ok(nako_http::view(
+        &mut view,
+        "admin/redirect/index.html",
+        &ctx,
+    ))
+}
+
+// ==========================
+
+#[derive(Serialize)]
+pub struct ListData {
+    list: Vec<redirect_entity::Model>,
+    count: u64,
+}
+
+#[derive(Deserialize)]
+pub struct ListQuery {
+    page: u64,
+    limit: u64,
+
+    source: Option<String>,
+    target: Option<String>,
+    match_type: Option<String>,
+    code: Option<i32>,
+    status: Option<i32>,
+}
+
+// 数据列表
+pub async fn list(
+    state: web::Data<AppState>,
+    query: web::Query<ListQuery>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let page: u64 = query.page;
+    let per_page: u64 = query.limit;
+
+    let search_where = redirect::RedirectWhere {
+        source: query.source.clone(),
+        target: query.target.clone(),
+        match_type: query.match_type.clone(),
+        code: query.code,
+        status: query.status,
+    };
+    let search_where = search_where.format();
+
+    let (list, _num_pages) =
+        redirect::RedirectModel::search_in_page(db, page, per_page, search_where.clone())
+            .await
+            .unwrap_or_default();
+    let count = redirect::RedirectModel::search_count(db, search_where.clone())
+        .await
+        .unwrap_or(0);
+
+    let res = ListData {
+        list,
+        count,
+    };
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-get-success"),
+        res,
+    ))
+}
+
+// ==========================
+
+#[derive(Deserialize)]
+pub struct CreateQuery {
+    source: Option<String>,
+}
+
+// 添加，可从 404 记录带上来源链接
+pub async fn create(
+    state: web::Data<AppState>,
+    query: web::Query<CreateQuery>,
+) -> Result<HttpResponse, Error> {
+    let mut view = state.view.get();
+
+    let mut ctx = nako_http::view_data();
+    ctx.insert("source", &query.source.clone().unwrap_or_default());
+    ctx.insert("match_types", &match_types());
+    ctx.insert("codes", &service::redirect::CODES);
+
+    Ok(nako_http::view(
+        &mut view,
+        "admin/redirect/create.html",
+        &ctx,
+    ))
+}
+
+// 表单数据
+#[derive(Deserialize)]
+pub struct SaveForm {
+    source: String,
+    match_type: String,
+    target: String,
+    code: i32,
+    status: i32,
+}
+
+impl SaveForm {
+    // 检测数据
+    async fn check(&self, state: &AppState, id: u32) -> Option<String> {
+        if self.source.trim().is_empty() {
+            return Some(i18n::t("admin-redirect-source-required"));
+        }
+        if self.status != 0 && self.status != 1 {
+            return Some(i18n::t("admin-status-required"));
+        }
+
+        if let Err(err) = service::redirect::check(
+            self.match_type.as_str(),
+            self.source.trim(),
+            self.target.trim(),
+            self.code,
+        ) {
+            return Some(err);
+        }
+
+        let exists = redirect::RedirectModel::find_by_source(&state.db, self.source.trim())
+            .await
+            .unwrap_or_default();
+        if exists.filter(|v| v.id != id).is_some() {
+            return Some(i18n::t("admin-redirect-source-exists"));
+        }
+
+        None
+    }
+
+    fn model(&self) -> redirect_entity::Model {
+        redirect_entity::Model {
+            source: self.source.trim().to_string(),
+            match_type: self.match_type.clone(),
+            target: self.target.trim().to_string(),
+            code: self.code,
+            status: self.status,
+            ..entity::default()
+        }
+    }
+}
+
+// 添加保存
+pub async fn create_save(
+    state: web::Data<AppState>,
+    params: web::Form<SaveForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if let Some(err) = params.check(&state, 0).await {
+        return Ok(nako_http::error_response_json(err.as_str()));
+    }
+
+    let create_data = redirect::RedirectModel::create(
+        db,
+        redirect_entity::Model {
+            add_time: time::now().timestamp(),
+            ..params.model()
+        },
+    )
+    .await;
+    if create_data.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t("admin-add-failed")));
+    }
+
+    // 对应的 404 记录已处理
+    if params.match_type == MATCH_EXACT {
+        not_found::NotFoundModel::delete_by_path(db, params.source.trim())
+            .await
+            .unwrap_or_default();
+    }
+
+    service::redirect::load(db).await;
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-add-success"),
+        "",
+    ))
+}
+
+// ==========================
+
+#[derive(Deserialize)]
+pub struct UpdateQuery {
+    id: u32,
+}
+
+// 更新
+pub async fn update(
+    state: web::Data<AppState>,
+    query: web::Query<UpdateQuery>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+    let mut view = state.view.get();
+
+    if query.id == 0 {
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
+    }
+
+    let info = redirect::RedirectModel::find_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if info.id == 0 {
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-redirect-not-found"),
+            "",
+        ));
+    }
+
+    let mut ctx = nako_http::view_data();
+    ctx.insert("data", &info);
+    ctx.insert("match_types", &match_types());
+    ctx.insert("codes", &service::redirect::CODES);
+
+    Ok(nako_http::view(
+        &mut view,
+        "admin/redirect/update.html",
+        &ctx,
+    ))
+}
+
+// 更新保存
+pub async fn update_save(
+    state: web::Data<AppState>,
+    query: web::Query<UpdateQuery>,
+    params: web::Form<SaveForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if query.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
+    }
+
+    let info = redirect::RedirectModel::find_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if info.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-redirect-update-not-found",
+        )));
+    }
+
+    if let Some(err) = params.check(&state, info.id).await {
+        return Ok(nako_http::error_response_json(err.as_str()));
+    }
+
+    let data = redirect::RedirectModel::update_by_id(db, query.id, params.model()).await;
+    if data.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-update-failed",
+        )));
+    }
+
+    service::redirect::load(db).await;
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
+}
+
+// ==========================
+
+#[derive(Deserialize)]
+pub struct DeleteForm {
+    id: u32,
+}
+
+// 删除
+pub async fn delete(
+    state: web::Data<AppState>,
+    query: web::Form<DeleteForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if query.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
+    }
+
+    let data = redirect::RedirectModel::find_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-redirect-delete-not-found",
+        )));
+    }
+
+    let delete_data = redirect::RedirectModel::delete(db, query.id).await;
+    if delete_data.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-delete-failed",
+        )));
+    }
+
+    service::redirect::load(db).await;
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-delete-success"),
+        "",
+    ))
+}
+
+// ==========================
+
+#[derive(Deserialize)]
+pub struct UpdateStatusQuery {
+    id: u32,
+}
+
+// 表单数据
+#[derive(Deserialize)]
+pub struct UpdateStatusForm {
+    status: i32,
+}
+
+// 更新状态
+pub async fn update_status(
+    state: web::Data<AppState>,
+    query: web::Query<UpdateStatusQuery>,
+    params: web::Form<UpdateStatusForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if query.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
+    }
+
+    if params.status != 0 && params.status != 1 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-status-required",
+        )));
+    }
+
+    let data = redirect::RedirectModel::find_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-redirect-update-not-found",
+        )));
+    }
+
+    let status = redirect::RedirectModel::update_status_by_id(db, query.id, params.status).await;
+    if status.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-update-failed",
+        )));
+    }
+
+    service::redirect::load(db).await;
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
+}
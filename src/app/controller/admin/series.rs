@@ -0,0 +1,585 @@
+use actix_web::{web, Error, HttpResponse, Result};
+
+use crate::nako::global::{AppState, Deserialize, Serialize};
+use crate::nako::{http as nako_http, i18n, permalink, time};
+
+use crate::app::entity::{self, art as art_entity, series as series_entity};
+use crate::app::model::{art, series, series_art};
+use crate::app::service::http;
+
+// 首页
+pub async fn index(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    let mut view = state.view.get();
+
+    let ctx = nako_http::view_data();
+
+    Ok(nako_http::view(&mut view, "admin/series/index.html", &ctx))
+}
+
+// ==========================
+
+#[derive(Serialize)]
+pub struct SeriesItem {
+    #[serde(flatten)]
+    series: series_entity::Model,
+    art_count: u64,
+}
+
+#[derive(Serialize)]
+pub struct ListData {
+    list: Vec<SeriesItem>,
+    count: u64,
+}
+
+#[derive(Deserialize)]
+pub struct ListQuery {
+    page: u64,
+    limit: u64,
+
+    title: Option<String>,
+    slug: Option<String>,
+    status: Option<i32>,
+}
+
+// 数据列表
+pub async fn list(
+    state: web::Data<AppState>,
+    query: web::Query<ListQuery>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let page: u64 = query.page;
+    let per_page: u64 = query.limit;
+
+    let search_where = series::SeriesWhere {
+        title: query.title.clone(),
+        slug: query.slug.clone(),
+        status: query.status,
+    };
+    let search_where = search_where.format();
+
+    let (data, _num_pages) =
+        series::SeriesModel::search_in_page(db, page, per_page, search_where.clone())
+            .await
+            .unwrap_or_default();
+    let count = series::SeriesModel::search_count(db, search_where.clone())
+        .await
+        .unwrap_or(0);
+
+    let mut list: Vec<SeriesItem> = Vec::new();
+    for v in data {
+        let art_count = series_art::SeriesArtModel::find_art_count(db, v.id, None)
+            .await
+            .unwrap_or(0);
+
+        list.push(SeriesItem {
+            series: v,
+            art_count,
+        });
+    }
+
+    let res = ListData { list, count };
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-get-success"),
+        res,
+    ))
+}
+
+// ==========================
+
+// 添加
+pub async fn create(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    let mut view = state.view.get();
+
+    let ctx = nako_http::view_data();
+
+    Ok(nako_http::view(&mut view, "admin/series/create.html", &ctx))
+}
+
+// 表单数据
+#[derive(Deserialize)]
+pub struct SaveForm {
+    title: String,
+    slug: String,
+    desc: String,
+    cover: String,
+    sort: i32,
+    status: i32,
+}
+
+impl SaveForm {
+    // 检测数据，返回格式化后的标识
+    async fn check(&self, state: &AppState, id: u32) -> Result<String, String> {
+        if self.title.trim().is_empty() {
+            return Err(i18n::t("admin-series-name-required"));
+        }
+        if self.status != 0 && self.status != 1 {
+            return Err(i18n::t("admin-status-required"));
+        }
+
+        // 标识为空时根据名称生成
+        let mut slug = permalink::slugify(self.slug.as_str());
+        if slug.is_empty() {
+            slug = permalink::slugify(self.title.as_str());
+        }
+        if slug.is_empty() {
+            return Err(i18n::t("admin-series-slug-required"));
+        }
+
+        let exists = series::SeriesModel::find_by_slug(&state.db, slug.as_str())
+            .await
+            .unwrap_or_default();
+        if exists.filter(|v| v.id != id).is_some() {
+            return Err(i18n::t("admin-series-slug-exists"));
+        }
+
+        Ok(slug)
+    }
+
+    fn model(&self, slug: String) -> series_entity::Model {
+        let desc = self.desc.trim();
+
+        series_entity::Model {
+            title: self.title.trim().to_string(),
+            slug,
+            desc: if desc.is_empty() {
+                None
+            } else {
+                Some(desc.to_string())
+            },
+            cover: self.cover.trim().to_string(),
+            sort: self.sort,
+            status: self.status,
+            ..entity::default()
+        }
+    }
+}
+
+// 添加保存
+pub async fn create_save(
+    state: web::Data<AppState>,
+    params: web::Form<SaveForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let slug = match params.check(&state, 0).await {
+        Ok(v) => v,
+        Err(err) => return Ok(nako_http::error_response_json(err.as_str())),
+    };
+
+    let create_data = series::SeriesModel::create(
+        db,
+        series_entity::Model {
+            add_time: time::now().timestamp(),
+            ..params.model(slug)
+        },
+    )
+    .await;
+    if create_data.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t("admin-add-failed")));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-add-success"),
+        "",
+    ))
+}
+
+// ==========================
+
+#[derive(Deserialize)]
+pub struct UpdateQuery {
+    id: u32,
+}
+
+// 更新
+pub async fn update(
+    state: web::Data<AppState>,
+    query: web::Query<UpdateQuery>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+    let mut view = state.view.get();
+
+    if query.id == 0 {
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
+    }
+
+    let info = series::SeriesModel::find_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if info.id == 0 {
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-series-not-found"),
+            "",
+        ));
+    }
+
+    let mut ctx = nako_http::view_data();
+    ctx.insert("data", &info);
+
+    Ok(nako_http::view(&mut view, "admin/series/update.html", &ctx))
+}
+
+// 更新保存
+pub async fn update_save(
+    state: web::Data<AppState>,
+    query: web::Query<UpdateQuery>,
+    params: web::Form<SaveForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if query.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
+    }
+
+    let info = series::SeriesModel::find_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if info.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-series-update-not-found",
+        )));
+    }
+
+    let slug = match params.check(&state, info.id).await {
+        Ok(v) => v,
+        Err(err) => return Ok(nako_http::error_response_json(err.as_str())),
+    };
+
+    let data = series::SeriesModel::update_by_id(db, query.id, params.model(slug)).await;
+    if data.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-update-failed",
+        )));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
+}
+
+// ==========================
+
+#[derive(Deserialize)]
+pub struct DeleteForm {
+    id: u32,
+}
+
+// 删除，文章保留
+pub async fn delete(
+    state: web::Data<AppState>,
+    params: web::Form<DeleteForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if params.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
+    }
+
+    let data = series::SeriesModel::find_by_id(db, params.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-series-delete-not-found",
+        )));
+    }
+
+    let delete_data = series::SeriesModel::delete(db, params.id).await;
+    if delete_data.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-delete-failed",
+        )));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-delete-success"),
+        "",
+    ))
+}
+
+// ==========================
+
+// 表单数据
+#[derive(Deserialize)]
+pub struct UpdateStatusForm {
+    status: i32,
+}
+
+// 更新状态
+pub async fn update_status(
+    state: web::Data<AppState>,
+    query: web::Query<UpdateQuery>,
+    params: web::Form<UpdateStatusForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if query.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
+    }
+
+    if params.status != 0 && params.status != 1 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-status-required",
+        )));
+    }
+
+    let data = series::SeriesModel::find_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-series-update-not-found",
+        )));
+    }
+
+    let status = series::SeriesModel::update_status_by_id(db, query.id, params.status).await;
+    if status.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-update-failed",
+        )));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
+}
+
+// ==========================
+
+#[derive(Serialize)]
+pub struct SeriesArtItem {
+    id: u32,
+    uuid: String,
+    title: String,
+    status: Option<i32>,
+    add_time: Option<i64>,
+    sort: i32,
+}
+
+// 专题文章
+pub async fn arts(
+    state: web::Data<AppState>,
+    query: web::Query<UpdateQuery>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+    let mut view = state.view.get();
+
+    if query.id == 0 {
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
+    }
+
+    let info = series::SeriesModel::find_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if info.id == 0 {
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-series-not-found"),
+            "",
+        ));
+    }
+
+    let mut ctx = nako_http::view_data();
+    ctx.insert("data", &info);
+
+    Ok(nako_http::view(&mut view, "admin/series/arts.html", &ctx))
+}
+
+// 专题文章列表，包含未发布的文章
+pub async fn arts_list(
+    state: web::Data<AppState>,
+    query: web::Query<UpdateQuery>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let rows = series_art::SeriesArtModel::find_by_series_id(db, query.id)
+        .await
+        .unwrap_or_default();
+    let arts = series_art::SeriesArtModel::find_arts(db, query.id, None)
+        .await
+        .unwrap_or_default();
+
+    let list = arts
+        .into_iter()
+        .map(|a| SeriesArtItem {
+            sort: rows
+                .iter()
+                .find(|r| r.art_id == a.id)
+                .map(|r| r.sort)
+                .unwrap_or_default(),
+            id: a.id,
+            uuid: a.uuid,
+            title: a.title,
+            status: a.status,
+            add_time: a.add_time,
+        })
+        .collect::<Vec<SeriesArtItem>>();
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-get-success"),
+        list,
+    ))
+}
+
+// 表单数据，文章可填写 ID 或者 UUID
+#[derive(Deserialize)]
+pub struct ArtAddForm {
+    art: String,
+}
+
+// 添加文章到专题末尾
+pub async fn art_add(
+    state: web::Data<AppState>,
+    query: web::Query<UpdateQuery>,
+    params: web::Form<ArtAddForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let info = series::SeriesModel::find_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if info.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-series-not-found",
+        )));
+    }
+
+    let key = params.art.trim();
+    if key.is_empty() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-art-required",
+        )));
+    }
+
+    let art_data: art_entity::Model = match key.parse::<u32>() {
+        Ok(id) => art::ArtModel::find_by_id(db, id).await,
+        Err(_) => art::ArtModel::find_by_uuid(db, key).await,
+    }
+    .unwrap_or_default()
+    .unwrap_or_default();
+    if art_data.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-art-not-found",
+        )));
+    }
+
+    let exists = series_art::SeriesArtModel::find_one(db, info.id, art_data.id)
+        .await
+        .unwrap_or_default();
+    if exists.is_some() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-series-art-exists",
+        )));
+    }
+
+    let create_data = series_art::SeriesArtModel::create(db, info.id, art_data.id).await;
+    if create_data.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t("admin-add-failed")));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-add-success"),
+        "",
+    ))
+}
+
+// 表单数据
+#[derive(Deserialize)]
+pub struct ArtDeleteForm {
+    art_id: u32,
+}
+
+// 从专题移除文章
+pub async fn art_delete(
+    state: web::Data<AppState>,
+    query: web::Query<UpdateQuery>,
+    params: web::Form<ArtDeleteForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let delete_data = series_art::SeriesArtModel::delete(db, query.id, params.art_id).await;
+    match delete_data {
+        Ok(res) if res.rows_affected > 0 => Ok(nako_http::success_response_json(
+            &i18n::t("admin-remove-success"),
+            "",
+        )),
+        Ok(_) => Ok(nako_http::error_response_json(&i18n::t(
+            "admin-series-art-not-in",
+        ))),
+        Err(_) => Ok(nako_http::error_response_json(&i18n::t(
+            "admin-remove-failed",
+        ))),
+    }
+}
+
+// 表单数据，逗号分隔的文章 ID，按顺序排列
+#[derive(Deserialize)]
+pub struct ArtSortForm {
+    ids: String,
+}
+
+// 专题文章排序
+pub async fn art_sort(
+    state: web::Data<AppState>,
+    query: web::Query<UpdateQuery>,
+    params: web::Form<ArtSortForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let rows = series_art::SeriesArtModel::find_by_series_id(db, query.id)
+        .await
+        .unwrap_or_default();
+
+    let mut ids: Vec<u32> = Vec::new();
+    for id in params.ids.split(',') {
+        let id = id.trim().parse::<u32>().unwrap_or_default();
+        if rows.iter().any(|r| r.art_id == id) && !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+
+    if ids.len() != rows.len() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-series-arts-changed",
+        )));
+    }
+
+    if series_art::SeriesArtModel::reorder(db, query.id, &ids)
+        .await
+        .is_err()
+    {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-series-sort-failed",
+        )));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-series-sort-success"),
+        "",
+    ))
+}
@@ -2,7 +2,7 @@ use actix_web::{web, Error, HttpResponse, Result};
 use std::collections::HashMap;
 
 use crate::nako::global::AppState;
-use crate::nako::http as nako_http;
+use crate::nako::{http as nako_http, i18n};
 
 use crate::app::entity::{self, setting as setting_entity};
 use crate::app::model::setting;
@@ -11,7 +11,7 @@ use crate::app::service;
 // 首页
 pub async fn index(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let settings = setting::SettingModel::find_all(db)
         .await
@@ -26,6 +26,7 @@ pub async fn index(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
 
     let mut ctx = nako_http::view_data();
     ctx.insert("data", &data);
+    ctx.insert("locales", &i18n::locales());
 
     Ok(nako_http::view(&mut view, "admin/setting/index.html", &ctx))
 }
@@ -50,12 +51,17 @@ pub async fn setting_save(
             )
             .await;
             if status.is_err() {
-                return Ok(nako_http::error_response_json("更新失败"));
+                return Ok(nako_http::error_response_json(&i18n::t(
+                    "admin-update-failed",
+                )));
             }
         }
     }
 
     service::setting::clear(&mut state.get_ref().clone()).await;
 
-    Ok(nako_http::success_response_json("更新成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
 }
@@ -1,15 +1,15 @@
 use actix_web::{web, Error, HttpRequest, HttpResponse, Result};
 
 use crate::nako::global::{AppState, Deserialize, Serialize, Validate};
-use crate::nako::{http as nako_http, time};
+use crate::nako::{http as nako_http, i18n, time};
 
 use crate::app::entity::{self, tag as tag_entity};
-use crate::app::model::tag;
+use crate::app::model::{art_tag, tag};
 use crate::app::service::http;
 
 // 首页
 pub async fn index(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let ctx = nako_http::view_data();
 
@@ -20,7 +20,7 @@ pub async fn index(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
 
 #[derive(Serialize)]
 pub struct ListData {
-    list: Vec<tag_entity::Model>,
+    list: Vec<art_tag::TagCount>,
     count: u64,
 }
 
@@ -57,12 +57,28 @@ pub async fn list(
         .await
         .unwrap_or(0);
 
+    // 文章数量
+    let counts = art_tag::ArtTagModel::find_tag_counts(db)
+        .await
+        .unwrap_or_default();
+    let list = list
+        .into_iter()
+        .map(|t| art_tag::TagCount {
+            count: counts.get(&t.id).copied().unwrap_or_default(),
+            tag: t,
+            weight: 1,
+        })
+        .collect();
+
     let res: ListData = ListData {
         list: list,
         count: count,
     };
 
-    Ok(nako_http::success_response_json("获取成功", res))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-get-success"),
+        res,
+    ))
 }
 
 // ==========================
@@ -78,10 +94,14 @@ pub async fn detail(
     query: web::Query<DetailQuery>,
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     if query.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "ID不能为空", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
     }
 
     let data = tag::TagModel::find_by_id(db, query.id)
@@ -89,7 +109,11 @@ pub async fn detail(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "标签不存在", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-tag-not-found"),
+            "",
+        ));
     }
 
     let mut ctx = nako_http::view_data();
@@ -102,7 +126,7 @@ pub async fn detail(
 
 // 添加
 pub async fn create(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let ctx = nako_http::view_data();
 
@@ -123,10 +147,14 @@ pub async fn create_save(
     params: web::Form<CreateForm>,
 ) -> Result<HttpResponse, Error> {
     if params.name.as_str() == "" {
-        return Ok(nako_http::error_response_json("标签不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-tag-required",
+        )));
     }
     if params.status != 0 && params.status != 1 {
-        return Ok(nako_http::error_response_json("状态不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-status-required",
+        )));
     }
 
     let db = &state.db;
@@ -136,7 +164,7 @@ pub async fn create_save(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id > 0 {
-        return Ok(nako_http::error_response_json("标签已经存在"));
+        return Ok(nako_http::error_response_json(&i18n::t("admin-tag-exists")));
     }
 
     let add_time = time::now().timestamp();
@@ -160,10 +188,13 @@ pub async fn create_save(
     )
     .await;
     if create_data.is_ok() {
-        return Ok(nako_http::success_response_json("添加成功", ""));
+        return Ok(nako_http::success_response_json(
+            &i18n::t("admin-add-success"),
+            "",
+        ));
     }
 
-    Ok(nako_http::error_response_json("添加失败"))
+    Ok(nako_http::error_response_json(&i18n::t("admin-add-failed")))
 }
 
 // ==========================
@@ -179,10 +210,14 @@ pub async fn update(
     query: web::Query<UpdateQuery>,
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     if query.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "ID不能为空", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
     }
 
     let info = tag::TagModel::find_by_id(db, query.id)
@@ -190,7 +225,11 @@ pub async fn update(
         .unwrap_or_default()
         .unwrap_or_default();
     if info.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "标签不存在", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-tag-not-found"),
+            "",
+        ));
     }
 
     let mut ctx = nako_http::view_data();
@@ -210,11 +249,11 @@ pub struct UpdateForm {
 
 #[derive(Debug, Validate, Deserialize, Clone)]
 pub struct UpdateValidate {
-    #[validate(required(message = "标签名称不能为空"))]
+    #[validate(required(message = "admin-tag-name-required"))]
     name: Option<String>,
-    #[validate(required(message = "标签标识不能为空"))]
+    #[validate(required(message = "admin-tag-slug-required"))]
     sort: Option<i32>,
-    #[validate(required(message = "状态不能为空"))]
+    #[validate(required(message = "admin-status-required"))]
     status: Option<i32>,
 }
 
@@ -225,7 +264,9 @@ pub async fn update_save(
     params: web::Form<UpdateForm>,
 ) -> Result<HttpResponse, Error> {
     if query.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
     let vali_data = UpdateValidate {
@@ -237,7 +278,7 @@ pub async fn update_save(
     let vali = vali_data.validate();
     if vali.is_err() {
         return Ok(nako_http::error_response_json(
-            format!("{}", vali.unwrap_err()).as_str(),
+            i18n::validate_message(&vali.unwrap_err()).as_str(),
         ));
     }
 
@@ -248,7 +289,9 @@ pub async fn update_save(
         .unwrap_or_default()
         .unwrap_or_default();
     if info.id == 0 {
-        return Ok(nako_http::error_response_json("要更改的标签不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-tag-update-not-found",
+        )));
     }
 
     let info_by_name = tag::TagModel::find_by_name(db, params.name.as_str())
@@ -257,7 +300,9 @@ pub async fn update_save(
         .unwrap_or_default();
     if info_by_name.id > 0 {
         if info.id != info_by_name.id {
-            return Ok(nako_http::error_response_json("标签标识已经存在"));
+            return Ok(nako_http::error_response_json(&i18n::t(
+                "admin-tag-slug-exists",
+            )));
         }
     }
 
@@ -275,10 +320,30 @@ pub async fn update_save(
     )
     .await;
     if data.is_err() {
-        return Ok(nako_http::error_response_json("更新失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-update-failed",
+        )));
+    }
+
+    // 重命名后更新文章标签
+    if info.name != params.name {
+        let art_ids = art_tag::ArtTagModel::find_art_ids_by_tag_id(db, info.id)
+            .await
+            .unwrap_or_default();
+        if art_tag::ArtTagModel::refresh_art_tags(db, &art_ids)
+            .await
+            .is_err()
+        {
+            return Ok(nako_http::error_response_json(&i18n::t(
+                "admin-tag-update-arts-failed",
+            )));
+        }
     }
 
-    Ok(nako_http::success_response_json("更新成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
 }
 
 // ==========================
@@ -296,7 +361,9 @@ pub async fn delete(
     let db = &state.db;
 
     if query.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
     let data = tag::TagModel::find_by_id(db, query.id)
@@ -304,15 +371,104 @@ pub async fn delete(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(nako_http::error_response_json("要删除的标签不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-tag-delete-not-found",
+        )));
+    }
+
+    let art_ids = art_tag::ArtTagModel::find_art_ids_by_tag_id(db, query.id)
+        .await
+        .unwrap_or_default();
+
+    // 移除文章关联
+    if art_tag::ArtTagModel::delete_by_tag_id(db, query.id)
+        .await
+        .is_err()
+    {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-tag-delete-arts-failed",
+        )));
     }
 
     let delete_data = tag::TagModel::delete(db, query.id).await;
     if delete_data.is_err() {
-        return Ok(nako_http::error_response_json("删除失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-delete-failed",
+        )));
+    }
+
+    art_tag::ArtTagModel::refresh_art_tags(db, &art_ids)
+        .await
+        .unwrap_or_default();
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-delete-success"),
+        "",
+    ))
+}
+
+// ==========================
+
+#[derive(Deserialize)]
+pub struct MergeForm {
+    id: u32,
+    name: String,
+}
+
+// 合并到其他标签
+pub async fn merge(
+    state: web::Data<AppState>,
+    params: web::Form<MergeForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if params.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
+    }
+    if params.name.trim() == "" {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-tag-target-required",
+        )));
+    }
+
+    let data = tag::TagModel::find_by_id(db, params.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-tag-merge-not-found",
+        )));
+    }
+
+    let target = tag::TagModel::find_by_name(db, params.name.trim())
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if target.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-tag-target-not-found",
+        )));
+    }
+    if target.id == data.id {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-tag-merge-self",
+        )));
+    }
+
+    let merge_data = art_tag::ArtTagModel::merge(db, data.id, target.id).await;
+    if merge_data.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-tag-merge-failed",
+        )));
     }
 
-    Ok(nako_http::success_response_json("删除成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-tag-merge-success"),
+        "",
+    ))
 }
 
 // ==========================
@@ -337,11 +493,15 @@ pub async fn update_status(
     let db = &state.db;
 
     if query.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
     if params.status != 0 && params.status != 1 {
-        return Ok(nako_http::error_response_json("状态不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-status-required",
+        )));
     }
 
     let data = tag::TagModel::find_by_id(db, query.id)
@@ -349,7 +509,9 @@ pub async fn update_status(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(nako_http::error_response_json("要更改的标签不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-tag-update-not-found",
+        )));
     }
 
     // 更新
@@ -363,8 +525,13 @@ pub async fn update_status(
     )
     .await;
     if status.is_err() {
-        return Ok(nako_http::error_response_json("更新失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-update-failed",
+        )));
     }
 
-    Ok(nako_http::success_response_json("更新成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
 }
@@ -0,0 +1,346 @@
+use actix_web::{web, Error, HttpRequest, HttpResponse, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::nako::global::{AppState, Session};
+use crate::nako::{config, http as nako_http, i18n, theme, time, view as nako_view};
+
+use crate::app::entity::{self, template_version as template_version_entity};
+use crate::app::model::template;
+use crate::app::service;
+
+/// 模板列表项
+#[derive(Serialize)]
+pub struct TemplateItem {
+    name: String,
+    is_override: bool,
+    update_time: i64,
+}
+
+// 首页
+pub async fn index(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+    let mut view = state.view.get();
+
+    let overrides = template::TemplateModel::find_all(db)
+        .await
+        .unwrap_or_default();
+
+    let list = theme::editable_templates()
+        .into_iter()
+        .map(|name| {
+            let data = overrides.iter().find(|v| v.name == name);
+
+            TemplateItem {
+                is_override: data.is_some(),
+                update_time: data.map(|v| v.update_time).unwrap_or_default(),
+                name,
+            }
+        })
+        .collect::<Vec<TemplateItem>>();
+
+    let mut ctx = nako_http::view_data();
+    ctx.insert("list", &list);
+
+    Ok(nako_http::view(
+        &mut view,
+        "admin/template/index.html",
+        &ctx,
+    ))
+}
+
+#[derive(Deserialize)]
+pub struct NameQuery {
+    name: String,
+}
+
+// 编辑
+pub async fn edit(
+    state: web::Data<AppState>,
+    web::Query(query): web::Query<NameQuery>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+    let mut view = state.view.get();
+
+    if !theme::is_template_name(query.name.as_str()) {
+        return Ok(service::http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-template-name-error"),
+            "",
+        ));
+    }
+
+    let data = template::TemplateModel::find_by_name(db, query.name.as_str())
+        .await
+        .unwrap_or_default();
+
+    let is_override = data.is_some();
+    let content = match data {
+        Some(v) => v.content,
+        None => theme::source(query.name.as_str()).unwrap_or_default(),
+    };
+
+    let versions = template::TemplateModel::find_versions(db, query.name.as_str(), 30)
+        .await
+        .unwrap_or_default();
+
+    let mut ctx = nako_http::view_data();
+    ctx.insert("name", &query.name);
+    ctx.insert("content", &content);
+    ctx.insert("is_override", &is_override);
+    ctx.insert("has_source", &theme::source(query.name.as_str()).is_some());
+    ctx.insert("versions", &versions);
+
+    Ok(nako_http::view(&mut view, "admin/template/edit.html", &ctx))
+}
+
+/// 表单数据
+#[derive(Deserialize)]
+pub struct TemplateForm {
+    name: String,
+    content: String,
+    target: Option<String>,
+}
+
+// 保存
+pub async fn save(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    session: Session,
+    web::Form(params): web::Form<TemplateForm>,
+) -> Result<HttpResponse, Error> {
+    if !theme::is_template_name(params.name.as_str()) {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-template-name-error",
+        )));
+    }
+
+    if let Err(err) = nako_view::check(
+        &state.view.get(),
+        params.name.as_str(),
+        params.content.as_str(),
+    ) {
+        return Ok(nako_http::error_response_json(err.as_str()));
+    }
+
+    if let Err(err) = save_template(
+        &req,
+        &state,
+        &session,
+        params.name.as_str(),
+        params.content.as_str(),
+    )
+    .await
+    {
+        return Ok(nako_http::error_response_json(err.as_str()));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-save-success"),
+        "",
+    ))
+}
+
+// 校验语法
+pub async fn check(
+    state: web::Data<AppState>,
+    web::Form(params): web::Form<TemplateForm>,
+) -> Result<HttpResponse, Error> {
+    if !theme::is_template_name(params.name.as_str()) {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-template-name-error",
+        )));
+    }
+
+    if let Err(err) = nako_view::check(
+        &state.view.get(),
+        params.name.as_str(),
+        params.content.as_str(),
+    ) {
+        return Ok(nako_http::error_response_json(err.as_str()));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-template-check-success"),
+        "",
+    ))
+}
+
+// 使用示例数据预览，可预览继承该模板的页面
+pub async fn preview(
+    state: web::Data<AppState>,
+    web::Form(params): web::Form<TemplateForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if !theme::is_template_name(params.name.as_str()) {
+        return Ok(nako_http::text(i18n::t("admin-template-name-error")));
+    }
+
+    let view = match nako_view::check(
+        &state.view.get(),
+        params.name.as_str(),
+        params.content.as_str(),
+    ) {
+        Ok(v) => v,
+        Err(err) => return Ok(nako_http::text(err)),
+    };
+
+    let target = match params.target {
+        Some(v) if !v.is_empty() => v,
+        _ => params.name.clone(),
+    };
+
+    let ctx = service::template::sample_context(db).await;
+
+    match view.render(target.as_str(), &ctx) {
+        Ok(v) => Ok(nako_http::html(v)),
+        Err(err) => Ok(nako_http::text(nako_view::error_message(&err))),
+    }
+}
+
+/// 版本表单
+#[derive(Deserialize)]
+pub struct VersionForm {
+    id: u32,
+}
+
+// 查看历史版本
+pub async fn version(
+    state: web::Data<AppState>,
+    web::Query(query): web::Query<VersionForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let data = template::TemplateModel::find_version_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-template-version-not-found",
+        )));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-get-success"),
+        data,
+    ))
+}
+
+// 恢复到历史版本
+pub async fn revert(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    session: Session,
+    web::Form(params): web::Form<VersionForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let data = template::TemplateModel::find_version_by_id(db, params.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-template-version-not-found",
+        )));
+    }
+
+    if let Err(err) = nako_view::check(&state.view.get(), data.name.as_str(), data.content.as_str())
+    {
+        return Ok(nako_http::error_response_json(err.as_str()));
+    }
+
+    if let Err(err) = save_template(
+        &req,
+        &state,
+        &session,
+        data.name.as_str(),
+        data.content.as_str(),
+    )
+    .await
+    {
+        return Ok(nako_http::error_response_json(err.as_str()));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-restore-success"),
+        "",
+    ))
+}
+
+// 删除修改，恢复为主题中的模板
+pub async fn reset(
+    state: web::Data<AppState>,
+    web::Form(params): web::Form<NameQuery>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if template::TemplateModel::delete_by_name(db, params.name.as_str())
+        .await
+        .is_err()
+    {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-restore-failed",
+        )));
+    }
+
+    nako_view::set_override(params.name.as_str(), None);
+    if let Err(err) = rebuild(&state) {
+        return Ok(nako_http::error_response_json(err.as_str()));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-restore-success"),
+        "",
+    ))
+}
+
+// 保存模板并重新加载
+async fn save_template(
+    req: &HttpRequest,
+    state: &AppState,
+    session: &Session,
+    name: &str,
+    content: &str,
+) -> Result<(), String> {
+    let db = &state.db;
+
+    let user_id = session
+        .get::<u32>("login_id")
+        .unwrap_or_default()
+        .unwrap_or_default();
+
+    let mut ip: String = "0.0.0.0".to_string();
+    if let Some(val) = req.peer_addr() {
+        ip = val.ip().to_string();
+    }
+
+    template::TemplateModel::save(
+        db,
+        template_version_entity::Model {
+            name: name.to_string(),
+            content: content.to_string(),
+            user_id,
+            add_time: time::now().timestamp(),
+            add_ip: ip,
+            ..entity::default()
+        },
+    )
+    .await
+    .map_err(|_| i18n::t("admin-save-failed"))?;
+
+    nako_view::set_override(name, Some(content.to_string()));
+
+    rebuild(state)
+}
+
+// 重新加载模板
+fn rebuild(state: &AppState) -> Result<(), String> {
+    let is_embed = config::section::<bool>("app", "is_embed", true);
+
+    let view = nako_view::build(is_embed)?;
+    state.view.set(view);
+
+    Ok(())
+}
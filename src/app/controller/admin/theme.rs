@@ -0,0 +1,153 @@
+use std::io::Read;
+
+use actix_multipart::form::{tempfile::TempFile, MultipartForm};
+use actix_web::{web, Error, HttpRequest, HttpResponse, Result};
+use serde::Deserialize;
+
+use crate::nako::global::{AppState, Session};
+use crate::nako::{config, http as nako_http, i18n, theme, utils, view as nako_view};
+
+use crate::app::model::setting;
+use crate::app::service;
+
+// 首页
+pub async fn index(state: web::Data<AppState>, session: Session) -> Result<HttpResponse, Error> {
+    let mut view = state.view.get();
+
+    let settings = service::setting::settings(&mut state.get_ref().clone()).await;
+    let active = theme::active_from(&settings);
+
+    let preview = session
+        .get::<String>(theme::PREVIEW_KEY)
+        .unwrap_or_default()
+        .unwrap_or_default();
+
+    let mut ctx = nako_http::view_data();
+    ctx.insert("list", &theme::list());
+    ctx.insert("active", &active);
+    ctx.insert("preview", &preview);
+    ctx.insert("default_theme", &theme::default_name());
+
+    Ok(nako_http::view(&mut view, "admin/theme/index.html", &ctx))
+}
+
+#[derive(Deserialize)]
+pub struct NameQuery {
+    name: Option<String>,
+}
+
+// 启用主题
+pub async fn activate(
+    state: web::Data<AppState>,
+    session: Session,
+    web::Form(params): web::Form<NameQuery>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let name = params.name.unwrap_or_default();
+    let data = match theme::find(name.as_str()) {
+        Some(v) => v,
+        None => {
+            return Ok(nako_http::error_response_json(&i18n::t(
+                "admin-theme-not-found",
+            )))
+        }
+    };
+
+    if !data.is_valid() {
+        return Ok(nako_http::error_response_json(&i18n::t_with(
+            "admin-theme-missing-templates",
+            &[("names", data.missing.join(", "))],
+        )));
+    }
+
+    if setting::SettingModel::save_by_key(db, theme::SETTING_KEY, name.as_str())
+        .await
+        .is_err()
+    {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-theme-enable-failed",
+        )));
+    }
+
+    service::setting::clear(&mut state.get_ref().clone()).await;
+    session.remove(theme::PREVIEW_KEY);
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-theme-enable-success"),
+        "",
+    ))
+}
+
+// 预览主题，只对当前登陆用户生效
+pub async fn preview(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    session: Session,
+    web::Query(query): web::Query<NameQuery>,
+) -> Result<HttpResponse, Error> {
+    let name = query.name.unwrap_or_default();
+
+    if name.is_empty() {
+        session.remove(theme::PREVIEW_KEY);
+
+        let url = utils::url_for_static(req, "admin.theme-index");
+        return Ok(nako_http::redirect(url));
+    }
+
+    if !theme::exists(name.as_str()) {
+        let mut view = state.view.get();
+        return Ok(service::http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-theme-not-found"),
+            "",
+        ));
+    }
+
+    if session.insert(theme::PREVIEW_KEY, name).is_err() {
+        let mut view = state.view.get();
+        return Ok(service::http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-theme-preview-failed"),
+            "",
+        ));
+    }
+
+    let url = utils::url_for_static(req, "blog.index");
+    Ok(nako_http::redirect(url))
+}
+
+#[derive(Debug, MultipartForm)]
+pub struct UploadForm {
+    file: TempFile,
+}
+
+// 上传主题包
+pub async fn upload(
+    state: web::Data<AppState>,
+    MultipartForm(mut form): MultipartForm<UploadForm>,
+) -> Result<HttpResponse, Error> {
+    let mut data: Vec<u8> = Vec::new();
+    if form.file.file.read_to_end(&mut data).is_err() || data.is_empty() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-theme-read-failed",
+        )));
+    }
+
+    let data = match theme::install(data.as_slice()) {
+        Ok(v) => v,
+        Err(err) => return Ok(nako_http::error_response_json(err.as_str())),
+    };
+
+    // 重新加载模板
+    let is_embed = config::section::<bool>("app", "is_embed", true);
+    match nako_view::build(is_embed) {
+        Ok(v) => state.view.set(v),
+        Err(err) => return Ok(nako_http::error_response_json(err.as_str())),
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-upload-success"),
+        data.name,
+    ))
+}
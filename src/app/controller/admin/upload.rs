@@ -1,17 +1,15 @@
-use std::{fs, io::Read, path};
+use std::{fs, path};
 
 use actix_multipart::form::{tempfile::TempFile, MultipartForm};
 use actix_web::{web, Error, HttpRequest, HttpResponse, Result};
-use sea_orm::TryIntoModel;
 
 use crate::nako::global::{AppState, Serialize, Session};
 use crate::nako::{
-    app::{attach_path, upload_path, upload_url},
-    http as nako_http, time, utils,
+    app::{upload_path, upload_url},
+    http as nako_http, i18n, utils,
 };
 
-use crate::app::entity::{self, attach as attach_entity};
-use crate::app::model::attach;
+use crate::app::service::upload;
 
 #[derive(Debug, MultipartForm)]
 pub struct UploadForm {
@@ -32,85 +30,21 @@ pub async fn file(
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
 
-    let dir = upload_path("".to_string());
-
-    if !path::Path::new(dir.as_str()).exists() {
-        fs::create_dir_all(dir.as_str())?;
-    }
-
-    let add_time = time::now().timestamp();
-
     let add_ip: String = match req.peer_addr() {
         Some(val) => val.ip().to_string(),
         None => "0.0.0.0".to_string(),
     };
 
-    let mut res = Vec::new();
-
-    for mut f in form.files {
-        let file_name = f.file_name.unwrap_or("".to_string());
-        if file_name.as_str() == "" {
-            continue;
-        }
-
-        let ext = utils::get_extension(file_name.clone().as_str());
-        let name = format!("/{}.{}", utils::uuid(), ext);
-
-        let path = attach_path(name.clone());
-
-        let mut buffer = Vec::new();
-        if f.file.read_to_end(&mut buffer).is_err() {
-            return Ok(nako_http::error_response_json("上传失败"));
-        }
-
-        let contents = String::from_utf8_lossy(&buffer).to_string();
-
-        let md5 = utils::md5(contents.as_str());
-        let size = buffer.len() as u64;
-
-        // 判断是否有相同
-        let attach_data = attach::AttachModel::find_by_md5(db, md5.as_str())
-            .await
-            .unwrap_or_default()
-            .unwrap_or_default();
-        if attach_data.id > 0 {
-            res.push(FileData { id: attach_data.id });
-
-            continue;
-        }
-
-        if f.file.persist(path.clone()).is_err() {
-            return Ok(nako_http::error_response_json("上传失败"));
-        }
-
-        let create_data = attach::AttachModel::create(
-            db,
-            attach_entity::Model {
-                name: file_name.clone(),
-                path: name.clone(),
-                ext: ext.clone(),
-                size: size,
-                md5: md5.clone(),
-                r#type: i32::from(1),
-                status: i32::from(1),
-                add_time: add_time,
-                add_ip: add_ip.clone(),
-                ..entity::default()
-            },
-        )
-        .await;
-        if let Ok(data) = create_data {
-            if let Ok(data_model) = data.try_into_model() {
-                res.push(FileData { id: data_model.id });
-            }
-        } else {
-            if let Ok(_) = fs::remove_file(path.clone()) {}
-
-            return Ok(nako_http::error_response_json("上传失败"));
-        }
-    }
+    let data = upload::save_files(db, form.files, upload::UploadType::File, add_ip.as_str()).await;
+    let res: Vec<FileData> = match data {
+        Ok(list) => list.into_iter().map(|v| FileData { id: v.id }).collect(),
+        Err(err) => return Ok(nako_http::error_response_json(err.as_str())),
+    };
 
-    Ok(nako_http::success_response_json("上传成功", res))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-upload-success"),
+        res,
+    ))
 }
 
 // =================
@@ -135,92 +69,27 @@ pub async fn image(
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
 
-    let dir = upload_path("".to_string());
-
-    if !path::Path::new(dir.as_str()).exists() {
-        fs::create_dir_all(dir.as_str())?;
-    }
-
-    let add_time = time::now().timestamp();
-
     let add_ip: String = match req.peer_addr() {
         Some(val) => val.ip().to_string(),
         None => "0.0.0.0".to_string(),
     };
 
-    let mut res = Vec::new();
-
-    for mut f in form.files {
-        let file_name = f.file_name.unwrap_or("".to_string());
-        if file_name.as_str() == "" {
-            continue;
-        }
-
-        let ext = utils::get_extension(file_name.clone().as_str());
-        let name = format!("/images/{}.{}", utils::uuid(), ext);
-
-        let path = upload_path(name.clone());
-        let url = upload_url(name.clone());
-
-        let mut buffer = Vec::new();
-        if f.file.read_to_end(&mut buffer).is_err() {
-            return Ok(nako_http::error_response_json("上传失败"));
-        }
-
-        let contents = String::from_utf8_lossy(&buffer).to_string();
-
-        let md5 = utils::md5(contents.as_str());
-        let size = buffer.len() as u64;
-
-        // 判断是否有相同
-        let attach_data = attach::AttachModel::find_by_md5(db, md5.as_str())
-            .await
-            .unwrap_or_default()
-            .unwrap_or_default();
-        if attach_data.id > 0 {
-            res.push(ImageData {
-                id: attach_data.id,
-                url: upload_url(attach_data.path),
-            });
-
-            continue;
-        }
-
-        if f.file.persist(path.clone()).is_err() {
-            return Ok(nako_http::error_response_json("上传失败"));
-        }
-
-        let create_data = attach::AttachModel::create(
-            db,
-            attach_entity::Model {
-                name: file_name.clone(),
-                path: name.clone(),
-                ext: ext.clone(),
-                size: size,
-                md5: md5.clone(),
-                r#type: i32::from(2),
-                status: i32::from(1),
-                add_time: add_time,
-                add_ip: add_ip.clone(),
-                ..entity::default()
-            },
-        )
-        .await;
-        if let Ok(data) = create_data {
-            if let Ok(data_model) = data.try_into_model() {
-                res.push(ImageData {
-                    id: data_model.id,
-                    url: url,
-                });
-            }
-        } else {
-            if let Ok(_) = fs::remove_file(path.clone()) {}
-
-            return Ok(nako_http::error_response_json("上传失败"));
-        }
-    }
+    let data = upload::save_files(db, form.files, upload::UploadType::Image, add_ip.as_str()).await;
+    let res: Vec<ImageData> = match data {
+        Ok(list) => list
+            .into_iter()
+            .map(|v| ImageData {
+                id: v.id,
+                url: v.url,
+            })
+            .collect(),
+        Err(err) => return Ok(nako_http::error_response_json(err.as_str())),
+    };
 
-    Ok(nako_http::success_response_json("上传成功", res))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-upload-success"),
+        res,
+    ))
 }
 
 // =================
@@ -248,7 +117,9 @@ pub async fn avatar(
     };
 
     if file_name.as_str() == "" {
-        return Ok(nako_http::error_response_json("上传失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-upload-failed",
+        )));
     }
 
     let avatar_dir = upload_path("avatar/".to_string());
@@ -270,10 +141,15 @@ pub async fn avatar(
     let url = upload_url(name.clone());
 
     if form.file.file.persist(path.clone()).is_err() {
-        return Ok(nako_http::error_response_json("上传失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-upload-failed",
+        )));
     }
 
     let res = AvatarData { url: url };
 
-    Ok(nako_http::success_response_json("上传成功", res))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-upload-success"),
+        res,
+    ))
 }
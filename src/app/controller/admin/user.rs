@@ -1,7 +1,7 @@
 use actix_web::{web, Error, HttpRequest, HttpResponse, Result};
 
 use crate::nako::global::{AppState, Deserialize, Serialize, Session};
-use crate::nako::{app, auth as nako_auth, http as nako_http, time};
+use crate::nako::{app, auth as nako_auth, http as nako_http, i18n, time};
 
 use crate::app::entity::{self, user as user_entity};
 use crate::app::model::user;
@@ -9,7 +9,7 @@ use crate::app::service::http;
 
 // 首页
 pub async fn index(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let ctx = nako_http::view_data();
 
@@ -64,7 +64,10 @@ pub async fn list(
         count: count,
     };
 
-    Ok(nako_http::success_response_json("获取成功", res))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-get-success"),
+        res,
+    ))
 }
 
 // ==========================
@@ -80,10 +83,14 @@ pub async fn detail(
     query: web::Query<DetailQuery>,
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     if query.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "ID不能为空", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
     }
 
     let user_data = user::UserModel::find_user_by_id(db, query.id)
@@ -91,7 +98,11 @@ pub async fn detail(
         .unwrap_or_default()
         .unwrap_or_default();
     if user_data.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "账号不存在", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-account-not-found"),
+            "",
+        ));
     }
 
     let mut ctx = nako_http::view_data();
@@ -104,7 +115,7 @@ pub async fn detail(
 
 // 添加
 pub async fn create(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let ctx = nako_http::view_data();
 
@@ -116,6 +127,8 @@ pub async fn create(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
 pub struct CreateForm {
     username: String,
     nickname: String,
+    #[serde(default)]
+    email: String,
     sign: String,
     status: i32,
 }
@@ -127,13 +140,24 @@ pub async fn create_save(
     params: web::Form<CreateForm>,
 ) -> Result<HttpResponse, Error> {
     if params.username.as_str() == "" {
-        return Ok(nako_http::error_response_json("账号不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-account-required",
+        )));
     }
     if params.nickname.as_str() == "" {
-        return Ok(nako_http::error_response_json("昵称不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-nickname-required",
+        )));
+    }
+    if !params.email.is_empty() && !validator::validate_email(params.email.as_str()) {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-email-error",
+        )));
     }
     if params.status != 0 && params.status != 1 {
-        return Ok(nako_http::error_response_json("状态不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-status-required",
+        )));
     }
 
     let db = &state.db;
@@ -143,7 +167,9 @@ pub async fn create_save(
         .unwrap_or_default()
         .unwrap_or_default();
     if user_data.id > 0 {
-        return Ok(nako_http::error_response_json("账号已经存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-account-exists",
+        )));
     }
 
     let add_time = time::now().timestamp();
@@ -158,6 +184,7 @@ pub async fn create_save(
         user_entity::Model {
             username: params.username.clone(),
             nickname: params.nickname.clone(),
+            email: params.email.clone(),
             sign: Some(params.sign.clone()),
             status: Some(params.status),
             add_time: Some(add_time),
@@ -167,10 +194,13 @@ pub async fn create_save(
     )
     .await;
     if let Ok(_) = create_data {
-        return Ok(nako_http::success_response_json("添加成功", ""));
+        return Ok(nako_http::success_response_json(
+            &i18n::t("admin-add-success"),
+            "",
+        ));
     }
 
-    Ok(nako_http::error_response_json("添加失败"))
+    Ok(nako_http::error_response_json(&i18n::t("admin-add-failed")))
 }
 
 // ==========================
@@ -186,10 +216,14 @@ pub async fn update(
     query: web::Query<UpdateQuery>,
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     if query.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "ID不能为空", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
     }
 
     let user_info = user::UserModel::find_user_by_id(db, query.id)
@@ -197,7 +231,11 @@ pub async fn update(
         .unwrap_or_default()
         .unwrap_or_default();
     if user_info.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "账号不存在", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-account-not-found"),
+            "",
+        ));
     }
 
     let mut ctx = nako_http::view_data();
@@ -212,6 +250,8 @@ pub async fn update(
 pub struct UpdateForm {
     username: String,
     nickname: String,
+    #[serde(default)]
+    email: String,
     sign: String,
     status: i32,
 }
@@ -224,27 +264,44 @@ pub async fn update_save(
     session: Session,
 ) -> Result<HttpResponse, Error> {
     if query.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
     if params.username.as_str() == "" {
-        return Ok(nako_http::error_response_json("账号不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-account-required",
+        )));
     }
     if params.nickname.as_str() == "" {
-        return Ok(nako_http::error_response_json("昵称不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-nickname-required",
+        )));
+    }
+    if !params.email.is_empty() && !validator::validate_email(params.email.as_str()) {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-email-error",
+        )));
     }
     if params.status != 0 && params.status != 1 {
-        return Ok(nako_http::error_response_json("状态不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-status-required",
+        )));
     }
 
     let admin_id: u32 = app::get_admin_id();
     if admin_id == query.id {
-        return Ok(nako_http::error_response_json("当前账号不能被修改"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-user-cannot-update",
+        )));
     }
 
     if let Some(login_id) = session.get::<u32>("login_id")? {
         if login_id == query.id {
-            return Ok(nako_http::error_response_json("你不能修改你自己"));
+            return Ok(nako_http::error_response_json(&i18n::t(
+                "admin-user-cannot-update-self",
+            )));
         }
     }
 
@@ -255,7 +312,9 @@ pub async fn update_save(
         .unwrap_or_default()
         .unwrap_or_default();
     if user_info.id == 0 {
-        return Ok(nako_http::error_response_json("要更改的账号不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-user-update-not-found",
+        )));
     }
 
     let user_info_by_name = user::UserModel::find_user_by_name(db, params.username.as_str())
@@ -264,7 +323,9 @@ pub async fn update_save(
         .unwrap_or_default();
     if user_info_by_name.id > 0 {
         if user_info.id != user_info_by_name.id {
-            return Ok(nako_http::error_response_json("账号已经存在"));
+            return Ok(nako_http::error_response_json(&i18n::t(
+                "admin-account-exists",
+            )));
         }
     }
 
@@ -275,6 +336,7 @@ pub async fn update_save(
         user_entity::Model {
             username: params.username.clone(),
             nickname: params.nickname.clone(),
+            email: params.email.clone(),
             sign: Some(params.sign.clone()),
             status: Some(params.status),
             ..entity::default()
@@ -282,10 +344,15 @@ pub async fn update_save(
     )
     .await;
     if user_data.is_err() {
-        return Ok(nako_http::error_response_json("更新失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-update-failed",
+        )));
     }
 
-    Ok(nako_http::success_response_json("更新成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
 }
 
 // ==========================
@@ -304,12 +371,16 @@ pub async fn delete(
     let db = &state.db;
 
     if query.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
     let admin_id: u32 = app::get_admin_id();
     if admin_id == query.id {
-        return Ok(nako_http::error_response_json("当前账号不能被删除"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-user-cannot-delete",
+        )));
     }
 
     let login_id = session
@@ -317,7 +388,9 @@ pub async fn delete(
         .unwrap_or_default()
         .unwrap_or_default();
     if login_id == query.id {
-        return Ok(nako_http::error_response_json("你不能删除你自己"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-user-cannot-delete-self",
+        )));
     }
 
     let user_data = user::UserModel::find_user_by_id(db, query.id)
@@ -325,15 +398,22 @@ pub async fn delete(
         .unwrap_or_default()
         .unwrap_or_default();
     if user_data.id == 0 {
-        return Ok(nako_http::error_response_json("要删除的账号不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-user-delete-not-found",
+        )));
     }
 
     let delete_data = user::UserModel::delete_user(db, query.id).await;
     if delete_data.is_err() {
-        return Ok(nako_http::error_response_json("删除失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-delete-failed",
+        )));
     }
 
-    Ok(nako_http::success_response_json("删除成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-delete-success"),
+        "",
+    ))
 }
 
 // ==========================
@@ -357,21 +437,29 @@ pub async fn update_status(
     session: Session,
 ) -> Result<HttpResponse, Error> {
     if query.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
     if params.status != 0 && params.status != 1 {
-        return Ok(nako_http::error_response_json("状态不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-status-required",
+        )));
     }
 
     let admin_id: u32 = app::get_admin_id();
     if admin_id == query.id {
-        return Ok(nako_http::error_response_json("当前账号不能被修改"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-user-cannot-update",
+        )));
     }
 
     if let Some(login_id) = session.get::<u32>("login_id")? {
         if login_id == query.id {
-            return Ok(nako_http::error_response_json("你不能修改你自己"));
+            return Ok(nako_http::error_response_json(&i18n::t(
+                "admin-user-cannot-update-self",
+            )));
         }
     }
 
@@ -382,7 +470,9 @@ pub async fn update_status(
         .unwrap_or_default()
         .unwrap_or_default();
     if user_data.id == 0 {
-        return Ok(nako_http::error_response_json("要更改的账号不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-user-update-not-found",
+        )));
     }
 
     // 更新
@@ -396,10 +486,15 @@ pub async fn update_status(
     )
     .await;
     if user_data.is_err() {
-        return Ok(nako_http::error_response_json("更新失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-update-failed",
+        )));
     }
 
-    Ok(nako_http::success_response_json("更新成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
 }
 
 // ==========================
@@ -415,10 +510,14 @@ pub async fn update_password(
     query: web::Query<UpdatePasswordQuery>,
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     if query.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "ID不能为空", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
     }
 
     let user_info = user::UserModel::find_user_by_id(db, query.id)
@@ -426,7 +525,11 @@ pub async fn update_password(
         .unwrap_or_default()
         .unwrap_or_default();
     if user_info.id == 0 {
-        return Ok(http::error_admin_html(&mut view, "账号不存在", ""));
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-account-not-found"),
+            "",
+        ));
     }
 
     let mut ctx = nako_http::view_data();
@@ -454,21 +557,29 @@ pub async fn update_password_save(
     session: Session,
 ) -> Result<HttpResponse, Error> {
     if query.id == 0 {
-        return Ok(nako_http::error_response_json("ID不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
     }
 
     if params.password.as_str() == "" {
-        return Ok(nako_http::error_response_json("密码不能为空"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-password-required",
+        )));
     }
 
     let admin_id: u32 = app::get_admin_id();
     if admin_id == query.id {
-        return Ok(nako_http::error_response_json("当前账号不能被修改"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-user-cannot-update",
+        )));
     }
 
     if let Some(login_id) = session.get::<u32>("login_id")? {
         if login_id == query.id {
-            return Ok(nako_http::error_response_json("你不能修改你自己"));
+            return Ok(nako_http::error_response_json(&i18n::t(
+                "admin-user-cannot-update-self",
+            )));
         }
     }
 
@@ -479,12 +590,16 @@ pub async fn update_password_save(
         .unwrap_or_default()
         .unwrap_or_default();
     if user_info.id == 0 {
-        return Ok(nako_http::error_response_json("要更改的账号不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-user-update-not-found",
+        )));
     }
 
     let new_password = nako_auth::password_hash(params.password.as_str());
     if new_password.as_str() == "" {
-        return Ok(nako_http::error_response_json("更改密码失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-password-update-failed",
+        )));
     }
 
     // 更新
@@ -498,8 +613,13 @@ pub async fn update_password_save(
     )
     .await;
     if new_user_data.is_err() {
-        return Ok(nako_http::error_response_json("更新失败"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-update-failed",
+        )));
     }
 
-    Ok(nako_http::success_response_json("更新成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
 }
@@ -0,0 +1,646 @@
+use actix_web::{web, Error, HttpResponse, Result};
+
+use crate::nako::global::{AppState, Deserialize, Serialize};
+use crate::nako::{http as nako_http, i18n, time, utils};
+
+use crate::app::entity::{self, webhook as webhook_entity, webhook_log as webhook_log_entity};
+use crate::app::model::{webhook, webhook_log};
+use crate::app::service::{self, http};
+
+// 首页
+pub async fn index(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    let mut view = state.view.get();
+
+    let mut ctx = nako_http::view_data();
+    ctx.insert("events", &service::webhook::event_names());
+
+    Ok(nako_http::view(&mut view, "admin/webhook/index.html", &ctx))
+}
+
+// ==========================
+
+#[derive(Serialize)]
+pub struct ListData {
+    list: Vec<webhook_entity::Model>,
+    count: u64,
+}
+
+#[derive(Deserialize)]
+pub struct ListQuery {
+    page: u64,
+    limit: u64,
+
+    name: Option<String>,
+    url: Option<String>,
+    event: Option<String>,
+    status: Option<i32>,
+}
+
+// 数据列表
+pub async fn list(
+    state: web::Data<AppState>,
+    query: web::Query<ListQuery>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let page: u64 = query.page;
+    let per_page: u64 = query.limit;
+
+    let search_where = webhook::WebhookWhere {
+        name: query.name.clone(),
+        url: query.url.clone(),
+        event: query.event.clone(),
+        status: query.status,
+    };
+    let search_where = search_where.format();
+
+    let (list, _num_pages) =
+        webhook::WebhookModel::search_in_page(db, page, per_page, search_where.clone())
+            .await
+            .unwrap_or_default();
+    let count = webhook::WebhookModel::search_count(db, search_where.clone())
+        .await
+        .unwrap_or(0);
+
+    // 密钥不在列表显示
+    let list = list
+        .into_iter()
+        .map(|v| webhook_entity::Model {
+            secret: "".to_string(),
+            ..v
+        })
+        .collect();
+
+    let res = ListData { list, count };
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-get-success"),
+        res,
+    ))
+}
+
+// ==========================
+
+// 添加
+pub async fn create(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    let mut view = state.view.get();
+
+    let mut ctx = nako_http::view_data();
+    ctx.insert("events", &service::webhook::event_names());
+    ctx.insert("secret", &utils::uuid().replace('-', ""));
+
+    Ok(nako_http::view(
+        &mut view,
+        "admin/webhook/create.html",
+        &ctx,
+    ))
+}
+
+// 表单数据
+#[derive(Deserialize)]
+pub struct SaveForm {
+    name: String,
+    url: String,
+    secret: String,
+    #[serde(default)]
+    events: String,
+    status: i32,
+}
+
+impl SaveForm {
+    // 检测数据
+    fn check(&self) -> Option<String> {
+        if self.name.trim().is_empty() {
+            return Some(i18n::t("admin-name-required"));
+        }
+        if self.status != 0 && self.status != 1 {
+            return Some(i18n::t("admin-status-required"));
+        }
+
+        match url::Url::parse(self.url.trim()) {
+            Ok(v) if v.scheme() == "http" || v.scheme() == "https" => {}
+            _ => return Some(i18n::t("admin-webhook-url-error")),
+        }
+
+        let events = self.events();
+        if events.is_empty() {
+            return Some(i18n::t("admin-webhook-events-required"));
+        }
+
+        None
+    }
+
+    // 有效的事件
+    fn events(&self) -> Vec<&'static str> {
+        let selected = self.events.split(',').map(|v| v.trim()).collect::<Vec<_>>();
+
+        service::webhook::EVENTS
+            .iter()
+            .map(|v| v.0)
+            .filter(|v| selected.contains(v))
+            .collect()
+    }
+
+    fn model(&self) -> webhook_entity::Model {
+        webhook_entity::Model {
+            name: self.name.trim().to_string(),
+            url: self.url.trim().to_string(),
+            secret: self.secret.trim().to_string(),
+            events: self.events().join(","),
+            status: self.status,
+            ..entity::default()
+        }
+    }
+}
+
+// 添加保存
+pub async fn create_save(
+    state: web::Data<AppState>,
+    params: web::Form<SaveForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if let Some(err) = params.check() {
+        return Ok(nako_http::error_response_json(err.as_str()));
+    }
+
+    let create_data = webhook::WebhookModel::create(
+        db,
+        webhook_entity::Model {
+            add_time: time::now().timestamp(),
+            ..params.model()
+        },
+    )
+    .await;
+    if create_data.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t("admin-add-failed")));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-add-success"),
+        "",
+    ))
+}
+
+// ==========================
+
+#[derive(Deserialize)]
+pub struct UpdateQuery {
+    id: u32,
+}
+
+// 更新
+pub async fn update(
+    state: web::Data<AppState>,
+    query: web::Query<UpdateQuery>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+    let mut view = state.view.get();
+
+    if query.id == 0 {
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
+    }
+
+    let info = webhook::WebhookModel::find_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if info.id == 0 {
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-webhook-not-found"),
+            "",
+        ));
+    }
+
+    let events = info.events.split(',').collect::<Vec<_>>();
+
+    let mut ctx = nako_http::view_data();
+    ctx.insert("data", &info);
+    ctx.insert("selected", &events);
+    ctx.insert("events", &service::webhook::event_names());
+
+    Ok(nako_http::view(
+        &mut view,
+        "admin/webhook/update.html",
+        &ctx,
+    ))
+}
+
+// 更新保存
+pub async fn update_save(
+    state: web::Data<AppState>,
+    query: web::Query<UpdateQuery>,
+    params: web::Form<SaveForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if query.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
+    }
+
+    let info = webhook::WebhookModel::find_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if info.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-webhook-update-not-found",
+        )));
+    }
+
+    if let Some(err) = params.check() {
+        return Ok(nako_http::error_response_json(err.as_str()));
+    }
+
+    let data = webhook::WebhookModel::update_by_id(db, query.id, params.model()).await;
+    if data.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-update-failed",
+        )));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
+}
+
+// ==========================
+
+#[derive(Deserialize)]
+pub struct DeleteForm {
+    id: u32,
+}
+
+// 删除，投递记录一起删除
+pub async fn delete(
+    state: web::Data<AppState>,
+    query: web::Form<DeleteForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if query.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
+    }
+
+    let data = webhook::WebhookModel::find_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-webhook-delete-not-found",
+        )));
+    }
+
+    let delete_data = webhook::WebhookModel::delete(db, query.id).await;
+    if delete_data.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-delete-failed",
+        )));
+    }
+
+    let _ = webhook_log::WebhookLogModel::delete_by_webhook_id(db, query.id).await;
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-delete-success"),
+        "",
+    ))
+}
+
+// ==========================
+
+#[derive(Deserialize)]
+pub struct UpdateStatusQuery {
+    id: u32,
+}
+
+// 表单数据
+#[derive(Deserialize)]
+pub struct UpdateStatusForm {
+    status: i32,
+}
+
+// 更新状态
+pub async fn update_status(
+    state: web::Data<AppState>,
+    query: web::Query<UpdateStatusQuery>,
+    params: web::Form<UpdateStatusForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if query.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
+    }
+
+    if params.status != 0 && params.status != 1 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-status-required",
+        )));
+    }
+
+    let data = webhook::WebhookModel::find_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-webhook-update-not-found",
+        )));
+    }
+
+    let status = webhook::WebhookModel::update_status_by_id(db, query.id, params.status).await;
+    if status.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-update-failed",
+        )));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-update-success"),
+        "",
+    ))
+}
+
+// ==========================
+
+#[derive(Serialize)]
+struct PingData {
+    id: u32,
+    name: String,
+    url: String,
+}
+
+// 发送测试事件
+pub async fn test(
+    state: web::Data<AppState>,
+    query: web::Form<DeleteForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if query.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
+    }
+
+    let data = webhook::WebhookModel::find_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-webhook-not-found",
+        )));
+    }
+    if data.status != 1 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-webhook-disabled",
+        )));
+    }
+
+    let ping = PingData {
+        id: data.id,
+        name: data.name.clone(),
+        url: data.url.clone(),
+    };
+    if service::webhook::push(db, &data, service::webhook::PING, &ping)
+        .await
+        .is_none()
+    {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-webhook-send-failed",
+        )));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-webhook-test-queued"),
+        "",
+    ))
+}
+
+// ==========================
+
+#[derive(Deserialize)]
+pub struct LogQuery {
+    webhook_id: Option<u32>,
+}
+
+// 投递记录
+pub async fn log(
+    state: web::Data<AppState>,
+    query: web::Query<LogQuery>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+    let mut view = state.view.get();
+
+    let webhooks = webhook::WebhookModel::find_all(db)
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .map(|v| (v.id, v.name))
+        .collect::<Vec<_>>();
+
+    let mut events = service::webhook::event_names();
+    events.push((service::webhook::PING, i18n::t("admin-webhook-test")));
+
+    let mut ctx = nako_http::view_data();
+    ctx.insert("webhook_id", &query.webhook_id.unwrap_or_default());
+    ctx.insert("webhooks", &webhooks);
+    ctx.insert("events", &events);
+
+    Ok(nako_http::view(&mut view, "admin/webhook/log.html", &ctx))
+}
+
+#[derive(Serialize)]
+pub struct LogListData {
+    list: Vec<webhook_log_entity::Model>,
+    count: u64,
+}
+
+#[derive(Deserialize)]
+pub struct LogListQuery {
+    page: u64,
+    limit: u64,
+
+    webhook_id: Option<u32>,
+    event: Option<String>,
+    status: Option<i32>,
+}
+
+// 投递记录列表
+pub async fn log_list(
+    state: web::Data<AppState>,
+    query: web::Query<LogListQuery>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let page: u64 = query.page;
+    let per_page: u64 = query.limit;
+
+    let search_where = webhook_log::WebhookLogWhere {
+        webhook_id: query.webhook_id,
+        event: query.event.clone(),
+        status: query.status,
+    };
+    let search_where = search_where.format();
+
+    let (list, _num_pages) =
+        webhook_log::WebhookLogModel::search_in_page(db, page, per_page, search_where.clone())
+            .await
+            .unwrap_or_default();
+    let count = webhook_log::WebhookLogModel::search_count(db, search_where.clone())
+        .await
+        .unwrap_or(0);
+
+    // 列表不需要请求内容
+    let list = list
+        .into_iter()
+        .map(|v| webhook_log_entity::Model {
+            payload: "".to_string(),
+            ..v
+        })
+        .collect();
+
+    let res = LogListData { list, count };
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-get-success"),
+        res,
+    ))
+}
+
+#[derive(Deserialize)]
+pub struct LogDetailQuery {
+    id: u32,
+}
+
+// 投递详情
+pub async fn log_detail(
+    state: web::Data<AppState>,
+    query: web::Query<LogDetailQuery>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+    let mut view = state.view.get();
+
+    if query.id == 0 {
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-id-required"),
+            "",
+        ));
+    }
+
+    let data = webhook_log::WebhookLogModel::find_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 {
+        return Ok(http::error_admin_html(
+            &mut view,
+            &i18n::t("admin-record-not-found"),
+            "",
+        ));
+    }
+
+    let hook = webhook::WebhookModel::find_by_id(db, data.webhook_id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+
+    // 格式化请求内容
+    let payload = serde_json::from_str::<serde_json::Value>(data.payload.as_str())
+        .and_then(|v| serde_json::to_string_pretty(&v))
+        .unwrap_or(data.payload.clone());
+
+    let mut ctx = nako_http::view_data();
+    ctx.insert("data", &data);
+    ctx.insert("webhook", &hook);
+    ctx.insert("payload", &payload);
+
+    Ok(nako_http::view(
+        &mut view,
+        "admin/webhook/log_detail.html",
+        &ctx,
+    ))
+}
+
+// 重新发送
+pub async fn log_retry(
+    state: web::Data<AppState>,
+    query: web::Form<DeleteForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if query.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
+    }
+
+    let data = webhook_log::WebhookLogModel::find_by_id(db, query.id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-record-not-found",
+        )));
+    }
+    if data.status == webhook_log::STATUS_WAIT {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-webhook-log-pending",
+        )));
+    }
+
+    let res = webhook_log::WebhookLogModel::retry(db, data.id, time::now().timestamp()).await;
+    if res.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-webhook-resend-failed",
+        )));
+    }
+
+    service::webhook::wake();
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-webhook-queued"),
+        "",
+    ))
+}
+
+// 删除投递记录
+pub async fn log_delete(
+    state: web::Data<AppState>,
+    query: web::Form<DeleteForm>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    if query.id == 0 {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-id-required",
+        )));
+    }
+
+    let delete_data = webhook_log::WebhookLogModel::delete(db, query.id).await;
+    if delete_data.is_err() {
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "admin-delete-failed",
+        )));
+    }
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("admin-delete-success"),
+        "",
+    ))
+}
@@ -0,0 +1,269 @@
+use actix_web::{web, Error, HttpRequest, HttpResponse, Result};
+
+use crate::nako::global::{AppState, Deserialize, Serialize};
+use crate::nako::{http as nako_http, time, utils};
+
+use crate::app::entity::{self, art as art_entity};
+use crate::app::middleware::api_auth::ApiAuth;
+use crate::app::model::{art, cate};
+use crate::app::service::{permalink, redirect, related, webhook};
+
+const SCOPE: &str = "art";
+
+/// 文章数据
+#[derive(Deserialize)]
+pub struct ArtForm {
+    cate_id: Option<u32>,
+    title: Option<String>,
+    slug: Option<String>,
+    keywords: Option<String>,
+    description: Option<String>,
+    cover: Option<String>,
+    content: Option<String>,
+    brief: Option<String>,
+    tags: Option<Vec<String>>,
+    from: Option<String>,
+    is_top: Option<i32>,
+    comment_closed: Option<i32>,
+    status: Option<i32>,
+    add_time: Option<i64>,
+}
+
+impl ArtForm {
+    // 合并到文章数据
+    fn merge_into(self, data: &mut art_entity::Model) {
+        if let Some(v) = self.cate_id {
+            data.cate_id = v;
+        }
+        if let Some(v) = self.title {
+            data.title = v;
+        }
+        if let Some(v) = self.slug {
+            data.slug = Some(v);
+        }
+        if let Some(v) = self.keywords {
+            data.keywords = Some(v);
+        }
+        if let Some(v) = self.description {
+            data.description = Some(v);
+        }
+        if let Some(v) = self.cover {
+            data.cover = Some(v);
+        }
+        if let Some(v) = self.content {
+            data.content = v;
+        }
+        if let Some(v) = self.brief {
+            data.brief = Some(v);
+        }
+        if let Some(v) = self.tags {
+            data.tags = Some(v.join(","));
+        }
+        if let Some(v) = self.from {
+            data.from = Some(v);
+        }
+        if let Some(v) = self.is_top {
+            data.is_top = Some(v);
+        }
+        if let Some(v) = self.comment_closed {
+            data.comment_closed = v;
+        }
+        if let Some(v) = self.status {
+            data.status = Some(v);
+        }
+        if let Some(v) = self.add_time {
+            data.add_time = Some(v);
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ArtData {
+    id: u32,
+    uuid: String,
+    url: String,
+}
+
+// 检测文章数据
+async fn check_art(state: &AppState, data: &art_entity::Model) -> Option<&'static str> {
+    if data.title.is_empty() {
+        return Some("文章标题不能为空");
+    }
+    if data.status != Some(0) && data.status != Some(1) {
+        return Some("状态不能为空");
+    }
+
+    let cate_data = cate::CateModel::find_by_id(&state.db, data.cate_id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if cate_data.id == 0 {
+        return Some("所属分类不存在");
+    }
+
+    None
+}
+
+// 添加文章
+pub async fn create(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    auth: web::ReqData<ApiAuth>,
+    web::Json(params): web::Json<ArtForm>,
+) -> Result<HttpResponse, Error> {
+    if let Some(res) = auth.check_scope(SCOPE) {
+        return Ok(res);
+    }
+
+    let db = &state.db;
+
+    let mut ip: String = "0.0.0.0".to_string();
+    if let Some(val) = req.peer_addr() {
+        ip = val.ip().to_string();
+    }
+
+    let mut data = art_entity::Model {
+        uuid: utils::uuid(),
+        user_id: auth.user_id,
+        views: Some(0),
+        is_top: Some(0),
+        status: Some(1),
+        add_time: Some(time::now().timestamp()),
+        add_ip: Some(ip),
+        ..entity::default()
+    };
+    params.merge_into(&mut data);
+
+    if let Some(err) = check_art(&state, &data).await {
+        return Ok(nako_http::error_response_json(err));
+    }
+
+    // 文章别名
+    if let Err(err) = permalink::fill_slug(db, &mut data, None).await {
+        return Ok(nako_http::error_response_json(err.as_str()));
+    }
+
+    let uuid = data.uuid.clone();
+    let create_data = art::ArtModel::create(db, data).await;
+    if create_data.is_err() {
+        return Ok(nako_http::error_response_json("添加失败"));
+    }
+
+    let art_data = art::ArtModel::find_by_uuid(db, uuid.as_str())
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+
+    permalink::record_create(db, &art_data).await;
+    webhook::fire_art(db, None, &art_data).await;
+
+    // 相关文章
+    related::changed(&mut state.get_ref().clone(), &art_data, Vec::new()).await;
+
+    Ok(nako_http::success_response_json(
+        "添加成功",
+        ArtData {
+            id: art_data.id,
+            url: permalink::art_url(&art_data),
+            uuid: art_data.uuid,
+        },
+    ))
+}
+
+// 更新文章
+pub async fn update(
+    state: web::Data<AppState>,
+    auth: web::ReqData<ApiAuth>,
+    id: web::Path<u32>,
+    web::Json(params): web::Json<ArtForm>,
+) -> Result<HttpResponse, Error> {
+    if let Some(res) = auth.check_scope(SCOPE) {
+        return Ok(res);
+    }
+
+    let db = &state.db;
+
+    let info = art::ArtModel::find_by_id(db, *id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if info.id == 0 {
+        return Ok(nako_http::error_response_json("要更改的文章不存在"));
+    }
+
+    let mut data = info.clone();
+    params.merge_into(&mut data);
+
+    if let Some(err) = check_art(&state, &data).await {
+        return Ok(nako_http::error_response_json(err));
+    }
+
+    // 文章别名
+    if let Err(err) = permalink::fill_slug(db, &mut data, Some(&info)).await {
+        return Ok(nako_http::error_response_json(err.as_str()));
+    }
+
+    // 更新前的相关文章
+    let related_ids = related::neighbor_ids(db, &info).await;
+
+    let new_info = match art::ArtModel::update_by_id(db, data.id, data).await {
+        Ok(v) => v,
+        Err(_) => return Ok(nako_http::error_response_json("更新失败")),
+    };
+
+    // 链接变动时记录跳转
+    permalink::record_change(db, &info, &new_info).await;
+
+    webhook::fire_art(db, Some(&info), &new_info).await;
+
+    // 相关文章
+    related::changed(&mut state.get_ref().clone(), &new_info, related_ids).await;
+
+    Ok(nako_http::success_response_json(
+        "更新成功",
+        ArtData {
+            id: new_info.id,
+            url: permalink::art_url(&new_info),
+            uuid: new_info.uuid,
+        },
+    ))
+}
+
+// 删除文章
+pub async fn delete(
+    state: web::Data<AppState>,
+    auth: web::ReqData<ApiAuth>,
+    id: web::Path<u32>,
+) -> Result<HttpResponse, Error> {
+    if let Some(res) = auth.check_scope(SCOPE) {
+        return Ok(res);
+    }
+
+    let db = &state.db;
+
+    let data = art::ArtModel::find_by_id(db, *id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 {
+        return Ok(nako_http::error_response_json("要删除的文章不存在"));
+    }
+
+    // 删除后标签关联也被删除，先查找需清除相关文章缓存的文章
+    let mut related_ids = related::neighbor_ids(db, &data).await;
+    related_ids.push(data.id);
+
+    let delete_data = art::ArtModel::delete(db, data.id).await;
+    if delete_data.is_err() {
+        return Ok(nako_http::error_response_json("删除失败"));
+    }
+
+    // 文章的跳转已一起删除
+    redirect::load(db).await;
+
+    webhook::fire(db, webhook::ART_DELETED, &data).await;
+
+    related::clear(&mut state.get_ref().clone(), related_ids).await;
+
+    Ok(nako_http::success_response_json("删除成功", ""))
+}
@@ -0,0 +1,130 @@
+use actix_web::{web, Error, HttpResponse, Result};
+use std::collections::HashMap;
+
+use crate::nako::global::{AppState, Deserialize};
+use crate::nako::http as nako_http;
+
+use crate::app::entity::{self, comment as comment_entity};
+use crate::app::middleware::api_auth::ApiAuth;
+use crate::app::model::comment;
+use crate::app::service::{self, api};
+
+const SCOPE: &str = "comment";
+
+// 评论列表
+pub async fn list(
+    state: web::Data<AppState>,
+    auth: web::ReqData<ApiAuth>,
+    web::Query(params): web::Query<HashMap<String, String>>,
+) -> Result<HttpResponse, Error> {
+    if let Some(res) = auth.check_scope(SCOPE) {
+        return Ok(res);
+    }
+
+    let db = &state.db;
+
+    let (page, per_page) = api::page_params(&params, 20);
+
+    let search_where = comment::CommentWhere {
+        art_id: api::number_param::<u32>(&params, "art_id"),
+        username: api::string_param(&params, "username"),
+        email: api::string_param(&params, "email"),
+        content: api::string_param(&params, "content"),
+        status: api::number_param::<i32>(&params, "status"),
+    };
+    let search_where = search_where.format();
+
+    let (list, _num_pages) =
+        comment::CommentModel::search_in_page(db, page, per_page, search_where.clone())
+            .await
+            .unwrap_or_default();
+    let count = comment::CommentModel::search_count(db, search_where)
+        .await
+        .unwrap_or(0);
+
+    let res = api::ListData::new(list, count, page, per_page);
+
+    Ok(nako_http::success_response_json("获取成功", res))
+}
+
+#[derive(Deserialize)]
+pub struct StatusForm {
+    status: i32,
+}
+
+// 更改评论状态
+pub async fn update_status(
+    state: web::Data<AppState>,
+    auth: web::ReqData<ApiAuth>,
+    id: web::Path<u32>,
+    web::Json(params): web::Json<StatusForm>,
+) -> Result<HttpResponse, Error> {
+    if let Some(res) = auth.check_scope(SCOPE) {
+        return Ok(res);
+    }
+
+    if !comment::STATUSES.contains(&params.status) {
+        return Ok(nako_http::error_response_json("状态错误"));
+    }
+
+    let db = &state.db;
+
+    let data = comment::CommentModel::find_by_id(db, *id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 {
+        return Ok(nako_http::error_response_json("要更改的评论不存在"));
+    }
+
+    let status = comment::CommentModel::update_status_by_id(
+        db,
+        data.id,
+        comment_entity::Model {
+            status: Some(params.status),
+            ..entity::default()
+        },
+    )
+    .await;
+    let status = match status {
+        Ok(v) => v,
+        Err(_) => return Ok(nako_http::error_response_json("更新失败")),
+    };
+
+    // 审核通过后通知被回复的评论者
+    if data.status != Some(comment::STATUS_APPROVED)
+        && status.status == Some(comment::STATUS_APPROVED)
+    {
+        service::comment::approved(&state, &status).await;
+    }
+
+    Ok(nako_http::success_response_json("更新成功", ""))
+}
+
+// 删除评论
+pub async fn delete(
+    state: web::Data<AppState>,
+    auth: web::ReqData<ApiAuth>,
+    id: web::Path<u32>,
+) -> Result<HttpResponse, Error> {
+    if let Some(res) = auth.check_scope(SCOPE) {
+        return Ok(res);
+    }
+
+    let db = &state.db;
+
+    let data = comment::CommentModel::find_by_id(db, *id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 {
+        return Ok(nako_http::error_response_json("要删除的评论不存在"));
+    }
+
+    let delete_data = comment::CommentModel::delete(db, data.id).await;
+    if delete_data.is_err() {
+        return Ok(nako_http::error_response_json("删除失败"));
+    }
+
+    Ok(nako_http::success_response_json("删除成功", ""))
+}
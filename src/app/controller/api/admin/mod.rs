@@ -0,0 +1,3 @@
+pub mod art;
+pub mod comment;
+pub mod upload;
@@ -0,0 +1,59 @@
+use actix_multipart::form::{tempfile::TempFile, MultipartForm};
+use actix_web::{web, Error, HttpRequest, HttpResponse, Result};
+
+use crate::nako::global::AppState;
+use crate::nako::http as nako_http;
+
+use crate::app::middleware::api_auth::ApiAuth;
+use crate::app::service::upload;
+
+const SCOPE: &str = "upload";
+
+#[derive(Debug, MultipartForm)]
+pub struct UploadForm {
+    #[multipart(rename = "file")]
+    files: Vec<TempFile>,
+}
+
+// 保存上传
+async fn save(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    auth: web::ReqData<ApiAuth>,
+    form: UploadForm,
+    upload_type: upload::UploadType,
+) -> Result<HttpResponse, Error> {
+    if let Some(res) = auth.check_scope(SCOPE) {
+        return Ok(res);
+    }
+
+    let add_ip: String = match req.peer_addr() {
+        Some(val) => val.ip().to_string(),
+        None => "0.0.0.0".to_string(),
+    };
+
+    match upload::save_files(&state.db, form.files, upload_type, add_ip.as_str()).await {
+        Ok(res) => Ok(nako_http::success_response_json("上传成功", res)),
+        Err(err) => Ok(nako_http::error_response_json(err.as_str())),
+    }
+}
+
+// 上传文件
+pub async fn file(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    auth: web::ReqData<ApiAuth>,
+    MultipartForm(form): MultipartForm<UploadForm>,
+) -> Result<HttpResponse, Error> {
+    save(req, state, auth, form, upload::UploadType::File).await
+}
+
+// 上传图片
+pub async fn image(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    auth: web::ReqData<ApiAuth>,
+    MultipartForm(form): MultipartForm<UploadForm>,
+) -> Result<HttpResponse, Error> {
+    save(req, state, auth, form, upload::UploadType::Image).await
+}
@@ -0,0 +1,157 @@
+use actix_web::{web, Error, HttpResponse, Result};
+use std::collections::HashMap;
+
+use crate::nako::global::{AppState, Serialize};
+use crate::nako::http as nako_http;
+
+use crate::app::controller::api::cate::CateItem;
+use crate::app::entity::art as art_entity;
+use crate::app::model::{art, cate};
+use crate::app::service::api;
+
+/// 列表数据
+#[derive(Serialize)]
+pub struct ArtItem {
+    id: u32,
+    uuid: String,
+    cate_id: u32,
+    user_id: u32,
+    title: String,
+    keywords: Option<String>,
+    description: Option<String>,
+    cover: Option<String>,
+    brief: Option<String>,
+    tags: Vec<String>,
+    from: Option<String>,
+    views: Option<i64>,
+    is_top: Option<i32>,
+    add_time: Option<i64>,
+}
+
+impl From<art_entity::Model> for ArtItem {
+    fn from(data: art_entity::Model) -> Self {
+        Self {
+            id: data.id,
+            uuid: data.uuid,
+            cate_id: data.cate_id,
+            user_id: data.user_id,
+            title: data.title,
+            keywords: data.keywords,
+            description: data.description,
+            cover: data.cover,
+            brief: data.brief,
+            tags: split_tags(data.tags),
+            from: data.from,
+            views: data.views,
+            is_top: data.is_top,
+            add_time: data.add_time,
+        }
+    }
+}
+
+/// 详情数据
+#[derive(Serialize)]
+pub struct ArtDetail {
+    #[serde(flatten)]
+    item: ArtItem,
+    content: String,
+    cate: Option<CateItem>,
+}
+
+// 标签列表
+fn split_tags(tags: Option<String>) -> Vec<String> {
+    tags.unwrap_or_default()
+        .split(',')
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .collect()
+}
+
+// 文章列表
+pub async fn list(
+    state: web::Data<AppState>,
+    web::Query(params): web::Query<HashMap<String, String>>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let (page, per_page) = api::page_params(&params, 10);
+
+    // 分类标识
+    let mut cate_id = api::number_param::<u32>(&params, "cate_id");
+    if let Some(slug) = api::string_param(&params, "cate") {
+        let cate_data = cate::CateModel::find_by_slug(db, slug.as_str())
+            .await
+            .unwrap_or_default()
+            .unwrap_or_default();
+        cate_id = Some(cate_data.id);
+    }
+
+    // 包含子分类
+    let mut cate_ids = None;
+    if let Some(id) = cate_id {
+        if api::number_param::<i32>(&params, "subtree") == Some(1) {
+            let cates = cate::CateModel::find_open_cate(db)
+                .await
+                .unwrap_or_default();
+
+            let mut ids = cate::CateModel::descendant_ids(&cates, id);
+            ids.push(id);
+
+            cate_id = None;
+            cate_ids = Some(ids);
+        }
+    }
+
+    let search_where = art::ArtWhere {
+        uuid: api::string_param(&params, "uuid"),
+        cate_id,
+        cate_ids,
+        user_id: api::number_param::<u32>(&params, "user_id"),
+        title: api::string_param(&params, "title"),
+        tag: api::string_param(&params, "tag"),
+        is_top: api::number_param::<i32>(&params, "is_top"),
+        status: Some(1),
+    };
+
+    let (list, _num_pages) = art::ArtModel::list_in_page(db, page, per_page, search_where.clone())
+        .await
+        .unwrap_or_default();
+    let count = art::ArtModel::list_count(db, search_where)
+        .await
+        .unwrap_or(0);
+
+    let list = list.into_iter().map(ArtItem::from).collect();
+    let res = api::ListData::new(list, count, page, per_page);
+
+    Ok(nako_http::success_response_json("获取成功", res))
+}
+
+// 文章详情
+pub async fn detail(
+    state: web::Data<AppState>,
+    uuid: web::Path<String>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let data = art::ArtModel::find_by_uuid(db, uuid.as_str())
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 || data.status != Some(1) {
+        return Ok(nako_http::error_response_json("文章不存在"));
+    }
+
+    let cate_data = cate::CateModel::find_by_id(db, data.cate_id)
+        .await
+        .unwrap_or_default()
+        .map(CateItem::from);
+
+    let content = data.content.clone();
+    let res = ArtDetail {
+        item: ArtItem::from(data),
+        content,
+        cate: cate_data,
+    };
+
+    Ok(nako_http::success_response_json("获取成功", res))
+}
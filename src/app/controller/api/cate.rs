@@ -0,0 +1,66 @@
+use actix_web::{web, Error, HttpResponse, Result};
+
+use crate::nako::global::{AppState, Serialize};
+use crate::nako::http as nako_http;
+
+use crate::app::entity::cate as cate_entity;
+use crate::app::model::cate;
+
+/// 分类数据
+#[derive(Serialize)]
+pub struct CateItem {
+    id: u32,
+    pid: u32,
+    name: String,
+    slug: String,
+    desc: Option<String>,
+    sort: i32,
+}
+
+impl From<cate_entity::Model> for CateItem {
+    fn from(data: cate_entity::Model) -> Self {
+        Self {
+            id: data.id,
+            pid: data.pid,
+            name: data.name,
+            slug: data.slug,
+            desc: data.desc,
+            sort: data.sort,
+        }
+    }
+}
+
+// 分类列表
+pub async fn list(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let list: Vec<CateItem> = cate::CateModel::find_open_cate(db)
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .map(CateItem::from)
+        .collect();
+
+    Ok(nako_http::success_response_json("获取成功", list))
+}
+
+// 分类详情
+pub async fn detail(
+    state: web::Data<AppState>,
+    slug: web::Path<String>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let data = cate::CateModel::find_by_slug(db, slug.as_str())
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 || data.status != Some(1) {
+        return Ok(nako_http::error_response_json("分类不存在"));
+    }
+
+    Ok(nako_http::success_response_json(
+        "获取成功",
+        CateItem::from(data),
+    ))
+}
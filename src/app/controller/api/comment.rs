@@ -0,0 +1,65 @@
+use actix_web::{web, Error, HttpResponse, Result};
+use std::collections::HashMap;
+
+use crate::nako::global::{AppState, Serialize};
+use crate::nako::http as nako_http;
+
+use crate::app::entity::comment as comment_entity;
+use crate::app::model::{art, comment};
+use crate::app::service::api;
+
+/// 评论数据，不输出邮箱及IP
+#[derive(Serialize)]
+pub struct CommentItem {
+    id: u32,
+    art_id: u32,
+    reply_id: Option<u32>,
+    username: String,
+    content: String,
+    add_time: Option<i64>,
+}
+
+impl From<comment_entity::Model> for CommentItem {
+    fn from(data: comment_entity::Model) -> Self {
+        Self {
+            id: data.id,
+            art_id: data.art_id,
+            reply_id: data.reply_id,
+            username: data.username,
+            content: data.content,
+            add_time: data.add_time,
+        }
+    }
+}
+
+// 文章评论列表
+pub async fn list(
+    state: web::Data<AppState>,
+    uuid: web::Path<String>,
+    web::Query(params): web::Query<HashMap<String, String>>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let art_data = art::ArtModel::find_by_uuid(db, uuid.as_str())
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if art_data.id == 0 || art_data.status != Some(1) {
+        return Ok(nako_http::error_response_json("文章不存在"));
+    }
+
+    let (page, per_page) = api::page_params(&params, 10);
+
+    let (list, _num_pages) =
+        comment::CommentModel::find_in_page_by_artid(db, art_data.id, page, per_page)
+            .await
+            .unwrap_or_default();
+    let count = comment::CommentModel::find_count_by_artid(db, art_data.id)
+        .await
+        .unwrap_or(0);
+
+    let list = list.into_iter().map(CommentItem::from).collect();
+    let res = api::ListData::new(list, count, page, per_page);
+
+    Ok(nako_http::success_response_json("获取成功", res))
+}
@@ -0,0 +1,8 @@
+use actix_web::{Error, HttpResponse, Result};
+
+use crate::nako::http as nako_http;
+
+/// 接口不存在
+pub async fn index() -> Result<HttpResponse, Error> {
+    Ok(nako_http::error_response_json("接口不存在"))
+}
@@ -0,0 +1,45 @@
+use actix_web::{web, Error, HttpResponse, Result};
+
+use crate::nako::global::{AppState, Serialize};
+use crate::nako::http as nako_http;
+
+use crate::app::entity::friendlink as friendlink_entity;
+use crate::app::model::friendlink;
+
+/// 友情链接数据
+#[derive(Serialize)]
+pub struct FriendlinkItem {
+    id: u32,
+    title: String,
+    url: String,
+    target: Option<String>,
+    icon: Option<String>,
+    sort: Option<i32>,
+}
+
+impl From<friendlink_entity::Model> for FriendlinkItem {
+    fn from(data: friendlink_entity::Model) -> Self {
+        Self {
+            id: data.id,
+            title: data.title,
+            url: data.url,
+            target: data.target,
+            icon: data.icon,
+            sort: data.sort,
+        }
+    }
+}
+
+// 友情链接列表
+pub async fn list(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let list: Vec<FriendlinkItem> = friendlink::FriendlinkModel::list_open(db)
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .map(FriendlinkItem::from)
+        .collect();
+
+    Ok(nako_http::success_response_json("获取成功", list))
+}
@@ -0,0 +1,9 @@
+pub mod admin;
+pub mod art;
+pub mod cate;
+pub mod comment;
+pub mod error;
+pub mod friendlink;
+pub mod page;
+pub mod tag;
+pub mod xmlrpc;
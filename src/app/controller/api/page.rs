@@ -0,0 +1,94 @@
+use actix_web::{web, Error, HttpResponse, Result};
+use std::collections::HashMap;
+
+use crate::nako::global::{AppState, Serialize};
+use crate::nako::http as nako_http;
+
+use crate::app::entity::page as page_entity;
+use crate::app::model::page;
+use crate::app::service::api;
+
+/// 单页数据
+#[derive(Serialize)]
+pub struct PageItem {
+    id: u32,
+    slug: String,
+    title: String,
+    keywords: Option<String>,
+    description: Option<String>,
+    add_time: Option<i64>,
+}
+
+impl From<page_entity::Model> for PageItem {
+    fn from(data: page_entity::Model) -> Self {
+        Self {
+            id: data.id,
+            slug: data.slug,
+            title: data.title,
+            keywords: data.keywords,
+            description: data.description,
+            add_time: data.add_time,
+        }
+    }
+}
+
+/// 单页详情
+#[derive(Serialize)]
+pub struct PageDetail {
+    #[serde(flatten)]
+    item: PageItem,
+    content: String,
+}
+
+// 单页列表
+pub async fn list(
+    state: web::Data<AppState>,
+    web::Query(params): web::Query<HashMap<String, String>>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let (page, per_page) = api::page_params(&params, 10);
+
+    let search_where = page::PageWhere {
+        title: api::string_param(&params, "title"),
+        slug: None,
+        status: Some(1),
+    };
+
+    let (list, _num_pages) =
+        page::PageModel::search_in_page(db, page, per_page, search_where.clone())
+            .await
+            .unwrap_or_default();
+    let count = page::PageModel::search_count(db, search_where)
+        .await
+        .unwrap_or(0);
+
+    let list = list.into_iter().map(PageItem::from).collect();
+    let res = api::ListData::new(list, count, page, per_page);
+
+    Ok(nako_http::success_response_json("获取成功", res))
+}
+
+// 单页详情
+pub async fn detail(
+    state: web::Data<AppState>,
+    slug: web::Path<String>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let data = page::PageModel::find_by_slug(db, slug.as_str())
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 || data.status != Some(1) {
+        return Ok(nako_http::error_response_json("页面不存在"));
+    }
+
+    let content = data.content.clone();
+    let res = PageDetail {
+        item: PageItem::from(data),
+        content,
+    };
+
+    Ok(nako_http::success_response_json("获取成功", res))
+}
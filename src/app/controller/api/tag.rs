@@ -0,0 +1,96 @@
+use actix_web::{web, Error, HttpResponse, Result};
+use std::collections::HashMap;
+
+use crate::nako::global::{AppState, Serialize};
+use crate::nako::http as nako_http;
+
+use crate::app::entity::tag as tag_entity;
+use crate::app::model::{art_tag, tag};
+use crate::app::service::api;
+
+/// 标签数据
+#[derive(Serialize)]
+pub struct TagItem {
+    id: u32,
+    name: String,
+    desc: Option<String>,
+    sort: i32,
+    count: u64,
+}
+
+impl From<tag_entity::Model> for TagItem {
+    fn from(data: tag_entity::Model) -> Self {
+        Self {
+            id: data.id,
+            name: data.name,
+            desc: data.desc,
+            sort: data.sort,
+            count: 0,
+        }
+    }
+}
+
+// 标签列表
+pub async fn list(
+    state: web::Data<AppState>,
+    web::Query(params): web::Query<HashMap<String, String>>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let (page, per_page) = api::page_params(&params, 20);
+
+    let search_where = tag::TagWhere {
+        name: api::string_param(&params, "name"),
+        status: Some(1),
+    };
+
+    let (list, _num_pages) =
+        tag::TagModel::search_in_page(db, page, per_page, search_where.clone())
+            .await
+            .unwrap_or_default();
+    let count = tag::TagModel::search_count(db, search_where)
+        .await
+        .unwrap_or(0);
+
+    let counts = art_tag::ArtTagModel::find_tag_counts(db)
+        .await
+        .unwrap_or_default();
+    let list = list
+        .into_iter()
+        .map(|t| TagItem {
+            count: counts.get(&t.id).copied().unwrap_or_default(),
+            ..TagItem::from(t)
+        })
+        .collect();
+    let res = api::ListData::new(list, count, page, per_page);
+
+    Ok(nako_http::success_response_json("获取成功", res))
+}
+
+// 标签详情
+pub async fn detail(
+    state: web::Data<AppState>,
+    name: web::Path<String>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+
+    let data = tag::TagModel::find_by_name(db, name.as_str())
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 || data.status != Some(1) {
+        return Ok(nako_http::error_response_json("标签不存在"));
+    }
+
+    let counts = art_tag::ArtTagModel::find_tag_counts(db)
+        .await
+        .unwrap_or_default();
+
+    Ok(nako_http::success_response_json(
+        "获取成功",
+        TagItem {
+            count: counts.get(&data.id).copied().unwrap_or_default(),
+            ..TagItem::from(data)
+        },
+    ))
+}
@@ -0,0 +1,488 @@
+use actix_web::{web, Error, HttpRequest, HttpResponse, Result};
+
+use crate::nako::global::AppState;
+use crate::nako::time::{self, NaiveDateTime, TimeZone};
+use crate::nako::xmlrpc::{self, MethodCall, Value};
+use crate::nako::{auth as nako_auth, http as nako_http, utils};
+
+use crate::app::entity::{self, art as art_entity, cate as cate_entity, user as user_entity};
+use crate::app::model::{art, cate, user};
+use crate::app::service::{permalink, related, setting, upload, webhook};
+
+// 错误码
+const FAULT_PARSE: i64 = -32700;
+const FAULT_METHOD: i64 = -32601;
+const FAULT_AUTH: i64 = 403;
+const FAULT_NOT_FOUND: i64 = 404;
+const FAULT_DATA: i64 = 500;
+
+type RpcResult = Result<Value, (i64, String)>;
+
+fn fault(code: i64, message: &str) -> (i64, String) {
+    (code, message.to_string())
+}
+
+// XML-RPC 入口
+pub async fn index(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Bytes,
+) -> Result<HttpResponse, Error> {
+    let body = String::from_utf8_lossy(&body).to_string();
+
+    let call = match xmlrpc::parse_call(body.as_str()) {
+        Ok(v) => v,
+        Err(err) => return Ok(nako_http::xml(xmlrpc::fault(FAULT_PARSE, err.as_str()))),
+    };
+
+    let res = match call.method.as_str() {
+        "blogger.getUsersBlogs" => get_users_blogs(&req, &state, &call).await,
+        "blogger.deletePost" => delete_post(&state, &call).await,
+        "metaWeblog.newPost" => new_post(&req, &state, &call).await,
+        "metaWeblog.editPost" => edit_post(&state, &call).await,
+        "metaWeblog.getPost" => get_post(&req, &state, &call).await,
+        "metaWeblog.getRecentPosts" => get_recent_posts(&req, &state, &call).await,
+        "metaWeblog.getCategories" | "blogger.getCategories" => {
+            get_categories(&req, &state, &call).await
+        }
+        "metaWeblog.newMediaObject" => new_media_object(&req, &state, &call).await,
+        _ => Err(fault(FAULT_METHOD, "请求方法不存在")),
+    };
+
+    let body = match res {
+        Ok(v) => xmlrpc::response(v),
+        Err((code, message)) => xmlrpc::fault(code, message.as_str()),
+    };
+
+    Ok(nako_http::xml(body))
+}
+
+// 账号验证，客户端提交的是明文密码
+async fn login(
+    state: &AppState,
+    call: &MethodCall,
+    index: usize,
+) -> Result<user_entity::Model, (i64, String)> {
+    let username = call.param(index).as_str().unwrap_or_default().to_string();
+    let password = call
+        .param(index + 1)
+        .as_str()
+        .unwrap_or_default()
+        .to_string();
+    if username.is_empty() || password.is_empty() {
+        return Err(fault(FAULT_AUTH, "账号或者密码不能为空"));
+    }
+
+    let user_info = user::UserModel::find_user_by_name(&state.db, username.as_str())
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if user_info.id == 0 || user_info.status != Some(1) {
+        return Err(fault(FAULT_AUTH, "账号或者密码错误"));
+    }
+
+    // 后台密码为 md5 后再加密
+    let pass = user_info.password.clone().unwrap_or_default();
+    if !nako_auth::password_verify(utils::md5(password.as_str()).as_str(), pass.as_str()) {
+        return Err(fault(FAULT_AUTH, "账号或者密码错误"));
+    }
+
+    Ok(user_info)
+}
+
+// 请求地址
+fn host_url(req: &HttpRequest) -> String {
+    let conn = req.connection_info();
+
+    format!("{}://{}", conn.scheme(), conn.host())
+}
+
+// 解析时间
+fn parse_date(value: &Value) -> Option<i64> {
+    let date = match value {
+        Value::DateTime(v) | Value::String(v) => v.trim().trim_end_matches('Z').to_string(),
+        _ => return None,
+    };
+
+    ["%Y%m%dT%H:%M:%S", "%Y-%m-%dT%H:%M:%S", "%Y%m%dT%H%M%S"]
+        .iter()
+        .find_map(|f| NaiveDateTime::parse_from_str(date.as_str(), f).ok())
+        .map(|d| time::timezone().from_utc_datetime(&d).timestamp())
+}
+
+// 格式化文章
+fn format_post(
+    req: &HttpRequest,
+    data: art_entity::Model,
+    cate_data: Option<cate_entity::Model>,
+) -> Value {
+    let link = format!("{}{}", host_url(req), permalink::art_url(&data));
+    let categories = cate_data
+        .map(|c| vec![Value::String(c.name)])
+        .unwrap_or_default();
+
+    let date = time::from_timestamp(data.add_time.unwrap_or_default())
+        .format("%Y%m%dT%H:%M:%S")
+        .to_string();
+
+    Value::new_struct(vec![
+        ("postid", Value::String(data.id.to_string())),
+        ("userid", Value::String(data.user_id.to_string())),
+        ("title", Value::String(data.title)),
+        ("description", Value::String(data.content)),
+        ("mt_excerpt", Value::String(data.brief.unwrap_or_default())),
+        ("mt_keywords", Value::String(data.tags.unwrap_or_default())),
+        ("categories", Value::Array(categories)),
+        ("dateCreated", Value::DateTime(date)),
+        ("wp_slug", Value::String(data.slug.unwrap_or_default())),
+        ("link", Value::String(link.clone())),
+        ("permaLink", Value::String(link)),
+        (
+            "post_status",
+            Value::String(
+                if data.status == Some(1) {
+                    "publish"
+                } else {
+                    "draft"
+                }
+                .to_string(),
+            ),
+        ),
+    ])
+}
+
+// 文章数据合并到模型
+async fn merge_post(
+    state: &AppState,
+    post: &Value,
+    publish: Option<bool>,
+    data: &mut art_entity::Model,
+) -> Result<(), (i64, String)> {
+    if let Some(v) = post.get("title").and_then(|v| v.as_str()) {
+        data.title = v.to_string();
+    }
+    if let Some(v) = post.get("description").and_then(|v| v.as_str()) {
+        data.content = v.to_string();
+    }
+    if let Some(v) = post.get("wp_slug").and_then(|v| v.as_str()) {
+        data.slug = Some(v.to_string());
+    }
+    if let Some(v) = post.get("mt_excerpt").and_then(|v| v.as_str()) {
+        data.brief = Some(v.to_string());
+    }
+    if let Some(v) = post.get("mt_keywords").and_then(|v| v.as_str()) {
+        let tags: Vec<&str> = v
+            .split(',')
+            .map(|t| t.trim())
+            .filter(|t| !t.is_empty())
+            .collect();
+        data.tags = Some(tags.join(","));
+    }
+    if let Some(v) = post.get("dateCreated").and_then(parse_date) {
+        data.add_time = Some(v);
+    }
+    if let Some(v) = publish {
+        data.status = Some(if v { 1 } else { 0 });
+    }
+
+    // 分类只取第一个
+    let cate_name = post
+        .get("categories")
+        .and_then(|v| v.as_array())
+        .and_then(|v| v.first())
+        .and_then(|v| v.as_str())
+        .map(|v| v.to_string());
+
+    let cates = cate::CateModel::find_all(&state.db)
+        .await
+        .unwrap_or_default();
+
+    if let Some(name) = cate_name {
+        match cates.iter().find(|c| c.name == name || c.slug == name) {
+            Some(c) => data.cate_id = c.id,
+            None => return Err(fault(FAULT_DATA, "所属分类不存在")),
+        }
+    } else if data.cate_id == 0 {
+        match cates.iter().find(|c| c.status == Some(1)) {
+            Some(c) => data.cate_id = c.id,
+            None => return Err(fault(FAULT_DATA, "所属分类不存在")),
+        }
+    }
+
+    if data.title.is_empty() {
+        return Err(fault(FAULT_DATA, "文章标题不能为空"));
+    }
+
+    Ok(())
+}
+
+// blogger.getUsersBlogs(appkey, username, password)
+async fn get_users_blogs(req: &HttpRequest, state: &AppState, call: &MethodCall) -> RpcResult {
+    login(state, call, 1).await?;
+
+    let settings = setting::settings(&mut state.clone()).await;
+    let blog_name = settings
+        .get("website_name")
+        .cloned()
+        .unwrap_or("Nako Blog".to_string());
+
+    let host = host_url(req);
+
+    Ok(Value::Array(vec![Value::new_struct(vec![
+        ("blogid", Value::String("1".to_string())),
+        ("blogName", Value::String(blog_name)),
+        ("url", Value::String(format!("{}/", host))),
+        ("xmlrpc", Value::String(format!("{}/xmlrpc", host))),
+        ("isAdmin", Value::Bool(true)),
+    ])]))
+}
+
+// metaWeblog.newPost(blogid, username, password, struct, publish)
+async fn new_post(req: &HttpRequest, state: &AppState, call: &MethodCall) -> RpcResult {
+    let user_info = login(state, call, 1).await?;
+
+    let mut ip: String = "0.0.0.0".to_string();
+    if let Some(val) = req.peer_addr() {
+        ip = val.ip().to_string();
+    }
+
+    let mut data = art_entity::Model {
+        uuid: utils::uuid(),
+        user_id: user_info.id,
+        views: Some(0),
+        is_top: Some(0),
+        status: Some(1),
+        add_time: Some(time::now().timestamp()),
+        add_ip: Some(ip),
+        ..entity::default()
+    };
+
+    let publish = call.param(4).as_bool().unwrap_or(true);
+    merge_post(state, &call.param(3), Some(publish), &mut data).await?;
+
+    // 文章别名
+    if let Err(err) = permalink::fill_slug(&state.db, &mut data, None).await {
+        return Err(fault(FAULT_DATA, err.as_str()));
+    }
+
+    let uuid = data.uuid.clone();
+    if art::ArtModel::create(&state.db, data).await.is_err() {
+        return Err(fault(FAULT_DATA, "添加失败"));
+    }
+
+    let art_data = art::ArtModel::find_by_uuid(&state.db, uuid.as_str())
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+
+    permalink::record_create(&state.db, &art_data).await;
+    webhook::fire_art(&state.db, None, &art_data).await;
+
+    // 相关文章
+    related::changed(&mut state.clone(), &art_data, Vec::new()).await;
+
+    Ok(Value::String(art_data.id.to_string()))
+}
+
+// 查询文章
+async fn find_post(state: &AppState, value: &Value) -> Result<art_entity::Model, (i64, String)> {
+    let id = value.as_i64().unwrap_or_default() as u32;
+
+    let data = art::ArtModel::find_by_id(&state.db, id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if data.id == 0 {
+        return Err(fault(FAULT_NOT_FOUND, "文章不存在"));
+    }
+
+    Ok(data)
+}
+
+// metaWeblog.editPost(postid, username, password, struct, publish)
+async fn edit_post(state: &AppState, call: &MethodCall) -> RpcResult {
+    login(state, call, 1).await?;
+
+    let info = find_post(state, &call.param(0)).await?;
+
+    let mut data = info.clone();
+    let publish = call.param(4).as_bool();
+    merge_post(state, &call.param(3), publish, &mut data).await?;
+
+    // 文章别名
+    if let Err(err) = permalink::fill_slug(&state.db, &mut data, Some(&info)).await {
+        return Err(fault(FAULT_DATA, err.as_str()));
+    }
+
+    // 更新前的相关文章
+    let related_ids = related::neighbor_ids(&state.db, &info).await;
+
+    let new_info = match art::ArtModel::update_by_id(&state.db, data.id, data).await {
+        Ok(v) => v,
+        Err(_) => return Err(fault(FAULT_DATA, "更新失败")),
+    };
+
+    // 链接变动时记录跳转
+    permalink::record_change(&state.db, &info, &new_info).await;
+
+    webhook::fire_art(&state.db, Some(&info), &new_info).await;
+
+    // 相关文章
+    related::changed(&mut state.clone(), &new_info, related_ids).await;
+
+    Ok(Value::Bool(true))
+}
+
+// metaWeblog.getPost(postid, username, password)
+async fn get_post(req: &HttpRequest, state: &AppState, call: &MethodCall) -> RpcResult {
+    login(state, call, 1).await?;
+
+    let data = find_post(state, &call.param(0)).await?;
+
+    let cate_data = cate::CateModel::find_by_id(&state.db, data.cate_id)
+        .await
+        .unwrap_or_default();
+
+    Ok(format_post(req, data, cate_data))
+}
+
+// metaWeblog.getRecentPosts(blogid, username, password, numberOfPosts)
+async fn get_recent_posts(req: &HttpRequest, state: &AppState, call: &MethodCall) -> RpcResult {
+    login(state, call, 1).await?;
+
+    let mut per_page = call.param(3).as_i64().unwrap_or(10) as u64;
+    per_page = per_page.clamp(1, 100);
+
+    let (list, _) = art::ArtModel::search_in_page(
+        &state.db,
+        1,
+        per_page,
+        art::ArtWhere {
+            uuid: None,
+            cate_id: None,
+            cate_ids: None,
+            user_id: None,
+            title: None,
+            tag: None,
+            is_top: None,
+            status: None,
+        },
+    )
+    .await
+    .unwrap_or_default();
+
+    let cates = cate::CateModel::find_all(&state.db)
+        .await
+        .unwrap_or_default();
+
+    let posts = list
+        .into_iter()
+        .map(|data| {
+            let cate_data = cates.iter().find(|c| c.id == data.cate_id).cloned();
+            format_post(req, data, cate_data)
+        })
+        .collect();
+
+    Ok(Value::Array(posts))
+}
+
+// blogger.deletePost(appkey, postid, username, password, publish)
+async fn delete_post(state: &AppState, call: &MethodCall) -> RpcResult {
+    login(state, call, 2).await?;
+
+    let data = find_post(state, &call.param(1)).await?;
+
+    // 删除后标签关联也被删除，先查找需清除相关文章缓存的文章
+    let mut related_ids = related::neighbor_ids(&state.db, &data).await;
+    related_ids.push(data.id);
+
+    if art::ArtModel::delete(&state.db, data.id).await.is_err() {
+        return Err(fault(FAULT_DATA, "删除失败"));
+    }
+
+    webhook::fire(&state.db, webhook::ART_DELETED, &data).await;
+
+    related::clear(&mut state.clone(), related_ids).await;
+
+    Ok(Value::Bool(true))
+}
+
+// metaWeblog.getCategories(blogid, username, password)
+async fn get_categories(req: &HttpRequest, state: &AppState, call: &MethodCall) -> RpcResult {
+    login(state, call, 1).await?;
+
+    let cates = cate::CateModel::find_open_cate(&state.db)
+        .await
+        .unwrap_or_default();
+
+    let list = cates
+        .into_iter()
+        .map(|c| {
+            let url = utils::url_for(req.clone(), "blog.cate-name", [c.slug.as_str()]);
+
+            Value::new_struct(vec![
+                ("categoryId", Value::String(c.id.to_string())),
+                ("parentId", Value::String(c.pid.to_string())),
+                ("title", Value::String(c.name.clone())),
+                ("categoryName", Value::String(c.name)),
+                ("description", Value::String(c.desc.unwrap_or_default())),
+                ("htmlUrl", Value::String(url)),
+                ("rssUrl", Value::String("".to_string())),
+            ])
+        })
+        .collect();
+
+    Ok(Value::Array(list))
+}
+
+// metaWeblog.newMediaObject(blogid, username, password, struct)
+async fn new_media_object(req: &HttpRequest, state: &AppState, call: &MethodCall) -> RpcResult {
+    login(state, call, 1).await?;
+
+    let media = call.param(3);
+
+    let name = media
+        .get("name")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+    let bits = media
+        .get("bits")
+        .and_then(|v| v.as_bytes())
+        .unwrap_or_default();
+    if name.is_empty() || bits.is_empty() {
+        return Err(fault(FAULT_DATA, "上传文件不能为空"));
+    }
+
+    // 只有图片可以直接访问
+    let file_name = utils::get_path_filename(name.as_str());
+    if !utils::is_image(utils::get_extension(file_name.as_str()).to_lowercase()) {
+        return Err(fault(FAULT_DATA, "只支持上传图片"));
+    }
+
+    let mut ip: String = "0.0.0.0".to_string();
+    if let Some(val) = req.peer_addr() {
+        ip = val.ip().to_string();
+    }
+
+    let data = upload::save_file(
+        &state.db,
+        file_name.as_str(),
+        bits,
+        upload::UploadType::Image,
+        ip.as_str(),
+    )
+    .await
+    .map_err(|e| fault(FAULT_DATA, e.as_str()))?;
+
+    let mut url = data.url;
+    if url.starts_with('/') {
+        url = format!("{}{}", host_url(req), url);
+    }
+
+    Ok(Value::new_struct(vec![
+        ("id", Value::String(data.id.to_string())),
+        ("file", Value::String(data.name)),
+        ("url", Value::String(url)),
+    ]))
+}
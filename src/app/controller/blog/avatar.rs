@@ -0,0 +1,35 @@
+use actix_web::{web, Error, HttpResponse, Result};
+
+use crate::nako::avatar;
+use crate::nako::global::AppState;
+
+use crate::app::service;
+
+// 评论者头像
+pub async fn index(
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+) -> Result<HttpResponse, Error> {
+    let hash = path.into_inner();
+    if !avatar::is_hash(hash.as_str()) {
+        return Ok(HttpResponse::NotFound().body("404 Not Found"));
+    }
+
+    // 不属于已有邮箱的哈希只生成 identicon，不请求也不缓存
+    let image = match service::avatar::find_email(&state.db, hash.as_str()).await {
+        Some(email) => service::avatar::get(hash.as_str(), email.as_str()).await,
+        None => {
+            let image = service::avatar::identicon(hash.as_str());
+
+            return Ok(HttpResponse::Ok()
+                .content_type(image.content_type)
+                .insert_header(("Cache-Control", "public, max-age=300"))
+                .body(image.data));
+        }
+    };
+
+    Ok(HttpResponse::Ok()
+        .content_type(image.content_type)
+        .insert_header(("Cache-Control", "public, max-age=86400"))
+        .body(image.data))
+}
@@ -1,10 +1,11 @@
-use actix_web::{web, Error, HttpRequest, HttpResponse, Result};
+use actix_web::{http::StatusCode, web, Error, HttpRequest, HttpResponse, Result};
 use std::collections::HashMap;
 
 use crate::nako::global::AppState;
-use crate::nako::{app, http as nako_http};
+use crate::nako::{app, http as nako_http, i18n, utils};
 
-use crate::app::model::{art, cate, tag};
+use crate::app::model::{art, art_tag, cate};
+use crate::app::service::{self, seo::Seo};
 
 /// 分类全部列表
 pub async fn index(
@@ -13,7 +14,7 @@ pub async fn index(
     web::Query(params): web::Query<HashMap<String, String>>,
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let mut page: u64 = 1;
     if let Some(p) = params.get("page") {
@@ -37,6 +38,7 @@ pub async fn index(
         title: title,
         tag: None,
         cate_id: None,
+        cate_ids: None,
         user_id: None,
         is_top: None,
         status: Some(1),
@@ -56,14 +58,23 @@ pub async fn index(
     let hot_arts = art::ArtModel::find_one_year_hot(db, 6)
         .await
         .unwrap_or_default();
-    let cates = cate::CateModel::find_open_cate(db)
+    let cates = cate::CateModel::find_open_tree(db)
         .await
         .unwrap_or_default();
-    let tags = tag::TagModel::find_open_tags(db, 6)
+    let tags = art_tag::ArtTagModel::find_tag_cloud(db, 6)
         .await
         .unwrap_or_default();
 
+    // 搜索结果不需要收录
+    let settings = service::setting::settings(&mut state.get_ref().clone()).await;
+    let seo = Seo::builder(&req, &settings)
+        .title(i18n::t("blog-all-arts").as_str())
+        .page(page, num_pages)
+        .noindex(keywords.as_str() != "")
+        .build();
+
     let mut ctx = nako_http::view_data();
+    ctx.insert("seo", &seo);
     ctx.insert("arts", &arts);
     ctx.insert("page", &page);
     ctx.insert("num_pages", &num_pages);
@@ -81,14 +92,15 @@ pub async fn index(
     ))
 }
 
-/// 根据名称查询分类
+/// 根据名称查询分类，支持 `tech/rust` 形式的路径
 pub async fn name(
+    req: HttpRequest,
     state: web::Data<AppState>,
     slug: web::Path<String>,
     web::Query(params): web::Query<HashMap<String, String>>,
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let mut page: u64 = 1;
     if let Some(p) = params.get("page") {
@@ -98,19 +110,33 @@ pub async fn name(
     let per_page = 6;
 
     // 分类
-    let cate_data = cate::CateModel::find_by_slug(db, slug.as_str())
+    let cate_data = cate::CateModel::find_by_path(db, slug.as_str())
         .await
         .unwrap_or_default()
         .unwrap_or_default();
-    if cate_data.id == 0 {
-        return Ok(app::error_html(&mut view, "分类不存在"));
+    if cate_data.id == 0 || cate_data.status != Some(1) {
+        return Ok(app::error_html_status(
+            &mut view,
+            &i18n::t("blog-cate-not-found"),
+            StatusCode::NOT_FOUND,
+        ));
     }
 
+    let all_cates = cate::CateModel::find_open_cate(db)
+        .await
+        .unwrap_or_default();
+    let breadcrumbs = cate::CateModel::breadcrumbs(&all_cates, cate_data.id);
+
+    // 包含子分类文章
+    let mut cate_ids = cate::CateModel::descendant_ids(&all_cates, cate_data.id);
+    cate_ids.push(cate_data.id);
+
     let search_where = art::ArtWhere {
         title: None,
         uuid: None,
         tag: None,
-        cate_id: Some(cate_data.id),
+        cate_id: None,
+        cate_ids: Some(cate_ids),
         user_id: None,
         is_top: None,
         status: Some(1),
@@ -122,19 +148,35 @@ pub async fn name(
     let hot_arts = art::ArtModel::find_one_year_hot(db, 6)
         .await
         .unwrap_or_default();
-    let cates = cate::CateModel::find_open_cate(db)
+    let cates = cate::CateModel::find_open_tree(db)
         .await
         .unwrap_or_default();
-    let tags = tag::TagModel::find_open_tags(db, 6)
+    let tags = art_tag::ArtTagModel::find_tag_cloud(db, 6)
         .await
         .unwrap_or_default();
 
+    let settings = service::setting::settings(&mut state.get_ref().clone()).await;
+    let mut seo = Seo::builder(&req, &settings)
+        .title(cate_data.name.as_str())
+        .description(cate_data.desc.as_deref())
+        .canonical("blog.cate-name", [slug.as_str()])
+        .page(page, num_pages)
+        .noindex(arts.is_empty());
+    for crumb in breadcrumbs.iter() {
+        seo = seo.breadcrumb(
+            crumb.cate.name.as_str(),
+            utils::url_for(req.clone(), "blog.cate-name", [crumb.path.as_str()]).as_str(),
+        );
+    }
+
     let mut ctx = nako_http::view_data();
+    ctx.insert("seo", &seo.build());
     ctx.insert("arts", &arts);
     ctx.insert("page", &page);
     ctx.insert("num_pages", &num_pages);
 
     ctx.insert("cate_data", &cate_data);
+    ctx.insert("breadcrumbs", &breadcrumbs);
 
     ctx.insert("hot_arts", &hot_arts);
     ctx.insert("cates", &cates);
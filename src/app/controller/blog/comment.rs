@@ -1,24 +1,26 @@
 use actix_web::{web, Error, HttpRequest, HttpResponse, Result};
+use sea_orm::TryIntoModel;
 use std::collections::HashMap;
 
 use crate::nako::global::{AppState, Deserialize, Validate};
-use crate::nako::{http as nako_http, time};
+use crate::nako::{http as nako_http, i18n, time};
 
 use crate::app::entity::{self, comment as comment_entity};
 use crate::app::model::{art, comment};
+use crate::app::service::{self, mail, webhook};
 
 #[derive(Debug, Validate, Deserialize, Clone)]
 pub struct CreateValidate {
-    #[validate(required(message = "文章ID丢失"))]
+    #[validate(required(message = "comment-uuid-required"))]
     uuid: Option<String>,
-    #[validate(required(message = "你的称呼不能为空"))]
+    #[validate(required(message = "form-username-required"))]
     username: Option<String>,
     #[validate(
-        required(message = "你的联系邮箱不能为空"),
-        email(message = "你的联系邮箱格式错误")
+        required(message = "form-email-required"),
+        email(message = "form-email-invalid")
     )]
     email: Option<String>,
-    #[validate(required(message = "你的留言内容不能为空"))]
+    #[validate(required(message = "form-content-required"))]
     content: Option<String>,
 }
 
@@ -46,6 +48,16 @@ pub async fn create(
     if let Some(c) = params.get("message") {
         content = c.parse::<String>().unwrap_or("".to_string());
     }
+    let mut reply_id: u32 = 0;
+    if let Some(r) = params.get("reply_id") {
+        reply_id = r.parse::<u32>().unwrap_or(0);
+    }
+
+    // 有回复时邮件通知
+    let notify = match params.get("notify") {
+        Some(n) if !n.is_empty() && n != "0" => 1,
+        _ => 0,
+    };
 
     let vali_data = CreateValidate {
         uuid: Some(uuid.clone()),
@@ -57,7 +69,7 @@ pub async fn create(
     let vali = vali_data.validate();
     if vali.is_err() {
         return Ok(nako_http::error_response_json(
-            format!("{}", vali.unwrap_err()).as_str(),
+            i18n::validate_message(&vali.unwrap_err()).as_str(),
         ));
     }
 
@@ -67,7 +79,27 @@ pub async fn create(
         .unwrap_or_default()
         .unwrap_or_default();
     if art.id == 0 {
-        return Ok(nako_http::error_response_json("文章不存在"));
+        return Ok(nako_http::error_response_json(&i18n::t(
+            "blog-art-not-found",
+        )));
+    }
+
+    let settings = service::setting::settings(&mut state.get_ref().clone()).await;
+    if service::comment::is_closed(&art, &settings) {
+        return Ok(nako_http::error_response_json(&i18n::t("comment-closed")));
+    }
+
+    // 只能回复同一文章已显示的评论
+    if reply_id > 0 {
+        let reply = comment::CommentModel::find_by_id(db, reply_id)
+            .await
+            .unwrap_or_default()
+            .unwrap_or_default();
+        if reply.art_id != art.id || reply.status != Some(1) {
+            return Ok(nako_http::error_response_json(&i18n::t(
+                "comment-reply-not-found",
+            )));
+        }
     }
 
     let add_time = time::now().timestamp();
@@ -77,23 +109,47 @@ pub async fn create(
         ip = val.ip().to_string();
     }
 
-    let create_data = comment::CommentModel::create(
-        db,
-        comment_entity::Model {
-            art_id: art.id,
-            username: username.clone(),
-            email: Some(email.clone()),
-            content: content.clone(),
-            status: Some(0),
-            add_time: Some(add_time),
-            add_ip: Some(ip.clone()),
-            ..entity::default()
-        },
-    )
-    .await;
-    if create_data.is_err() {
-        return Ok(nako_http::error_response_json("提交回复失败"));
+    let mut create_data = comment_entity::Model {
+        art_id: art.id,
+        reply_id: if reply_id > 0 { Some(reply_id) } else { None },
+        username: username.clone(),
+        email: Some(email.clone()),
+        content: content.clone(),
+        status: Some(comment::STATUS_PENDING),
+        notify,
+        add_time: Some(add_time),
+        add_ip: Some(ip.clone()),
+        ..entity::default()
+    };
+
+    // 按规则审核
+    let status = service::comment::moderate(db, &settings, &create_data).await;
+    create_data.status = Some(status);
+
+    let create_data = comment::CommentModel::create(db, create_data).await;
+
+    let data = match create_data.and_then(|v| v.try_into_model()) {
+        Ok(v) => v,
+        Err(_) => return Ok(nako_http::error_response_json(&i18n::t("comment-failed"))),
+    };
+
+    // 垃圾评论不通知
+    if status != comment::STATUS_SPAM {
+        mail::notify_comment(&state, &art, &data).await;
+        webhook::fire(db, webhook::COMMENT_CREATED, &data).await;
+    }
+
+    if status == comment::STATUS_APPROVED {
+        service::comment::approved(&state, &data).await;
+
+        return Ok(nako_http::success_response_json(
+            &i18n::t("comment-success"),
+            "",
+        ));
     }
 
-    Ok(nako_http::success_response_json("提交回复成功", ""))
+    Ok(nako_http::success_response_json(
+        &i18n::t("comment-pending"),
+        "",
+    ))
 }
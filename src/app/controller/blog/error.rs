@@ -1,25 +1,23 @@
-use actix_web::{http::Method, web, Error, HttpRequest, HttpResponse, Result};
+use actix_web::{
+    http::{Method, StatusCode},
+    web, Error, HttpRequest, HttpResponse, Result,
+};
 
 use crate::nako::global::AppState;
-use crate::nako::{app, http as nako_http};
+use crate::nako::{app, http as nako_http, i18n};
 
 /// 错误页面
 pub async fn index(req: HttpRequest, state: web::Data<AppState>) -> Result<HttpResponse, Error> {
-    let mut view = state.view.clone();
-
-    let error = "发生了错误，请重试";
+    let mut view = state.view.get();
 
     let method = req.method();
     if method == Method::POST {
-        return Ok(nako_http::error_response_json(error));
+        return Ok(nako_http::error_response_json(&i18n::t("error-retry")));
     }
 
-    let mut ctx = nako_http::view_data();
-    ctx.insert("message", &error.to_string());
-
-    Ok(nako_http::view(
+    Ok(app::error_html_status(
         &mut view,
-        app::view_path("error.html").as_str(),
-        &ctx,
+        &i18n::t("error-not-found"),
+        StatusCode::NOT_FOUND,
     ))
 }
@@ -1,22 +1,110 @@
-use actix_web::{web, Error, HttpRequest, HttpResponse, Result};
+use actix_web::{http::StatusCode, web, Error, HttpRequest, HttpResponse, Result};
+use sea_orm::TryIntoModel;
 use std::collections::HashMap;
 
-use crate::nako::global::{AppState, Deserialize, Validate};
-use crate::nako::{http as nako_http, time};
+use crate::nako::global::{AppState, Deserialize, Serialize, Validate};
+use crate::nako::{app, avatar, http as nako_http, i18n, time, utils};
 
 use crate::app::entity::{self, guestbook as guestbook_entity};
 use crate::app::model::guestbook;
+use crate::app::service::{self, mail, seo::Seo, webhook};
+
+/// 留言数据，不输出电话、邮箱、QQ、微信及IP
+#[derive(Serialize)]
+pub struct GuestbookItem {
+    id: u32,
+    name: String,
+    message: String,
+    avatar: String,
+    reply: Option<String>,
+    reply_time: i64,
+    add_time: Option<i64>,
+}
+
+impl From<guestbook_entity::Model> for GuestbookItem {
+    fn from(data: guestbook_entity::Model) -> Self {
+        Self {
+            id: data.id,
+            avatar: avatar::private_url(data.email.unwrap_or_default().as_str()),
+            name: data.name,
+            message: data.message,
+            reply: data.reply.filter(|v| !v.is_empty()),
+            reply_time: data.reply_time,
+            add_time: data.add_time,
+        }
+    }
+}
+
+/// 留言列表，后台开启后显示已审核的留言
+pub async fn index(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    web::Query(params): web::Query<HashMap<String, String>>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+    let mut view = state.view.get();
+
+    let settings = service::setting::settings(&mut state.get_ref().clone()).await;
+    if settings.get("guestbook_public").map(|v| v.as_str()) != Some("1") {
+        return Ok(app::error_html_status(
+            &mut view,
+            &i18n::t("error-not-found"),
+            StatusCode::NOT_FOUND,
+        ));
+    }
+
+    let mut page: u64 = 1;
+    if let Some(p) = params.get("page") {
+        page = p.parse::<u64>().unwrap_or(1).max(1);
+    }
+
+    let per_page = 10;
+
+    let (list, num_pages) = guestbook::GuestbookModel::find_approved_in_page(db, page, per_page)
+        .await
+        .unwrap_or_default();
+    let count = guestbook::GuestbookModel::find_approved_count(db)
+        .await
+        .unwrap_or(0);
+
+    let list = list
+        .into_iter()
+        .map(GuestbookItem::from)
+        .collect::<Vec<GuestbookItem>>();
+
+    let seo = Seo::builder(&req, &settings)
+        .title(i18n::t("blog-guestbook").as_str())
+        .page(page, num_pages)
+        .breadcrumb(
+            i18n::t("blog-guestbook").as_str(),
+            utils::url_for_static(req.clone(), "blog.guestbook-index").as_str(),
+        )
+        .build();
+
+    let mut ctx = nako_http::view_data();
+    ctx.insert("seo", &seo);
+    ctx.insert("list", &list);
+    ctx.insert("count", &count);
+    ctx.insert("page", &page);
+    ctx.insert("num_pages", &num_pages);
+
+    Ok(nako_http::view(
+        &mut view,
+        app::view_path("guestbook.html").as_str(),
+        &ctx,
+    ))
+}
 
 #[derive(Debug, Validate, Deserialize, Clone)]
 pub struct CreateValidate {
-    #[validate(required(message = "你的称呼不能为空"))]
+    #[validate(required(message = "form-username-required"))]
     name: Option<String>,
     #[validate(
-        required(message = "你的联系邮箱不能为空"),
-        email(message = "你的联系邮箱格式错误")
+        required(message = "form-email-required"),
+        email(message = "form-email-invalid")
     )]
     email: Option<String>,
-    #[validate(required(message = "你的留言内容不能为空"))]
+    #[validate(required(message = "form-content-required"))]
     message: Option<String>,
 }
 
@@ -50,7 +138,7 @@ pub async fn create(
     let vali = vali_data.validate();
     if vali.is_err() {
         return Ok(nako_http::error_response_json(
-            format!("{}", vali.unwrap_err()).as_str(),
+            i18n::validate_message(&vali.unwrap_err()).as_str(),
         ));
     }
 
@@ -74,9 +162,17 @@ pub async fn create(
         },
     )
     .await;
-    if create_data.is_err() {
-        return Ok(nako_http::error_response_json("提交留言失败"));
-    }
 
-    Ok(nako_http::success_response_json("提交留言成功", ""))
+    let data = match create_data.and_then(|v| v.try_into_model()) {
+        Ok(v) => v,
+        Err(_) => return Ok(nako_http::error_response_json(&i18n::t("guestbook-failed"))),
+    };
+
+    mail::notify_guestbook(&state, &data).await;
+    webhook::fire(db, webhook::GUESTBOOK_CREATED, &data).await;
+
+    Ok(nako_http::success_response_json(
+        &i18n::t("guestbook-success"),
+        "",
+    ))
 }
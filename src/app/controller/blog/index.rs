@@ -3,18 +3,19 @@ use actix_web::{web, Error, HttpResponse, Result};
 use crate::nako::global::AppState;
 use crate::nako::{app, http as nako_http};
 
-use crate::app::model::{art, cate, friendlink, tag};
+use crate::app::model::{art, art_tag, cate, friendlink};
 
 /// 首页
 pub async fn index(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let search_where = art::ArtWhere {
         title: None,
         uuid: None,
         tag: None,
         cate_id: None,
+        cate_ids: None,
         user_id: None,
         is_top: None,
         status: Some(1),
@@ -26,10 +27,10 @@ pub async fn index(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
     let hot_arts = art::ArtModel::find_one_year_hot(db, 6)
         .await
         .unwrap_or_default();
-    let cates = cate::CateModel::find_open_cate(db)
+    let cates = cate::CateModel::find_open_tree(db)
         .await
         .unwrap_or_default();
-    let tags = tag::TagModel::find_open_tags(db, 6)
+    let tags = art_tag::ArtTagModel::find_tag_cloud(db, 6)
         .await
         .unwrap_or_default();
 
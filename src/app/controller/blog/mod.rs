@@ -1,8 +1,10 @@
+pub mod avatar;
 pub mod cate;
 pub mod comment;
 pub mod error;
 pub mod guestbook;
 pub mod index;
 pub mod page;
+pub mod series;
 pub mod tag;
 pub mod view;
@@ -1,17 +1,19 @@
-use actix_web::{web, Error, HttpResponse, Result};
+use actix_web::{http::StatusCode, web, Error, HttpRequest, HttpResponse, Result};
 
 use crate::nako::global::AppState;
-use crate::nako::{app, http as nako_http};
+use crate::nako::{app, http as nako_http, i18n, utils};
 
 use crate::app::model::page;
+use crate::app::service::{self, seo::Seo};
 
 /// 单页
 pub async fn index(
+    req: HttpRequest,
     state: web::Data<AppState>,
     slug: web::Path<String>,
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     // 页面详情
     let data = page::PageModel::find_by_slug(db, slug.as_str())
@@ -19,10 +21,26 @@ pub async fn index(
         .unwrap_or_default()
         .unwrap_or_default();
     if data.id == 0 {
-        return Ok(app::error_html(&mut view, "文章不存在"));
+        return Ok(app::error_html_status(
+            &mut view,
+            &i18n::t("blog-art-not-found"),
+            StatusCode::NOT_FOUND,
+        ));
     }
 
+    let settings = service::setting::settings(&mut state.get_ref().clone()).await;
+    let seo = Seo::builder(&req, &settings)
+        .title(data.title.as_str())
+        .description(data.description.as_deref())
+        .canonical("blog.page-index", [data.slug.as_str()])
+        .breadcrumb(
+            data.title.as_str(),
+            utils::url_for(req.clone(), "blog.page-index", [data.slug.as_str()]).as_str(),
+        )
+        .build();
+
     let mut ctx = nako_http::view_data();
+    ctx.insert("seo", &seo);
     ctx.insert("data", &data);
 
     let tpl = match data.tpl {
@@ -0,0 +1,77 @@
+use actix_web::{http::StatusCode, web, Error, HttpRequest, HttpResponse, Result};
+
+use crate::nako::global::AppState;
+use crate::nako::{app, http as nako_http, i18n, utils};
+
+use crate::app::model::{art, art_tag, cate, series, series_art};
+use crate::app::service::{self, seo::Seo};
+
+/// 专题，按专题内顺序显示已发布的文章
+pub async fn index(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    slug: web::Path<String>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+    let mut view = state.view.get();
+
+    let series_data = series::SeriesModel::find_by_slug(db, slug.as_str())
+        .await
+        .unwrap_or_default()
+        .filter(|v| v.status == 1)
+        .unwrap_or_default();
+    if series_data.id == 0 {
+        return Ok(app::error_html_status(
+            &mut view,
+            &i18n::t("error-not-found"),
+            StatusCode::NOT_FOUND,
+        ));
+    }
+
+    let arts = series_art::SeriesArtModel::find_arts(db, series_data.id, Some(1))
+        .await
+        .unwrap_or_default();
+
+    let hot_arts = art::ArtModel::find_one_year_hot(db, 6)
+        .await
+        .unwrap_or_default();
+    let cates = cate::CateModel::find_open_tree(db)
+        .await
+        .unwrap_or_default();
+    let tags = art_tag::ArtTagModel::find_tag_cloud(db, 6)
+        .await
+        .unwrap_or_default();
+
+    let settings = service::setting::settings(&mut state.get_ref().clone()).await;
+    let seo = Seo::builder(&req, &settings)
+        .title(series_data.title.as_str())
+        .description(series_data.desc.as_deref())
+        .canonical("blog.series-index", [series_data.slug.as_str()])
+        .image(Some(series_data.cover.as_str()))
+        .noindex(arts.is_empty())
+        .breadcrumb(
+            series_data.title.as_str(),
+            utils::url_for(
+                req.clone(),
+                "blog.series-index",
+                [series_data.slug.as_str()],
+            )
+            .as_str(),
+        )
+        .build();
+
+    let mut ctx = nako_http::view_data();
+    ctx.insert("seo", &seo);
+    ctx.insert("series", &series_data);
+    ctx.insert("arts", &arts);
+
+    ctx.insert("hot_arts", &hot_arts);
+    ctx.insert("cates", &cates);
+    ctx.insert("tags", &tags);
+
+    Ok(nako_http::view(
+        &mut view,
+        app::view_path("series.html").as_str(),
+        &ctx,
+    ))
+}
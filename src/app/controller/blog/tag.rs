@@ -1,19 +1,22 @@
-use actix_web::{web, Error, HttpResponse, Result};
+use actix_web::{web, Error, HttpRequest, HttpResponse, Result};
+use fluent_bundle::FluentArgs;
 use std::collections::HashMap;
 
 use crate::nako::global::AppState;
-use crate::nako::{app, http as nako_http};
+use crate::nako::{app, http as nako_http, i18n, utils};
 
-use crate::app::model::{art, cate, tag};
+use crate::app::model::{art, art_tag, cate, tag};
+use crate::app::service::{self, seo::Seo};
 
 /// 根据名称查询分类
 pub async fn index(
+    req: HttpRequest,
     state: web::Data<AppState>,
     name: web::Path<String>,
     web::Query(params): web::Query<HashMap<String, String>>,
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let mut page: u64 = 1;
     if let Some(p) = params.get("page") {
@@ -27,6 +30,7 @@ pub async fn index(
         uuid: None,
         tag: Some(name.clone()),
         cate_id: None,
+        cate_ids: None,
         user_id: None,
         is_top: None,
         status: Some(1),
@@ -44,14 +48,32 @@ pub async fn index(
     let hot_arts = art::ArtModel::find_one_year_hot(db, 6)
         .await
         .unwrap_or_default();
-    let cates = cate::CateModel::find_open_cate(db)
+    let cates = cate::CateModel::find_open_tree(db)
         .await
         .unwrap_or_default();
-    let tags = tag::TagModel::find_open_tags(db, 6)
+    let tags = art_tag::ArtTagModel::find_tag_cloud(db, 6)
         .await
         .unwrap_or_default();
 
+    // 没有文章的标签不需要收录
+    let settings = service::setting::settings(&mut state.get_ref().clone()).await;
+    let mut args = FluentArgs::new();
+    args.set("name", name.to_string());
+    let tag_title = i18n::t_args("blog-tag", Some(&args));
+    let seo = Seo::builder(&req, &settings)
+        .title(tag_title.as_str())
+        .description(tag_data.desc.as_deref())
+        .canonical("blog.tag-index", [name.as_str()])
+        .page(page, num_pages)
+        .noindex(tag_data.id == 0 || arts.is_empty())
+        .breadcrumb(
+            tag_title.as_str(),
+            utils::url_for(req.clone(), "blog.tag-index", [name.as_str()]).as_str(),
+        )
+        .build();
+
     let mut ctx = nako_http::view_data();
+    ctx.insert("seo", &seo);
     ctx.insert("arts", &arts);
     ctx.insert("page", &page);
     ctx.insert("num_pages", &num_pages);
@@ -1,24 +1,22 @@
-use actix_web::{web, Error, HttpResponse, Result};
+use actix_web::{http::StatusCode, web, Error, HttpRequest, HttpResponse, Result};
 use std::collections::HashMap;
 
 use crate::nako::global::AppState;
-use crate::nako::{app, http as nako_http};
+use crate::nako::{app, http as nako_http, i18n, utils};
 
-use crate::app::model::{art, cate, comment, tag};
+use crate::app::entity::art as art_entity;
+use crate::app::model::{art, art_tag, cate, comment, series_art, user};
+use crate::app::service::{self, seo::Seo};
 
 /// 详情
 pub async fn index(
+    req: HttpRequest,
     state: web::Data<AppState>,
     uuid: web::Path<String>,
     web::Query(params): web::Query<HashMap<String, String>>,
 ) -> Result<HttpResponse, Error> {
     let db = &state.db;
-    let mut view = state.view.clone();
-
-    let mut page: u64 = 1;
-    if let Some(p) = params.get("page") {
-        page = p.parse::<u64>().unwrap_or(1);
-    }
+    let mut view = state.view.get();
 
     // 文章详情
     let art = art::ArtModel::find_by_uuid(db, uuid.as_str())
@@ -26,7 +24,52 @@ pub async fn index(
         .unwrap_or_default()
         .unwrap_or_default();
     if art.id == 0 {
-        return Ok(app::error_html(&mut view, "文章不存在"));
+        return Ok(app::error_html_status(
+            &mut view,
+            &i18n::t("blog-art-not-found"),
+            StatusCode::NOT_FOUND,
+        ));
+    }
+
+    render(req, state, art, params).await
+}
+
+/// 自定义链接的详情，日期或者分类不一致时跳转到正确的链接
+pub async fn permalink(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    path: web::Path<HashMap<String, String>>,
+    web::Query(params): web::Query<HashMap<String, String>>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+    let mut view = state.view.get();
+
+    match service::permalink::find(db, &path).await {
+        Some((art, true)) => render(req, state, art, params).await,
+        Some((art, false)) => Ok(nako_http::redirect_permanent(service::permalink::art_url(
+            &art,
+        ))),
+        None => Ok(app::error_html_status(
+            &mut view,
+            &i18n::t("blog-art-not-found"),
+            StatusCode::NOT_FOUND,
+        )),
+    }
+}
+
+// 渲染详情
+async fn render(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    art: art_entity::Model,
+    params: HashMap<String, String>,
+) -> Result<HttpResponse, Error> {
+    let db = &state.db;
+    let mut view = state.view.get();
+
+    let mut page: u64 = 1;
+    if let Some(p) = params.get("page") {
+        page = p.parse::<u64>().unwrap_or(1);
     }
 
     // 分类
@@ -35,6 +78,9 @@ pub async fn index(
         .unwrap_or_default()
         .unwrap_or_default();
 
+    let all_cates = cate::CateModel::find_all(db).await.unwrap_or_default();
+    let breadcrumbs = cate::CateModel::breadcrumbs(&all_cates, art.cate_id);
+
     // 回复
     let (comments, comments_num_pages) =
         comment::CommentModel::find_in_page_by_artid(db, art.id, page, 6)
@@ -44,14 +90,76 @@ pub async fn index(
         .await
         .unwrap_or(0);
 
+    // SEO
+    let settings = service::setting::settings(&mut state.get_ref().clone()).await;
+    let author = user::UserModel::find_user_by_id(db, art.user_id)
+        .await
+        .unwrap_or_default()
+        .map(|v| v.nickname)
+        .unwrap_or_default();
+
+    let mut seo = Seo::builder(&req, &settings)
+        .canonical_path(service::permalink::art_url(&art).as_str())
+        .article(&art, author.as_str());
+    for crumb in breadcrumbs.iter() {
+        seo = seo.breadcrumb(
+            crumb.cate.name.as_str(),
+            utils::url_for(req.clone(), "blog.cate-name", [crumb.path.as_str()]).as_str(),
+        );
+    }
+    seo = seo.breadcrumb(
+        art.title.as_str(),
+        service::permalink::art_url(&art).as_str(),
+    );
+
     let mut ctx = nako_http::view_data();
+    ctx.insert("seo", &seo.build());
     ctx.insert("art", &art);
     ctx.insert("cate_data", &cate_data);
+    ctx.insert("breadcrumbs", &breadcrumbs);
 
     ctx.insert("page", &page);
     ctx.insert("comments", &comments);
     ctx.insert("comments_count", &comments_count);
     ctx.insert("comments_num_pages", &comments_num_pages);
+    ctx.insert(
+        "comment_closed",
+        &service::comment::is_closed(&art, &settings),
+    );
+
+    // 专题导航
+    let series_nav = series_art::SeriesArtModel::find_nav_by_art_id(db, art.id)
+        .await
+        .unwrap_or_default();
+    ctx.insert("series_nav", &series_nav);
+
+    // 上一篇、下一篇
+    let prev_art = art::ArtModel::find_prev(db, &art, None)
+        .await
+        .unwrap_or_default();
+    let next_art = art::ArtModel::find_next(db, &art, None)
+        .await
+        .unwrap_or_default();
+    ctx.insert("prev_art", &prev_art);
+    ctx.insert("next_art", &next_art);
+
+    // 同分类的上一篇、下一篇
+    let mut cate_prev_art = None;
+    let mut cate_next_art = None;
+    if art.cate_id > 0 {
+        cate_prev_art = art::ArtModel::find_prev(db, &art, Some(art.cate_id))
+            .await
+            .unwrap_or_default();
+        cate_next_art = art::ArtModel::find_next(db, &art, Some(art.cate_id))
+            .await
+            .unwrap_or_default();
+    }
+    ctx.insert("cate_prev_art", &cate_prev_art);
+    ctx.insert("cate_next_art", &cate_next_art);
+
+    // 相关文章
+    let related_arts = service::related::find(&mut state.get_ref().clone(), &art).await;
+    ctx.insert("related_arts", &related_arts);
 
     if let Some(tags_string) = art.tags.clone() {
         let art_tags = tags_string.split(",").collect::<Vec<&str>>();
@@ -67,10 +175,10 @@ pub async fn index(
     let hot_arts = art::ArtModel::find_one_year_hot(db, 6)
         .await
         .unwrap_or_default();
-    let cates = cate::CateModel::find_open_cate(db)
+    let cates = cate::CateModel::find_open_tree(db)
         .await
         .unwrap_or_default();
-    let tags = tag::TagModel::find_open_tags(db, 6)
+    let tags = art_tag::ArtTagModel::find_tag_cloud(db, 6)
         .await
         .unwrap_or_default();
 
@@ -8,6 +8,7 @@ pub struct Model {
     #[serde(skip_deserializing)]
     pub id: u32,
     pub uuid: String,
+    pub slug: Option<String>,
     pub cate_id: u32,
     pub user_id: u32,
     pub title: String,
@@ -19,8 +20,9 @@ pub struct Model {
     pub brief: Option<String>,
     pub tags: Option<String>,
     pub from: Option<String>,
-    pub views: Option<u64>,
+    pub views: Option<i64>,
     pub is_top: Option<i32>,
+    pub comment_closed: i32,
     pub status: Option<i32>,
     pub add_time: Option<i64>,
     pub add_ip: Option<String>,
@@ -54,4 +56,14 @@ impl Related<super::user::Entity> for Entity {
     }
 }
 
+impl Related<super::tag::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::art_tag::Relation::Tag.def()
+    }
+
+    fn via() -> Option<RelationDef> {
+        Some(super::art_tag::Relation::Art.def().rev())
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,41 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "nako_art_tag")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub art_id: u32,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub tag_id: u32,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::art::Entity",
+        from = "Column::ArtId",
+        to = "super::art::Column::Id"
+    )]
+    Art,
+    #[sea_orm(
+        belongs_to = "super::tag::Entity",
+        from = "Column::TagId",
+        to = "super::tag::Column::Id"
+    )]
+    Tag,
+}
+
+impl Related<super::art::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Art.def()
+    }
+}
+
+impl Related<super::tag::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Tag.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -10,7 +10,7 @@ pub struct Model {
     pub name: String,
     pub path: String,
     pub ext: String,
-    pub size: u64,
+    pub size: i64,
     pub md5: String,
     pub r#type: i32,
     pub status: i32,
@@ -14,6 +14,7 @@ pub struct Model {
     #[sea_orm(column_type = "Text")]
     pub content: String,
     pub status: Option<i32>,
+    pub notify: i32,
     pub add_time: Option<i64>,
     pub add_ip: Option<String>,
 }
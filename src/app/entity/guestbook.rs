@@ -13,6 +13,9 @@ pub struct Model {
     pub email: Option<String>,
     pub qq: Option<String>,
     pub weixin: Option<String>,
+    pub reply: Option<String>,
+    pub reply_time: i64,
+    pub approved: i32,
     pub status: Option<i32>,
     pub add_time: Option<i64>,
     pub add_ip: Option<String>,
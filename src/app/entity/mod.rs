@@ -1,13 +1,26 @@
 pub mod art;
+pub mod art_tag;
 pub mod attach;
 pub mod cate;
 pub mod comment;
+pub mod comment_rule;
 pub mod friendlink;
 pub mod guestbook;
+pub mod mail;
+pub mod not_found;
+pub mod not_found_referrer;
 pub mod page;
+pub mod redirect;
+pub mod series;
+pub mod series_art;
 pub mod setting;
 pub mod tag;
+pub mod template;
+pub mod template_version;
 pub mod user;
+pub mod user_token;
+pub mod webhook;
+pub mod webhook_log;
 
 #[inline]
 pub fn default<T: Default>() -> T {
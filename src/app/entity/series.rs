@@ -0,0 +1,31 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "nako_series")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    #[serde(skip_deserializing)]
+    pub id: u32,
+    pub title: String,
+    pub slug: String,
+    pub desc: Option<String>,
+    pub cover: String,
+    pub sort: i32,
+    pub status: i32,
+    pub add_time: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::series_art::Entity")]
+    SeriesArt,
+}
+
+impl Related<super::series_art::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SeriesArt.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
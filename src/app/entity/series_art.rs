@@ -0,0 +1,42 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "nako_series_art")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub series_id: u32,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub art_id: u32,
+    pub sort: i32,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::series::Entity",
+        from = "Column::SeriesId",
+        to = "super::series::Column::Id"
+    )]
+    Series,
+    #[sea_orm(
+        belongs_to = "super::art::Entity",
+        from = "Column::ArtId",
+        to = "super::art::Column::Id"
+    )]
+    Art,
+}
+
+impl Related<super::series::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Series.def()
+    }
+}
+
+impl Related<super::art::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Art.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
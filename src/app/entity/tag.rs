@@ -16,6 +16,25 @@ pub struct Model {
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
-pub enum Relation {}
+pub enum Relation {
+    #[sea_orm(has_many = "super::art_tag::Entity")]
+    ArtTag,
+}
+
+impl Related<super::art_tag::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ArtTag.def()
+    }
+}
+
+impl Related<super::art::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::art_tag::Relation::Art.def()
+    }
+
+    fn via() -> Option<RelationDef> {
+        Some(super::art_tag::Relation::Tag.def().rev())
+    }
+}
 
 impl ActiveModelBehavior for ActiveModel {}
@@ -10,6 +10,7 @@ pub struct Model {
     pub username: String,
     pub password: Option<String>,
     pub nickname: String,
+    pub email: String,
     pub avatar: Option<String>,
     pub sign: Option<String>,
     pub status: Option<i32>,
@@ -0,0 +1,26 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "nako_webhook_log")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    #[serde(skip_deserializing)]
+    pub id: u32,
+    pub webhook_id: u32,
+    pub event: String,
+    #[sea_orm(column_type = "Text")]
+    pub payload: String,
+    pub status: i32,
+    pub attempts: i32,
+    pub response_code: i32,
+    pub response: String,
+    pub next_time: i64,
+    pub send_time: i64,
+    pub add_time: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -4,6 +4,7 @@ use actix_web_lab::middleware::Next;
 
 use crate::nako::global::AppState;
 use crate::nako::http;
+use crate::nako::i18n;
 use crate::nako::utils;
 
 use crate::app::service;
@@ -33,7 +34,7 @@ pub async fn auth(
     }
 
     let state = req.app_data::<web::Data<AppState>>().unwrap();
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let session = req.get_session();
 
@@ -42,7 +43,7 @@ pub async fn auth(
         .unwrap_or_default()
         .unwrap_or_default();
     if login_id <= 0 {
-        let message = "请先登陆";
+        let message = &i18n::t("admin-login-required");
 
         let url: String = utils::url_for_static(req.request().clone(), "admin.auth-login");
 
@@ -0,0 +1,101 @@
+use actix_web::{
+    body::BoxBody, dev, dev::ServiceRequest, http::header, web, Error, HttpMessage, HttpResponse,
+};
+use actix_web_lab::middleware::Next;
+use fluent_bundle::FluentArgs;
+
+use crate::nako::global::AppState;
+use crate::nako::http;
+use crate::nako::i18n;
+
+use crate::app::model::{user, user_token};
+
+/// 令牌授权信息
+#[derive(Clone)]
+pub struct ApiAuth {
+    pub user_id: u32,
+    pub token_id: u32,
+    pub scopes: Vec<String>,
+}
+
+impl ApiAuth {
+    /// 检测权限
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.iter().any(|v| v == scope)
+    }
+
+    /// 没有权限时返回错误响应
+    pub fn check_scope(&self, scope: &str) -> Option<HttpResponse> {
+        if self.has_scope(scope) {
+            return None;
+        }
+
+        let mut args = FluentArgs::new();
+        args.set("scope", scope.to_string());
+
+        Some(http::error_response_json(
+            i18n::t_args("api-token-no-scope", Some(&args)).as_str(),
+        ))
+    }
+}
+
+// 获取请求令牌
+fn bearer_token(req: &ServiceRequest) -> Option<String> {
+    let value = req.headers().get(header::AUTHORIZATION)?.to_str().ok()?;
+
+    let token = value.strip_prefix("Bearer ")?.trim();
+    if token.is_empty() {
+        return None;
+    }
+
+    Some(token.to_string())
+}
+
+//  令牌检测
+pub async fn auth(
+    req: ServiceRequest,
+    next: Next<BoxBody>,
+) -> Result<dev::ServiceResponse<BoxBody>, Error> {
+    let token = match bearer_token(&req) {
+        Some(v) => v,
+        None => {
+            return Ok(req.into_response(http::error_response_json(&i18n::t("api-token-required"))))
+        }
+    };
+
+    let state = req.app_data::<web::Data<AppState>>().unwrap();
+    let db = &state.db;
+
+    let token_data = user_token::UserTokenModel::find_by_token(db, token.as_str())
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if token_data.id == 0 {
+        return Ok(req.into_response(http::error_response_json(&i18n::t("api-token-invalid"))));
+    }
+
+    let user_data = user::UserModel::find_user_by_id(db, token_data.user_id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if user_data.id == 0 || user_data.status != Some(1) {
+        return Ok(req.into_response(http::error_response_json(&i18n::t("auth-user-disabled"))));
+    }
+
+    let _ = user_token::UserTokenModel::update_used_time(db, token_data.id).await;
+
+    let scopes = token_data
+        .scopes
+        .split(',')
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .collect();
+
+    req.extensions_mut().insert(ApiAuth {
+        user_id: user_data.id,
+        token_id: token_data.id,
+        scopes,
+    });
+
+    next.call(req).await
+}
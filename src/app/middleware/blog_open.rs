@@ -3,7 +3,7 @@ use actix_web_lab::middleware::Next;
 
 use crate::app::service;
 use crate::app::service::setting;
-use crate::nako::{global::AppState, http as nako_http};
+use crate::nako::{global::AppState, http as nako_http, i18n};
 
 //  检测网站是否开启
 pub async fn check(
@@ -11,7 +11,7 @@ pub async fn check(
     next: Next<BoxBody>,
 ) -> Result<dev::ServiceResponse<BoxBody>, Error> {
     let state = req.app_data::<web::Data<AppState>>().unwrap();
-    let mut view = state.view.clone();
+    let mut view = state.view.get();
 
     let setting_data = setting::settings(&mut state.get_ref().clone()).await;
 
@@ -24,7 +24,7 @@ pub async fn check(
         return next.call(req).await;
     }
 
-    let error = "网站关闭维护中...";
+    let error = &i18n::t("site-closed");
 
     let method = req.method();
     if method == Method::POST {
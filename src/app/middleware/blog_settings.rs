@@ -1,7 +1,8 @@
+use actix_session::SessionExt;
 use actix_web::{body::BoxBody, dev, dev::ServiceRequest, web, Error};
 use actix_web_lab::middleware::Next;
 
-use crate::nako::{global::AppState, view as nako_view};
+use crate::nako::{global::AppState, theme, view as nako_view};
 
 use crate::app::service::setting;
 
@@ -12,7 +13,22 @@ pub async fn settings(
 ) -> Result<dev::ServiceResponse<BoxBody>, Error> {
     let state = req.app_data::<web::Data<AppState>>().unwrap();
 
-    let setting_data = setting::settings(&mut state.get_ref().clone()).await;
+    let mut setting_data = setting::settings(&mut state.get_ref().clone()).await;
+
+    // 登陆后可预览主题
+    let session = req.get_session();
+    let login_id = session
+        .get::<u32>("login_id")
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if login_id > 0 {
+        if let Some(name) = session
+            .get::<String>(theme::PREVIEW_KEY)
+            .unwrap_or_default()
+        {
+            setting_data.insert(theme::SETTING_KEY.to_string(), name);
+        }
+    }
 
     nako_view::SETTINGS.with(|states| {
         states.borrow_mut().replace(setting_data.clone());
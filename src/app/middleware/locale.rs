@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+
+use actix_web::{
+    body::BoxBody,
+    cookie::{time::Duration, Cookie},
+    dev,
+    dev::ServiceRequest,
+    http::header,
+    web, Error,
+};
+use actix_web_lab::middleware::Next;
+use serde::Deserialize;
+
+use crate::nako::{global::AppState, i18n};
+
+use crate::app::service::setting;
+
+#[derive(Deserialize)]
+struct LangQuery {
+    lang: Option<String>,
+}
+
+//  当前请求的语言
+pub async fn locale(
+    req: ServiceRequest,
+    next: Next<BoxBody>,
+) -> Result<dev::ServiceResponse<BoxBody>, Error> {
+    let state = req.app_data::<web::Data<AppState>>().unwrap();
+
+    let setting_data = setting::settings(&mut state.get_ref().clone()).await;
+
+    // 链接中的 lang 参数切换语言并保存到 cookie
+    let query_lang = query_lang(&req);
+    let locale = request_locale(&req, &setting_data);
+
+    let mut res = i18n::scope(locale.clone(), next.call(req)).await?;
+
+    if let Some(lang) = query_lang {
+        let cookie = Cookie::build(i18n::COOKIE_NAME, lang)
+            .path("/")
+            .max_age(Duration::days(365))
+            .finish();
+        res.response_mut().add_cookie(&cookie)?;
+    }
+
+    if let Ok(v) = header::HeaderValue::from_str(locale.as_str()) {
+        res.headers_mut().insert(header::CONTENT_LANGUAGE, v);
+    }
+
+    Ok(res)
+}
+
+// 链接中的语言
+fn query_lang(req: &ServiceRequest) -> Option<String> {
+    web::Query::<LangQuery>::from_query(req.query_string())
+        .ok()
+        .and_then(|q| q.into_inner().lang)
+        .and_then(|v| i18n::find(v.as_str()))
+}
+
+/// 请求的语言，依次为链接参数、cookie、网站设置及浏览器语言
+pub fn request_locale(req: &ServiceRequest, settings: &HashMap<String, String>) -> String {
+    if let Some(v) = query_lang(req) {
+        return v;
+    }
+
+    let cookie_lang = req.cookie(i18n::COOKIE_NAME).map(|v| v.value().to_string());
+
+    let accept_language = req
+        .headers()
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string());
+
+    i18n::resolve(
+        cookie_lang.as_deref(),
+        settings.get(i18n::SETTING_KEY).map(|v| v.as_str()),
+        accept_language.as_deref(),
+    )
+}
@@ -1,3 +1,6 @@
 pub mod admin_auth;
+pub mod api_auth;
 pub mod blog_settings;
 pub mod blog_open;
+pub mod locale;
+pub mod redirect;
@@ -0,0 +1,81 @@
+use actix_web::{
+    body::BoxBody,
+    dev,
+    dev::ServiceRequest,
+    http::{header, Method, StatusCode},
+    web, Error, HttpResponse,
+};
+use actix_web_lab::middleware::Next;
+
+use crate::nako::{app, config, global::AppState, i18n, view as nako_view};
+
+use crate::app::middleware::locale;
+use crate::app::service::{redirect, setting};
+
+//  路由前匹配跳转规则，并记录 404 链接
+pub async fn redirect(
+    req: ServiceRequest,
+    next: Next<BoxBody>,
+) -> Result<dev::ServiceResponse<BoxBody>, Error> {
+    if req.method() != Method::GET && req.method() != Method::HEAD {
+        return next.call(req).await;
+    }
+
+    // 后台不处理
+    let admin_prefix = format!(
+        "/{}",
+        config::section::<String>("app", "admin_prefix", "admin".to_string())
+    );
+    let path = req.path().to_string();
+    if path == admin_prefix || path.starts_with(format!("{}/", admin_prefix).as_str()) {
+        return next.call(req).await;
+    }
+
+    let state = req.app_data::<web::Data<AppState>>().unwrap().clone();
+
+    if let Some(matched) = redirect::find(path.as_str()) {
+        redirect::hit(state.db.clone(), matched.id);
+
+        let res = if matched.code == redirect::CODE_GONE {
+            gone(&req, &state).await
+        } else {
+            redirect::response(&matched, req.query_string())
+        };
+
+        return Ok(req.into_response(res));
+    }
+
+    let res = next.call(req).await?;
+
+    if res.status() == StatusCode::NOT_FOUND {
+        let referrer = res
+            .request()
+            .headers()
+            .get(header::REFERER)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+
+        redirect::log_not_found(state.db.clone(), path.as_str(), referrer.as_str());
+    }
+
+    Ok(res)
+}
+
+// 已删除页面
+async fn gone(req: &ServiceRequest, state: &web::Data<AppState>) -> HttpResponse {
+    let setting_data = setting::settings(&mut state.get_ref().clone()).await;
+
+    nako_view::SETTINGS.with(|states| {
+        states.borrow_mut().replace(setting_data.clone());
+    });
+
+    let lang = locale::request_locale(req, &setting_data);
+
+    i18n::scope(lang, async {
+        let mut view = state.view.get();
+
+        app::error_html_status(&mut view, &i18n::t("error-gone"), StatusCode::GONE)
+    })
+    .await
+}
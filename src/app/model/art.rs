@@ -1,14 +1,34 @@
 use sea_orm::*;
-use sea_query::Expr;
+use sea_query::{Expr, Query, SimpleExpr};
 
-use crate::app::entity::{art, art::Entity as Art, cate, cate::Entity as Cate};
+use crate::app::entity::{art, art::Entity as Art, art_tag, cate, cate::Entity as Cate, tag};
+use crate::app::model::art_tag::ArtTagModel;
+use crate::app::model::redirect::RedirectModel;
+use crate::app::model::series_art::SeriesArtModel;
 use crate::nako::time;
 
+// 标签条件
+fn tag_condition(name: String) -> SimpleExpr {
+    art::Column::Id.in_subquery(
+        Query::select()
+            .column((art_tag::Entity, art_tag::Column::ArtId))
+            .from(art_tag::Entity)
+            .inner_join(
+                tag::Entity,
+                Expr::col((tag::Entity, tag::Column::Id))
+                    .equals((art_tag::Entity, art_tag::Column::TagId)),
+            )
+            .and_where(Expr::col((tag::Entity, tag::Column::Name)).eq(name))
+            .to_owned(),
+    )
+}
+
 /// 条件
 #[derive(Clone)]
 pub struct ArtWhere {
     pub uuid: Option<String>,
     pub cate_id: Option<u32>,
+    pub cate_ids: Option<Vec<u32>>,
     pub user_id: Option<u32>,
     pub title: Option<String>,
     pub tag: Option<String>,
@@ -49,6 +69,7 @@ impl ArtWhere {
             tag: tag,
             uuid: uuid,
             cate_id: None,
+            cate_ids: None,
             user_id: None,
             is_top: is_top,
             status: status,
@@ -56,6 +77,15 @@ impl ArtWhere {
     }
 }
 
+/// 文章标题
+#[derive(Clone, FromQueryResult)]
+pub struct ArtTitle {
+    pub id: u32,
+    pub cate_id: u32,
+    pub title: String,
+    pub add_time: Option<i64>,
+}
+
 pub struct ArtModel;
 
 impl ArtModel {
@@ -63,6 +93,18 @@ impl ArtModel {
         Art::find_by_id(id).one(db).await
     }
 
+    pub async fn find_by_slug(db: &DbConn, slug: &str) -> Result<Option<art::Model>, DbErr> {
+        Art::find().filter(art::Column::Slug.eq(slug)).one(db).await
+    }
+
+    /// 分类下的全部文章
+    pub async fn find_by_cate_ids(db: &DbConn, cate_ids: Vec<u32>) -> Result<Vec<art::Model>, DbErr> {
+        Art::find()
+            .filter(art::Column::CateId.is_in(cate_ids))
+            .all(db)
+            .await
+    }
+
     pub async fn find_by_uuid(db: &DbConn, uuid: &str) -> Result<Option<art::Model>, DbErr> {
         Art::find().filter(art::Column::Uuid.eq(uuid)).one(db).await
     }
@@ -84,6 +126,10 @@ impl ArtModel {
         paginator.fetch_page(page - 1).await.map(|p| (p, num_pages))
     }
 
+    pub async fn find_all(db: &DbConn) -> Result<Vec<art::Model>, DbErr> {
+        Art::find().order_by_asc(art::Column::Id).all(db).await
+    }
+
     // 搜索
     pub async fn search_count(db: &DbConn, wheres: ArtWhere) -> Result<u64, DbErr> {
         Art::find()
@@ -93,15 +139,16 @@ impl ArtModel {
             .apply_if(wheres.cate_id, |query, v| {
                 query.filter(art::Column::CateId.eq(v))
             })
+            .apply_if(wheres.cate_ids, |query, v| {
+                query.filter(art::Column::CateId.is_in(v))
+            })
             .apply_if(wheres.user_id, |query, v| {
                 query.filter(art::Column::UserId.eq(v))
             })
             .apply_if(wheres.title, |query, v| {
                 query.filter(art::Column::Title.contains(format!("%{}%", v).as_str()))
             })
-            .apply_if(wheres.tag, |query, v| {
-                query.filter(Expr::cust_with_values("FIND_IN_SET(?, `tags`)", [v]))
-            })
+            .apply_if(wheres.tag, |query, v| query.filter(tag_condition(v)))
             .apply_if(wheres.is_top, |query, v| {
                 query.filter(art::Column::IsTop.eq(v))
             })
@@ -125,15 +172,16 @@ impl ArtModel {
             .apply_if(wheres.cate_id, |query, v| {
                 query.filter(art::Column::CateId.eq(v))
             })
+            .apply_if(wheres.cate_ids, |query, v| {
+                query.filter(art::Column::CateId.is_in(v))
+            })
             .apply_if(wheres.user_id, |query, v| {
                 query.filter(art::Column::UserId.eq(v))
             })
             .apply_if(wheres.title, |query, v| {
                 query.filter(art::Column::Title.contains(format!("%{}%", v).as_str()))
             })
-            .apply_if(wheres.tag, |query, v| {
-                query.filter(Expr::cust_with_values("FIND_IN_SET(?, `tags`)", [v]))
-            })
+            .apply_if(wheres.tag, |query, v| query.filter(tag_condition(v)))
             .apply_if(wheres.is_top, |query, v| {
                 query.filter(art::Column::IsTop.eq(v))
             })
@@ -161,15 +209,16 @@ impl ArtModel {
             .apply_if(wheres.cate_id, |query, v| {
                 query.filter(art::Column::CateId.eq(v))
             })
+            .apply_if(wheres.cate_ids, |query, v| {
+                query.filter(art::Column::CateId.is_in(v))
+            })
             .apply_if(wheres.user_id, |query, v| {
                 query.filter(art::Column::UserId.eq(v))
             })
             .apply_if(wheres.title, |query, v| {
                 query.filter(art::Column::Title.contains(format!("%{}%", v).as_str()))
             })
-            .apply_if(wheres.tag, |query, v| {
-                query.filter(Expr::cust_with_values("FIND_IN_SET(?, `tags`)", [v]))
-            })
+            .apply_if(wheres.tag, |query, v| query.filter(tag_condition(v)))
             .apply_if(wheres.is_top, |query, v| {
                 query.filter(art::Column::IsTop.eq(v))
             })
@@ -193,15 +242,16 @@ impl ArtModel {
             .apply_if(wheres.cate_id, |query, v| {
                 query.filter(art::Column::CateId.eq(v))
             })
+            .apply_if(wheres.cate_ids, |query, v| {
+                query.filter(art::Column::CateId.is_in(v))
+            })
             .apply_if(wheres.user_id, |query, v| {
                 query.filter(art::Column::UserId.eq(v))
             })
             .apply_if(wheres.title, |query, v| {
                 query.filter(art::Column::Title.contains(format!("%{}%", v).as_str()))
             })
-            .apply_if(wheres.tag, |query, v| {
-                query.filter(Expr::cust_with_values("FIND_IN_SET(?, `tags`)", [v]))
-            })
+            .apply_if(wheres.tag, |query, v| query.filter(tag_condition(v)))
             .apply_if(wheres.is_top, |query, v| {
                 query.filter(art::Column::IsTop.eq(v))
             })
@@ -226,15 +276,16 @@ impl ArtModel {
             .apply_if(wheres.cate_id, |query, v| {
                 query.filter(art::Column::CateId.eq(v))
             })
+            .apply_if(wheres.cate_ids, |query, v| {
+                query.filter(art::Column::CateId.is_in(v))
+            })
             .apply_if(wheres.user_id, |query, v| {
                 query.filter(art::Column::UserId.eq(v))
             })
             .apply_if(wheres.title, |query, v| {
                 query.filter(art::Column::Title.contains(format!("%{}%", v).as_str()))
             })
-            .apply_if(wheres.tag, |query, v| {
-                query.filter(Expr::cust_with_values("FIND_IN_SET(?, `tags`)", [v]))
-            })
+            .apply_if(wheres.tag, |query, v| query.filter(tag_condition(v)))
             .apply_if(wheres.is_top, |query, v| {
                 query.filter(art::Column::IsTop.eq(v))
             })
@@ -266,9 +317,84 @@ impl ArtModel {
         paginator.fetch_page(0).await.map(|p| p)
     }
 
+    /// 上一篇，发布时间更早的已发布文章，可限定分类
+    pub async fn find_prev(
+        db: &DbConn,
+        art: &art::Model,
+        cate_id: Option<u32>,
+    ) -> Result<Option<art::Model>, DbErr> {
+        let add_time = art.add_time.unwrap_or_default();
+
+        Art::find()
+            .filter(art::Column::Status.eq(1))
+            .filter(
+                Condition::any().add(art::Column::AddTime.lt(add_time)).add(
+                    Condition::all()
+                        .add(art::Column::AddTime.eq(add_time))
+                        .add(art::Column::Id.lt(art.id)),
+                ),
+            )
+            .apply_if(cate_id, |query, v| query.filter(art::Column::CateId.eq(v)))
+            .order_by_desc(art::Column::AddTime)
+            .order_by_desc(art::Column::Id)
+            .one(db)
+            .await
+    }
+
+    /// 下一篇，发布时间更晚的已发布文章，可限定分类
+    pub async fn find_next(
+        db: &DbConn,
+        art: &art::Model,
+        cate_id: Option<u32>,
+    ) -> Result<Option<art::Model>, DbErr> {
+        let add_time = art.add_time.unwrap_or_default();
+
+        Art::find()
+            .filter(art::Column::Status.eq(1))
+            .filter(
+                Condition::any().add(art::Column::AddTime.gt(add_time)).add(
+                    Condition::all()
+                        .add(art::Column::AddTime.eq(add_time))
+                        .add(art::Column::Id.gt(art.id)),
+                ),
+            )
+            .apply_if(cate_id, |query, v| query.filter(art::Column::CateId.eq(v)))
+            .order_by_asc(art::Column::AddTime)
+            .order_by_asc(art::Column::Id)
+            .one(db)
+            .await
+    }
+
+    /// 已发布文章的标题，用于计算相关文章
+    pub async fn find_published_titles(db: &DbConn) -> Result<Vec<ArtTitle>, DbErr> {
+        Art::find()
+            .select_only()
+            .column(art::Column::Id)
+            .column(art::Column::CateId)
+            .column(art::Column::Title)
+            .column(art::Column::AddTime)
+            .filter(art::Column::Status.eq(1))
+            .into_model::<ArtTitle>()
+            .all(db)
+            .await
+    }
+
+    /// 根据 ID 查询已发布的文章
+    pub async fn find_published_by_ids(
+        db: &DbConn,
+        ids: Vec<u32>,
+    ) -> Result<Vec<art::Model>, DbErr> {
+        Art::find()
+            .filter(art::Column::Id.is_in(ids))
+            .filter(art::Column::Status.eq(1))
+            .all(db)
+            .await
+    }
+
     pub async fn create(db: &DbConn, form_data: art::Model) -> Result<art::ActiveModel, DbErr> {
-        art::ActiveModel {
+        let res = art::ActiveModel {
             uuid: Set(form_data.uuid.to_owned()),
+            slug: Set(form_data.slug.to_owned()),
             cate_id: Set(form_data.cate_id.to_owned()),
             user_id: Set(form_data.user_id.to_owned()),
             cover: Set(form_data.cover.to_owned()),
@@ -281,13 +407,19 @@ impl ArtModel {
             from: Set(form_data.from.to_owned()),
             views: Set(form_data.views.to_owned()),
             is_top: Set(form_data.is_top.to_owned()),
+            comment_closed: Set(form_data.comment_closed),
             status: Set(form_data.status.to_owned()),
             add_time: Set(form_data.add_time.to_owned()),
             add_ip: Set(form_data.add_ip.to_owned()),
             ..Default::default()
         }
         .save(db)
-        .await
+        .await?;
+
+        let data = res.clone().try_into_model()?;
+        ArtTagModel::sync(db, data.id, data.tags.as_deref()).await?;
+
+        Ok(res)
     }
 
     pub async fn update_by_id(
@@ -301,8 +433,9 @@ impl ArtModel {
             .ok_or(DbErr::Custom("Cannot find art.".to_owned()))
             .map(Into::into)?;
 
-        art::ActiveModel {
+        let data = art::ActiveModel {
             id: art.id,
+            slug: Set(form_data.slug.to_owned()),
             cate_id: Set(form_data.cate_id.to_owned()),
             title: Set(form_data.title.to_owned()),
             keywords: Set(form_data.keywords.to_owned()),
@@ -313,12 +446,17 @@ impl ArtModel {
             tags: Set(form_data.tags.to_owned()),
             from: Set(form_data.from.to_owned()),
             is_top: Set(form_data.is_top.to_owned()),
+            comment_closed: Set(form_data.comment_closed),
             status: Set(form_data.status.to_owned()),
             add_time: Set(form_data.add_time.to_owned()),
             ..Default::default()
         }
         .update(db)
-        .await
+        .await?;
+
+        ArtTagModel::sync(db, data.id, data.tags.as_deref()).await?;
+
+        Ok(data)
     }
 
     pub async fn update_status_by_id(
@@ -342,7 +480,7 @@ impl ArtModel {
     }
 
     /// 添加阅读量
-    pub async fn view_add(db: &DbConn, id: u32, num: u64) -> Result<art::Model, DbErr> {
+    pub async fn view_add(db: &DbConn, id: u32, num: i64) -> Result<art::Model, DbErr> {
         let art: art::Model = Art::find_by_id(id)
             .one(db)
             .await
@@ -363,6 +501,20 @@ impl ArtModel {
         .await
     }
 
+    /// 转移分类下的文章
+    pub async fn update_cate_by_cate_id(
+        db: &DbConn,
+        cate_id: u32,
+        new_cate_id: u32,
+    ) -> Result<u64, DbErr> {
+        Art::update_many()
+            .col_expr(art::Column::CateId, Expr::value(new_cate_id))
+            .filter(art::Column::CateId.eq(cate_id))
+            .exec(db)
+            .await
+            .map(|res| res.rows_affected)
+    }
+
     pub async fn delete(db: &DbConn, id: u32) -> Result<DeleteResult, DbErr> {
         let art: art::ActiveModel = Art::find_by_id(id)
             .one(db)
@@ -370,10 +522,17 @@ impl ArtModel {
             .ok_or(DbErr::Custom("Cannot find art.".to_owned()))
             .map(Into::into)?;
 
+        ArtTagModel::delete_by_art_id(db, id).await?;
+        RedirectModel::delete_by_art_id(db, id).await?;
+        SeriesArtModel::delete_by_art_id(db, id).await?;
+
         art.delete(db).await
     }
 
     pub async fn delete_all(db: &DbConn) -> Result<DeleteResult, DbErr> {
+        ArtTagModel::delete_all(db).await?;
+        SeriesArtModel::delete_all(db).await?;
+
         Art::delete_many().exec(db).await
     }
 }
@@ -0,0 +1,236 @@
+use std::collections::HashMap;
+
+use sea_orm::*;
+use sea_query::Expr;
+
+use crate::app::entity::{
+    art, art::Entity as Art, art_tag, art_tag::Entity as ArtTag, tag, tag::Entity as Tag,
+};
+use crate::app::model::tag::TagModel;
+use crate::nako::global::Serialize;
+use crate::nako::time;
+
+/// 标签及文章数量
+#[derive(Clone, Serialize)]
+pub struct TagCount {
+    #[serde(flatten)]
+    pub tag: tag::Model,
+    pub count: u64,
+    pub weight: u32,
+}
+
+#[derive(FromQueryResult)]
+struct TagCountRow {
+    tag_id: u32,
+    count: i64,
+}
+
+pub struct ArtTagModel;
+
+impl ArtTagModel {
+    /// 解析逗号分隔的标签
+    pub fn parse_tags(tags: &str) -> Vec<String> {
+        let mut list: Vec<String> = Vec::new();
+
+        for name in tags.split([',', '，']) {
+            let name: String = name.trim().chars().take(50).collect();
+            if !name.is_empty() && !list.contains(&name) {
+                list.push(name);
+            }
+        }
+
+        list
+    }
+
+    /// 文章的标签
+    pub async fn find_tags_by_art_id(db: &DbConn, art_id: u32) -> Result<Vec<tag::Model>, DbErr> {
+        Tag::find()
+            .join(JoinType::InnerJoin, tag::Relation::ArtTag.def())
+            .filter(art_tag::Column::ArtId.eq(art_id))
+            .order_by_asc(tag::Column::Id)
+            .all(db)
+            .await
+    }
+
+    /// 标签下的文章 ID
+    pub async fn find_art_ids_by_tag_id(db: &DbConn, tag_id: u32) -> Result<Vec<u32>, DbErr> {
+        Ok(ArtTag::find()
+            .filter(art_tag::Column::TagId.eq(tag_id))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|v| v.art_id)
+            .collect())
+    }
+
+    /// 同步文章标签，不存在的标签会被创建
+    pub async fn sync(db: &DbConn, art_id: u32, tags: Option<&str>) -> Result<Vec<u32>, DbErr> {
+        let mut tag_ids: Vec<u32> = Vec::new();
+
+        for name in Self::parse_tags(tags.unwrap_or_default()) {
+            let exists = TagModel::find_by_name(db, name.as_str()).await?;
+
+            let tag_id = match exists {
+                Some(v) => v.id,
+                None => {
+                    TagModel::create(
+                        db,
+                        tag::Model {
+                            name: name.clone(),
+                            sort: 100,
+                            status: Some(1),
+                            add_time: Some(time::now().timestamp()),
+                            add_ip: Some("".to_string()),
+                            ..Default::default()
+                        },
+                    )
+                    .await?
+                    .try_into_model()?
+                    .id
+                }
+            };
+
+            if !tag_ids.contains(&tag_id) {
+                tag_ids.push(tag_id);
+            }
+        }
+
+        Self::delete_by_art_id(db, art_id).await?;
+
+        if !tag_ids.is_empty() {
+            ArtTag::insert_many(tag_ids.iter().map(|tag_id| art_tag::ActiveModel {
+                art_id: Set(art_id),
+                tag_id: Set(*tag_id),
+            }))
+            .exec(db)
+            .await?;
+        }
+
+        Ok(tag_ids)
+    }
+
+    /// 根据关联重写文章的标签字段
+    pub async fn refresh_art_tags(db: &DbConn, art_ids: &[u32]) -> Result<(), DbErr> {
+        for art_id in art_ids {
+            let names = Self::find_tags_by_art_id(db, *art_id)
+                .await?
+                .into_iter()
+                .map(|t| t.name)
+                .collect::<Vec<String>>();
+
+            Art::update_many()
+                .col_expr(art::Column::Tags, Expr::value(names.join(",")))
+                .filter(art::Column::Id.eq(*art_id))
+                .exec(db)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// 合并标签，返回受影响的文章数量
+    pub async fn merge(db: &DbConn, tag_id: u32, target_id: u32) -> Result<u64, DbErr> {
+        let art_ids = Self::find_art_ids_by_tag_id(db, tag_id).await?;
+        let target_art_ids = Self::find_art_ids_by_tag_id(db, target_id).await?;
+
+        let new_ids = art_ids
+            .iter()
+            .filter(|id| !target_art_ids.contains(id))
+            .copied()
+            .collect::<Vec<u32>>();
+        if !new_ids.is_empty() {
+            ArtTag::insert_many(new_ids.iter().map(|art_id| art_tag::ActiveModel {
+                art_id: Set(*art_id),
+                tag_id: Set(target_id),
+            }))
+            .exec(db)
+            .await?;
+        }
+
+        Self::delete_by_tag_id(db, tag_id).await?;
+        TagModel::delete(db, tag_id).await?;
+
+        Self::refresh_art_tags(db, &art_ids).await?;
+
+        Ok(art_ids.len() as u64)
+    }
+
+    /// 已发布文章的标签数量
+    pub async fn find_tag_counts(db: &DbConn) -> Result<HashMap<u32, u64>, DbErr> {
+        let rows = ArtTag::find()
+            .select_only()
+            .column(art_tag::Column::TagId)
+            .column_as(art_tag::Column::ArtId.count(), "count")
+            .join(JoinType::InnerJoin, art_tag::Relation::Art.def())
+            .filter(art::Column::Status.eq(1))
+            .group_by(art_tag::Column::TagId)
+            .into_model::<TagCountRow>()
+            .all(db)
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|v| (v.tag_id, v.count as u64))
+            .collect())
+    }
+
+    /// 标签云，按文章数量排序，权重为 1-5
+    pub async fn find_tag_cloud(db: &DbConn, limit: usize) -> Result<Vec<TagCount>, DbErr> {
+        let counts = Self::find_tag_counts(db).await?;
+
+        let mut list = Tag::find()
+            .filter(tag::Column::Status.eq(1))
+            .order_by_desc(tag::Column::Sort)
+            .order_by_asc(tag::Column::Id)
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|t| TagCount {
+                count: counts.get(&t.id).copied().unwrap_or_default(),
+                tag: t,
+                weight: 1,
+            })
+            .collect::<Vec<TagCount>>();
+
+        list.sort_by_key(|v| std::cmp::Reverse(v.count));
+        list.truncate(limit);
+
+        let max = list.iter().map(|v| v.count).max().unwrap_or_default();
+        let min = list.iter().map(|v| v.count).min().unwrap_or_default();
+        if max > min {
+            for item in list.iter_mut() {
+                item.weight = 1 + ((item.count - min) * 4 / (max - min)) as u32;
+            }
+        }
+
+        Ok(list)
+    }
+
+    /// 从文章标签字段生成关联数据，返回处理的文章数量
+    pub async fn sync_all(db: &DbConn) -> Result<u64, DbErr> {
+        let arts = Art::find().order_by_asc(art::Column::Id).all(db).await?;
+        for data in arts.iter() {
+            Self::sync(db, data.id, data.tags.as_deref()).await?;
+        }
+
+        Ok(arts.len() as u64)
+    }
+
+    pub async fn delete_by_art_id(db: &DbConn, art_id: u32) -> Result<DeleteResult, DbErr> {
+        ArtTag::delete_many()
+            .filter(art_tag::Column::ArtId.eq(art_id))
+            .exec(db)
+            .await
+    }
+
+    pub async fn delete_by_tag_id(db: &DbConn, tag_id: u32) -> Result<DeleteResult, DbErr> {
+        ArtTag::delete_many()
+            .filter(art_tag::Column::TagId.eq(tag_id))
+            .exec(db)
+            .await
+    }
+
+    pub async fn delete_all(db: &DbConn) -> Result<DeleteResult, DbErr> {
+        ArtTag::delete_many().exec(db).await
+    }
+}
@@ -1,6 +1,8 @@
 use sea_orm::*;
+use sea_query::Expr;
 
 use crate::app::entity::{cate, cate::Entity as Cate};
+use crate::nako::global::Serialize;
 
 /// 条件
 #[derive(Clone)]
@@ -36,6 +38,16 @@ impl CateWhere {
     }
 }
 
+/// 分类树节点
+#[derive(Clone, Serialize)]
+pub struct CateNode {
+    #[serde(flatten)]
+    pub cate: cate::Model,
+    pub path: String,
+    pub level: u32,
+    pub children: Vec<CateNode>,
+}
+
 pub struct CateModel;
 
 impl CateModel {
@@ -125,6 +137,166 @@ impl CateModel {
             .await
     }
 
+    /// 根据路径查询分类，路径为 `tech/rust` 或者单个标识
+    pub async fn find_by_path(db: &DbConn, path: &str) -> Result<Option<cate::Model>, DbErr> {
+        let path = path.trim_matches('/');
+        let slug = path.rsplit('/').next().unwrap_or_default();
+
+        let cate = match Self::find_by_slug(db, slug).await? {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+
+        if path.contains('/') {
+            let cates = Self::find_all(db).await?;
+            if Self::path(&cates, cate.id) != path {
+                return Ok(None);
+            }
+        }
+
+        Ok(Some(cate))
+    }
+
+    /// 上级分类，从顶级开始，不含自身
+    pub fn ancestors(cates: &[cate::Model], id: u32) -> Vec<cate::Model> {
+        let mut list: Vec<cate::Model> = Vec::new();
+
+        let mut pid = match cates.iter().find(|c| c.id == id) {
+            Some(v) => v.pid,
+            None => return list,
+        };
+
+        while pid > 0 && pid != id && !list.iter().any(|c| c.id == pid) {
+            match cates.iter().find(|c| c.id == pid) {
+                Some(v) => {
+                    pid = v.pid;
+                    list.push(v.clone());
+                }
+                None => break,
+            }
+        }
+
+        list.reverse();
+        list
+    }
+
+    /// 下级分类 ID，不含自身
+    pub fn descendant_ids(cates: &[cate::Model], id: u32) -> Vec<u32> {
+        let mut ids: Vec<u32> = Vec::new();
+        let mut parents = vec![id];
+
+        while let Some(pid) = parents.pop() {
+            for c in cates.iter().filter(|c| c.pid == pid) {
+                if c.id != id && !ids.contains(&c.id) {
+                    ids.push(c.id);
+                    parents.push(c.id);
+                }
+            }
+        }
+
+        ids
+    }
+
+    /// 分类路径
+    pub fn path(cates: &[cate::Model], id: u32) -> String {
+        Self::breadcrumbs(cates, id)
+            .into_iter()
+            .map(|c| c.cate.slug)
+            .collect::<Vec<String>>()
+            .join("/")
+    }
+
+    /// 面包屑，包含自身
+    pub fn breadcrumbs(cates: &[cate::Model], id: u32) -> Vec<CateNode> {
+        let mut list = Self::ancestors(cates, id);
+        if let Some(v) = cates.iter().find(|c| c.id == id) {
+            list.push(v.clone());
+        }
+
+        let mut path: Vec<String> = Vec::new();
+        list.into_iter()
+            .enumerate()
+            .map(|(i, c)| {
+                path.push(c.slug.clone());
+
+                CateNode {
+                    cate: c,
+                    path: path.join("/"),
+                    level: i as u32,
+                    children: Vec::new(),
+                }
+            })
+            .collect()
+    }
+
+    /// 判断设置父级后是否会形成循环
+    pub fn is_cycle(cates: &[cate::Model], id: u32, pid: u32) -> bool {
+        pid == id || Self::descendant_ids(cates, id).contains(&pid)
+    }
+
+    /// 分类树
+    pub fn tree(cates: &[cate::Model]) -> Vec<CateNode> {
+        fn children(cates: &[cate::Model], pid: u32, path: &str, level: u32) -> Vec<CateNode> {
+            cates
+                .iter()
+                .filter(|c| c.pid == pid && c.id != pid)
+                .map(|c| {
+                    let path = if path.is_empty() {
+                        c.slug.clone()
+                    } else {
+                        format!("{}/{}", path, c.slug)
+                    };
+
+                    CateNode {
+                        cate: c.clone(),
+                        children: children(cates, c.id, path.as_str(), level + 1),
+                        path,
+                        level,
+                    }
+                })
+                .collect()
+        }
+
+        // 父级不存在的分类作为顶级
+        let mut list: Vec<CateNode> = Vec::new();
+        for c in cates
+            .iter()
+            .filter(|c| c.pid == 0 || !cates.iter().any(|p| p.id == c.pid))
+        {
+            list.push(CateNode {
+                cate: c.clone(),
+                path: c.slug.clone(),
+                level: 0,
+                children: children(cates, c.id, c.slug.as_str(), 1),
+            });
+        }
+
+        list
+    }
+
+    /// 分类树展开为列表
+    pub fn tree_list(cates: &[cate::Model]) -> Vec<CateNode> {
+        fn flatten(nodes: Vec<CateNode>, list: &mut Vec<CateNode>) {
+            for mut node in nodes {
+                let children = std::mem::take(&mut node.children);
+                list.push(node);
+                flatten(children, list);
+            }
+        }
+
+        let mut list: Vec<CateNode> = Vec::new();
+        flatten(Self::tree(cates), &mut list);
+
+        list
+    }
+
+    /// 启用的分类树
+    pub async fn find_open_tree(db: &DbConn) -> Result<Vec<CateNode>, DbErr> {
+        let cates = Self::find_open_cate(db).await?;
+
+        Ok(Self::tree(&cates))
+    }
+
     pub async fn create(db: &DbConn, form_data: cate::Model) -> Result<cate::ActiveModel, DbErr> {
         cate::ActiveModel {
             pid: Set(form_data.pid.to_owned()),
@@ -190,6 +362,16 @@ impl CateModel {
         .await
     }
 
+    /// 转移子分类
+    pub async fn update_pid_by_pid(db: &DbConn, pid: u32, new_pid: u32) -> Result<u64, DbErr> {
+        Cate::update_many()
+            .col_expr(cate::Column::Pid, Expr::value(new_pid))
+            .filter(cate::Column::Pid.eq(pid))
+            .exec(db)
+            .await
+            .map(|res| res.rows_affected)
+    }
+
     pub async fn delete(db: &DbConn, id: u32) -> Result<DeleteResult, DbErr> {
         let cate: cate::ActiveModel = Cate::find_by_id(id)
             .one(db)
@@ -2,6 +2,21 @@ use sea_orm::*;
 
 use crate::app::entity::{comment, comment::Entity as Comment};
 
+/// 待审核
+pub const STATUS_PENDING: i32 = 0;
+
+/// 已通过
+pub const STATUS_APPROVED: i32 = 1;
+
+/// 垃圾评论
+pub const STATUS_SPAM: i32 = 2;
+
+/// 回收站
+pub const STATUS_TRASH: i32 = 3;
+
+/// 全部状态
+pub const STATUSES: [i32; 4] = [STATUS_PENDING, STATUS_APPROVED, STATUS_SPAM, STATUS_TRASH];
+
 /// 条件
 #[derive(Clone)]
 pub struct CommentWhere {
@@ -36,8 +51,10 @@ impl CommentWhere {
         }
 
         let mut status = None;
-        if self.status == Some(1) || self.status == Some(0) {
-            status = self.status;
+        if let Some(v) = self.status {
+            if STATUSES.contains(&v) {
+                status = Some(v);
+            }
         }
 
         Self {
@@ -50,6 +67,12 @@ impl CommentWhere {
     }
 }
 
+// 邮箱
+#[derive(FromQueryResult)]
+struct EmailData {
+    email: Option<String>,
+}
+
 pub struct CommentModel;
 
 impl CommentModel {
@@ -74,6 +97,38 @@ impl CommentModel {
         paginator.fetch_page(page - 1).await.map(|p| (p, num_pages))
     }
 
+    /// 各状态的数量
+    pub async fn find_count_by_status(db: &DbConn, status: i32) -> Result<u64, DbErr> {
+        Comment::find()
+            .filter(comment::Column::Status.eq(status))
+            .count(db)
+            .await
+    }
+
+    /// 邮箱是否有已通过的评论
+    pub async fn has_approved_email(db: &DbConn, email: &str) -> Result<bool, DbErr> {
+        Comment::find()
+            .filter(comment::Column::Email.eq(email))
+            .filter(comment::Column::Status.eq(STATUS_APPROVED))
+            .count(db)
+            .await
+            .map(|v| v > 0)
+    }
+
+    /// 已通过评论的邮箱
+    pub async fn find_approved_emails(db: &DbConn) -> Result<Vec<String>, DbErr> {
+        let list = Comment::find()
+            .select_only()
+            .column(comment::Column::Email)
+            .filter(comment::Column::Status.eq(STATUS_APPROVED))
+            .distinct()
+            .into_model::<EmailData>()
+            .all(db)
+            .await?;
+
+        Ok(list.into_iter().filter_map(|v| v.email).collect())
+    }
+
     pub async fn find_count_by_artid(db: &DbConn, artid: u32) -> Result<u64, DbErr> {
         Comment::find()
             .filter(comment::Column::ArtId.eq(artid))
@@ -155,10 +210,12 @@ impl CommentModel {
     ) -> Result<comment::ActiveModel, DbErr> {
         comment::ActiveModel {
             art_id: Set(form_data.art_id.to_owned()),
+            reply_id: Set(form_data.reply_id.to_owned()),
             username: Set(form_data.username.to_owned()),
             email: Set(form_data.email.to_owned()),
             content: Set(form_data.content.to_owned()),
             status: Set(form_data.status.to_owned()),
+            notify: Set(form_data.notify),
             add_time: Set(form_data.add_time.to_owned()),
             add_ip: Set(form_data.add_ip.to_owned()),
             ..Default::default()
@@ -220,6 +277,14 @@ impl CommentModel {
         comment.delete(db).await
     }
 
+    /// 删除某状态的全部评论
+    pub async fn delete_by_status(db: &DbConn, status: i32) -> Result<DeleteResult, DbErr> {
+        Comment::delete_many()
+            .filter(comment::Column::Status.eq(status))
+            .exec(db)
+            .await
+    }
+
     pub async fn delete_all(db: &DbConn) -> Result<DeleteResult, DbErr> {
         Comment::delete_many().exec(db).await
     }
@@ -0,0 +1,166 @@
+use sea_orm::*;
+use sea_query::Expr;
+
+use crate::app::entity::{comment_rule, comment_rule::Entity as CommentRule};
+
+/// 规则类型，IP、邮箱及关键字
+pub const TYPE_IP: &str = "ip";
+pub const TYPE_EMAIL: &str = "email";
+pub const TYPE_KEYWORD: &str = "keyword";
+
+/// 处理方式，直接通过或标记为垃圾评论
+pub const ACTION_ALLOW: &str = "allow";
+pub const ACTION_BLOCK: &str = "block";
+
+/// 条件
+#[derive(Clone)]
+pub struct CommentRuleWhere {
+    pub rule_type: Option<String>,
+    pub value: Option<String>,
+    pub action: Option<String>,
+}
+
+impl CommentRuleWhere {
+    /// 格式化
+    pub fn format(&self) -> Self {
+        let mut rule_type = None;
+        if self.rule_type != Some("".to_string()) {
+            rule_type = self.rule_type.clone();
+        }
+
+        let mut value = None;
+        if self.value != Some("".to_string()) {
+            value = self.value.clone();
+        }
+
+        let mut action = None;
+        if self.action != Some("".to_string()) {
+            action = self.action.clone();
+        }
+
+        Self {
+            rule_type,
+            value,
+            action,
+        }
+    }
+}
+
+pub struct CommentRuleModel;
+
+impl CommentRuleModel {
+    pub async fn find_by_id(db: &DbConn, id: u32) -> Result<Option<comment_rule::Model>, DbErr> {
+        CommentRule::find_by_id(id).one(db).await
+    }
+
+    pub async fn find_by_value(
+        db: &DbConn,
+        rule_type: &str,
+        value: &str,
+    ) -> Result<Option<comment_rule::Model>, DbErr> {
+        CommentRule::find()
+            .filter(comment_rule::Column::RuleType.eq(rule_type))
+            .filter(comment_rule::Column::Value.eq(value))
+            .one(db)
+            .await
+    }
+
+    pub async fn find_all(db: &DbConn) -> Result<Vec<comment_rule::Model>, DbErr> {
+        CommentRule::find()
+            .order_by_asc(comment_rule::Column::Id)
+            .all(db)
+            .await
+    }
+
+    // 搜索
+    pub async fn search_count(db: &DbConn, wheres: CommentRuleWhere) -> Result<u64, DbErr> {
+        Self::search(wheres).count(db).await
+    }
+
+    pub async fn search_in_page(
+        db: &DbConn,
+        page: u64,
+        per_page: u64,
+        wheres: CommentRuleWhere,
+    ) -> Result<(Vec<comment_rule::Model>, u64), DbErr> {
+        let paginator = Self::search(wheres)
+            .order_by_desc(comment_rule::Column::Id)
+            .paginate(db, per_page);
+        let num_pages = paginator.num_pages().await?;
+
+        paginator.fetch_page(page - 1).await.map(|p| (p, num_pages))
+    }
+
+    fn search(wheres: CommentRuleWhere) -> Select<CommentRule> {
+        CommentRule::find()
+            .apply_if(wheres.rule_type, |query, v| {
+                query.filter(comment_rule::Column::RuleType.eq(v))
+            })
+            .apply_if(wheres.value, |query, v| {
+                query.filter(comment_rule::Column::Value.contains(v.as_str()))
+            })
+            .apply_if(wheres.action, |query, v| {
+                query.filter(comment_rule::Column::Action.eq(v))
+            })
+    }
+
+    pub async fn create(
+        db: &DbConn,
+        form_data: comment_rule::Model,
+    ) -> Result<comment_rule::ActiveModel, DbErr> {
+        comment_rule::ActiveModel {
+            rule_type: Set(form_data.rule_type.to_owned()),
+            value: Set(form_data.value.to_owned()),
+            action: Set(form_data.action.to_owned()),
+            hits: Set(0),
+            add_time: Set(form_data.add_time),
+            ..Default::default()
+        }
+        .save(db)
+        .await
+    }
+
+    pub async fn update_by_id(
+        db: &DbConn,
+        id: u32,
+        form_data: comment_rule::Model,
+    ) -> Result<comment_rule::Model, DbErr> {
+        let rule: comment_rule::ActiveModel = CommentRule::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::Custom("Cannot find comment rule.".to_owned()))
+            .map(Into::into)?;
+
+        comment_rule::ActiveModel {
+            id: rule.id,
+            rule_type: Set(form_data.rule_type.to_owned()),
+            value: Set(form_data.value.to_owned()),
+            action: Set(form_data.action.to_owned()),
+            ..Default::default()
+        }
+        .update(db)
+        .await
+    }
+
+    /// 增加命中次数
+    pub async fn hit(db: &DbConn, id: u32) -> Result<UpdateResult, DbErr> {
+        CommentRule::update_many()
+            .col_expr(
+                comment_rule::Column::Hits,
+                Expr::col(comment_rule::Column::Hits).add(1),
+            )
+            .filter(comment_rule::Column::Id.eq(id))
+            .exec(db)
+            .await
+    }
+
+    pub async fn delete(db: &DbConn, id: u32) -> Result<DeleteResult, DbErr> {
+        let rule: comment_rule::ActiveModel = CommentRule::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::Custom("Cannot find comment rule.".to_owned()))
+            .map(Into::into)?;
+
+        rule.delete(db).await
+    }
+}
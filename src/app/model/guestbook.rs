@@ -1,4 +1,5 @@
 use sea_orm::*;
+use sea_query::Expr;
 
 use crate::app::entity::{guestbook, guestbook::Entity as Guestbook};
 
@@ -12,6 +13,7 @@ pub struct GuestbookWhere {
     pub qq: Option<String>,
     pub weixin: Option<String>,
     pub status: Option<i32>,
+    pub approved: Option<i32>,
 }
 
 impl GuestbookWhere {
@@ -52,6 +54,11 @@ impl GuestbookWhere {
             status = self.status;
         }
 
+        let mut approved = None;
+        if self.approved == Some(1) || self.approved == Some(0) {
+            approved = self.approved;
+        }
+
         Self {
             name: name,
             message: message,
@@ -60,10 +67,17 @@ impl GuestbookWhere {
             qq: qq,
             weixin: weixin,
             status: status,
+            approved,
         }
     }
 }
 
+// 邮箱
+#[derive(FromQueryResult)]
+struct EmailData {
+    email: Option<String>,
+}
+
 pub struct GuestbookModel;
 
 impl GuestbookModel {
@@ -88,6 +102,43 @@ impl GuestbookModel {
         paginator.fetch_page(page - 1).await.map(|p| (p, num_pages))
     }
 
+    /// 已审核的留言数量
+    pub async fn find_approved_count(db: &DbConn) -> Result<u64, DbErr> {
+        Guestbook::find()
+            .filter(guestbook::Column::Approved.eq(1))
+            .count(db)
+            .await
+    }
+
+    /// 已审核留言的邮箱
+    pub async fn find_approved_emails(db: &DbConn) -> Result<Vec<String>, DbErr> {
+        let list = Guestbook::find()
+            .select_only()
+            .column(guestbook::Column::Email)
+            .filter(guestbook::Column::Approved.eq(1))
+            .distinct()
+            .into_model::<EmailData>()
+            .all(db)
+            .await?;
+
+        Ok(list.into_iter().filter_map(|v| v.email).collect())
+    }
+
+    /// 已审核的留言
+    pub async fn find_approved_in_page(
+        db: &DbConn,
+        page: u64,
+        per_page: u64,
+    ) -> Result<(Vec<guestbook::Model>, u64), DbErr> {
+        let paginator = Guestbook::find()
+            .filter(guestbook::Column::Approved.eq(1))
+            .order_by_desc(guestbook::Column::AddTime)
+            .paginate(db, per_page);
+        let num_pages = paginator.num_pages().await?;
+
+        paginator.fetch_page(page - 1).await.map(|p| (p, num_pages))
+    }
+
     // 搜索
     pub async fn search_count(db: &DbConn, wheres: GuestbookWhere) -> Result<u64, DbErr> {
         Guestbook::find()
@@ -112,6 +163,9 @@ impl GuestbookModel {
             .apply_if(wheres.status, |query, v| {
                 query.filter(guestbook::Column::Status.eq(v))
             })
+            .apply_if(wheres.approved, |query, v| {
+                query.filter(guestbook::Column::Approved.eq(v))
+            })
             .count(db)
             .await
     }
@@ -144,6 +198,9 @@ impl GuestbookModel {
             .apply_if(wheres.status, |query, v| {
                 query.filter(guestbook::Column::Status.eq(v))
             })
+            .apply_if(wheres.approved, |query, v| {
+                query.filter(guestbook::Column::Approved.eq(v))
+            })
             .order_by_desc(guestbook::Column::AddTime)
             .paginate(db, per_page);
         let num_pages = paginator.num_pages().await?;
@@ -191,6 +248,41 @@ impl GuestbookModel {
         .await
     }
 
+    /// 更新回复
+    pub async fn update_reply_by_id(
+        db: &DbConn,
+        id: u32,
+        form_data: guestbook::Model,
+    ) -> Result<guestbook::Model, DbErr> {
+        let guestbook: guestbook::ActiveModel = Guestbook::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::Custom("Cannot find guestbook.".to_owned()))
+            .map(Into::into)?;
+
+        guestbook::ActiveModel {
+            id: guestbook.id,
+            reply: Set(form_data.reply.to_owned()),
+            reply_time: Set(form_data.reply_time),
+            ..Default::default()
+        }
+        .update(db)
+        .await
+    }
+
+    /// 前台显示审核
+    pub async fn update_approved_by_id(
+        db: &DbConn,
+        id: u32,
+        approved: i32,
+    ) -> Result<UpdateResult, DbErr> {
+        Guestbook::update_many()
+            .col_expr(guestbook::Column::Approved, Expr::value(approved))
+            .filter(guestbook::Column::Id.eq(id))
+            .exec(db)
+            .await
+    }
+
     pub async fn delete(db: &DbConn, id: u32) -> Result<DeleteResult, DbErr> {
         let guestbook: guestbook::ActiveModel = Guestbook::find_by_id(id)
             .one(db)
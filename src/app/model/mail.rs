@@ -0,0 +1,93 @@
+use sea_orm::*;
+use sea_query::Expr;
+
+use crate::app::entity::{mail, mail::Entity as Mail};
+
+/// 待发送
+pub const STATUS_WAIT: i32 = 0;
+
+/// 已发送
+pub const STATUS_SENT: i32 = 1;
+
+/// 发送失败，不再重试
+pub const STATUS_FAILED: i32 = 2;
+
+pub struct MailModel;
+
+impl MailModel {
+    pub async fn find_by_id(db: &DbConn, id: u32) -> Result<Option<mail::Model>, DbErr> {
+        Mail::find_by_id(id).one(db).await
+    }
+
+    /// 到达发送时间的邮件
+    pub async fn find_due(db: &DbConn, now: i64, limit: u64) -> Result<Vec<mail::Model>, DbErr> {
+        Mail::find()
+            .filter(mail::Column::Status.eq(STATUS_WAIT))
+            .filter(mail::Column::NextTime.lte(now))
+            .order_by_asc(mail::Column::Id)
+            .limit(limit)
+            .all(db)
+            .await
+    }
+
+    pub async fn create(db: &DbConn, form_data: mail::Model) -> Result<mail::Model, DbErr> {
+        mail::ActiveModel {
+            email: Set(form_data.email.to_owned()),
+            subject: Set(form_data.subject.to_owned()),
+            body: Set(form_data.body.to_owned()),
+            status: Set(STATUS_WAIT),
+            attempts: Set(0),
+            error: Set("".to_string()),
+            next_time: Set(form_data.add_time),
+            send_time: Set(0),
+            add_time: Set(form_data.add_time),
+            ..Default::default()
+        }
+        .insert(db)
+        .await
+    }
+
+    /// 发送成功
+    pub async fn update_sent(db: &DbConn, id: u32, now: i64) -> Result<UpdateResult, DbErr> {
+        Mail::update_many()
+            .col_expr(mail::Column::Status, Expr::value(STATUS_SENT))
+            .col_expr(
+                mail::Column::Attempts,
+                Expr::col(mail::Column::Attempts).add(1),
+            )
+            .col_expr(mail::Column::Error, Expr::value(""))
+            .col_expr(mail::Column::SendTime, Expr::value(now))
+            .filter(mail::Column::Id.eq(id))
+            .exec(db)
+            .await
+    }
+
+    /// 发送失败，next_time 为 None 时不再重试
+    pub async fn update_failed(
+        db: &DbConn,
+        id: u32,
+        attempts: i32,
+        next_time: Option<i64>,
+        error: &str,
+    ) -> Result<UpdateResult, DbErr> {
+        let mut update = Mail::update_many()
+            .col_expr(mail::Column::Attempts, Expr::value(attempts))
+            .col_expr(mail::Column::Error, Expr::value(error));
+
+        update = match next_time {
+            Some(v) => update.col_expr(mail::Column::NextTime, Expr::value(v)),
+            None => update.col_expr(mail::Column::Status, Expr::value(STATUS_FAILED)),
+        };
+
+        update.filter(mail::Column::Id.eq(id)).exec(db).await
+    }
+
+    /// 删除之前发送的邮件
+    pub async fn delete_sent_before(db: &DbConn, time: i64) -> Result<DeleteResult, DbErr> {
+        Mail::delete_many()
+            .filter(mail::Column::Status.eq(STATUS_SENT))
+            .filter(mail::Column::SendTime.lt(time))
+            .exec(db)
+            .await
+    }
+}
@@ -1,12 +1,23 @@
 pub mod art;
+pub mod art_tag;
 pub mod attach;
 pub mod cate;
 pub mod comment;
+pub mod comment_rule;
+pub mod mail;
+pub mod not_found;
 pub mod page;
+pub mod redirect;
+pub mod series;
+pub mod series_art;
 pub mod setting;
 pub mod tag;
 pub mod user;
+pub mod user_token;
 pub mod guestbook;
 pub mod friendlink;
+pub mod template;
+pub mod webhook;
+pub mod webhook_log;
 
-pub use sea_orm;
\ No newline at end of file
+pub use sea_orm;
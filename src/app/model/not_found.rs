@@ -0,0 +1,226 @@
+use sea_orm::*;
+use sea_query::Expr;
+
+use crate::app::entity::{
+    not_found, not_found::Entity as NotFound, not_found_referrer,
+    not_found_referrer::Entity as NotFoundReferrer,
+};
+
+/// 条件
+#[derive(Clone)]
+pub struct NotFoundWhere {
+    pub path: Option<String>,
+    pub referrer: Option<String>,
+}
+
+impl NotFoundWhere {
+    /// 格式化
+    pub fn format(&self) -> Self {
+        let mut path = None;
+        if self.path != Some("".to_string()) {
+            path = self.path.clone();
+        }
+
+        let mut referrer = None;
+        if self.referrer != Some("".to_string()) {
+            referrer = self.referrer.clone();
+        }
+
+        Self {
+            path,
+            referrer,
+        }
+    }
+}
+
+pub struct NotFoundModel;
+
+impl NotFoundModel {
+    pub async fn find_by_id(db: &DbConn, id: u32) -> Result<Option<not_found::Model>, DbErr> {
+        NotFound::find_by_id(id).one(db).await
+    }
+
+    pub async fn find_count(db: &DbConn) -> Result<u64, DbErr> {
+        NotFound::find().count(db).await
+    }
+
+    // 搜索
+    pub async fn search_count(db: &DbConn, wheres: NotFoundWhere) -> Result<u64, DbErr> {
+        Self::search(wheres).count(db).await
+    }
+
+    pub async fn search_in_page(
+        db: &DbConn,
+        page: u64,
+        per_page: u64,
+        wheres: NotFoundWhere,
+    ) -> Result<(Vec<not_found::Model>, u64), DbErr> {
+        let paginator = Self::search(wheres)
+            .order_by_desc(not_found::Column::Hits)
+            .order_by_desc(not_found::Column::LastTime)
+            .paginate(db, per_page);
+        let num_pages = paginator.num_pages().await?;
+
+        paginator.fetch_page(page - 1).await.map(|p| (p, num_pages))
+    }
+
+    fn search(wheres: NotFoundWhere) -> Select<NotFound> {
+        NotFound::find()
+            .apply_if(wheres.path, |query, v| {
+                query.filter(not_found::Column::Path.contains(v.as_str()))
+            })
+            .apply_if(wheres.referrer, |query, v| {
+                query.filter(not_found::Column::Referrer.contains(v.as_str()))
+            })
+    }
+
+    /// 来源列表
+    pub async fn find_referrers(
+        db: &DbConn,
+        id: u32,
+        limit: u64,
+    ) -> Result<Vec<not_found_referrer::Model>, DbErr> {
+        NotFoundReferrer::find()
+            .filter(not_found_referrer::Column::NotFoundId.eq(id))
+            .order_by_desc(not_found_referrer::Column::Hits)
+            .limit(limit)
+            .all(db)
+            .await
+    }
+
+    /// 记录访问，记录数或者链接的来源数达到上限后只更新已有的记录
+    pub async fn log(
+        db: &DbConn,
+        path: &str,
+        referrer: &str,
+        now: i64,
+        max: u64,
+        referrer_max: u64,
+    ) -> Result<(), DbErr> {
+        let data = NotFound::find()
+            .filter(not_found::Column::Path.eq(path))
+            .one(db)
+            .await?;
+
+        let id = match data {
+            Some(v) => {
+                let mut update = NotFound::update_many()
+                    .col_expr(
+                        not_found::Column::Hits,
+                        Expr::col(not_found::Column::Hits).add(1),
+                    )
+                    .col_expr(not_found::Column::LastTime, Expr::value(now));
+                if !referrer.is_empty() {
+                    update = update.col_expr(not_found::Column::Referrer, Expr::value(referrer));
+                }
+                update
+                    .filter(not_found::Column::Id.eq(v.id))
+                    .exec(db)
+                    .await?;
+
+                v.id
+            }
+            None => {
+                if Self::find_count(db).await? >= max {
+                    return Ok(());
+                }
+
+                not_found::ActiveModel {
+                    path: Set(path.to_owned()),
+                    hits: Set(1),
+                    referrer: Set(referrer.to_owned()),
+                    last_time: Set(now),
+                    add_time: Set(now),
+                    ..Default::default()
+                }
+                .insert(db)
+                .await?
+                .id
+            }
+        };
+
+        if referrer.is_empty() {
+            return Ok(());
+        }
+
+        let data = NotFoundReferrer::find()
+            .filter(not_found_referrer::Column::NotFoundId.eq(id))
+            .filter(not_found_referrer::Column::Referrer.eq(referrer))
+            .one(db)
+            .await?;
+
+        match data {
+            Some(v) => {
+                NotFoundReferrer::update_many()
+                    .col_expr(
+                        not_found_referrer::Column::Hits,
+                        Expr::col(not_found_referrer::Column::Hits).add(1),
+                    )
+                    .col_expr(not_found_referrer::Column::LastTime, Expr::value(now))
+                    .filter(not_found_referrer::Column::Id.eq(v.id))
+                    .exec(db)
+                    .await?;
+            }
+            None => {
+                let count = NotFoundReferrer::find()
+                    .filter(not_found_referrer::Column::NotFoundId.eq(id))
+                    .count(db)
+                    .await?;
+                if count >= referrer_max {
+                    return Ok(());
+                }
+
+                not_found_referrer::ActiveModel {
+                    not_found_id: Set(id),
+                    referrer: Set(referrer.to_owned()),
+                    hits: Set(1),
+                    last_time: Set(now),
+                    ..Default::default()
+                }
+                .insert(db)
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 删除记录及来源
+    pub async fn delete(db: &DbConn, id: u32) -> Result<DeleteResult, DbErr> {
+        let txn = db.begin().await?;
+
+        NotFoundReferrer::delete_many()
+            .filter(not_found_referrer::Column::NotFoundId.eq(id))
+            .exec(&txn)
+            .await?;
+
+        let res = NotFound::delete_by_id(id).exec(&txn).await?;
+
+        txn.commit().await?;
+
+        Ok(res)
+    }
+
+    /// 添加跳转后删除对应的记录
+    pub async fn delete_by_path(db: &DbConn, path: &str) -> Result<(), DbErr> {
+        let data = NotFound::find()
+            .filter(not_found::Column::Path.eq(path))
+            .one(db)
+            .await?;
+
+        if let Some(v) = data {
+            Self::delete(db, v.id).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn delete_all(db: &DbConn) -> Result<(), DbErr> {
+        let txn = db.begin().await?;
+
+        NotFoundReferrer::delete_many().exec(&txn).await?;
+        NotFound::delete_many().exec(&txn).await?;
+
+        txn.commit().await
+    }
+}
@@ -67,6 +67,10 @@ impl PageModel {
         paginator.fetch_page(page - 1).await.map(|p| (p, num_pages))
     }
 
+    pub async fn find_all(db: &DbConn) -> Result<Vec<page::Model>, DbErr> {
+        Page::find().order_by_asc(page::Column::Id).all(db).await
+    }
+
     // 搜索
     pub async fn search_count(db: &DbConn, wheres: PageWhere) -> Result<u64, DbErr> {
         Page::find()
@@ -0,0 +1,284 @@
+use sea_orm::*;
+use sea_query::Expr;
+
+use crate::app::entity::{redirect, redirect::Entity as Redirect};
+
+/// 匹配方式，完整匹配、前缀匹配及正则匹配
+pub const MATCH_EXACT: &str = "exact";
+pub const MATCH_PREFIX: &str = "prefix";
+pub const MATCH_REGEX: &str = "regex";
+
+/// 条件
+#[derive(Clone)]
+pub struct RedirectWhere {
+    pub source: Option<String>,
+    pub target: Option<String>,
+    pub match_type: Option<String>,
+    pub code: Option<i32>,
+    pub status: Option<i32>,
+}
+
+impl RedirectWhere {
+    /// 格式化
+    pub fn format(&self) -> Self {
+        let mut source = None;
+        if self.source != Some("".to_string()) {
+            source = self.source.clone();
+        }
+
+        let mut target = None;
+        if self.target != Some("".to_string()) {
+            target = self.target.clone();
+        }
+
+        let mut match_type = None;
+        if self.match_type != Some("".to_string()) {
+            match_type = self.match_type.clone();
+        }
+
+        let mut code = None;
+        if self.code.unwrap_or_default() > 0 {
+            code = self.code;
+        }
+
+        let mut status = None;
+        if self.status == Some(1) || self.status == Some(0) {
+            status = self.status;
+        }
+
+        Self {
+            source,
+            target,
+            match_type,
+            code,
+            status,
+        }
+    }
+}
+
+pub struct RedirectModel;
+
+impl RedirectModel {
+    pub async fn find_by_id(db: &DbConn, id: u32) -> Result<Option<redirect::Model>, DbErr> {
+        Redirect::find_by_id(id).one(db).await
+    }
+
+    pub async fn find_by_source(
+        db: &DbConn,
+        source: &str,
+    ) -> Result<Option<redirect::Model>, DbErr> {
+        Redirect::find()
+            .filter(redirect::Column::Source.eq(source))
+            .one(db)
+            .await
+    }
+
+    /// 启用的跳转规则
+    pub async fn find_open(db: &DbConn) -> Result<Vec<redirect::Model>, DbErr> {
+        Redirect::find()
+            .filter(redirect::Column::Status.eq(1))
+            .order_by_asc(redirect::Column::Id)
+            .all(db)
+            .await
+    }
+
+    // 搜索
+    pub async fn search_count(db: &DbConn, wheres: RedirectWhere) -> Result<u64, DbErr> {
+        Self::search(wheres).count(db).await
+    }
+
+    pub async fn search_in_page(
+        db: &DbConn,
+        page: u64,
+        per_page: u64,
+        wheres: RedirectWhere,
+    ) -> Result<(Vec<redirect::Model>, u64), DbErr> {
+        let paginator = Self::search(wheres)
+            .order_by_desc(redirect::Column::Id)
+            .paginate(db, per_page);
+        let num_pages = paginator.num_pages().await?;
+
+        paginator.fetch_page(page - 1).await.map(|p| (p, num_pages))
+    }
+
+    fn search(wheres: RedirectWhere) -> Select<Redirect> {
+        Redirect::find()
+            .apply_if(wheres.source, |query, v| {
+                query.filter(redirect::Column::Source.contains(v.as_str()))
+            })
+            .apply_if(wheres.target, |query, v| {
+                query.filter(redirect::Column::Target.contains(v.as_str()))
+            })
+            .apply_if(wheres.match_type, |query, v| {
+                query.filter(redirect::Column::MatchType.eq(v))
+            })
+            .apply_if(wheres.code, |query, v| {
+                query.filter(redirect::Column::Code.eq(v))
+            })
+            .apply_if(wheres.status, |query, v| {
+                query.filter(redirect::Column::Status.eq(v))
+            })
+    }
+
+    pub async fn create(
+        db: &DbConn,
+        form_data: redirect::Model,
+    ) -> Result<redirect::ActiveModel, DbErr> {
+        redirect::ActiveModel {
+            source: Set(form_data.source.to_owned()),
+            match_type: Set(form_data.match_type.to_owned()),
+            target: Set(form_data.target.to_owned()),
+            code: Set(form_data.code),
+            art_id: Set(0),
+            hits: Set(0),
+            status: Set(form_data.status),
+            add_time: Set(form_data.add_time),
+            ..Default::default()
+        }
+        .save(db)
+        .await
+    }
+
+    pub async fn update_by_id(
+        db: &DbConn,
+        id: u32,
+        form_data: redirect::Model,
+    ) -> Result<redirect::Model, DbErr> {
+        let redirect: redirect::ActiveModel = Redirect::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::Custom("Cannot find redirect.".to_owned()))
+            .map(Into::into)?;
+
+        redirect::ActiveModel {
+            id: redirect.id,
+            source: Set(form_data.source.to_owned()),
+            match_type: Set(form_data.match_type.to_owned()),
+            target: Set(form_data.target.to_owned()),
+            code: Set(form_data.code),
+            status: Set(form_data.status),
+            ..Default::default()
+        }
+        .update(db)
+        .await
+    }
+
+    pub async fn update_status_by_id(
+        db: &DbConn,
+        id: u32,
+        status: i32,
+    ) -> Result<redirect::Model, DbErr> {
+        let redirect: redirect::ActiveModel = Redirect::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::Custom("Cannot find redirect.".to_owned()))
+            .map(Into::into)?;
+
+        redirect::ActiveModel {
+            id: redirect.id,
+            status: Set(status),
+            ..Default::default()
+        }
+        .update(db)
+        .await
+    }
+
+    /// 增加命中次数
+    pub async fn hit(db: &DbConn, id: u32) -> Result<UpdateResult, DbErr> {
+        Redirect::update_many()
+            .col_expr(
+                redirect::Column::Hits,
+                Expr::col(redirect::Column::Hits).add(1),
+            )
+            .filter(redirect::Column::Id.eq(id))
+            .exec(db)
+            .await
+    }
+
+    pub async fn delete(db: &DbConn, id: u32) -> Result<DeleteResult, DbErr> {
+        let redirect: redirect::ActiveModel = Redirect::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::Custom("Cannot find redirect.".to_owned()))
+            .map(Into::into)?;
+
+        redirect.delete(db).await
+    }
+
+    /// 记录文章旧链接的跳转，指向旧链接的跳转一起改为新链接
+    pub async fn save_art_redirect(
+        db: &DbConn,
+        art_id: u32,
+        source: &str,
+        target: &str,
+        add_time: i64,
+    ) -> Result<(), DbErr> {
+        let txn = db.begin().await?;
+
+        // 新链接已可以访问
+        Redirect::delete_many()
+            .filter(redirect::Column::Source.eq(target))
+            .exec(&txn)
+            .await?;
+
+        Redirect::update_many()
+            .col_expr(redirect::Column::Target, Expr::value(target))
+            .filter(redirect::Column::Target.eq(source))
+            .exec(&txn)
+            .await?;
+
+        let data = Redirect::find()
+            .filter(redirect::Column::Source.eq(source))
+            .one(&txn)
+            .await?;
+
+        match data {
+            Some(v) => {
+                redirect::ActiveModel {
+                    id: Set(v.id),
+                    match_type: Set(MATCH_EXACT.to_owned()),
+                    target: Set(target.to_owned()),
+                    code: Set(301),
+                    art_id: Set(art_id),
+                    status: Set(1),
+                    ..Default::default()
+                }
+                .update(&txn)
+                .await?;
+            }
+            None => {
+                redirect::ActiveModel {
+                    source: Set(source.to_owned()),
+                    match_type: Set(MATCH_EXACT.to_owned()),
+                    target: Set(target.to_owned()),
+                    code: Set(301),
+                    art_id: Set(art_id),
+                    hits: Set(0),
+                    status: Set(1),
+                    add_time: Set(add_time),
+                    ..Default::default()
+                }
+                .insert(&txn)
+                .await?;
+            }
+        }
+
+        txn.commit().await
+    }
+
+    /// 删除文章的跳转
+    pub async fn delete_by_art_id(db: &DbConn, art_id: u32) -> Result<DeleteResult, DbErr> {
+        Redirect::delete_many()
+            .filter(redirect::Column::ArtId.eq(art_id))
+            .exec(db)
+            .await
+    }
+
+    /// 删除来源链接的跳转
+    pub async fn delete_by_source(db: &DbConn, source: &str) -> Result<DeleteResult, DbErr> {
+        Redirect::delete_many()
+            .filter(redirect::Column::Source.eq(source))
+            .exec(db)
+            .await
+    }
+}
@@ -0,0 +1,164 @@
+use sea_orm::*;
+use sea_query::Expr;
+
+use crate::app::entity::{series, series::Entity as Series};
+use crate::app::model::series_art::SeriesArtModel;
+
+/// 条件
+#[derive(Clone)]
+pub struct SeriesWhere {
+    pub title: Option<String>,
+    pub slug: Option<String>,
+    pub status: Option<i32>,
+}
+
+impl SeriesWhere {
+    /// 格式化
+    pub fn format(&self) -> Self {
+        let mut title = None;
+        if self.title != Some("".to_string()) {
+            title = self.title.clone();
+        }
+
+        let mut slug = None;
+        if self.slug != Some("".to_string()) {
+            slug = self.slug.clone();
+        }
+
+        let mut status = None;
+        if self.status == Some(1) || self.status == Some(0) {
+            status = self.status;
+        }
+
+        Self {
+            title,
+            slug,
+            status,
+        }
+    }
+}
+
+pub struct SeriesModel;
+
+impl SeriesModel {
+    pub async fn find_by_id(db: &DbConn, id: u32) -> Result<Option<series::Model>, DbErr> {
+        Series::find_by_id(id).one(db).await
+    }
+
+    pub async fn find_by_slug(db: &DbConn, slug: &str) -> Result<Option<series::Model>, DbErr> {
+        Series::find()
+            .filter(series::Column::Slug.eq(slug))
+            .one(db)
+            .await
+    }
+
+    pub async fn find_by_ids(db: &DbConn, ids: Vec<u32>) -> Result<Vec<series::Model>, DbErr> {
+        Series::find()
+            .filter(series::Column::Id.is_in(ids))
+            .order_by_desc(series::Column::Sort)
+            .order_by_asc(series::Column::Id)
+            .all(db)
+            .await
+    }
+
+    // 搜索
+    pub async fn search_count(db: &DbConn, wheres: SeriesWhere) -> Result<u64, DbErr> {
+        Self::search(wheres).count(db).await
+    }
+
+    pub async fn search_in_page(
+        db: &DbConn,
+        page: u64,
+        per_page: u64,
+        wheres: SeriesWhere,
+    ) -> Result<(Vec<series::Model>, u64), DbErr> {
+        let paginator = Self::search(wheres)
+            .order_by_desc(series::Column::Sort)
+            .order_by_desc(series::Column::Id)
+            .paginate(db, per_page);
+        let num_pages = paginator.num_pages().await?;
+
+        paginator.fetch_page(page - 1).await.map(|p| (p, num_pages))
+    }
+
+    fn search(wheres: SeriesWhere) -> Select<Series> {
+        Series::find()
+            .apply_if(wheres.title, |query, v| {
+                query.filter(series::Column::Title.contains(v.as_str()))
+            })
+            .apply_if(wheres.slug, |query, v| {
+                query.filter(series::Column::Slug.contains(v.as_str()))
+            })
+            .apply_if(wheres.status, |query, v| {
+                query.filter(series::Column::Status.eq(v))
+            })
+    }
+
+    pub async fn create(
+        db: &DbConn,
+        form_data: series::Model,
+    ) -> Result<series::ActiveModel, DbErr> {
+        series::ActiveModel {
+            title: Set(form_data.title.to_owned()),
+            slug: Set(form_data.slug.to_owned()),
+            desc: Set(form_data.desc.to_owned()),
+            cover: Set(form_data.cover.to_owned()),
+            sort: Set(form_data.sort),
+            status: Set(form_data.status),
+            add_time: Set(form_data.add_time),
+            ..Default::default()
+        }
+        .save(db)
+        .await
+    }
+
+    pub async fn update_by_id(
+        db: &DbConn,
+        id: u32,
+        form_data: series::Model,
+    ) -> Result<series::Model, DbErr> {
+        let series: series::ActiveModel = Series::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::Custom("Cannot find series.".to_owned()))
+            .map(Into::into)?;
+
+        series::ActiveModel {
+            id: series.id,
+            title: Set(form_data.title.to_owned()),
+            slug: Set(form_data.slug.to_owned()),
+            desc: Set(form_data.desc.to_owned()),
+            cover: Set(form_data.cover.to_owned()),
+            sort: Set(form_data.sort),
+            status: Set(form_data.status),
+            ..Default::default()
+        }
+        .update(db)
+        .await
+    }
+
+    pub async fn update_status_by_id(
+        db: &DbConn,
+        id: u32,
+        status: i32,
+    ) -> Result<UpdateResult, DbErr> {
+        Series::update_many()
+            .col_expr(series::Column::Status, Expr::value(status))
+            .filter(series::Column::Id.eq(id))
+            .exec(db)
+            .await
+    }
+
+    /// 删除专题及专题文章
+    pub async fn delete(db: &DbConn, id: u32) -> Result<DeleteResult, DbErr> {
+        let series: series::ActiveModel = Series::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::Custom("Cannot find series.".to_owned()))
+            .map(Into::into)?;
+
+        SeriesArtModel::delete_by_series_id(db, id).await?;
+
+        series.delete(db).await
+    }
+}
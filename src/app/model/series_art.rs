@@ -0,0 +1,180 @@
+use sea_orm::*;
+use sea_query::Expr;
+
+use crate::app::entity::{
+    art, art::Entity as Art, series, series::Entity as Series, series_art,
+    series_art::Entity as SeriesArt,
+};
+use crate::nako::global::Serialize;
+
+/// 文章所在专题的导航
+#[derive(Clone, Serialize)]
+pub struct SeriesNav {
+    pub series: series::Model,
+    pub index: usize,
+    pub total: usize,
+    pub prev: Option<art::Model>,
+    pub next: Option<art::Model>,
+}
+
+pub struct SeriesArtModel;
+
+impl SeriesArtModel {
+    pub async fn find_by_series_id(
+        db: &DbConn,
+        series_id: u32,
+    ) -> Result<Vec<series_art::Model>, DbErr> {
+        SeriesArt::find()
+            .filter(series_art::Column::SeriesId.eq(series_id))
+            .order_by_asc(series_art::Column::Sort)
+            .order_by_asc(series_art::Column::ArtId)
+            .all(db)
+            .await
+    }
+
+    pub async fn find_one(
+        db: &DbConn,
+        series_id: u32,
+        art_id: u32,
+    ) -> Result<Option<series_art::Model>, DbErr> {
+        SeriesArt::find_by_id((series_id, art_id)).one(db).await
+    }
+
+    /// 专题下的文章，按专题内排序
+    pub async fn find_arts(
+        db: &DbConn,
+        series_id: u32,
+        status: Option<i32>,
+    ) -> Result<Vec<art::Model>, DbErr> {
+        Art::find()
+            .join(JoinType::InnerJoin, series_art::Relation::Art.def().rev())
+            .filter(series_art::Column::SeriesId.eq(series_id))
+            .apply_if(status, |query, v| query.filter(art::Column::Status.eq(v)))
+            .order_by_asc(series_art::Column::Sort)
+            .order_by_asc(art::Column::Id)
+            .all(db)
+            .await
+    }
+
+    /// 专题下的文章数量
+    pub async fn find_art_count(
+        db: &DbConn,
+        series_id: u32,
+        status: Option<i32>,
+    ) -> Result<u64, DbErr> {
+        Art::find()
+            .join(JoinType::InnerJoin, series_art::Relation::Art.def().rev())
+            .filter(series_art::Column::SeriesId.eq(series_id))
+            .apply_if(status, |query, v| query.filter(art::Column::Status.eq(v)))
+            .count(db)
+            .await
+    }
+
+    /// 文章所在的启用专题
+    pub async fn find_series_by_art_id(
+        db: &DbConn,
+        art_id: u32,
+    ) -> Result<Vec<series::Model>, DbErr> {
+        Series::find()
+            .join(JoinType::InnerJoin, series::Relation::SeriesArt.def())
+            .filter(series_art::Column::ArtId.eq(art_id))
+            .filter(series::Column::Status.eq(1))
+            .order_by_desc(series::Column::Sort)
+            .order_by_asc(series::Column::Id)
+            .all(db)
+            .await
+    }
+
+    /// 文章在各专题中的位置及上一篇、下一篇，只计算已发布的文章
+    pub async fn find_nav_by_art_id(db: &DbConn, art_id: u32) -> Result<Vec<SeriesNav>, DbErr> {
+        let mut list: Vec<SeriesNav> = Vec::new();
+
+        for series in Self::find_series_by_art_id(db, art_id).await? {
+            let arts = Self::find_arts(db, series.id, Some(1)).await?;
+
+            if let Some(pos) = arts.iter().position(|a| a.id == art_id) {
+                list.push(SeriesNav {
+                    series,
+                    index: pos + 1,
+                    total: arts.len(),
+                    prev: pos.checked_sub(1).and_then(|i| arts.get(i)).cloned(),
+                    next: arts.get(pos + 1).cloned(),
+                });
+            }
+        }
+
+        Ok(list)
+    }
+
+    /// 添加到专题末尾
+    pub async fn create(
+        db: &DbConn,
+        series_id: u32,
+        art_id: u32,
+    ) -> Result<series_art::Model, DbErr> {
+        let sort = Self::find_by_series_id(db, series_id)
+            .await?
+            .iter()
+            .map(|v| v.sort)
+            .max()
+            .map(|v| v + 1)
+            .unwrap_or(1);
+
+        series_art::ActiveModel {
+            series_id: Set(series_id),
+            art_id: Set(art_id),
+            sort: Set(sort),
+        }
+        .insert(db)
+        .await
+    }
+
+    pub async fn update_sort(
+        db: &DbConn,
+        series_id: u32,
+        art_id: u32,
+        sort: i32,
+    ) -> Result<UpdateResult, DbErr> {
+        SeriesArt::update_many()
+            .col_expr(series_art::Column::Sort, Expr::value(sort))
+            .filter(series_art::Column::SeriesId.eq(series_id))
+            .filter(series_art::Column::ArtId.eq(art_id))
+            .exec(db)
+            .await
+    }
+
+    /// 按给定的文章顺序重新排序
+    pub async fn reorder(db: &DbConn, series_id: u32, art_ids: &[u32]) -> Result<(), DbErr> {
+        for (i, art_id) in art_ids.iter().enumerate() {
+            Self::update_sort(db, series_id, *art_id, i as i32 + 1).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn delete(db: &DbConn, series_id: u32, art_id: u32) -> Result<DeleteResult, DbErr> {
+        SeriesArt::delete_many()
+            .filter(series_art::Column::SeriesId.eq(series_id))
+            .filter(series_art::Column::ArtId.eq(art_id))
+            .exec(db)
+            .await
+    }
+
+    pub async fn delete_by_series_id(db: &DbConn, series_id: u32) -> Result<DeleteResult, DbErr> {
+        SeriesArt::delete_many()
+            .filter(series_art::Column::SeriesId.eq(series_id))
+            .exec(db)
+            .await
+    }
+
+    pub async fn delete_by_art_id(db: &DbConn, art_id: u32) -> Result<DeleteResult, DbErr> {
+        SeriesArt::delete_many()
+            .filter(series_art::Column::ArtId.eq(art_id))
+            .exec(db)
+            .await
+    }
+
+    pub async fn delete_all(db: &DbConn) -> Result<DeleteResult, DbErr> {
+        SeriesArt::delete_many().exec(db).await
+    }
+}
@@ -53,4 +53,36 @@ impl SettingModel {
         .update(db)
         .await
     }
+
+    /// 按名称保存，不存在时新增
+    pub async fn save_by_key(db: &DbConn, key: &str, value: &str) -> Result<(), DbErr> {
+        let setting = Setting::find()
+            .filter(setting::Column::Key.eq(key))
+            .one(db)
+            .await?;
+
+        match setting {
+            Some(v) => {
+                setting::ActiveModel {
+                    id: Set(v.id),
+                    value: Set(value.to_owned()),
+                    ..Default::default()
+                }
+                .update(db)
+                .await?;
+            }
+            None => {
+                setting::ActiveModel {
+                    key: Set(key.to_owned()),
+                    value: Set(value.to_owned()),
+                    desc: Set("主题".to_owned()),
+                    ..Default::default()
+                }
+                .insert(db)
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
 }
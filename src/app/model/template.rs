@@ -0,0 +1,107 @@
+use sea_orm::*;
+
+use crate::app::entity::{
+    template, template::Entity as Template, template_version,
+    template_version::Entity as TemplateVersion,
+};
+
+pub struct TemplateModel;
+
+impl TemplateModel {
+    pub async fn find_all(db: &DbConn) -> Result<Vec<template::Model>, DbErr> {
+        Template::find()
+            .order_by_asc(template::Column::Name)
+            .all(db)
+            .await
+    }
+
+    pub async fn find_by_name(db: &DbConn, name: &str) -> Result<Option<template::Model>, DbErr> {
+        Template::find()
+            .filter(template::Column::Name.eq(name))
+            .one(db)
+            .await
+    }
+
+    /// 保存模板，同时记录历史版本
+    pub async fn save(
+        db: &DbConn,
+        form_data: template_version::Model,
+    ) -> Result<template::Model, DbErr> {
+        let txn = db.begin().await?;
+
+        let data = Template::find()
+            .filter(template::Column::Name.eq(form_data.name.as_str()))
+            .one(&txn)
+            .await?;
+
+        let res = match data {
+            Some(v) => {
+                template::ActiveModel {
+                    id: Set(v.id),
+                    content: Set(form_data.content.to_owned()),
+                    user_id: Set(form_data.user_id),
+                    update_time: Set(form_data.add_time),
+                    ..Default::default()
+                }
+                .update(&txn)
+                .await?
+            }
+            None => {
+                template::ActiveModel {
+                    name: Set(form_data.name.to_owned()),
+                    content: Set(form_data.content.to_owned()),
+                    user_id: Set(form_data.user_id),
+                    update_time: Set(form_data.add_time),
+                    add_time: Set(form_data.add_time),
+                    ..Default::default()
+                }
+                .insert(&txn)
+                .await?
+            }
+        };
+
+        template_version::ActiveModel {
+            name: Set(form_data.name.to_owned()),
+            content: Set(form_data.content.to_owned()),
+            user_id: Set(form_data.user_id),
+            add_time: Set(form_data.add_time),
+            add_ip: Set(form_data.add_ip.to_owned()),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+
+        txn.commit().await?;
+
+        Ok(res)
+    }
+
+    /// 删除模板，恢复为主题中的模板，历史版本保留
+    pub async fn delete_by_name(db: &DbConn, name: &str) -> Result<DeleteResult, DbErr> {
+        Template::delete_many()
+            .filter(template::Column::Name.eq(name))
+            .exec(db)
+            .await
+    }
+
+    /// 历史版本
+    pub async fn find_versions(
+        db: &DbConn,
+        name: &str,
+        limit: u64,
+    ) -> Result<Vec<template_version::Model>, DbErr> {
+        TemplateVersion::find()
+            .filter(template_version::Column::Name.eq(name))
+            .order_by_desc(template_version::Column::Id)
+            .limit(limit)
+            .all(db)
+            .await
+    }
+
+    pub async fn find_version_by_id(
+        db: &DbConn,
+        id: u32,
+    ) -> Result<Option<template_version::Model>, DbErr> {
+        TemplateVersion::find_by_id(id).one(db).await
+    }
+}
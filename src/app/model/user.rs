@@ -50,6 +50,17 @@ impl UserModel {
             .await
     }
 
+    /// 根据账号精确查询
+    pub async fn find_user_by_username(
+        db: &DbConn,
+        username: &str,
+    ) -> Result<Option<user::Model>, DbErr> {
+        User::find()
+            .filter(user::Column::Username.eq(username))
+            .one(db)
+            .await
+    }
+
     pub async fn find_users_count(db: &DbConn) -> Result<u64, DbErr> {
         User::find().count(db).await
     }
@@ -112,7 +123,9 @@ impl UserModel {
     ) -> Result<user::ActiveModel, DbErr> {
         user::ActiveModel {
             username: Set(form_data.username.to_owned()),
+            password: Set(Some(form_data.password.to_owned().unwrap_or_default())),
             nickname: Set(form_data.nickname.to_owned()),
+            email: Set(form_data.email.to_owned()),
             sign: Set(form_data.sign.to_owned()),
             status: Set(form_data.status.to_owned()),
             add_time: Set(form_data.add_time.to_owned()),
@@ -138,6 +151,7 @@ impl UserModel {
             id: user.id,
             username: Set(form_data.username.to_owned()),
             nickname: Set(form_data.nickname.to_owned()),
+            email: Set(form_data.email.to_owned()),
             sign: Set(form_data.sign.to_owned()),
             status: Set(form_data.status.to_owned()),
             ..Default::default()
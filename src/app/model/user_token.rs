@@ -0,0 +1,109 @@
+use sea_orm::*;
+
+use crate::app::entity::{user_token, user_token::Entity as UserToken};
+use crate::nako::{random, time, utils};
+
+/// 令牌前缀
+pub const TOKEN_PREFIX: &str = "nako_";
+
+/// 可用权限
+pub const SCOPES: [&str; 3] = ["art", "upload", "comment"];
+
+pub struct UserTokenModel;
+
+impl UserTokenModel {
+    /// 生成令牌
+    pub fn generate() -> String {
+        format!("{}{}", TOKEN_PREFIX, random::random_b64(40))
+    }
+
+    /// 令牌哈希，数据库只保存哈希值
+    pub fn hash(token: &str) -> String {
+        utils::sha256(token)
+    }
+
+    /// 格式化权限
+    pub fn format_scopes(scopes: Vec<String>) -> String {
+        scopes
+            .into_iter()
+            .map(|v| v.trim().to_string())
+            .filter(|v| SCOPES.contains(&v.as_str()))
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+
+    pub async fn find_by_id(db: &DbConn, id: u32) -> Result<Option<user_token::Model>, DbErr> {
+        UserToken::find_by_id(id).one(db).await
+    }
+
+    /// 根据令牌查询可用的令牌
+    pub async fn find_by_token(
+        db: &DbConn,
+        token: &str,
+    ) -> Result<Option<user_token::Model>, DbErr> {
+        let now = time::now().timestamp();
+
+        UserToken::find()
+            .filter(user_token::Column::Token.eq(Self::hash(token)))
+            .filter(user_token::Column::Status.eq(1))
+            .filter(
+                Condition::any()
+                    .add(user_token::Column::ExpireTime.is_null())
+                    .add(user_token::Column::ExpireTime.eq(0))
+                    .add(user_token::Column::ExpireTime.gt(now)),
+            )
+            .one(db)
+            .await
+    }
+
+    pub async fn find_all_by_user_id(
+        db: &DbConn,
+        user_id: u32,
+    ) -> Result<Vec<user_token::Model>, DbErr> {
+        UserToken::find()
+            .filter(user_token::Column::UserId.eq(user_id))
+            .order_by_desc(user_token::Column::Id)
+            .all(db)
+            .await
+    }
+
+    pub async fn create(
+        db: &DbConn,
+        form_data: user_token::Model,
+    ) -> Result<user_token::ActiveModel, DbErr> {
+        user_token::ActiveModel {
+            user_id: Set(form_data.user_id.to_owned()),
+            name: Set(form_data.name.to_owned()),
+            token: Set(form_data.token.to_owned()),
+            token_prefix: Set(form_data.token_prefix.to_owned()),
+            scopes: Set(form_data.scopes.to_owned()),
+            expire_time: Set(form_data.expire_time.to_owned()),
+            status: Set(form_data.status.to_owned()),
+            add_time: Set(form_data.add_time.to_owned()),
+            add_ip: Set(form_data.add_ip.to_owned()),
+            ..Default::default()
+        }
+        .save(db)
+        .await
+    }
+
+    /// 更新使用时间
+    pub async fn update_used_time(db: &DbConn, id: u32) -> Result<user_token::Model, DbErr> {
+        user_token::ActiveModel {
+            id: Set(id),
+            last_used_time: Set(Some(time::now().timestamp())),
+            ..Default::default()
+        }
+        .update(db)
+        .await
+    }
+
+    /// 撤销令牌
+    pub async fn revoke(db: &DbConn, id: u32, user_id: u32) -> Result<DeleteResult, DbErr> {
+        UserToken::delete_many()
+            .filter(user_token::Column::Id.eq(id))
+            .filter(user_token::Column::UserId.eq(user_id))
+            .exec(db)
+            .await
+    }
+}
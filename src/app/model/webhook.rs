@@ -0,0 +1,174 @@
+use sea_orm::*;
+
+use crate::app::entity::{webhook, webhook::Entity as Webhook};
+
+/// 条件
+#[derive(Clone)]
+pub struct WebhookWhere {
+    pub name: Option<String>,
+    pub url: Option<String>,
+    pub event: Option<String>,
+    pub status: Option<i32>,
+}
+
+impl WebhookWhere {
+    /// 格式化
+    pub fn format(&self) -> Self {
+        let mut name = None;
+        if self.name != Some("".to_string()) {
+            name = self.name.clone();
+        }
+
+        let mut url = None;
+        if self.url != Some("".to_string()) {
+            url = self.url.clone();
+        }
+
+        let mut event = None;
+        if self.event != Some("".to_string()) {
+            event = self.event.clone();
+        }
+
+        let mut status = None;
+        if self.status == Some(1) || self.status == Some(0) {
+            status = self.status;
+        }
+
+        Self {
+            name,
+            url,
+            event,
+            status,
+        }
+    }
+}
+
+pub struct WebhookModel;
+
+impl WebhookModel {
+    pub async fn find_by_id(db: &DbConn, id: u32) -> Result<Option<webhook::Model>, DbErr> {
+        Webhook::find_by_id(id).one(db).await
+    }
+
+    /// 启用的 webhook
+    pub async fn find_open(db: &DbConn) -> Result<Vec<webhook::Model>, DbErr> {
+        Webhook::find()
+            .filter(webhook::Column::Status.eq(1))
+            .order_by_asc(webhook::Column::Id)
+            .all(db)
+            .await
+    }
+
+    pub async fn find_all(db: &DbConn) -> Result<Vec<webhook::Model>, DbErr> {
+        Webhook::find()
+            .order_by_asc(webhook::Column::Id)
+            .all(db)
+            .await
+    }
+
+    // 搜索
+    pub async fn search_count(db: &DbConn, wheres: WebhookWhere) -> Result<u64, DbErr> {
+        Self::search(wheres).count(db).await
+    }
+
+    pub async fn search_in_page(
+        db: &DbConn,
+        page: u64,
+        per_page: u64,
+        wheres: WebhookWhere,
+    ) -> Result<(Vec<webhook::Model>, u64), DbErr> {
+        let paginator = Self::search(wheres)
+            .order_by_desc(webhook::Column::Id)
+            .paginate(db, per_page);
+        let num_pages = paginator.num_pages().await?;
+
+        paginator.fetch_page(page - 1).await.map(|p| (p, num_pages))
+    }
+
+    fn search(wheres: WebhookWhere) -> Select<Webhook> {
+        Webhook::find()
+            .apply_if(wheres.name, |query, v| {
+                query.filter(webhook::Column::Name.contains(v.as_str()))
+            })
+            .apply_if(wheres.url, |query, v| {
+                query.filter(webhook::Column::Url.contains(v.as_str()))
+            })
+            .apply_if(wheres.event, |query, v| {
+                query.filter(webhook::Column::Events.contains(v.as_str()))
+            })
+            .apply_if(wheres.status, |query, v| {
+                query.filter(webhook::Column::Status.eq(v))
+            })
+    }
+
+    pub async fn create(
+        db: &DbConn,
+        form_data: webhook::Model,
+    ) -> Result<webhook::ActiveModel, DbErr> {
+        webhook::ActiveModel {
+            name: Set(form_data.name.to_owned()),
+            url: Set(form_data.url.to_owned()),
+            secret: Set(form_data.secret.to_owned()),
+            events: Set(form_data.events.to_owned()),
+            status: Set(form_data.status),
+            add_time: Set(form_data.add_time),
+            ..Default::default()
+        }
+        .save(db)
+        .await
+    }
+
+    pub async fn update_by_id(
+        db: &DbConn,
+        id: u32,
+        form_data: webhook::Model,
+    ) -> Result<webhook::Model, DbErr> {
+        let webhook: webhook::ActiveModel = Webhook::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::Custom("Cannot find webhook.".to_owned()))
+            .map(Into::into)?;
+
+        webhook::ActiveModel {
+            id: webhook.id,
+            name: Set(form_data.name.to_owned()),
+            url: Set(form_data.url.to_owned()),
+            secret: Set(form_data.secret.to_owned()),
+            events: Set(form_data.events.to_owned()),
+            status: Set(form_data.status),
+            ..Default::default()
+        }
+        .update(db)
+        .await
+    }
+
+    pub async fn update_status_by_id(
+        db: &DbConn,
+        id: u32,
+        status: i32,
+    ) -> Result<webhook::Model, DbErr> {
+        let webhook: webhook::ActiveModel = Webhook::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::Custom("Cannot find webhook.".to_owned()))
+            .map(Into::into)?;
+
+        webhook::ActiveModel {
+            id: webhook.id,
+            status: Set(status),
+            ..Default::default()
+        }
+        .update(db)
+        .await
+    }
+
+    pub async fn delete(db: &DbConn, id: u32) -> Result<DeleteResult, DbErr> {
+        let webhook: webhook::ActiveModel = Webhook::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::Custom("Cannot find webhook.".to_owned()))
+            .map(Into::into)?;
+
+        webhook.delete(db).await
+    }
+}
@@ -0,0 +1,206 @@
+use sea_orm::*;
+use sea_query::Expr;
+
+use crate::app::entity::{webhook_log, webhook_log::Entity as WebhookLog};
+
+/// 待发送
+pub const STATUS_WAIT: i32 = 0;
+
+/// 发送成功
+pub const STATUS_SUCCESS: i32 = 1;
+
+/// 发送失败，不再重试
+pub const STATUS_FAILED: i32 = 2;
+
+/// 条件
+#[derive(Clone)]
+pub struct WebhookLogWhere {
+    pub webhook_id: Option<u32>,
+    pub event: Option<String>,
+    pub status: Option<i32>,
+}
+
+impl WebhookLogWhere {
+    /// 格式化
+    pub fn format(&self) -> Self {
+        let mut webhook_id = None;
+        if self.webhook_id.unwrap_or_default() > 0 {
+            webhook_id = self.webhook_id;
+        }
+
+        let mut event = None;
+        if self.event != Some("".to_string()) {
+            event = self.event.clone();
+        }
+
+        let mut status = None;
+        if let Some(v) = self.status {
+            if [STATUS_WAIT, STATUS_SUCCESS, STATUS_FAILED].contains(&v) {
+                status = Some(v);
+            }
+        }
+
+        Self {
+            webhook_id,
+            event,
+            status,
+        }
+    }
+}
+
+pub struct WebhookLogModel;
+
+impl WebhookLogModel {
+    pub async fn find_by_id(db: &DbConn, id: u32) -> Result<Option<webhook_log::Model>, DbErr> {
+        WebhookLog::find_by_id(id).one(db).await
+    }
+
+    /// 到达发送时间的记录
+    pub async fn find_due(
+        db: &DbConn,
+        now: i64,
+        limit: u64,
+    ) -> Result<Vec<webhook_log::Model>, DbErr> {
+        WebhookLog::find()
+            .filter(webhook_log::Column::Status.eq(STATUS_WAIT))
+            .filter(webhook_log::Column::NextTime.lte(now))
+            .order_by_asc(webhook_log::Column::Id)
+            .limit(limit)
+            .all(db)
+            .await
+    }
+
+    // 搜索
+    pub async fn search_count(db: &DbConn, wheres: WebhookLogWhere) -> Result<u64, DbErr> {
+        Self::search(wheres).count(db).await
+    }
+
+    pub async fn search_in_page(
+        db: &DbConn,
+        page: u64,
+        per_page: u64,
+        wheres: WebhookLogWhere,
+    ) -> Result<(Vec<webhook_log::Model>, u64), DbErr> {
+        let paginator = Self::search(wheres)
+            .order_by_desc(webhook_log::Column::Id)
+            .paginate(db, per_page);
+        let num_pages = paginator.num_pages().await?;
+
+        paginator.fetch_page(page - 1).await.map(|p| (p, num_pages))
+    }
+
+    fn search(wheres: WebhookLogWhere) -> Select<WebhookLog> {
+        WebhookLog::find()
+            .apply_if(wheres.webhook_id, |query, v| {
+                query.filter(webhook_log::Column::WebhookId.eq(v))
+            })
+            .apply_if(wheres.event, |query, v| {
+                query.filter(webhook_log::Column::Event.eq(v))
+            })
+            .apply_if(wheres.status, |query, v| {
+                query.filter(webhook_log::Column::Status.eq(v))
+            })
+    }
+
+    pub async fn create(
+        db: &DbConn,
+        form_data: webhook_log::Model,
+    ) -> Result<webhook_log::Model, DbErr> {
+        webhook_log::ActiveModel {
+            webhook_id: Set(form_data.webhook_id),
+            event: Set(form_data.event.to_owned()),
+            payload: Set(form_data.payload.to_owned()),
+            status: Set(STATUS_WAIT),
+            attempts: Set(0),
+            response_code: Set(0),
+            response: Set("".to_string()),
+            next_time: Set(form_data.add_time),
+            send_time: Set(0),
+            add_time: Set(form_data.add_time),
+            ..Default::default()
+        }
+        .insert(db)
+        .await
+    }
+
+    /// 发送成功
+    pub async fn update_success(
+        db: &DbConn,
+        id: u32,
+        code: i32,
+        response: &str,
+        now: i64,
+    ) -> Result<UpdateResult, DbErr> {
+        WebhookLog::update_many()
+            .col_expr(webhook_log::Column::Status, Expr::value(STATUS_SUCCESS))
+            .col_expr(
+                webhook_log::Column::Attempts,
+                Expr::col(webhook_log::Column::Attempts).add(1),
+            )
+            .col_expr(webhook_log::Column::ResponseCode, Expr::value(code))
+            .col_expr(webhook_log::Column::Response, Expr::value(response))
+            .col_expr(webhook_log::Column::SendTime, Expr::value(now))
+            .filter(webhook_log::Column::Id.eq(id))
+            .exec(db)
+            .await
+    }
+
+    /// 发送失败，next_time 为 None 时不再重试
+    pub async fn update_failed(
+        db: &DbConn,
+        id: u32,
+        attempts: i32,
+        next_time: Option<i64>,
+        code: i32,
+        response: &str,
+        now: i64,
+    ) -> Result<UpdateResult, DbErr> {
+        let mut update = WebhookLog::update_many()
+            .col_expr(webhook_log::Column::Attempts, Expr::value(attempts))
+            .col_expr(webhook_log::Column::ResponseCode, Expr::value(code))
+            .col_expr(webhook_log::Column::Response, Expr::value(response))
+            .col_expr(webhook_log::Column::SendTime, Expr::value(now));
+
+        update = match next_time {
+            Some(v) => update.col_expr(webhook_log::Column::NextTime, Expr::value(v)),
+            None => update.col_expr(webhook_log::Column::Status, Expr::value(STATUS_FAILED)),
+        };
+
+        update.filter(webhook_log::Column::Id.eq(id)).exec(db).await
+    }
+
+    /// 重新发送
+    pub async fn retry(db: &DbConn, id: u32, now: i64) -> Result<UpdateResult, DbErr> {
+        WebhookLog::update_many()
+            .col_expr(webhook_log::Column::Status, Expr::value(STATUS_WAIT))
+            .col_expr(webhook_log::Column::Attempts, Expr::value(0))
+            .col_expr(webhook_log::Column::NextTime, Expr::value(now))
+            .filter(webhook_log::Column::Id.eq(id))
+            .exec(db)
+            .await
+    }
+
+    pub async fn delete(db: &DbConn, id: u32) -> Result<DeleteResult, DbErr> {
+        WebhookLog::delete_many()
+            .filter(webhook_log::Column::Id.eq(id))
+            .exec(db)
+            .await
+    }
+
+    /// 删除 webhook 的全部记录
+    pub async fn delete_by_webhook_id(db: &DbConn, webhook_id: u32) -> Result<DeleteResult, DbErr> {
+        WebhookLog::delete_many()
+            .filter(webhook_log::Column::WebhookId.eq(webhook_id))
+            .exec(db)
+            .await
+    }
+
+    /// 删除之前发送成功的记录
+    pub async fn delete_success_before(db: &DbConn, time: i64) -> Result<DeleteResult, DbErr> {
+        WebhookLog::delete_many()
+            .filter(webhook_log::Column::Status.eq(STATUS_SUCCESS))
+            .filter(webhook_log::Column::SendTime.lt(time))
+            .exec(db)
+            .await
+    }
+}
@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use crate::nako::global::Serialize;
+use crate::nako::page::{Pagination, PaginationMeta};
+
+// 每页最大数量
+const MAX_PER_PAGE: u64 = 50;
+
+/// 列表数据
+#[derive(Serialize)]
+pub struct ListData<T> {
+    pub list: Vec<T>,
+    pub pagination: PaginationMeta,
+}
+
+impl<T> ListData<T> {
+    pub fn new(list: Vec<T>, total: u64, page: u64, per_page: u64) -> Self {
+        let pagination = Pagination::new(total as usize, page as usize, per_page as usize);
+
+        Self {
+            list,
+            pagination: pagination.meta(),
+        }
+    }
+}
+
+// 分页参数
+pub fn page_params(params: &HashMap<String, String>, def_per_page: u64) -> (u64, u64) {
+    let mut page: u64 = 1;
+    if let Some(p) = params.get("page") {
+        page = p.parse::<u64>().unwrap_or(1).max(1);
+    }
+
+    let mut per_page: u64 = def_per_page;
+    if let Some(p) = params.get("per_page") {
+        per_page = p.parse::<u64>().unwrap_or(def_per_page);
+    }
+    let per_page = per_page.clamp(1, MAX_PER_PAGE);
+
+    (page, per_page)
+}
+
+// 字符串参数
+pub fn string_param(params: &HashMap<String, String>, name: &str) -> Option<String> {
+    match params.get(name) {
+        Some(v) if !v.is_empty() => Some(v.clone()),
+        _ => None,
+    }
+}
+
+// 数字参数
+pub fn number_param<T: std::str::FromStr>(
+    params: &HashMap<String, String>,
+    name: &str,
+) -> Option<T> {
+    match params.get(name) {
+        Some(v) => v.parse::<T>().ok(),
+        None => None,
+    }
+}
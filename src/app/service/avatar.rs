@@ -0,0 +1,255 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::RwLock;
+use std::time::{Duration, SystemTime};
+
+use once_cell::sync::Lazy;
+use sea_orm::DbConn;
+
+use crate::nako::{avatar, config, time, utils};
+
+use crate::app::model::{comment, guestbook};
+
+/// 缓存的图片格式
+const EXTENSIONS: [(&str, &str); 4] = [
+    ("jpg", "image/jpeg"),
+    ("png", "image/png"),
+    ("gif", "image/gif"),
+    ("svg", "image/svg+xml"),
+];
+
+/// 未知哈希时重新读取邮箱的最短间隔秒数
+const RELOAD_SECONDS: i64 = 60;
+
+// 已通过的评论及留言邮箱，哈希对应邮箱
+static EMAILS: Lazy<RwLock<Emails>> = Lazy::new(|| RwLock::new(Emails::default()));
+
+#[derive(Default)]
+struct Emails {
+    hashes: HashMap<String, String>,
+    load_time: i64,
+}
+
+/// 头像图片
+pub struct Image {
+    pub content_type: String,
+    pub data: Vec<u8>,
+}
+
+/// 哈希对应的邮箱，只包含已通过的评论及留言，未找到时最多每分钟重新读取一次
+pub async fn find_email(db: &DbConn, hash: &str) -> Option<String> {
+    let load_time = {
+        let emails = match EMAILS.read() {
+            Ok(v) => v,
+            Err(e) => e.into_inner(),
+        };
+        if let Some(v) = emails.hashes.get(hash) {
+            return Some(v.clone());
+        }
+
+        emails.load_time
+    };
+
+    let now = time::now().timestamp();
+    if now - load_time < RELOAD_SECONDS {
+        return None;
+    }
+
+    let mut list = comment::CommentModel::find_approved_emails(db)
+        .await
+        .unwrap_or_default();
+    list.extend(
+        guestbook::GuestbookModel::find_approved_emails(db)
+            .await
+            .unwrap_or_default(),
+    );
+
+    let mut hashes = HashMap::new();
+    for email in list {
+        let email = email.trim().to_lowercase();
+        if email.is_empty() {
+            continue;
+        }
+
+        hashes.insert(avatar::private_hash(email.as_str()), email.clone());
+        hashes.insert(utils::md5(email.as_str()), email);
+    }
+
+    let email = hashes.get(hash).cloned();
+
+    let data = Emails {
+        hashes,
+        load_time: now,
+    };
+    match EMAILS.write() {
+        Ok(mut v) => *v = data,
+        Err(e) => *e.into_inner() = data,
+    }
+
+    email
+}
+
+/// 获取邮箱的头像，先读缓存，过期后用邮箱的 md5 请求 Gravatar，没有时生成 identicon
+pub async fn get(hash: &str, email: &str) -> Image {
+    let cached = find_cached(hash);
+    if let Some((_, image, true)) = &cached {
+        return Image {
+            content_type: image.content_type.clone(),
+            data: image.data.clone(),
+        };
+    }
+
+    let image = match fetch(utils::md5(email).as_str()).await {
+        Ok(Some(image)) => image,
+        Ok(None) => identicon(hash),
+        Err(err) => {
+            log::warn!("avatar {} fetch err: {}", hash, err);
+
+            // 请求失败时继续使用过期的缓存，没有缓存时不保存 identicon，以便下次重试
+            return match cached {
+                Some((_, image, _)) => image,
+                None => identicon(hash),
+            };
+        }
+    };
+
+    if let Some((path, _, _)) = cached {
+        let _ = fs::remove_file(path);
+    }
+    save(hash, &image);
+
+    image
+}
+
+/// 本地生成的 identicon
+pub fn identicon(hash: &str) -> Image {
+    Image {
+        content_type: "image/svg+xml".to_string(),
+        data: avatar::identicon(hash, avatar::size()).into_bytes(),
+    }
+}
+
+// 缓存文件
+fn cache_file(hash: &str, ext: &str) -> PathBuf {
+    PathBuf::from(avatar::cache_path()).join(format!("{}.{}", hash, ext))
+}
+
+// 查找缓存，返回路径、图片及是否未过期
+fn find_cached(hash: &str) -> Option<(PathBuf, Image, bool)> {
+    let cache_days = config::section::<u64>("avatar", "cache_days", 7);
+
+    for (ext, content_type) in EXTENSIONS {
+        let path = cache_file(hash, ext);
+        let data = match fs::read(&path) {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+
+        let fresh = fs::metadata(&path)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|t| SystemTime::now().duration_since(t).ok())
+            .map(|age| age < Duration::from_secs(cache_days * 86400))
+            .unwrap_or(false);
+
+        let image = Image {
+            content_type: content_type.to_string(),
+            data,
+        };
+
+        return Some((path, image, fresh));
+    }
+
+    None
+}
+
+// 保存缓存
+fn save(hash: &str, image: &Image) {
+    let ext = EXTENSIONS
+        .iter()
+        .find(|(_, v)| *v == image.content_type)
+        .map(|(k, _)| *k)
+        .unwrap_or("svg");
+
+    let dir = avatar::cache_path();
+    if let Err(err) = fs::create_dir_all(dir.as_str()) {
+        log::error!("avatar cache dir {} err: {}", dir, err);
+        return;
+    }
+
+    // 缓存文件数量上限
+    let max_files = config::section::<usize>("avatar", "max_files", 10000);
+    let count = fs::read_dir(dir.as_str())
+        .map(|v| v.count())
+        .unwrap_or_default();
+    if count >= max_files {
+        log::warn!("avatar cache {} is full", dir);
+        return;
+    }
+
+    if let Err(err) = fs::write(cache_file(hash, ext), &image.data) {
+        log::error!("avatar {} save err: {}", hash, err);
+    }
+}
+
+// 请求 Gravatar，没有头像或者隐私模式时返回 None
+async fn fetch(hash: &str) -> Result<Option<Image>, String> {
+    if avatar::privacy() {
+        return Ok(None);
+    }
+
+    let gravatar_url = config::section::<String>(
+        "avatar",
+        "gravatar_url",
+        "https://www.gravatar.com/avatar/".to_string(),
+    );
+    if gravatar_url.is_empty() {
+        return Ok(None);
+    }
+
+    let timeout = config::section::<u64>("avatar", "timeout", 5);
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(timeout))
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let url = format!(
+        "{}/{}?s={}&d=404",
+        gravatar_url.trim_end_matches('/'),
+        hash,
+        avatar::size()
+    );
+    let res = client.get(url).send().await.map_err(|e| e.to_string())?;
+
+    if res.status().as_u16() == 404 {
+        return Ok(None);
+    }
+    if !res.status().is_success() {
+        return Err(format!("status {}", res.status()));
+    }
+
+    let content_type = res
+        .headers()
+        .get("Content-Type")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.split(';').next())
+        .unwrap_or_default()
+        .trim()
+        .to_lowercase();
+
+    // 只接受位图，避免缓存第三方的 svg
+    if !EXTENSIONS
+        .iter()
+        .any(|(ext, v)| *ext != "svg" && *v == content_type)
+    {
+        return Err(format!("content type {}", content_type));
+    }
+
+    let data = res.bytes().await.map_err(|e| e.to_string())?;
+
+    Ok(Some(Image {
+        content_type,
+        data: data.to_vec(),
+    }))
+}
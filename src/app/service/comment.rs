@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+use sea_orm::DbConn;
+
+use crate::nako::{global::AppState, i18n, time};
+
+use crate::app::entity::{
+    art as art_entity, comment as comment_entity, comment_rule as comment_rule_entity,
+};
+use crate::app::model::{
+    comment::{self, STATUS_APPROVED, STATUS_PENDING, STATUS_SPAM},
+    comment_rule::{self, ACTION_ALLOW, ACTION_BLOCK, TYPE_EMAIL, TYPE_IP, TYPE_KEYWORD},
+};
+use crate::app::service::{mail, webhook};
+
+/// 自动通过有已通过评论的邮箱
+pub const SETTING_AUTO_APPROVE: &str = "comment_auto_approve";
+
+/// 文章发布多少天后关闭评论，0 为不关闭
+pub const SETTING_CLOSE_DAYS: &str = "comment_close_days";
+
+/// 文章是否已关闭评论
+pub fn is_closed(art: &art_entity::Model, settings: &HashMap<String, String>) -> bool {
+    if art.comment_closed == 1 {
+        return true;
+    }
+
+    let days = settings
+        .get(SETTING_CLOSE_DAYS)
+        .and_then(|v| v.trim().parse::<i64>().ok())
+        .unwrap_or(0);
+    if days <= 0 {
+        return false;
+    }
+
+    let add_time = art.add_time.unwrap_or_default();
+    add_time > 0 && time::now().timestamp() > add_time + days * 86400
+}
+
+/// 检测规则
+pub fn check_rule(rule_type: &str, value: &str, action: &str) -> Result<(), String> {
+    if ![ACTION_ALLOW, ACTION_BLOCK].contains(&action) {
+        return Err(i18n::t("admin-rule-action-error"));
+    }
+
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(i18n::t("admin-rule-content-required"));
+    }
+
+    match rule_type {
+        TYPE_IP => {
+            let valid = match value.strip_suffix('*') {
+                Some(prefix) => {
+                    let ipv4 = prefix.chars().all(|c| c.is_ascii_digit() || c == '.');
+                    let ipv6 = prefix.contains(':')
+                        && prefix.chars().all(|c| c.is_ascii_hexdigit() || c == ':');
+                    !prefix.is_empty() && (ipv4 || ipv6)
+                }
+                None => value.parse::<IpAddr>().is_ok(),
+            };
+            if !valid {
+                return Err(i18n::t("admin-rule-ip-error"));
+            }
+        }
+        TYPE_EMAIL => {
+            if !value.contains('@') {
+                return Err(i18n::t("admin-rule-email-error"));
+            }
+        }
+        TYPE_KEYWORD => {}
+        _ => return Err(i18n::t("admin-rule-type-error")),
+    }
+
+    Ok(())
+}
+
+/// 评论是否匹配规则
+pub fn matches(rule: &comment_rule_entity::Model, data: &comment_entity::Model) -> bool {
+    let value = rule.value.trim().to_lowercase();
+    if value.is_empty() {
+        return false;
+    }
+
+    let email = data.email.clone().unwrap_or_default().to_lowercase();
+
+    match rule.rule_type.as_str() {
+        TYPE_IP => {
+            let ip = data.add_ip.clone().unwrap_or_default();
+            match value.strip_suffix('*') {
+                Some(prefix) => ip.starts_with(prefix),
+                None => ip == value,
+            }
+        }
+        TYPE_EMAIL => {
+            if value.starts_with('@') {
+                email.ends_with(value.as_str())
+            } else {
+                email == value
+            }
+        }
+        TYPE_KEYWORD => [
+            data.username.to_lowercase(),
+            email,
+            data.content.to_lowercase(),
+        ]
+        .iter()
+        .any(|v| v.contains(value.as_str())),
+        _ => false,
+    }
+}
+
+/// 新评论的状态，屏蔽规则优先，其次为通过规则及自动通过
+pub async fn moderate(
+    db: &DbConn,
+    settings: &HashMap<String, String>,
+    data: &comment_entity::Model,
+) -> i32 {
+    let rules = comment_rule::CommentRuleModel::find_all(db)
+        .await
+        .unwrap_or_default();
+
+    for (action, status) in [(ACTION_BLOCK, STATUS_SPAM), (ACTION_ALLOW, STATUS_APPROVED)] {
+        let rule = rules
+            .iter()
+            .filter(|v| v.action == action)
+            .find(|v| matches(v, data));
+        if let Some(rule) = rule {
+            let _ = comment_rule::CommentRuleModel::hit(db, rule.id).await;
+            return status;
+        }
+    }
+
+    let email = data.email.clone().unwrap_or_default();
+    if settings.get(SETTING_AUTO_APPROVE).map(|v| v.as_str()) == Some("1") && !email.is_empty() {
+        let approved = comment::CommentModel::has_approved_email(db, email.as_str())
+            .await
+            .unwrap_or_default();
+        if approved {
+            return STATUS_APPROVED;
+        }
+    }
+
+    STATUS_PENDING
+}
+
+/// 评论审核通过，通知被回复的评论者
+pub async fn approved(state: &AppState, data: &comment_entity::Model) {
+    mail::notify_reply(state, data).await;
+    webhook::fire(&state.db, webhook::COMMENT_APPROVED, data).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(rule_type: &str, value: &str) -> comment_rule_entity::Model {
+        comment_rule_entity::Model {
+            rule_type: rule_type.to_string(),
+            value: value.to_string(),
+            action: ACTION_BLOCK.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn comment() -> comment_entity::Model {
+        comment_entity::Model {
+            username: "Nako".to_string(),
+            email: Some("Test@Example.com".to_string()),
+            content: "Buy cheap pills".to_string(),
+            add_ip: Some("192.168.1.10".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn match_ip() {
+        let data = comment();
+
+        assert!(matches(&rule(TYPE_IP, "192.168.1.10"), &data));
+        assert!(matches(&rule(TYPE_IP, "192.168.*"), &data));
+        assert!(!matches(&rule(TYPE_IP, "192.168.1.1"), &data));
+        assert!(!matches(&rule(TYPE_IP, "10.*"), &data));
+    }
+
+    #[test]
+    fn match_email() {
+        let data = comment();
+
+        assert!(matches(&rule(TYPE_EMAIL, "test@example.com"), &data));
+        assert!(matches(&rule(TYPE_EMAIL, " @EXAMPLE.com "), &data));
+        assert!(!matches(&rule(TYPE_EMAIL, "@example.org"), &data));
+        assert!(!matches(&rule(TYPE_EMAIL, "example.com"), &data));
+    }
+
+    #[test]
+    fn match_keyword() {
+        let data = comment();
+
+        assert!(matches(&rule(TYPE_KEYWORD, "cheap"), &data));
+        assert!(matches(&rule(TYPE_KEYWORD, "nako"), &data));
+        assert!(matches(&rule(TYPE_KEYWORD, "example"), &data));
+        assert!(!matches(&rule(TYPE_KEYWORD, "casino"), &data));
+    }
+
+    #[test]
+    fn match_empty() {
+        let data = comment();
+
+        assert!(!matches(&rule(TYPE_KEYWORD, "  "), &data));
+        assert!(!matches(&rule("other", "nako"), &data));
+    }
+}
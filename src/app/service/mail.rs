@@ -0,0 +1,370 @@
+use std::time::Duration;
+
+use fluent_bundle::FluentArgs;
+use once_cell::sync::Lazy;
+use sea_orm::DbConn;
+use tokio::sync::Notify;
+
+use crate::nako::{app, config, global::AppState, i18n, mail as nako_mail, time, view};
+
+use crate::app::entity::{
+    self, art as art_entity, comment as comment_entity, guestbook as guestbook_entity,
+    mail as mail_entity,
+};
+use crate::app::model::{art, comment, mail, user};
+use crate::app::service::{permalink, seo, setting};
+
+// 每次发送的数量
+const BATCH: usize = 20;
+
+// 没有新邮件时的检查间隔
+const POLL_SECS: u64 = 30;
+
+// 有新邮件时唤醒发送队列
+static WAKE: Lazy<Notify> = Lazy::new(Notify::new);
+
+/// 是否开启邮件通知
+pub fn enabled() -> bool {
+    config::section::<bool>("mail", "enable", false)
+}
+
+/// 启动发送队列
+pub fn start(db: DbConn) {
+    if !enabled() {
+        return;
+    }
+
+    actix_web::rt::spawn(async move {
+        loop {
+            if deliver(&db).await >= BATCH {
+                continue;
+            }
+
+            let _ =
+                actix_web::rt::time::timeout(Duration::from_secs(POLL_SECS), WAKE.notified()).await;
+        }
+    });
+}
+
+/// 发送到期的邮件，失败时按次数延后重试，返回处理的数量
+pub async fn deliver(db: &DbConn) -> usize {
+    let conf = nako_mail::MailConfig::load();
+    let max_attempts = config::section::<i32>("mail", "max_attempts", 5);
+    let retry_interval = config::section::<i64>("mail", "retry_interval", 60);
+
+    let now = time::now().timestamp();
+    let list = match mail::MailModel::find_due(db, now, BATCH as u64).await {
+        Ok(v) => v,
+        Err(err) => {
+            log::error!("mail queue err: {}", err);
+            return 0;
+        }
+    };
+
+    let count = list.len();
+    for data in list {
+        let res = match nako_mail::send(&conf, &data.email, &data.subject, &data.body).await {
+            Ok(_) => mail::MailModel::update_sent(db, data.id, time::now().timestamp()).await,
+            Err(err) => {
+                log::error!("mail {} to {} err: {}", data.id, data.email, err);
+
+                let attempts = data.attempts + 1;
+                let next_time = if attempts < max_attempts {
+                    Some(now + retry_delay(retry_interval, attempts))
+                } else {
+                    None
+                };
+                let error: String = err.chars().take(255).collect();
+
+                mail::MailModel::update_failed(db, data.id, attempts, next_time, error.as_str())
+                    .await
+            }
+        };
+
+        if let Err(err) = res {
+            log::error!("mail {} update err: {}", data.id, err);
+        }
+    }
+
+    // 清理已发送的邮件
+    let keep_days = config::section::<i64>("mail", "keep_days", 30);
+    if keep_days > 0 {
+        let _ = mail::MailModel::delete_sent_before(db, now - keep_days * 86400).await;
+    }
+
+    count
+}
+
+/// 重试间隔按次数翻倍
+pub fn retry_delay(interval: i64, attempts: i32) -> i64 {
+    interval * 2_i64.pow((attempts - 1).clamp(0, 10) as u32)
+}
+
+/// 加入发送队列
+pub async fn queue(db: &DbConn, email: &str, subject: &str, body: String) {
+    if !enabled() || email.is_empty() {
+        return;
+    }
+
+    let res = mail::MailModel::create(
+        db,
+        mail_entity::Model {
+            email: email.to_string(),
+            subject: subject.to_string(),
+            body,
+            add_time: time::now().timestamp(),
+            ..entity::default()
+        },
+    )
+    .await;
+
+    match res {
+        Ok(_) => WAKE.notify_one(),
+        Err(err) => log::error!("mail queue {} err: {}", email, err),
+    }
+}
+
+/// 新评论通知文章作者
+pub async fn notify_comment(
+    state: &AppState,
+    art: &art_entity::Model,
+    data: &comment_entity::Model,
+) {
+    if !enabled() {
+        return;
+    }
+
+    let email = author_email(&state.db, art.user_id).await;
+    if email.is_empty() {
+        return;
+    }
+
+    let site = match Site::new(state).await {
+        Some(v) => v,
+        None => return,
+    };
+
+    let mut ctx = tera::Context::new();
+    ctx.insert("art", art);
+    ctx.insert("comment", data);
+    ctx.insert("art_link", &site.link(permalink::art_url(art).as_str()));
+    ctx.insert("admin_link", &site.admin_link());
+
+    let mut args = FluentArgs::new();
+    args.set("title", art.title.clone());
+
+    site.queue(
+        state,
+        email.as_str(),
+        "mail-comment-subject",
+        args,
+        "mail/comment.html",
+        ctx,
+    )
+    .await;
+}
+
+/// 回复的评论审核通过后，通知开启了回复通知的评论者
+pub async fn notify_reply(state: &AppState, data: &comment_entity::Model) {
+    if !enabled() || data.status != Some(1) {
+        return;
+    }
+
+    let db = &state.db;
+
+    let reply_id = match data.reply_id {
+        Some(v) if v > 0 => v,
+        _ => return,
+    };
+
+    let parent = match comment::CommentModel::find_by_id(db, reply_id).await {
+        Ok(Some(v)) => v,
+        _ => return,
+    };
+
+    let email = parent.email.clone().unwrap_or_default();
+    if parent.notify != 1 || parent.status != Some(1) || email.is_empty() {
+        return;
+    }
+
+    // 回复自己不通知
+    if data.email.as_deref() == Some(email.as_str()) {
+        return;
+    }
+
+    let art = match art::ArtModel::find_by_id(db, data.art_id).await {
+        Ok(Some(v)) => v,
+        _ => return,
+    };
+
+    let site = match Site::new(state).await {
+        Some(v) => v,
+        None => return,
+    };
+    let art_link = format!(
+        "{}#comment-{}",
+        site.link(permalink::art_url(&art).as_str()),
+        data.id
+    );
+
+    let mut ctx = tera::Context::new();
+    ctx.insert("art", &art);
+    ctx.insert("comment", &parent);
+    ctx.insert("reply", data);
+    ctx.insert("art_link", &art_link);
+
+    let mut args = FluentArgs::new();
+    args.set("title", art.title.clone());
+
+    site.queue(
+        state,
+        email.as_str(),
+        "mail-reply-subject",
+        args,
+        "mail/reply.html",
+        ctx,
+    )
+    .await;
+}
+
+/// 新留言通知管理员
+pub async fn notify_guestbook(state: &AppState, data: &guestbook_entity::Model) {
+    if !enabled() {
+        return;
+    }
+
+    let email = author_email(&state.db, app::get_admin_id()).await;
+    if email.is_empty() {
+        return;
+    }
+
+    let site = match Site::new(state).await {
+        Some(v) => v,
+        None => return,
+    };
+
+    let mut ctx = tera::Context::new();
+    ctx.insert("guestbook", data);
+    ctx.insert("admin_link", &site.admin_link());
+
+    site.queue(
+        state,
+        email.as_str(),
+        "mail-guestbook-subject",
+        FluentArgs::new(),
+        "mail/guestbook.html",
+        ctx,
+    )
+    .await;
+}
+
+// 作者邮箱，没有设置时使用管理员邮箱
+async fn author_email(db: &DbConn, user_id: u32) -> String {
+    let admin_id = app::get_admin_id();
+
+    for id in [user_id, admin_id] {
+        if id == 0 {
+            continue;
+        }
+
+        if let Ok(Some(v)) = user::UserModel::find_user_by_id(db, id).await {
+            if !v.email.is_empty() {
+                return v.email;
+            }
+        }
+    }
+
+    "".to_string()
+}
+
+// 邮件中的网站信息，链接只使用网站设置中的网站地址，不使用请求的 Host
+struct Site {
+    name: String,
+    url: String,
+    locale: String,
+}
+
+impl Site {
+    async fn new(state: &AppState) -> Option<Self> {
+        let settings = setting::settings(&mut state.clone()).await;
+
+        let url = settings
+            .get(seo::SETTING_KEY)
+            .map(|v| v.trim().trim_end_matches('/').to_string())
+            .unwrap_or_default();
+        if url.is_empty() {
+            log::warn!("mail notify skipped, website url is not set");
+            return None;
+        }
+
+        Some(Self {
+            name: settings.get("website_name").cloned().unwrap_or_default(),
+            url,
+            // 使用网站语言
+            locale: i18n::resolve(
+                None,
+                settings.get(i18n::SETTING_KEY).map(|v| v.as_str()),
+                None,
+            ),
+        })
+    }
+
+    fn link(&self, path: &str) -> String {
+        format!("{}/{}", self.url, path.trim_start_matches('/'))
+    }
+
+    fn admin_link(&self) -> String {
+        let admin_prefix = config::section::<String>("app", "admin_prefix", "admin".to_string());
+
+        self.link(admin_prefix.as_str())
+    }
+
+    // 渲染邮件模板并加入发送队列
+    async fn queue(
+        &self,
+        state: &AppState,
+        email: &str,
+        subject: &str,
+        args: FluentArgs<'_>,
+        tpl: &str,
+        mut ctx: tera::Context,
+    ) {
+        ctx.insert("site_name", &self.name);
+        ctx.insert("site_url", &self.url);
+
+        let (subject, body) = i18n::scope(self.locale.clone(), async {
+            (
+                i18n::t_args(subject, Some(&args)),
+                state.view.get().render(tpl, &ctx),
+            )
+        })
+        .await;
+
+        match body {
+            Ok(body) => queue(&state.db, email, subject.as_str(), body).await,
+            Err(err) => log::error!("mail {} render err: {}", tpl, view::error_message(&err)),
+        }
+    }
+}
+
+/// 发送测试邮件，不经过发送队列，使用默认语言
+pub async fn send_test(tpl: &tera::Tera, email: &str) -> Result<(), String> {
+    let conf = nako_mail::MailConfig::load();
+    conf.check()?;
+
+    let mut ctx = tera::Context::new();
+    ctx.insert("email", email);
+    ctx.insert("host", &conf.host);
+
+    let body = tpl
+        .render("mail/test.html", &ctx)
+        .map_err(|e| view::error_message(&e))?;
+
+    nako_mail::send(
+        &conf,
+        email,
+        i18n::t("mail-test-subject").as_str(),
+        body.as_str(),
+    )
+    .await
+}
@@ -0,0 +1,461 @@
+use std::fs;
+use std::io::{Cursor, Read, Write};
+use std::path::Path;
+
+use pulldown_cmark::{html, Options, Parser};
+use sea_orm::{DbConn, TryIntoModel};
+use zip::{write::FileOptions, ZipArchive, ZipWriter};
+
+use crate::nako::global::{Deserialize, Serialize};
+use crate::nako::time::{self, NaiveDate, NaiveDateTime, TimeZone};
+use crate::nako::{i18n, utils};
+
+use crate::app::entity::{self, art as art_entity, page as page_entity};
+use crate::app::model::{art, cate, page};
+use crate::app::service::permalink;
+
+/// 头部信息
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct FrontMatter {
+    #[serde(rename = "type")]
+    pub typ: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cate: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cover: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brief: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tpl: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_top: Option<i32>,
+    pub status: Option<i32>,
+    pub date: Option<String>,
+}
+
+/// 导入报告
+#[derive(Serialize, Default)]
+pub struct ImportReport {
+    pub created: u64,
+    pub updated: u64,
+    pub skipped: u64,
+    pub messages: Vec<String>,
+}
+
+// 格式化时间
+fn format_date(t: Option<i64>) -> Option<String> {
+    t.map(|v| time::from_timestamp(v).to_rfc3339())
+}
+
+// 解析时间
+fn parse_date(date: &str) -> Option<i64> {
+    if let Ok(v) = time::DateTime::parse_from_rfc3339(date) {
+        return Some(v.timestamp());
+    }
+
+    let tz = time::timezone();
+
+    if let Ok(v) = NaiveDateTime::parse_from_str(date, "%Y-%m-%d %H:%M:%S") {
+        return tz.from_local_datetime(&v).single().map(|d| d.timestamp());
+    }
+
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .ok()
+        .and_then(|v| v.and_hms_opt(0, 0, 0))
+        .and_then(|v| tz.from_local_datetime(&v).single())
+        .map(|d| d.timestamp())
+}
+
+// 生成文件内容
+fn render(front: &FrontMatter, content: &str) -> Result<String, String> {
+    let yaml = serde_yaml::to_string(front).map_err(|e| e.to_string())?;
+
+    Ok(format!("---\n{}---\n\n{}\n", yaml, content))
+}
+
+/// 拆分头部及内容
+pub fn split(data: &str) -> Result<(FrontMatter, String), String> {
+    let data = data.trim_start_matches('\u{feff}');
+
+    let rest = match data.strip_prefix("---") {
+        Some(v) => v.trim_start_matches('\r').trim_start_matches('\n'),
+        None => return Err(i18n::t("admin-import-no-front-matter")),
+    };
+
+    let (yaml, content) = match rest.find("\n---") {
+        Some(i) => (&rest[..i], &rest[i + 4..]),
+        None => return Err(i18n::t("admin-import-front-matter-error")),
+    };
+
+    let front: FrontMatter = serde_yaml::from_str(yaml).map_err(|e| e.to_string())?;
+
+    // 跳过结束行
+    let content = match content.find('\n') {
+        Some(i) => &content[i + 1..],
+        None => "",
+    };
+    let content = content
+        .trim_start_matches(['\r', '\n'])
+        .trim_end()
+        .to_string();
+
+    Ok((front, content))
+}
+
+// markdown 转 html
+fn markdown_to_html(data: &str) -> String {
+    let parser = Parser::new_ext(data, Options::all());
+
+    let mut out = String::new();
+    html::push_html(&mut out, parser);
+
+    out
+}
+
+/// 导出全部文章及页面为 zip
+pub async fn export(db: &DbConn) -> Result<Vec<u8>, String> {
+    let arts = art::ArtModel::find_all(db)
+        .await
+        .map_err(|e| e.to_string())?;
+    let pages = page::PageModel::find_all(db)
+        .await
+        .map_err(|e| e.to_string())?;
+    let cates = cate::CateModel::find_all(db)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = FileOptions::default();
+
+    for data in arts {
+        let tags = data
+            .tags
+            .clone()
+            .unwrap_or_default()
+            .split(',')
+            .filter(|v| !v.is_empty())
+            .map(|v| v.to_string())
+            .collect();
+
+        let front = FrontMatter {
+            typ: "art".to_string(),
+            title: data.title.clone(),
+            uuid: Some(data.uuid.clone()),
+            slug: data.slug.clone(),
+            cate: cates
+                .iter()
+                .find(|c| c.id == data.cate_id)
+                .map(|c| c.slug.clone()),
+            tags,
+            keywords: data.keywords.clone(),
+            description: data.description.clone(),
+            cover: data.cover.clone(),
+            brief: data.brief.clone(),
+            from: data.from.clone(),
+            is_top: data.is_top,
+            status: data.status,
+            date: format_date(data.add_time),
+            ..FrontMatter::default()
+        };
+
+        let body = render(&front, data.content.as_str())?;
+
+        zip.start_file(format!("arts/{}.html", data.uuid), options)
+            .map_err(|e| e.to_string())?;
+        zip.write_all(body.as_bytes()).map_err(|e| e.to_string())?;
+    }
+
+    for data in pages {
+        let front = FrontMatter {
+            typ: "page".to_string(),
+            title: data.title.clone(),
+            slug: Some(data.slug.clone()),
+            keywords: data.keywords.clone(),
+            description: data.description.clone(),
+            tpl: data.tpl.clone(),
+            status: data.status,
+            date: format_date(data.add_time),
+            ..FrontMatter::default()
+        };
+
+        let body = render(&front, data.content.as_str())?;
+
+        zip.start_file(format!("pages/{}.html", data.slug), options)
+            .map_err(|e| e.to_string())?;
+        zip.write_all(body.as_bytes()).map_err(|e| e.to_string())?;
+    }
+
+    let cursor = zip.finish().map_err(|e| e.to_string())?;
+
+    Ok(cursor.into_inner())
+}
+
+// 支持的文件
+fn is_content_file(name: &str) -> bool {
+    let ext = utils::get_extension(name).to_lowercase();
+
+    ext == "md" || ext == "markdown" || ext == "html" || ext == "htm"
+}
+
+/// 读取 zip 文件
+pub fn read_zip(data: &[u8]) -> Result<Vec<(String, String)>, String> {
+    let mut archive = ZipArchive::new(Cursor::new(data)).map_err(|e| e.to_string())?;
+
+    let mut files = Vec::new();
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i).map_err(|e| e.to_string())?;
+        if !file.is_file() || !is_content_file(file.name()) {
+            continue;
+        }
+
+        let name = file.name().to_string();
+
+        let mut content = String::new();
+        if file.read_to_string(&mut content).is_err() {
+            continue;
+        }
+
+        files.push((name, content));
+    }
+
+    Ok(files)
+}
+
+/// 读取目录
+pub fn read_dir(path: &Path) -> Result<Vec<(String, String)>, String> {
+    let mut files = Vec::new();
+
+    let entries = fs::read_dir(path).map_err(|e| e.to_string())?;
+    for entry in entries.flatten() {
+        let file_path = entry.path();
+        if file_path.is_dir() {
+            files.extend(read_dir(file_path.as_path())?);
+            continue;
+        }
+
+        let name = file_path.to_string_lossy().to_string();
+        if !is_content_file(name.as_str()) {
+            continue;
+        }
+
+        if let Ok(content) = fs::read_to_string(file_path.as_path()) {
+            files.push((name, content));
+        }
+    }
+
+    Ok(files)
+}
+
+/// 导入文件，文章按 uuid 更新，页面按 slug 更新
+pub async fn import(
+    db: &DbConn,
+    files: Vec<(String, String)>,
+    user_id: u32,
+    add_ip: &str,
+) -> ImportReport {
+    let mut report = ImportReport::default();
+
+    let cates = cate::CateModel::find_all(db).await.unwrap_or_default();
+    let default_cate_id = cates
+        .iter()
+        .find(|c| c.status == Some(1))
+        .map(|c| c.id)
+        .unwrap_or_default();
+
+    for (name, data) in files {
+        let (front, content) = match split(data.as_str()) {
+            Ok(v) => v,
+            Err(err) => {
+                report.messages.push(format!("{}: {}", name, err));
+                report.skipped += 1;
+                continue;
+            }
+        };
+
+        let ext = utils::get_extension(name.as_str()).to_lowercase();
+        let content = if ext == "md" || ext == "markdown" {
+            markdown_to_html(content.as_str())
+        } else {
+            content
+        };
+
+        if front.title.is_empty() {
+            report.messages.push(i18n::t_with(
+                "admin-import-file-title-required",
+                &[("file", name.clone())],
+            ));
+            report.skipped += 1;
+            continue;
+        }
+
+        let add_time = front
+            .date
+            .as_deref()
+            .and_then(parse_date)
+            .unwrap_or(time::now().timestamp());
+
+        let is_page = front.typ == "page" || (front.typ.is_empty() && name.contains("pages/"));
+
+        if is_page {
+            let slug = match front.slug.clone() {
+                Some(v) if !v.is_empty() => v,
+                _ => {
+                    report.messages.push(i18n::t_with(
+                        "admin-import-file-slug-required",
+                        &[("file", name.clone())],
+                    ));
+                    report.skipped += 1;
+                    continue;
+                }
+            };
+
+            let exists = page::PageModel::find_by_slug(db, slug.as_str())
+                .await
+                .unwrap_or_default()
+                .unwrap_or_default();
+
+            let data = page_entity::Model {
+                user_id,
+                slug,
+                title: front.title,
+                keywords: front.keywords,
+                description: front.description,
+                content,
+                tpl: Some(front.tpl.unwrap_or("page.html".to_string())),
+                status: Some(front.status.unwrap_or(1)),
+                add_time: Some(add_time),
+                add_ip: Some(add_ip.to_string()),
+                ..entity::default()
+            };
+
+            if exists.id > 0 {
+                match page::PageModel::update_by_id(db, exists.id, data).await {
+                    Ok(_) => report.updated += 1,
+                    Err(_) => report.messages.push(i18n::t_with(
+                        "admin-import-file-update-failed",
+                        &[("file", name.clone())],
+                    )),
+                }
+            } else {
+                match page::PageModel::create(db, data).await {
+                    Ok(_) => report.created += 1,
+                    Err(_) => report.messages.push(i18n::t_with(
+                        "admin-import-file-add-failed",
+                        &[("file", name.clone())],
+                    )),
+                }
+            }
+
+            continue;
+        }
+
+        let cate_id = match front.cate.as_deref() {
+            Some(slug) => match cates.iter().find(|c| c.slug == slug) {
+                Some(c) => c.id,
+                None => {
+                    report.messages.push(i18n::t_with(
+                        "admin-import-file-cate-missing",
+                        &[("file", name.clone()), ("slug", slug.to_string())],
+                    ));
+                    default_cate_id
+                }
+            },
+            None => default_cate_id,
+        };
+        if cate_id == 0 {
+            report.messages.push(i18n::t_with(
+                "admin-import-file-no-cate",
+                &[("file", name.clone())],
+            ));
+            report.skipped += 1;
+            continue;
+        }
+
+        let uuid = front.uuid.clone().unwrap_or_default();
+        let exists = if uuid.is_empty() {
+            art_entity::Model::default()
+        } else {
+            art::ArtModel::find_by_uuid(db, uuid.as_str())
+                .await
+                .unwrap_or_default()
+                .unwrap_or_default()
+        };
+
+        let mut data = art_entity::Model {
+            uuid: uuid.clone(),
+            cate_id,
+            user_id,
+            title: front.title,
+            slug: front.slug,
+            keywords: front.keywords,
+            description: front.description,
+            cover: front.cover,
+            content,
+            brief: front.brief,
+            tags: Some(front.tags.join(",")),
+            from: front.from,
+            views: Some(0),
+            is_top: Some(front.is_top.unwrap_or(0)),
+            comment_closed: exists.comment_closed,
+            status: Some(front.status.unwrap_or(1)),
+            add_time: Some(add_time),
+            add_ip: Some(add_ip.to_string()),
+            ..entity::default()
+        };
+
+        // 别名已被使用时重新生成
+        let old = if exists.id > 0 { Some(&exists) } else { None };
+        if let Err(err) = permalink::fill_slug(db, &mut data, old).await {
+            report.messages.push(format!("{}: {}", name, err));
+            data.slug = None;
+            permalink::fill_slug(db, &mut data, old).await.ok();
+        }
+
+        if exists.id > 0 {
+            match art::ArtModel::update_by_id(db, exists.id, data).await {
+                Ok(v) => {
+                    permalink::record_change(db, &exists, &v).await;
+                    report.updated += 1;
+                }
+                Err(_) => report.messages.push(i18n::t_with(
+                    "admin-import-file-update-failed",
+                    &[("file", name.clone())],
+                )),
+            }
+        } else {
+            // uuid 字段长度为 36
+            if uuid.is_empty() || uuid.chars().count() > 36 {
+                data.uuid = utils::uuid();
+            }
+
+            match art::ArtModel::create(db, data).await {
+                Ok(v) => {
+                    if let Ok(v) = v.try_into_model() {
+                        permalink::record_create(db, &v).await;
+                    }
+                    report.created += 1;
+                }
+                Err(_) => report.messages.push(i18n::t_with(
+                    "admin-import-file-add-failed",
+                    &[("file", name.clone())],
+                )),
+            }
+        }
+    }
+
+    report
+}
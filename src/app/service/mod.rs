@@ -1,2 +1,15 @@
+pub mod api;
+pub mod avatar;
+pub mod comment;
 pub mod http;
-pub mod setting;
\ No newline at end of file
+pub mod markdown;
+pub mod mail;
+pub mod permalink;
+pub mod redirect;
+pub mod related;
+pub mod seo;
+pub mod setting;
+pub mod template;
+pub mod upload;
+pub mod webhook;
+pub mod wxr;
@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+
+use sea_orm::DbConn;
+
+use crate::nako::{i18n, permalink, time, utils};
+
+use crate::app::entity::art as art_entity;
+use crate::app::model::{art, cate, redirect};
+use crate::app::service;
+
+/// 文章链接
+pub fn art_url(data: &art_entity::Model) -> String {
+    permalink::url(&fields(data))
+}
+
+// 生成链接需要的字段
+fn fields(data: &art_entity::Model) -> permalink::Fields {
+    permalink::Fields {
+        id: data.id,
+        uuid: data.uuid.clone(),
+        slug: data.slug.clone(),
+        cate_id: data.cate_id,
+        add_time: data.add_time,
+    }
+}
+
+/// 加载分类路径，分类变动后需重新加载
+pub async fn load_cates(db: &DbConn) {
+    let cates = cate::CateModel::find_all(db).await.unwrap_or_default();
+
+    let paths = cates
+        .iter()
+        .map(|c| (c.id, cate::CateModel::path(&cates, c.id)))
+        .collect::<HashMap<u32, String>>();
+
+    permalink::set_cate_paths(paths);
+}
+
+/// 文章别名，未填写时保留原别名或者根据标题生成，填写的别名已被使用时返回错误
+pub async fn fill_slug(
+    db: &DbConn,
+    data: &mut art_entity::Model,
+    old: Option<&art_entity::Model>,
+) -> Result<(), String> {
+    let exclude_id = old.map(|v| v.id).unwrap_or_default();
+
+    let slug = permalink::slugify(data.slug.as_deref().unwrap_or_default());
+    if !slug.is_empty() {
+        if is_used(db, slug.as_str(), exclude_id).await {
+            return Err(i18n::t_with("admin-slug-used", &[("slug", slug.clone())]));
+        }
+
+        data.slug = Some(slug);
+        return Ok(());
+    }
+
+    if let Some(v) = old.and_then(|v| v.slug.clone()).filter(|v| !v.is_empty()) {
+        data.slug = Some(v);
+        return Ok(());
+    }
+
+    let mut base = permalink::slugify(data.title.as_str());
+    if base.is_empty() {
+        base = utils::uuid().chars().take(8).collect();
+    }
+
+    // 重复时添加序号
+    let mut slug = base.clone();
+    let mut num = 2;
+    while is_used(db, slug.as_str(), exclude_id).await {
+        slug = format!("{}-{}", base, num);
+        num += 1;
+    }
+
+    data.slug = Some(slug);
+    Ok(())
+}
+
+// 别名是否已被其他文章使用
+async fn is_used(db: &DbConn, slug: &str, exclude_id: u32) -> bool {
+    match art::ArtModel::find_by_slug(db, slug).await {
+        Ok(Some(v)) => v.id != exclude_id,
+        _ => false,
+    }
+}
+
+/// 文章链接变动时记录旧链接的跳转
+pub async fn record_change(db: &DbConn, old: &art_entity::Model, new: &art_entity::Model) {
+    let (source, target) = (art_url(old), art_url(new));
+    if source == target {
+        return;
+    }
+
+    record(db, old.id, source.as_str(), target.as_str()).await;
+    service::redirect::load(db).await;
+}
+
+/// 新文章的链接已可以访问，删除来源为该链接的跳转
+pub async fn record_create(db: &DbConn, data: &art_entity::Model) {
+    let url = art_url(data);
+
+    match redirect::RedirectModel::delete_by_source(db, url.as_str()).await {
+        Ok(v) if v.rows_affected > 0 => service::redirect::load(db).await,
+        Ok(_) => {}
+        Err(err) => log::error!("redirect {} delete err: {}", url, err),
+    }
+}
+
+// 记录跳转
+async fn record(db: &DbConn, art_id: u32, source: &str, target: &str) {
+    if source == target {
+        return;
+    }
+
+    if let Err(err) = redirect::RedirectModel::save_art_redirect(
+        db,
+        art_id,
+        source,
+        target,
+        time::now().timestamp(),
+    )
+    .await
+    {
+        log::error!("redirect {} -> {} save err: {}", source, target, err);
+    }
+}
+
+/// 分类变动前的文章链接
+pub async fn cate_art_urls(db: &DbConn, cate_ids: Vec<u32>) -> Vec<(u32, String)> {
+    if !permalink::pattern().contains("{cate}") {
+        return Vec::new();
+    }
+
+    art::ArtModel::find_by_cate_ids(db, cate_ids)
+        .await
+        .unwrap_or_default()
+        .iter()
+        .map(|v| (v.id, art_url(v)))
+        .collect()
+}
+
+/// 分类变动后重新加载分类路径，并记录文章旧链接的跳转
+pub async fn record_cate_change(db: &DbConn, before: Vec<(u32, String)>) {
+    load_cates(db).await;
+
+    if before.is_empty() {
+        return;
+    }
+
+    for (id, source) in before {
+        if let Ok(Some(data)) = art::ArtModel::find_by_id(db, id).await {
+            record(db, id, source.as_str(), art_url(&data).as_str()).await;
+        }
+    }
+
+    service::redirect::load(db).await;
+}
+
+/// 根据链接中的变量查找文章，日期或者分类不一致时返回 false
+pub async fn find(
+    db: &DbConn,
+    params: &HashMap<String, String>,
+) -> Option<(art_entity::Model, bool)> {
+    let data = if let Some(slug) = params.get("slug") {
+        art::ArtModel::find_by_slug(db, slug.as_str()).await
+    } else if let Some(uuid) = params.get("uuid") {
+        art::ArtModel::find_by_uuid(db, uuid.as_str()).await
+    } else if let Some(id) = params.get("id") {
+        art::ArtModel::find_by_id(db, id.parse::<u32>().unwrap_or_default()).await
+    } else {
+        return None;
+    };
+
+    // 自定义链接可以被猜测，只能访问已发布的文章
+    let data = data.ok().flatten().filter(|v| v.status == Some(1))?;
+
+    let date = time::from_timestamp(data.add_time.unwrap_or_default());
+    let matched = [
+        ("year", date.format("%Y").to_string()),
+        ("month", date.format("%m").to_string()),
+        ("day", date.format("%d").to_string()),
+        (
+            "cate",
+            permalink::cate_path(data.cate_id).unwrap_or_default(),
+        ),
+    ]
+    .iter()
+    .all(|(name, value)| params.get(*name).is_none_or(|v| v == value));
+
+    Some((data, matched))
+}
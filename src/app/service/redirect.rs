@@ -0,0 +1,274 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use actix_web::{http::header, HttpResponse};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use sea_orm::DbConn;
+
+use crate::nako::{config, i18n, time};
+
+use crate::app::entity::redirect as redirect_entity;
+use crate::app::model::{
+    not_found,
+    redirect::{self, MATCH_EXACT, MATCH_PREFIX, MATCH_REGEX},
+};
+
+/// 可用的状态码，410 表示已删除，不需要目标链接
+pub const CODES: [i32; 3] = [301, 302, 410];
+
+/// 已删除
+pub const CODE_GONE: i32 = 410;
+
+// 启用的跳转规则
+static RULES: Lazy<RwLock<Rules>> = Lazy::new(|| RwLock::new(Rules::default()));
+
+#[derive(Default)]
+struct Rules {
+    exact: HashMap<String, Rule>,
+    prefix: Vec<Rule>,
+    regex: Vec<(Regex, Rule)>,
+}
+
+#[derive(Clone)]
+struct Rule {
+    id: u32,
+    source: String,
+    target: String,
+    code: i32,
+}
+
+/// 匹配到的跳转
+#[derive(Clone, Debug)]
+pub struct Matched {
+    pub id: u32,
+    pub code: i32,
+    pub target: String,
+}
+
+/// 加载启用的跳转规则，规则变动后需重新加载
+pub async fn load(db: &DbConn) {
+    let list = redirect::RedirectModel::find_open(db)
+        .await
+        .unwrap_or_default();
+
+    set_rules(list);
+}
+
+// 设置跳转规则
+fn set_rules(list: Vec<redirect_entity::Model>) {
+    let mut rules = Rules::default();
+    for data in list {
+        let rule = Rule {
+            id: data.id,
+            source: data.source.clone(),
+            target: data.target.clone(),
+            code: data.code,
+        };
+
+        match data.match_type.as_str() {
+            MATCH_PREFIX => rules.prefix.push(rule),
+            MATCH_REGEX => match Regex::new(data.source.as_str()) {
+                Ok(re) => rules.regex.push((re, rule)),
+                Err(err) => log::error!("redirect {} regex err: {}", data.source, err),
+            },
+            _ => {
+                rules.exact.insert(data.source, rule);
+            }
+        }
+    }
+
+    // 较长的前缀优先
+    rules
+        .prefix
+        .sort_by_key(|v| std::cmp::Reverse(v.source.len()));
+
+    match RULES.write() {
+        Ok(mut v) => *v = rules,
+        Err(e) => *e.into_inner() = rules,
+    }
+}
+
+/// 校验规则
+pub fn check(match_type: &str, source: &str, target: &str, code: i32) -> Result<(), String> {
+    if !CODES.contains(&code) {
+        return Err(i18n::t("admin-redirect-code-error"));
+    }
+
+    match match_type {
+        MATCH_EXACT | MATCH_PREFIX => {
+            if !source.starts_with('/') {
+                return Err(i18n::t("admin-redirect-source-slash"));
+            }
+        }
+        MATCH_REGEX => {
+            if let Err(err) = Regex::new(source) {
+                return Err(i18n::t_with(
+                    "admin-redirect-regex-error",
+                    &[("error", err.to_string())],
+                ));
+            }
+        }
+        _ => return Err(i18n::t("admin-redirect-match-error")),
+    }
+
+    if code != CODE_GONE && target.is_empty() {
+        return Err(i18n::t("admin-redirect-target-required"));
+    }
+
+    Ok(())
+}
+
+/// 查找跳转，依次匹配完整链接、前缀及正则
+/// 前缀匹配时剩余部分追加到目标链接，正则匹配时目标链接可使用 $1 等分组
+pub fn find(path: &str) -> Option<Matched> {
+    let rules = match RULES.read() {
+        Ok(v) => v,
+        Err(e) => e.into_inner(),
+    };
+
+    if let Some(rule) = rules.exact.get(path) {
+        return Some(rule.matched(rule.target.clone()));
+    }
+
+    for rule in rules.prefix.iter() {
+        if let Some(rest) = path.strip_prefix(rule.source.as_str()) {
+            return Some(rule.matched(format!("{}{}", rule.target, rest)));
+        }
+    }
+
+    for (re, rule) in rules.regex.iter() {
+        if let Some(caps) = re.captures(path) {
+            let mut target = String::new();
+            caps.expand(rule.target.as_str(), &mut target);
+
+            return Some(rule.matched(target));
+        }
+    }
+
+    None
+}
+
+impl Rule {
+    fn matched(&self, target: String) -> Matched {
+        Matched {
+            id: self.id,
+            code: self.code,
+            target,
+        }
+    }
+}
+
+/// 跳转响应，带上原链接的参数
+pub fn response(matched: &Matched, query: &str) -> HttpResponse {
+    let mut target = matched.target.clone();
+    if !query.is_empty() && !target.contains('?') {
+        target = format!("{}?{}", target, query);
+    }
+
+    let mut res = if matched.code == 302 {
+        HttpResponse::Found()
+    } else {
+        HttpResponse::MovedPermanently()
+    };
+
+    res.append_header((header::LOCATION, target)).finish()
+}
+
+/// 记录命中次数
+pub fn hit(db: DbConn, id: u32) {
+    actix_web::rt::spawn(async move {
+        if let Err(err) = redirect::RedirectModel::hit(&db, id).await {
+            log::error!("redirect {} hit err: {}", id, err);
+        }
+    });
+}
+
+/// 记录 404 链接及来源
+pub fn log_not_found(db: DbConn, path: &str, referrer: &str) {
+    if !config::section::<bool>("redirect", "log_404", true) {
+        return;
+    }
+
+    let max = config::section::<u64>("redirect", "log_404_max", 10000);
+    let referrer_max = config::section::<u64>("redirect", "log_404_referrer_max", 50);
+    let path: String = path.chars().take(255).collect();
+    let referrer: String = referrer.chars().take(255).collect();
+
+    actix_web::rt::spawn(async move {
+        let now = time::now().timestamp();
+        if let Err(err) = not_found::NotFoundModel::log(
+            &db,
+            path.as_str(),
+            referrer.as_str(),
+            now,
+            max,
+            referrer_max,
+        )
+        .await
+        {
+            log::error!("404 {} log err: {}", path, err);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(id: u32, match_type: &str, source: &str, target: &str) -> redirect_entity::Model {
+        redirect_entity::Model {
+            id,
+            source: source.to_string(),
+            match_type: match_type.to_string(),
+            target: target.to_string(),
+            code: 301,
+            status: 1,
+            ..Default::default()
+        }
+    }
+
+    fn target(path: &str) -> Option<String> {
+        find(path).map(|v| v.target)
+    }
+
+    // 规则为全局数据，在同一个测试中设置及查找
+    #[test]
+    fn find_rules() {
+        set_rules(vec![
+            rule(1, MATCH_EXACT, "/old", "/new"),
+            rule(2, MATCH_PREFIX, "/docs/", "/help/"),
+            rule(3, MATCH_PREFIX, "/docs/v1/", "/archive/"),
+            rule(4, MATCH_EXACT, "/docs/a", "/exact"),
+            rule(5, MATCH_REGEX, r"^/p/(\d+)$", "/a/${1}"),
+            rule(6, MATCH_REGEX, "(", "/bad"),
+        ]);
+
+        assert_eq!(target("/old"), Some("/new".to_string()));
+        assert_eq!(target("/old/x"), None);
+
+        // 完整链接优先于前缀
+        assert_eq!(target("/docs/a"), Some("/exact".to_string()));
+
+        // 较长的前缀优先，剩余部分追加到目标链接
+        assert_eq!(target("/docs/v1/x"), Some("/archive/x".to_string()));
+        assert_eq!(target("/docs/x"), Some("/help/x".to_string()));
+
+        assert_eq!(target("/p/12"), Some("/a/12".to_string()));
+        assert_eq!(target("/p/x"), None);
+
+        assert_eq!(find("/docs/x").map(|v| v.id), Some(2));
+        assert_eq!(target("/none"), None);
+    }
+
+    #[test]
+    fn check_rule() {
+        assert!(check(MATCH_EXACT, "/old", "/new", 301).is_ok());
+        assert!(check(MATCH_EXACT, "/old", "", CODE_GONE).is_ok());
+        assert!(check(MATCH_EXACT, "/old", "", 301).is_err());
+        assert!(check(MATCH_PREFIX, "old", "/new", 301).is_err());
+        assert!(check(MATCH_REGEX, "(", "/new", 301).is_err());
+        assert!(check(MATCH_EXACT, "/old", "/new", 200).is_err());
+        assert!(check("other", "/old", "/new", 301).is_err());
+    }
+}
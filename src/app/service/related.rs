@@ -0,0 +1,216 @@
+use std::collections::{HashMap, HashSet};
+
+use ::redis::aio::ConnectionManager;
+use sea_orm::DbConn;
+
+use crate::app::entity::art as art_entity;
+use crate::app::model::{art, art_tag};
+use crate::nako::{global::AppState, redis};
+
+/// 相关文章数量
+pub const LIMIT: usize = 6;
+
+// 缓存时间，文章变动时清除同标签及同分类文章的缓存，标题相似的文章在过期后更新
+const CACHE_TIME: u32 = 60 * 60 * 24;
+
+// 权重，每个相同标签、同一分类及标题相似度
+const TAG_SCORE: f64 = 3.0;
+const CATE_SCORE: f64 = 2.0;
+const TITLE_SCORE: f64 = 5.0;
+
+// 最低分数，避免只有一两个相同字的文章
+const MIN_SCORE: f64 = 1.0;
+
+fn cache_key(art_id: u32) -> String {
+    format!("nako:art_related:{}", art_id)
+}
+
+/// 标题相似度，按相邻两个字符计算的 Jaccard 系数，中英文都适用
+pub fn similarity(a: &str, b: &str) -> f64 {
+    fn bigrams(s: &str) -> HashSet<(char, char)> {
+        let chars = s
+            .to_lowercase()
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .collect::<Vec<char>>();
+
+        chars.windows(2).map(|w| (w[0], w[1])).collect()
+    }
+
+    let (a, b) = (bigrams(a), bigrams(b));
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let same = a.intersection(&b).count() as f64;
+    same / ((a.len() + b.len()) as f64 - same)
+}
+
+/// 计算相关文章 ID，按相同标签、同一分类及标题相似度排序
+pub async fn compute(db: &DbConn, data: &art_entity::Model) -> Vec<u32> {
+    let mut tag_counts: HashMap<u32, u32> = HashMap::new();
+    let tags = art_tag::ArtTagModel::find_tags_by_art_id(db, data.id)
+        .await
+        .unwrap_or_default();
+    for tag in tags {
+        let art_ids = art_tag::ArtTagModel::find_art_ids_by_tag_id(db, tag.id)
+            .await
+            .unwrap_or_default();
+        for art_id in art_ids {
+            *tag_counts.entry(art_id).or_default() += 1;
+        }
+    }
+
+    let mut list = art::ArtModel::find_published_titles(db)
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|v| v.id != data.id)
+        .map(|v| {
+            let mut score = similarity(data.title.as_str(), v.title.as_str()) * TITLE_SCORE;
+            score += tag_counts.get(&v.id).copied().unwrap_or_default() as f64 * TAG_SCORE;
+            if data.cate_id > 0 && v.cate_id == data.cate_id {
+                score += CATE_SCORE;
+            }
+
+            (score, v)
+        })
+        .filter(|(score, _)| *score >= MIN_SCORE)
+        .collect::<Vec<_>>();
+
+    // 分数相同时新文章在前
+    list.sort_by(|a, b| {
+        b.0.total_cmp(&a.0)
+            .then(b.1.add_time.cmp(&a.1.add_time))
+            .then(b.1.id.cmp(&a.1.id))
+    });
+
+    list.into_iter().take(LIMIT).map(|(_, v)| v.id).collect()
+}
+
+/// 重新计算并缓存相关文章
+pub async fn refresh(state: &mut AppState, data: &art_entity::Model) -> Vec<u32> {
+    let ids = compute(&state.db, data).await;
+
+    let value = ids
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+    let _ = redis::set::<String>(
+        &mut state.redis,
+        cache_key(data.id).as_str(),
+        value,
+        CACHE_TIME,
+    )
+    .await;
+
+    ids
+}
+
+/// 和文章有相同标签或者同一分类的其他文章，这些文章的相关文章会随该文章变动
+pub async fn neighbor_ids(db: &DbConn, data: &art_entity::Model) -> Vec<u32> {
+    let mut ids = HashSet::new();
+
+    let tags = art_tag::ArtTagModel::find_tags_by_art_id(db, data.id)
+        .await
+        .unwrap_or_default();
+    for tag in tags {
+        let art_ids = art_tag::ArtTagModel::find_art_ids_by_tag_id(db, tag.id)
+            .await
+            .unwrap_or_default();
+        ids.extend(art_ids);
+    }
+
+    if data.cate_id > 0 {
+        let titles = art::ArtModel::find_published_titles(db)
+            .await
+            .unwrap_or_default();
+        ids.extend(
+            titles
+                .iter()
+                .filter(|v| v.cate_id == data.cate_id)
+                .map(|v| v.id),
+        );
+    }
+
+    ids.remove(&data.id);
+    ids.into_iter().collect()
+}
+
+/// 文章添加、更新、发布或者取消发布后重新计算，并清除同标签及同分类文章的缓存，
+/// old_ids 为更新前同标签及同分类的文章，分类或者标签变动后也需要清除
+pub async fn changed(state: &mut AppState, data: &art_entity::Model, old_ids: Vec<u32>) {
+    let mut ids = neighbor_ids(&state.db, data).await;
+    for id in old_ids {
+        if id != data.id && !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    clear(state, ids).await;
+
+    refresh(state, data).await;
+}
+
+/// 清除文章的相关文章缓存
+pub async fn clear(state: &mut AppState, art_ids: Vec<u32>) {
+    let keys = art_ids.into_iter().map(cache_key).collect();
+    let _ = redis::delete(&mut state.redis, keys).await;
+}
+
+/// 清除全部相关文章缓存，用于导入等批量变动
+pub async fn clear_all(conn: &mut ConnectionManager) {
+    let keys = redis::keys(conn, "nako:art_related:*")
+        .await
+        .unwrap_or_default();
+    let _ = redis::delete(conn, keys).await;
+}
+
+/// 相关文章，没有缓存时计算，只返回已发布的文章
+pub async fn find(state: &mut AppState, data: &art_entity::Model) -> Vec<art_entity::Model> {
+    let cached = redis::get::<String>(&mut state.redis, cache_key(data.id).as_str())
+        .await
+        .unwrap_or_default();
+
+    let ids = match cached {
+        Some(v) => v
+            .split(',')
+            .filter_map(|id| id.parse::<u32>().ok())
+            .collect::<Vec<u32>>(),
+        None => refresh(state, data).await,
+    };
+    if ids.is_empty() {
+        return Vec::new();
+    }
+
+    let arts = art::ArtModel::find_published_by_ids(&state.db, ids.clone())
+        .await
+        .unwrap_or_default();
+
+    ids.iter()
+        .filter_map(|id| arts.iter().find(|a| a.id == *id).cloned())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn similarity_score() {
+        assert_eq!(similarity("Rust 入门", "rust 入门"), 1.0);
+        assert_eq!(similarity("abc", "xyz"), 0.0);
+
+        // 只有一个字符或者没有字母数字时没有可比较的部分
+        assert_eq!(similarity("a", "a"), 0.0);
+        assert_eq!(similarity("!!", "!!"), 0.0);
+
+        // ab、bc 与 bc、cd 相同一个
+        let score = similarity("abc", "bcd");
+        assert!((score - 1.0 / 3.0).abs() < 1e-9);
+
+        assert!(
+            similarity("Rust 异步编程", "Rust 异步入门") > similarity("Rust 异步编程", "Go 入门")
+        );
+    }
+}
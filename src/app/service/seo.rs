@@ -0,0 +1,234 @@
+use std::collections::HashMap;
+
+use actix_web::HttpRequest;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::nako::{i18n, time, utils};
+
+use crate::app::entity::art as art_entity;
+
+/// 网站地址的设置名称，为空时使用请求的地址
+pub const SETTING_KEY: &str = "website_url";
+
+/// 页面 SEO 信息
+#[derive(Serialize, Clone, Default, Debug)]
+pub struct Seo {
+    pub site_name: String,
+    pub title: String,
+    pub description: String,
+    pub canonical: String,
+    pub og_type: String,
+    pub image: String,
+    pub twitter_card: String,
+    pub locale: String,
+    pub published_time: String,
+    pub prev: String,
+    pub next: String,
+    pub noindex: bool,
+    pub json_ld: Vec<String>,
+}
+
+/// 构建页面 SEO 信息
+pub struct SeoBuilder {
+    req: HttpRequest,
+    site_url: String,
+    seo: Seo,
+    breadcrumbs: Vec<(String, String)>,
+    posting: Option<Value>,
+}
+
+impl Seo {
+    pub fn builder(req: &HttpRequest, settings: &HashMap<String, String>) -> SeoBuilder {
+        let site_url = site_url(req, settings);
+        let site_name = settings.get("website_name").cloned().unwrap_or_default();
+
+        SeoBuilder {
+            req: req.clone(),
+            seo: Seo {
+                title: site_name.clone(),
+                description: settings
+                    .get("website_description")
+                    .cloned()
+                    .unwrap_or_default(),
+                canonical: format!("{}{}", site_url, req.path()),
+                og_type: "website".to_string(),
+                twitter_card: "summary".to_string(),
+                locale: i18n::locale().replace('-', "_"),
+                site_name,
+                ..Seo::default()
+            },
+            site_url,
+            breadcrumbs: Vec::new(),
+            posting: None,
+        }
+    }
+}
+
+impl SeoBuilder {
+    pub fn title(mut self, title: &str) -> Self {
+        self.seo.title = title.to_string();
+        self
+    }
+
+    /// 描述，为空时保留网站描述
+    pub fn description(mut self, description: Option<&str>) -> Self {
+        if let Some(v) = description.map(|v| v.trim()).filter(|v| !v.is_empty()) {
+            self.seo.description = v.to_string();
+        }
+        self
+    }
+
+    /// 规范链接，参数为路由名称
+    pub fn canonical<U, I>(mut self, name: &str, elements: U) -> Self
+    where
+        U: IntoIterator<Item = I>,
+        I: AsRef<str>,
+    {
+        let path = utils::url_for(self.req.clone(), name, elements);
+        self.seo.canonical = self.absolute(path.as_str());
+        self
+    }
+
+    /// 规范链接，参数为相对地址
+    pub fn canonical_path(mut self, path: &str) -> Self {
+        self.seo.canonical = self.absolute(path);
+        self
+    }
+
+    /// 分享图片，有图片时使用大图卡片
+    pub fn image(mut self, path: Option<&str>) -> Self {
+        if let Some(v) = path.filter(|v| !v.is_empty()) {
+            self.seo.image = self.absolute(v);
+            self.seo.twitter_card = "summary_large_image".to_string();
+        }
+        self
+    }
+
+    /// 分页，第一页以外的规范链接带上页码
+    pub fn page(mut self, page: u64, num_pages: u64) -> Self {
+        let base = self.seo.canonical.clone();
+        let link = |p: u64| {
+            if p <= 1 {
+                base.clone()
+            } else {
+                format!("{}?page={}", base, p)
+            }
+        };
+
+        if page > 1 {
+            self.seo.canonical = link(page);
+            self.seo.prev = link(page - 1);
+        }
+        if page < num_pages {
+            self.seo.next = link(page + 1);
+        }
+        self
+    }
+
+    /// 搜索结果及空页面不需要收录
+    pub fn noindex(mut self, noindex: bool) -> Self {
+        self.seo.noindex = noindex;
+        self
+    }
+
+    /// 面包屑，路径为相对地址
+    pub fn breadcrumb(mut self, name: &str, path: &str) -> Self {
+        let url = self.absolute(path);
+        self.breadcrumbs.push((name.to_string(), url));
+        self
+    }
+
+    /// 文章
+    pub fn article(mut self, art: &art_entity::Model, author: &str) -> Self {
+        self = self
+            .title(art.title.as_str())
+            .description(art.description.as_deref().or(art.brief.as_deref()))
+            .image(art.cover.as_deref());
+        self.seo.og_type = "article".to_string();
+
+        let published = art
+            .add_time
+            .map(|t| time::from_timestamp(t).to_rfc3339())
+            .unwrap_or_default();
+        self.seo.published_time = published.clone();
+
+        let mut posting = json!({
+            "@context": "https://schema.org",
+            "@type": "BlogPosting",
+            "headline": art.title,
+            "description": self.seo.description,
+            "mainEntityOfPage": self.seo.canonical,
+            "url": self.seo.canonical,
+            "datePublished": published,
+            "author": { "@type": "Person", "name": author },
+            "publisher": { "@type": "Organization", "name": self.seo.site_name },
+        });
+        if !self.seo.image.is_empty() {
+            posting["image"] = Value::String(self.seo.image.clone());
+        }
+        if let Some(keywords) = art.keywords.as_deref().filter(|v| !v.is_empty()) {
+            posting["keywords"] = Value::String(keywords.to_string());
+        }
+        self.posting = Some(posting);
+
+        self
+    }
+
+    pub fn build(mut self) -> Seo {
+        if let Some(posting) = self.posting.take() {
+            self.seo.json_ld.push(ld_json(&posting));
+        }
+
+        if !self.breadcrumbs.is_empty() {
+            let mut items = vec![(i18n::t("blog-home"), format!("{}/", self.site_url))];
+            items.extend(self.breadcrumbs.clone());
+
+            let list = items
+                .into_iter()
+                .enumerate()
+                .map(|(i, (name, url))| {
+                    json!({
+                        "@type": "ListItem",
+                        "position": i + 1,
+                        "name": name,
+                        "item": url,
+                    })
+                })
+                .collect::<Vec<Value>>();
+
+            self.seo.json_ld.push(ld_json(&json!({
+                "@context": "https://schema.org",
+                "@type": "BreadcrumbList",
+                "itemListElement": list,
+            })));
+        }
+
+        self.seo
+    }
+
+    // 绝对地址
+    fn absolute(&self, path: &str) -> String {
+        if path.starts_with("http://") || path.starts_with("https://") {
+            return path.to_string();
+        }
+
+        format!("{}/{}", self.site_url, path.trim_start_matches('/'))
+    }
+}
+
+/// 网站地址，不带结尾的 /
+pub fn site_url(req: &HttpRequest, settings: &HashMap<String, String>) -> String {
+    match settings.get(SETTING_KEY).map(|v| v.trim()) {
+        Some(v) if !v.is_empty() => v.trim_end_matches('/').to_string(),
+        _ => {
+            let info = req.connection_info();
+            format!("{}://{}", info.scheme(), info.host())
+        }
+    }
+}
+
+// 输出到 script 标签中的 JSON
+fn ld_json(data: &Value) -> String {
+    data.to_string().replace("</", "<\\/")
+}
@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+use sea_orm::DbConn;
+
+use crate::nako::{i18n, view as nako_view};
+
+use crate::app::model::{art, art_tag, cate, comment, friendlink, page, tag, template};
+
+/// 读取后台编辑的模板，替换模板覆盖
+pub async fn load(db: &DbConn) {
+    let data = match template::TemplateModel::find_all(db).await {
+        Ok(v) => v,
+        Err(err) => {
+            log::warn!("template overrides load err: {err}");
+            return;
+        }
+    };
+
+    let data = data
+        .into_iter()
+        .map(|v| (v.name, v.content))
+        .collect::<HashMap<String, String>>();
+
+    nako_view::set_overrides(data);
+}
+
+/// 预览用的示例数据，包含前台各页面使用的变量
+pub async fn sample_context(db: &DbConn) -> tera::Context {
+    let search_where = art::ArtWhere {
+        title: None,
+        uuid: None,
+        tag: None,
+        cate_id: None,
+        cate_ids: None,
+        user_id: None,
+        is_top: None,
+        status: Some(1),
+    };
+    let (arts, num_pages) = art::ArtModel::list_in_page(db, 1, 10, search_where)
+        .await
+        .unwrap_or_default();
+
+    let art = arts.first().cloned().unwrap_or_default();
+
+    let cate_data = cate::CateModel::find_by_id(db, art.cate_id)
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    let all_cates = cate::CateModel::find_all(db).await.unwrap_or_default();
+    let breadcrumbs = cate::CateModel::breadcrumbs(&all_cates, art.cate_id);
+
+    let (comments, comments_num_pages) =
+        comment::CommentModel::find_in_page_by_artid(db, art.id, 1, 6)
+            .await
+            .unwrap_or_default();
+    let comments_count = comment::CommentModel::find_count_by_artid(db, art.id)
+        .await
+        .unwrap_or(0);
+
+    let art_tags = art
+        .tags
+        .clone()
+        .unwrap_or_default()
+        .split(',')
+        .filter(|v| !v.is_empty())
+        .map(|v| v.to_string())
+        .collect::<Vec<String>>();
+
+    let tag_data = tag::TagModel::find_open_tags(db, 1)
+        .await
+        .unwrap_or_default()
+        .first()
+        .cloned()
+        .unwrap_or_default();
+
+    let data = page::PageModel::find_all(db)
+        .await
+        .unwrap_or_default()
+        .first()
+        .cloned()
+        .unwrap_or_default();
+
+    let hot_arts = art::ArtModel::find_one_year_hot(db, 6)
+        .await
+        .unwrap_or_default();
+    let cates = cate::CateModel::find_open_tree(db)
+        .await
+        .unwrap_or_default();
+    let tags = art_tag::ArtTagModel::find_tag_cloud(db, 6)
+        .await
+        .unwrap_or_default();
+    let friendlinks = friendlink::FriendlinkModel::list_open(db)
+        .await
+        .unwrap_or_default();
+
+    let mut ctx = tera::Context::new();
+    ctx.insert("arts", &arts);
+    ctx.insert("page", &1);
+    ctx.insert("num_pages", &num_pages);
+    ctx.insert("keywords", &"");
+
+    ctx.insert("art", &art);
+    ctx.insert("art_tags", &art_tags);
+    ctx.insert("cate_data", &cate_data);
+    ctx.insert("breadcrumbs", &breadcrumbs);
+    ctx.insert("comments", &comments);
+    ctx.insert("comments_count", &comments_count);
+    ctx.insert("comments_num_pages", &comments_num_pages);
+
+    ctx.insert("tag_name", &tag_data.name);
+    ctx.insert("tag_data", &tag_data);
+    ctx.insert("data", &data);
+    ctx.insert("message", &i18n::t("admin-preview"));
+
+    ctx.insert("hot_arts", &hot_arts);
+    ctx.insert("cates", &cates);
+    ctx.insert("tags", &tags);
+    ctx.insert("friendlinks", &friendlinks);
+
+    ctx
+}
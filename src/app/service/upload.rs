@@ -0,0 +1,152 @@
+use std::{fs, io::Read, path};
+
+use actix_multipart::form::tempfile::TempFile;
+use sea_orm::{DbConn, TryIntoModel};
+
+use crate::nako::global::Serialize;
+use crate::nako::{
+    app::{attach_path, upload_path, upload_url},
+    i18n, time, utils,
+};
+
+use crate::app::entity::{self, attach as attach_entity};
+use crate::app::model::attach;
+
+/// 附件类型
+#[derive(Clone, Copy, PartialEq)]
+pub enum UploadType {
+    File,
+    Image,
+}
+
+impl UploadType {
+    fn value(&self) -> i32 {
+        match self {
+            UploadType::File => 1,
+            UploadType::Image => 2,
+        }
+    }
+}
+
+/// 上传结果
+#[derive(Serialize, Clone)]
+pub struct UploadData {
+    pub id: u32,
+    pub name: String,
+    pub url: String,
+}
+
+// 保存上传文件
+pub async fn save_files(
+    db: &DbConn,
+    files: Vec<TempFile>,
+    upload_type: UploadType,
+    add_ip: &str,
+) -> Result<Vec<UploadData>, String> {
+    let mut res = Vec::new();
+
+    for mut f in files {
+        let file_name = f.file_name.clone().unwrap_or_default();
+        if file_name.is_empty() {
+            continue;
+        }
+
+        let mut buffer = Vec::new();
+        if f.file.read_to_end(&mut buffer).is_err() {
+            return Err(i18n::t("admin-upload-failed"));
+        }
+
+        let data = save_file(db, file_name.as_str(), &buffer, upload_type, add_ip).await?;
+        res.push(data);
+    }
+
+    Ok(res)
+}
+
+// 保存文件内容
+pub async fn save_file(
+    db: &DbConn,
+    file_name: &str,
+    buffer: &[u8],
+    upload_type: UploadType,
+    add_ip: &str,
+) -> Result<UploadData, String> {
+    let ext = utils::get_extension(file_name);
+
+    let name = match upload_type {
+        UploadType::File => format!("/{}.{}", utils::uuid(), ext),
+        UploadType::Image => format!("/images/{}.{}", utils::uuid(), ext),
+    };
+
+    let contents = String::from_utf8_lossy(buffer).to_string();
+
+    let md5 = utils::md5(contents.as_str());
+    let size = buffer.len() as i64;
+
+    // 判断是否有相同
+    let attach_data = attach::AttachModel::find_by_md5(db, md5.as_str())
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if attach_data.id > 0 {
+        return Ok(UploadData {
+            id: attach_data.id,
+            name: attach_data.name,
+            url: attach_url(attach_data.r#type, attach_data.path),
+        });
+    }
+
+    let path = match upload_type {
+        UploadType::File => attach_path(name.clone()),
+        UploadType::Image => upload_path(name.clone()),
+    };
+
+    if let Some(dir) = path::Path::new(path.as_str()).parent() {
+        if !dir.exists() {
+            fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+    }
+
+    if fs::write(path.as_str(), buffer).is_err() {
+        return Err(i18n::t("admin-upload-failed"));
+    }
+
+    let create_data = attach::AttachModel::create(
+        db,
+        attach_entity::Model {
+            name: file_name.to_string(),
+            path: name.clone(),
+            ext,
+            size,
+            md5,
+            r#type: upload_type.value(),
+            status: 1,
+            add_time: time::now().timestamp(),
+            add_ip: add_ip.to_string(),
+            ..entity::default()
+        },
+    )
+    .await;
+
+    match create_data.map(|data| data.try_into_model()) {
+        Ok(Ok(data_model)) => Ok(UploadData {
+            id: data_model.id,
+            name: file_name.to_string(),
+            url: attach_url(data_model.r#type, data_model.path),
+        }),
+        _ => {
+            let _ = fs::remove_file(path);
+
+            Err(i18n::t("admin-upload-failed"))
+        }
+    }
+}
+
+// 附件访问链接，只有图片可以直接访问
+pub fn attach_url(attach_type: i32, path: String) -> String {
+    if attach_type == UploadType::Image.value() {
+        return upload_url(path);
+    }
+
+    "".to_string()
+}
@@ -0,0 +1,288 @@
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use sea_orm::DbConn;
+use serde::Serialize;
+use tokio::sync::Notify;
+
+use crate::nako::{config, i18n, time, utils};
+
+use crate::app::entity::{
+    self, art as art_entity, user as user_entity, webhook as webhook_entity,
+    webhook_log as webhook_log_entity,
+};
+use crate::app::model::{webhook, webhook_log};
+use crate::app::service::mail;
+
+/// 事件
+pub const ART_PUBLISHED: &str = "art.published";
+pub const ART_UPDATED: &str = "art.updated";
+pub const ART_DELETED: &str = "art.deleted";
+pub const COMMENT_CREATED: &str = "comment.created";
+pub const COMMENT_APPROVED: &str = "comment.approved";
+pub const GUESTBOOK_CREATED: &str = "guestbook.created";
+pub const USER_LOGIN: &str = "user.login";
+
+/// 测试事件，不需要订阅
+pub const PING: &str = "ping";
+
+/// 可订阅的事件，名称为翻译的 key
+pub const EVENTS: [(&str, &str); 7] = [
+    (ART_PUBLISHED, "admin-webhook-art-published"),
+    (ART_UPDATED, "admin-webhook-art-updated"),
+    (ART_DELETED, "admin-webhook-art-deleted"),
+    (COMMENT_CREATED, "admin-webhook-comment-created"),
+    (COMMENT_APPROVED, "admin-webhook-comment-approved"),
+    (GUESTBOOK_CREATED, "admin-webhook-guestbook-created"),
+    (USER_LOGIN, "admin-webhook-user-login"),
+];
+
+/// 翻译后的事件名称
+pub fn event_names() -> Vec<(&'static str, String)> {
+    EVENTS.iter().map(|(k, v)| (*k, i18n::t(v))).collect()
+}
+
+// 每次发送的数量
+const BATCH: usize = 20;
+
+// 没有新记录时的检查间隔
+const POLL_SECS: u64 = 30;
+
+// 有新记录时唤醒发送队列
+static WAKE: Lazy<Notify> = Lazy::new(Notify::new);
+
+#[derive(Serialize)]
+struct Payload<'a, T: Serialize> {
+    event: &'a str,
+    time: i64,
+    data: &'a T,
+}
+
+/// 是否订阅了事件
+pub fn subscribed(hook: &webhook_entity::Model, event: &str) -> bool {
+    event == PING || hook.events.split(',').any(|v| v.trim() == event)
+}
+
+/// 触发事件，为订阅的 webhook 添加投递记录
+pub async fn fire<T: Serialize>(db: &DbConn, event: &str, data: &T) {
+    let hooks = match webhook::WebhookModel::find_open(db).await {
+        Ok(v) => v,
+        Err(err) => {
+            log::error!("webhook {} err: {}", event, err);
+            return;
+        }
+    };
+
+    for hook in hooks.iter().filter(|v| subscribed(v, event)) {
+        push(db, hook, event, data).await;
+    }
+}
+
+/// 文章保存后，首次发布为 art.published，其他为 art.updated，新建的草稿不触发
+pub async fn fire_art(db: &DbConn, old: Option<&art_entity::Model>, new: &art_entity::Model) {
+    let published = new.status == Some(1) && old.map(|v| v.status) != Some(Some(1));
+
+    if published {
+        fire(db, ART_PUBLISHED, new).await;
+    } else if old.is_some() {
+        fire(db, ART_UPDATED, new).await;
+    }
+}
+
+/// 用户登录，不发送密码
+pub async fn fire_login(db: &DbConn, user: &user_entity::Model) {
+    let data = user_entity::Model {
+        password: None,
+        ..user.clone()
+    };
+
+    fire(db, USER_LOGIN, &data).await;
+}
+
+/// 添加投递记录
+pub async fn push<T: Serialize>(
+    db: &DbConn,
+    hook: &webhook_entity::Model,
+    event: &str,
+    data: &T,
+) -> Option<webhook_log_entity::Model> {
+    let now = time::now().timestamp();
+
+    let payload = Payload {
+        event,
+        time: now,
+        data,
+    };
+    let payload = match serde_json::to_string(&payload) {
+        Ok(v) => v,
+        Err(err) => {
+            log::error!("webhook {} payload err: {}", event, err);
+            return None;
+        }
+    };
+
+    let res = webhook_log::WebhookLogModel::create(
+        db,
+        webhook_log_entity::Model {
+            webhook_id: hook.id,
+            event: event.to_string(),
+            payload,
+            add_time: now,
+            ..entity::default()
+        },
+    )
+    .await;
+
+    match res {
+        Ok(v) => {
+            WAKE.notify_one();
+            Some(v)
+        }
+        Err(err) => {
+            log::error!("webhook {} {} err: {}", hook.id, event, err);
+            None
+        }
+    }
+}
+
+/// 唤醒发送队列
+pub fn wake() {
+    WAKE.notify_one();
+}
+
+/// 启动发送队列
+pub fn start(db: DbConn) {
+    actix_web::rt::spawn(async move {
+        loop {
+            if deliver(&db).await >= BATCH {
+                continue;
+            }
+
+            let _ =
+                actix_web::rt::time::timeout(Duration::from_secs(POLL_SECS), WAKE.notified()).await;
+        }
+    });
+}
+
+/// 发送到期的记录，失败时按次数延后重试，返回处理的数量
+pub async fn deliver(db: &DbConn) -> usize {
+    let max_attempts = config::section::<i32>("webhook", "max_attempts", 5);
+    let retry_interval = config::section::<i64>("webhook", "retry_interval", 60);
+
+    let now = time::now().timestamp();
+    let list = match webhook_log::WebhookLogModel::find_due(db, now, BATCH as u64).await {
+        Ok(v) => v,
+        Err(err) => {
+            log::error!("webhook queue err: {}", err);
+            return 0;
+        }
+    };
+
+    let count = list.len();
+    for data in list {
+        let hook = webhook::WebhookModel::find_by_id(db, data.webhook_id)
+            .await
+            .unwrap_or_default()
+            .filter(|v| v.status == 1);
+
+        let res = match hook {
+            Some(hook) => send(&hook, &data).await,
+            None => Err((0, i18n::t("admin-webhook-unavailable"), false)),
+        };
+
+        let send_time = time::now().timestamp();
+        let res = match res {
+            Ok((code, body)) => {
+                webhook_log::WebhookLogModel::update_success(
+                    db,
+                    data.id,
+                    code,
+                    body.as_str(),
+                    send_time,
+                )
+                .await
+            }
+            Err((code, err, retry)) => {
+                log::error!("webhook log {} err: {}", data.id, err);
+
+                let attempts = data.attempts + 1;
+                let next_time = if retry && attempts < max_attempts {
+                    Some(now + mail::retry_delay(retry_interval, attempts))
+                } else {
+                    None
+                };
+
+                webhook_log::WebhookLogModel::update_failed(
+                    db,
+                    data.id,
+                    attempts,
+                    next_time,
+                    code,
+                    err.as_str(),
+                    send_time,
+                )
+                .await
+            }
+        };
+
+        if let Err(err) = res {
+            log::error!("webhook log {} update err: {}", data.id, err);
+        }
+    }
+
+    // 清理发送成功的记录
+    let keep_days = config::section::<i64>("webhook", "keep_days", 30);
+    if keep_days > 0 {
+        let _ =
+            webhook_log::WebhookLogModel::delete_success_before(db, now - keep_days * 86400).await;
+    }
+
+    count
+}
+
+// 截取响应内容
+fn excerpt(data: &str) -> String {
+    data.chars().take(255).collect()
+}
+
+// 发送请求，返回状态码及响应内容，失败时返回是否需要重试
+async fn send(
+    hook: &webhook_entity::Model,
+    data: &webhook_log_entity::Model,
+) -> Result<(i32, String), (i32, String, bool)> {
+    let timeout = config::section::<u64>("webhook", "timeout", 10);
+
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(timeout))
+        .build()
+        .map_err(|e| (0, excerpt(&e.to_string()), false))?;
+
+    let mut request = client
+        .post(hook.url.as_str())
+        .header("Content-Type", "application/json")
+        .header("User-Agent", "nako-blog-webhook")
+        .header("X-Nako-Event", data.event.as_str())
+        .header("X-Nako-Delivery", data.id.to_string());
+
+    // 使用密钥签名请求内容
+    if !hook.secret.is_empty() {
+        let sign = utils::hmac_sha256(data.payload.as_bytes(), hook.secret.as_bytes());
+        request = request.header("X-Nako-Signature", format!("sha256={}", sign));
+    }
+
+    let res = request
+        .body(data.payload.clone())
+        .send()
+        .await
+        .map_err(|e| (0, excerpt(&e.to_string()), true))?;
+
+    let code = res.status().as_u16() as i32;
+    let success = res.status().is_success();
+    let body = excerpt(&res.text().await.unwrap_or_default());
+
+    if success {
+        Ok((code, body))
+    } else {
+        Err((code, body, true))
+    }
+}
@@ -0,0 +1,650 @@
+use std::collections::HashMap;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use sea_orm::{DbConn, TryIntoModel};
+
+use crate::nako::global::Serialize;
+use crate::nako::{i18n, time, utils};
+
+use crate::app::entity::{
+    self, art as art_entity, cate as cate_entity, comment as comment_entity, page as page_entity,
+    tag as tag_entity,
+};
+use crate::app::model::{art, cate, comment, page, tag};
+use crate::app::service::{permalink, upload};
+
+/// WXR 分类
+#[derive(Clone, Default)]
+pub struct WxrCate {
+    pub slug: String,
+    pub parent: String,
+    pub name: String,
+    pub desc: String,
+}
+
+/// WXR 标签
+#[derive(Clone, Default)]
+pub struct WxrTag {
+    pub slug: String,
+    pub name: String,
+    pub desc: String,
+}
+
+/// WXR 评论
+#[derive(Clone, Default)]
+pub struct WxrComment {
+    pub id: String,
+    pub parent: String,
+    pub author: String,
+    pub email: String,
+    pub ip: String,
+    pub date: String,
+    pub date_gmt: String,
+    pub content: String,
+    pub approved: String,
+    pub comment_type: String,
+}
+
+/// WXR 内容
+#[derive(Clone, Default)]
+pub struct WxrItem {
+    pub title: String,
+    pub link: String,
+    pub post_id: String,
+    pub post_type: String,
+    pub slug: String,
+    pub status: String,
+    pub date: String,
+    pub date_gmt: String,
+    pub content: String,
+    pub excerpt: String,
+    pub attachment_url: String,
+    pub cates: Vec<String>,
+    pub tags: Vec<String>,
+    pub comments: Vec<WxrComment>,
+}
+
+/// WXR 数据
+#[derive(Clone, Default)]
+pub struct WxrData {
+    pub cates: Vec<WxrCate>,
+    pub tags: Vec<WxrTag>,
+    pub items: Vec<WxrItem>,
+}
+
+// 获取属性
+fn attr(e: &BytesStart, name: &str) -> String {
+    e.attributes()
+        .flatten()
+        .find(|a| a.key.as_ref() == name.as_bytes())
+        .and_then(|a| a.unescape_value().ok().map(|v| v.to_string()))
+        .unwrap_or_default()
+}
+
+/// 解析 WXR 文件
+pub fn parse(data: &str) -> Result<WxrData, String> {
+    let mut reader = Reader::from_str(data);
+
+    let mut wxr = WxrData::default();
+
+    let mut text = String::new();
+    let mut term = (String::new(), String::new());
+
+    let mut cur_cate: Option<WxrCate> = None;
+    let mut cur_tag: Option<WxrTag> = None;
+    let mut cur_item: Option<WxrItem> = None;
+    let mut cur_comment: Option<WxrComment> = None;
+
+    let mut is_rss = false;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => {
+                text.clear();
+
+                match e.name().as_ref() {
+                    b"rss" => is_rss = true,
+                    b"wp:category" if cur_item.is_none() => cur_cate = Some(WxrCate::default()),
+                    b"wp:tag" if cur_item.is_none() => cur_tag = Some(WxrTag::default()),
+                    b"item" => cur_item = Some(WxrItem::default()),
+                    b"wp:comment" if cur_item.is_some() => {
+                        cur_comment = Some(WxrComment::default())
+                    }
+                    b"category" if cur_item.is_some() => {
+                        term = (attr(&e, "domain"), attr(&e, "nicename"));
+                    }
+                    _ => {}
+                }
+            }
+            Ok(Event::Text(e)) => {
+                let t = e.unescape().map_err(|e| e.to_string())?;
+                text.push_str(t.as_ref());
+            }
+            Ok(Event::CData(e)) => {
+                text.push_str(String::from_utf8_lossy(e.into_inner().as_ref()).as_ref());
+            }
+            Ok(Event::End(e)) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                let value = text.trim().to_string();
+
+                if let Some(c) = cur_comment.as_mut() {
+                    match name.as_str() {
+                        "wp:comment_id" => c.id = value,
+                        "wp:comment_parent" => c.parent = value,
+                        "wp:comment_author" => c.author = value,
+                        "wp:comment_author_email" => c.email = value,
+                        "wp:comment_author_IP" => c.ip = value,
+                        "wp:comment_date" => c.date = value,
+                        "wp:comment_date_gmt" => c.date_gmt = value,
+                        "wp:comment_content" => c.content = value,
+                        "wp:comment_approved" => c.approved = value,
+                        "wp:comment_type" => c.comment_type = value,
+                        "wp:comment" => {
+                            if let (Some(item), Some(c)) = (cur_item.as_mut(), cur_comment.take()) {
+                                item.comments.push(c);
+                            }
+                        }
+                        _ => {}
+                    }
+                } else if let Some(item) = cur_item.as_mut() {
+                    match name.as_str() {
+                        "title" => item.title = value,
+                        "link" => item.link = value,
+                        "content:encoded" => item.content = text.clone(),
+                        "excerpt:encoded" => item.excerpt = value,
+                        "wp:post_id" => item.post_id = value,
+                        "wp:post_type" => item.post_type = value,
+                        "wp:post_name" => item.slug = value,
+                        "wp:status" => item.status = value,
+                        "wp:post_date" => item.date = value,
+                        "wp:post_date_gmt" => item.date_gmt = value,
+                        "wp:attachment_url" => item.attachment_url = value,
+                        "category" => match term.0.as_str() {
+                            "category" => item.cates.push(term.1.clone()),
+                            "post_tag" => item.tags.push(value),
+                            _ => {}
+                        },
+                        "item" => {
+                            if let Some(item) = cur_item.take() {
+                                wxr.items.push(item);
+                            }
+                        }
+                        _ => {}
+                    }
+                } else if let Some(c) = cur_cate.as_mut() {
+                    match name.as_str() {
+                        "wp:category_nicename" => c.slug = value,
+                        "wp:category_parent" => c.parent = value,
+                        "wp:cat_name" => c.name = value,
+                        "wp:category_description" => c.desc = value,
+                        "wp:category" => {
+                            if let Some(c) = cur_cate.take() {
+                                wxr.cates.push(c);
+                            }
+                        }
+                        _ => {}
+                    }
+                } else if let Some(t) = cur_tag.as_mut() {
+                    match name.as_str() {
+                        "wp:tag_slug" => t.slug = value,
+                        "wp:tag_name" => t.name = value,
+                        "wp:tag_description" => t.desc = value,
+                        "wp:tag" => {
+                            if let Some(t) = cur_tag.take() {
+                                wxr.tags.push(t);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+
+                text.clear();
+            }
+            Ok(Event::Eof) => break,
+            Ok(_) => {}
+            Err(e) => {
+                return Err(i18n::t_with(
+                    "admin-import-parse-failed",
+                    &[("error", e.to_string())],
+                ))
+            }
+        }
+    }
+
+    if !is_rss {
+        return Err(i18n::t("admin-import-not-wxr"));
+    }
+
+    Ok(wxr)
+}
+
+/// 导入选项
+pub struct ImportOptions {
+    pub user_id: u32,
+    pub dry_run: bool,
+    pub download_media: bool,
+    pub add_ip: String,
+}
+
+/// 导入报告
+#[derive(Serialize, Default)]
+pub struct ImportReport {
+    pub dry_run: bool,
+    pub cates: u64,
+    pub tags: u64,
+    pub arts: u64,
+    pub pages: u64,
+    pub comments: u64,
+    pub attachs: u64,
+    pub skipped: u64,
+    pub links: Vec<ImportLink>,
+    pub messages: Vec<String>,
+}
+
+/// 原链接对应的新文章
+#[derive(Serialize)]
+pub struct ImportLink {
+    pub link: String,
+    pub uuid: String,
+}
+
+// 截取字符
+fn cut(data: &str, len: usize) -> String {
+    data.chars().take(len).collect()
+}
+
+// 解析时间，GMT 时间为空时使用本地时间
+fn parse_time(gmt: &str, local: &str) -> i64 {
+    let date = if gmt.is_empty() || gmt.starts_with("0000") {
+        local
+    } else {
+        gmt
+    };
+    if date.is_empty() || date.starts_with("0000") {
+        return time::now().timestamp();
+    }
+
+    time::parse(date).timestamp()
+}
+
+// 下载文件
+async fn download(url: &str) -> Result<Vec<u8>, String> {
+    let res = reqwest::get(url).await.map_err(|e| e.to_string())?;
+    if !res.status().is_success() {
+        return Err(i18n::t_with(
+            "admin-import-status-code",
+            &[("code", res.status().to_string())],
+        ));
+    }
+
+    let data = res.bytes().await.map_err(|e| e.to_string())?;
+
+    Ok(data.to_vec())
+}
+
+/// 导入数据
+pub async fn import(db: &DbConn, data: WxrData, opts: ImportOptions) -> ImportReport {
+    let mut report = ImportReport {
+        dry_run: opts.dry_run,
+        ..ImportReport::default()
+    };
+
+    let now = time::now().timestamp();
+
+    // 分类，父级需要先导入
+    let mut cate_ids: HashMap<String, u32> = HashMap::new();
+    let mut pending = data.cates.clone();
+    while !pending.is_empty() {
+        let (ready, rest): (Vec<WxrCate>, Vec<WxrCate>) = pending
+            .into_iter()
+            .partition(|c| c.parent.is_empty() || cate_ids.contains_key(&c.parent));
+        if ready.is_empty() {
+            for c in rest {
+                report.messages.push(i18n::t_with(
+                    "admin-import-cate-parent-missing",
+                    &[("name", c.name.clone()), ("parent", c.parent.clone())],
+                ));
+                report.skipped += 1;
+            }
+            break;
+        }
+
+        for c in ready {
+            let exists = cate::CateModel::find_by_slug(db, c.slug.as_str())
+                .await
+                .unwrap_or_default()
+                .unwrap_or_default();
+            if exists.id > 0 {
+                cate_ids.insert(c.slug.clone(), exists.id);
+                continue;
+            }
+
+            report.cates += 1;
+            if opts.dry_run {
+                cate_ids.insert(c.slug.clone(), 0);
+                continue;
+            }
+
+            let pid = cate_ids.get(&c.parent).copied().unwrap_or_default();
+            let create_data = cate::CateModel::create(
+                db,
+                cate_entity::Model {
+                    pid,
+                    name: cut(c.name.as_str(), 50),
+                    slug: cut(c.slug.as_str(), 50),
+                    desc: Some(cut(c.desc.as_str(), 200)),
+                    sort: 100,
+                    list_tpl: "list.html".to_string(),
+                    view_tpl: "view.html".to_string(),
+                    status: Some(1),
+                    add_time: Some(now),
+                    add_ip: Some(opts.add_ip.clone()),
+                    ..entity::default()
+                },
+            )
+            .await;
+            match create_data.map(|v| v.try_into_model()) {
+                Ok(Ok(v)) => {
+                    cate_ids.insert(c.slug.clone(), v.id);
+                }
+                _ => report.messages.push(i18n::t_with(
+                    "admin-import-cate-failed",
+                    &[("name", c.name.clone())],
+                )),
+            }
+        }
+
+        pending = rest;
+    }
+
+    // 标签
+    let mut tag_names: Vec<String> = data.tags.iter().map(|t| t.name.clone()).collect();
+    for item in data.items.iter() {
+        tag_names.extend(item.tags.iter().cloned());
+    }
+    tag_names.sort();
+    tag_names.dedup();
+
+    for name in tag_names {
+        let exists = tag::TagModel::find_by_name(db, name.as_str())
+            .await
+            .unwrap_or_default()
+            .unwrap_or_default();
+        if exists.id > 0 {
+            continue;
+        }
+
+        report.tags += 1;
+        if opts.dry_run {
+            continue;
+        }
+
+        let desc = data
+            .tags
+            .iter()
+            .find(|t| t.name == name)
+            .map(|t| cut(t.desc.as_str(), 200));
+        let create_data = tag::TagModel::create(
+            db,
+            tag_entity::Model {
+                name: cut(name.as_str(), 50),
+                desc,
+                sort: 100,
+                status: Some(1),
+                add_time: Some(now),
+                add_ip: Some(opts.add_ip.clone()),
+                ..entity::default()
+            },
+        )
+        .await;
+        if create_data.is_err() {
+            report.messages.push(i18n::t_with(
+                "admin-import-tag-failed",
+                &[("name", name.clone())],
+            ));
+        }
+    }
+
+    // 附件
+    let mut media_urls: Vec<(String, String)> = Vec::new();
+    for item in data.items.iter().filter(|i| i.post_type == "attachment") {
+        if item.attachment_url.is_empty() {
+            continue;
+        }
+
+        report.attachs += 1;
+        if opts.dry_run || !opts.download_media {
+            continue;
+        }
+
+        let file_name = utils::get_path_filename(item.attachment_url.as_str());
+        let upload_type =
+            if utils::is_image(utils::get_extension(file_name.as_str()).to_lowercase()) {
+                upload::UploadType::Image
+            } else {
+                upload::UploadType::File
+            };
+
+        let res = match download(item.attachment_url.as_str()).await {
+            Ok(buffer) => {
+                upload::save_file(
+                    db,
+                    file_name.as_str(),
+                    &buffer,
+                    upload_type,
+                    opts.add_ip.as_str(),
+                )
+                .await
+            }
+            Err(err) => Err(err),
+        };
+
+        match res {
+            Ok(v) => {
+                if !v.url.is_empty() {
+                    media_urls.push((item.attachment_url.clone(), v.url));
+                }
+            }
+            Err(err) => report.messages.push(i18n::t_with(
+                "admin-import-media-failed",
+                &[("url", item.attachment_url.clone()), ("error", err)],
+            )),
+        }
+    }
+
+    // 替换内容里的附件链接
+    let rewrite = |content: &str| -> String {
+        let mut content = content.to_string();
+        for (from, to) in media_urls.iter() {
+            content = content.replace(from.as_str(), to.as_str());
+        }
+        content
+    };
+
+    // 默认分类，按文件中的顺序取第一个导入的分类
+    let first_cate_id = data
+        .cates
+        .iter()
+        .filter_map(|c| cate_ids.get(&c.slug).copied())
+        .find(|v| *v > 0);
+    let default_cate_id = match first_cate_id {
+        Some(v) => v,
+        None => cate::CateModel::find_open_cate(db)
+            .await
+            .unwrap_or_default()
+            .first()
+            .map(|c| c.id)
+            .unwrap_or_default(),
+    };
+
+    for item in data.items.iter() {
+        let status = if item.status == "publish" { 1 } else { 0 };
+        let add_time = parse_time(item.date_gmt.as_str(), item.date.as_str());
+
+        match item.post_type.as_str() {
+            "post" => {
+                let cate_id = item
+                    .cates
+                    .first()
+                    .and_then(|c| cate_ids.get(c))
+                    .copied()
+                    .unwrap_or(default_cate_id);
+                if cate_id == 0 && !opts.dry_run {
+                    report.messages.push(i18n::t_with(
+                        "admin-import-art-no-cate",
+                        &[("title", item.title.clone())],
+                    ));
+                    report.skipped += 1;
+                    continue;
+                }
+
+                let comments: Vec<&WxrComment> = item
+                    .comments
+                    .iter()
+                    .filter(|c| {
+                        c.approved == "1"
+                            && (c.comment_type.is_empty() || c.comment_type == "comment")
+                    })
+                    .collect();
+
+                report.arts += 1;
+                report.comments += comments.len() as u64;
+                if opts.dry_run {
+                    continue;
+                }
+
+                let uuid = utils::uuid();
+                let mut art_data = art_entity::Model {
+                    uuid: uuid.clone(),
+                    cate_id,
+                    user_id: opts.user_id,
+                    title: cut(item.title.as_str(), 150),
+                    content: rewrite(item.content.as_str()),
+                    brief: Some(cut(item.excerpt.as_str(), 150)),
+                    tags: Some(item.tags.join(",")),
+                    views: Some(0),
+                    is_top: Some(0),
+                    status: Some(status),
+                    add_time: Some(add_time),
+                    add_ip: Some(opts.add_ip.clone()),
+                    ..entity::default()
+                };
+
+                // 中文别名会被编码，此时根据标题生成，重复时也重新生成
+                if !item.slug.contains('%') {
+                    art_data.slug = Some(item.slug.clone());
+                }
+                if permalink::fill_slug(db, &mut art_data, None).await.is_err() {
+                    art_data.slug = None;
+                    permalink::fill_slug(db, &mut art_data, None).await.ok();
+                }
+
+                let create_data = art::ArtModel::create(db, art_data).await;
+
+                let art_info = match create_data.map(|v| v.try_into_model()) {
+                    Ok(Ok(v)) => v,
+                    _ => {
+                        report.messages.push(i18n::t_with(
+                            "admin-import-art-failed",
+                            &[("title", item.title.clone())],
+                        ));
+                        continue;
+                    }
+                };
+                let art_id = art_info.id;
+                permalink::record_create(db, &art_info).await;
+
+                if !item.link.is_empty() {
+                    report.links.push(ImportLink {
+                        link: item.link.clone(),
+                        uuid,
+                    });
+                }
+
+                // 评论按 ID 排序，保证回复在父评论之后
+                let mut comments = comments;
+                comments.sort_by_key(|c| c.id.parse::<u64>().unwrap_or_default());
+
+                let mut comment_ids: HashMap<String, u32> = HashMap::new();
+                for c in comments {
+                    let create_data = comment::CommentModel::create(
+                        db,
+                        comment_entity::Model {
+                            art_id,
+                            reply_id: comment_ids.get(&c.parent).copied(),
+                            username: cut(c.author.as_str(), 50),
+                            email: Some(cut(c.email.as_str(), 150)),
+                            content: c.content.clone(),
+                            status: Some(1),
+                            add_time: Some(parse_time(c.date_gmt.as_str(), c.date.as_str())),
+                            add_ip: Some(c.ip.clone()),
+                            ..entity::default()
+                        },
+                    )
+                    .await;
+                    match create_data.map(|v| v.try_into_model()) {
+                        Ok(Ok(v)) => {
+                            comment_ids.insert(c.id.clone(), v.id);
+                        }
+                        _ => report.messages.push(i18n::t_with(
+                            "admin-import-comment-failed",
+                            &[("title", item.title.clone()), ("id", c.id.to_string())],
+                        )),
+                    }
+                }
+            }
+            "page" => {
+                let slug = if item.slug.is_empty() {
+                    item.post_id.clone()
+                } else {
+                    item.slug.clone()
+                };
+
+                let exists = page::PageModel::find_by_slug(db, slug.as_str())
+                    .await
+                    .unwrap_or_default()
+                    .unwrap_or_default();
+                if exists.id > 0 {
+                    report.messages.push(i18n::t_with(
+                        "admin-import-page-exists",
+                        &[("slug", slug.clone())],
+                    ));
+                    report.skipped += 1;
+                    continue;
+                }
+
+                report.pages += 1;
+                if opts.dry_run {
+                    continue;
+                }
+
+                let create_data = page::PageModel::create(
+                    db,
+                    page_entity::Model {
+                        user_id: opts.user_id,
+                        slug: cut(slug.as_str(), 150),
+                        title: cut(item.title.as_str(), 200),
+                        content: rewrite(item.content.as_str()),
+                        tpl: Some("page.html".to_string()),
+                        status: Some(status),
+                        add_time: Some(add_time),
+                        add_ip: Some(opts.add_ip.clone()),
+                        ..entity::default()
+                    },
+                )
+                .await;
+                if create_data.is_err() {
+                    report.messages.push(i18n::t_with(
+                        "admin-import-page-failed",
+                        &[("title", item.title.clone())],
+                    ));
+                }
+            }
+            "attachment" => {}
+            _ => report.skipped += 1,
+        }
+    }
+
+    report
+}
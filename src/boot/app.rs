@@ -1,3 +1,5 @@
+use std::io;
+
 use actix_files::Files as Fs;
 use actix_session::{config::PersistentSession, storage::RedisSessionStore, SessionMiddleware};
 use actix_web::{
@@ -8,22 +10,36 @@ use actix_web::{
     web, App, Error, HttpResponse, HttpServer, Result,
 };
 
+use actix_web_lab::middleware::from_fn;
 use listenfd::ListenFd;
 use mime_guess::from_path;
-use tera::Tera;
 
-use crate::boot::error;
-use crate::nako::{config, db, embed, global::AppState, log as nako_log, redis, view as nako_view};
-use crate::route::{admin, blog};
+use crate::app::middleware::redirect;
+use crate::app::service;
+use crate::boot::{error, reload};
+use crate::migration;
+use crate::nako::{
+    config, db, embed, global::AppState, log as nako_log, redis, theme, view as nako_view,
+};
+use crate::route::{admin, api, blog};
 
 // app 运行
 pub async fn start() -> std::io::Result<()> {
+    // 校验配置
+    let conf = match config::AppConfig::load() {
+        Ok(v) => v,
+        Err(errors) => {
+            for err in errors.iter() {
+                eprintln!("配置错误: {}", err);
+            }
+
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "配置错误"));
+        }
+    };
+
     let rust_log = config::section::<String>("app", "rust_log", "error".to_string());
     std::env::set_var("RUST_LOG", rust_log.as_str());
 
-    // 导入环境变量
-    dotenvy::dotenv().ok();
-
     // 日志
     let logger = nako_log::setup_logger();
     match logger {
@@ -31,40 +47,64 @@ pub async fn start() -> std::io::Result<()> {
         Err(err) => log::error!("set log err: {err}"),
     }
 
-    let host = config::section::<String>("server", "host", "127.0.0.1".to_string());
-    let port = config::section::<String>("server", "port", "8080".to_string());
-    let server_url = format!("{host}:{port}");
+    let server_url = format!("{}:{}", conf.server.host, conf.server.port);
 
-    let conn = db::connect().await.unwrap_or_default();
+    let conn = match db::connect().await {
+        Ok(v) => v,
+        Err(err) => {
+            eprintln!("数据库连接失败: {}", err);
 
-    let mut view: Tera;
+            return Err(io::Error::other(err.to_string()));
+        }
+    };
+
+    // 启动时执行数据库迁移
+    if conf.db.auto_migrate {
+        if let Err(err) = migration::up(&conn).await {
+            log::error!("migrate err: {err}");
+        }
+    }
 
     // 是否打包
     let is_embed = config::section::<bool>("app", "is_embed", true);
-    if is_embed {
-        view = Tera::default();
-
-        for file in embed::Templates::iter() {
-            let filename = file.as_ref();
-            view.add_raw_template(
-                filename.clone(),
-                embed::get_tpl_data(filename.clone()).as_str(),
-            )
-            .unwrap_or_default();
+
+    // 后台编辑的模板
+    service::template::load(&conn).await;
+
+    // 文章链接需要的分类路径
+    service::permalink::load_cates(&conn).await;
+
+    // 跳转规则
+    service::redirect::load(&conn).await;
+
+    // 邮件发送队列
+    service::mail::start(conn.clone());
+
+    // Webhook 发送队列
+    service::webhook::start(conn.clone());
+
+    let view = match nako_view::build(is_embed) {
+        Ok(v) => nako_view::Views::new(v),
+        Err(err) => {
+            eprintln!("模板加载失败: {}", err);
+
+            return Err(io::Error::other(err));
         }
-    } else {
-        view = Tera::new(concat!(
-            env!("CARGO_MANIFEST_DIR"),
-            "/assert/templates/**/*"
-        ))
-        .unwrap_or_default();
+    };
+
+    // 热更新配置及模板
+    if config::section::<bool>("app", "hot_reload", false) {
+        reload::watch(view.clone(), is_embed);
     }
 
-    // 设置模板函数
-    nako_view::set_fns(&mut view);
+    let redis = match redis::create_redis_pool(conf.redis_url.clone()).await {
+        Ok(v) => v,
+        Err(err) => {
+            eprintln!("redis 连接失败: {}", err);
 
-    let redis_url = config::section::<String>("redis", "url", "8080".to_string());
-    let redis = redis::create_redis_pool(redis_url).await.unwrap();
+            return Err(io::Error::other(err.to_string()));
+        }
+    };
 
     let state = AppState {
         db: conn,
@@ -72,19 +112,20 @@ pub async fn start() -> std::io::Result<()> {
         redis: redis,
     };
 
-    let session_redis_url =
-        config::section::<String>("session", "redis_url", "redis://127.0.0.1:6379".to_string());
-    let redis_store = RedisSessionStore::new(session_redis_url.clone())
+    let redis_store = RedisSessionStore::new(conf.session.redis_url.clone())
         .await
         .unwrap();
 
+    let session_key = session_key(&conf.session.secret);
+
     let mut listenfd = ListenFd::from_env();
     let mut server = HttpServer::new(move || {
         App::new()
+            .wrap(from_fn(redirect::redirect))
             .wrap(ErrorHandlers::new().handler(StatusCode::NOT_FOUND, error::not_found))
             .wrap(Logger::default())
             .wrap(
-                SessionMiddleware::builder(redis_store.clone(), Key::from(&[0; 64]))
+                SessionMiddleware::builder(redis_store.clone(), session_key.clone())
                     .cookie_secure(false)
                     .session_lifecycle(
                         PersistentSession::default().session_ttl(time::Duration::days(5)),
@@ -120,6 +161,7 @@ pub async fn start() -> std::io::Result<()> {
                 srv.call(req)
             })
             .service(Fs::new("/upload", "./storage/upload"))
+            .service(handle_theme_asset)
             .configure(|cfg: &mut web::ServiceConfig| {
                 if is_embed {
                     cfg.service(handle_embedded_static);
@@ -128,6 +170,7 @@ pub async fn start() -> std::io::Result<()> {
                 }
             })
             .configure(admin::route)
+            .configure(api::route)
             .configure(blog::route)
             .default_service(web::to(error::app_default))
     });
@@ -141,6 +184,16 @@ pub async fn start() -> std::io::Result<()> {
     server.run().await
 }
 
+/// 会话密钥，未配置时每次启动随机生成
+fn session_key(secret: &[u8]) -> Key {
+    if secret.len() >= 64 {
+        return Key::from(secret);
+    }
+
+    log::warn!("session secret is not set, run `nako-blog gen-key` to create one");
+    Key::generate()
+}
+
 /// 静态资源
 #[actix_web::get("/static/{_:.*}")]
 async fn handle_embedded_static(path: web::Path<String>) -> Result<HttpResponse, Error> {
@@ -153,3 +206,16 @@ async fn handle_embedded_static(path: web::Path<String>) -> Result<HttpResponse,
         None => Ok(HttpResponse::NotFound().body("404 Not Found")),
     }
 }
+
+/// 主题资源
+#[actix_web::get("/themes/{theme}/{path:.*}")]
+async fn handle_theme_asset(path: web::Path<(String, String)>) -> Result<HttpResponse, Error> {
+    let (name, path) = path.into_inner();
+
+    match theme::asset(name.as_str(), path.as_str()) {
+        Some(content) => Ok(HttpResponse::Ok()
+            .content_type(from_path(path.as_str()).first_or_octet_stream().as_ref())
+            .body(content)),
+        None => Ok(HttpResponse::NotFound().body("404 Not Found")),
+    }
+}
@@ -0,0 +1,427 @@
+use std::fs;
+use std::path::Path;
+
+use clap::{Parser, Subcommand};
+use rand::RngCore;
+use sea_orm::TryIntoModel;
+
+use crate::app::entity::user as user_entity;
+use crate::app::model::{art_tag, user};
+use crate::app::service::{mail, markdown, related, wxr};
+use crate::boot;
+use crate::migration;
+use crate::nako::{auth, config, db, random, redis, time, utils, view};
+
+/// Nako Blog 命令行
+#[derive(Parser)]
+#[command(name = "nako-blog", version, about = "Nako Blog 博客系统")]
+pub struct Cli {
+    /// 配置文件路径，默认为当前目录的 conf.ini
+    #[arg(short, long, global = true)]
+    config: Option<String>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// 启动服务，不带子命令时默认执行
+    Serve,
+    /// 数据库迁移
+    Migrate {
+        #[command(subcommand)]
+        action: Option<MigrateAction>,
+    },
+    /// 创建管理员，未指定密码时随机生成
+    CreateAdmin {
+        username: String,
+        #[arg(long)]
+        password: Option<String>,
+        #[arg(long)]
+        nickname: Option<String>,
+    },
+    /// 重置管理员密码，未指定密码时随机生成
+    ResetPassword {
+        username: String,
+        #[arg(long)]
+        password: Option<String>,
+    },
+    /// 缓存管理
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+    /// 导出文章及页面为压缩包
+    Export { file: String },
+    /// 导入 Markdown 目录或者压缩包
+    Import {
+        path: String,
+        #[arg(long, default_value_t = 1)]
+        user_id: u32,
+    },
+    /// 导入 WordPress 数据
+    ImportWxr {
+        file: String,
+        /// 只输出导入报告
+        #[arg(long)]
+        dry_run: bool,
+        /// 不下载附件
+        #[arg(long)]
+        no_media: bool,
+        #[arg(long, default_value_t = 1)]
+        user_id: u32,
+    },
+    /// 根据文章标签字段重建标签关联
+    #[command(alias = "migrate-tags")]
+    Reindex,
+    /// 生成会话密钥
+    GenKey,
+    /// 发送测试邮件，检查 [mail] 配置
+    MailTest { email: String },
+}
+
+#[derive(Subcommand)]
+enum MigrateAction {
+    /// 执行全部未执行的迁移
+    Up,
+    /// 回滚迁移
+    Down {
+        #[arg(default_value_t = 1)]
+        steps: u32,
+    },
+    /// 迁移状态
+    Status,
+}
+
+#[derive(Subcommand)]
+enum CacheAction {
+    /// 清空设置及页面缓存
+    Clear,
+}
+
+// 运行命令
+pub async fn run() -> std::io::Result<()> {
+    let cli = Cli::parse();
+
+    if let Some(file) = cli.config {
+        if !Path::new(file.as_str()).exists() {
+            fail(format!("配置文件不存在: {}", file));
+        }
+
+        config::set_file(file.as_str());
+    }
+
+    match cli.command.unwrap_or(Command::Serve) {
+        Command::Serve => boot::app::start().await,
+        Command::Migrate { action } => migrate(action.unwrap_or(MigrateAction::Up)).await,
+        Command::CreateAdmin {
+            username,
+            password,
+            nickname,
+        } => create_admin(username, password, nickname).await,
+        Command::ResetPassword { username, password } => reset_password(username, password).await,
+        Command::Cache {
+            action: CacheAction::Clear,
+        } => cache_clear().await,
+        Command::Export { file } => export(file).await,
+        Command::Import { path, user_id } => import(path, user_id).await,
+        Command::ImportWxr {
+            file,
+            dry_run,
+            no_media,
+            user_id,
+        } => import_wxr(file, dry_run, !no_media, user_id).await,
+        Command::Reindex => reindex().await,
+        Command::GenKey => gen_key(),
+        Command::MailTest { email } => mail_test(email).await,
+    }
+}
+
+// 输出错误并以非零状态退出
+fn fail(message: String) -> ! {
+    eprintln!("{}", message);
+    std::process::exit(1)
+}
+
+// 数据库连接
+async fn connect() -> db::sea_orm::DatabaseConnection {
+    match db::connect().await {
+        Ok(v) => v,
+        Err(err) => fail(format!("数据库连接失败: {}", err)),
+    }
+}
+
+// 数据库迁移
+async fn migrate(action: MigrateAction) -> std::io::Result<()> {
+    let conn = connect().await;
+
+    let res = match action {
+        MigrateAction::Up => migration::up(&conn).await,
+        MigrateAction::Down { steps } => migration::down(&conn, steps).await,
+        MigrateAction::Status => match migration::status(&conn).await {
+            Ok(list) => {
+                for (name, done) in list {
+                    println!("{} {}", if done { "已执行" } else { "未执行" }, name);
+                }
+                return Ok(());
+            }
+            Err(err) => Err(err),
+        },
+    };
+
+    match res {
+        Ok(_) => println!("迁移完成"),
+        Err(err) => fail(format!("迁移失败: {}", err)),
+    }
+
+    Ok(())
+}
+
+// 创建管理员
+async fn create_admin(
+    username: String,
+    password: Option<String>,
+    nickname: Option<String>,
+) -> std::io::Result<()> {
+    let conn = connect().await;
+
+    let exists = user::UserModel::find_user_by_username(&conn, username.as_str())
+        .await
+        .unwrap_or_default();
+    if exists.is_some() {
+        fail(format!("账号已经存在: {}", username));
+    }
+
+    // 后台登录时密码先 md5，保存的为 md5 后再加密
+    let (password, generated) = password_or_random(password);
+
+    let create_data = user::UserModel::create_user(
+        &conn,
+        user_entity::Model {
+            username: username.clone(),
+            password: Some(auth::password_hash(utils::md5(password.as_str()).as_str())),
+            nickname: nickname.unwrap_or(username.clone()),
+            status: Some(1),
+            add_time: Some(time::now().timestamp()),
+            add_ip: Some("127.0.0.1".to_string()),
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let id = match create_data.and_then(|v| v.try_into_model()) {
+        Ok(v) => v.id,
+        Err(err) => fail(format!("创建失败: {}", err)),
+    };
+
+    println!("创建成功: {} (ID: {})", username, id);
+    if generated {
+        println!("密码: {}", password);
+    }
+
+    Ok(())
+}
+
+// 重置密码
+async fn reset_password(username: String, password: Option<String>) -> std::io::Result<()> {
+    let conn = connect().await;
+
+    let user_info = user::UserModel::find_user_by_username(&conn, username.as_str())
+        .await
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if user_info.id == 0 {
+        fail(format!("账号不存在: {}", username));
+    }
+
+    // 后台登录时密码先 md5，保存的为 md5 后再加密
+    let (password, generated) = password_or_random(password);
+    if let Err(err) = user::UserModel::update_password_by_id(
+        &conn,
+        user_info.id,
+        user_entity::Model {
+            password: Some(auth::password_hash(utils::md5(password.as_str()).as_str())),
+            ..Default::default()
+        },
+    )
+    .await
+    {
+        fail(format!("重置失败: {}", err));
+    }
+
+    println!("重置成功: {}", username);
+    if generated {
+        println!("密码: {}", password);
+    }
+
+    Ok(())
+}
+
+// 未指定密码时随机生成
+fn password_or_random(password: Option<String>) -> (String, bool) {
+    match password {
+        Some(v) if !v.is_empty() => (v, false),
+        _ => (random::random_b64(12), true),
+    }
+}
+
+// 清空缓存
+async fn cache_clear() -> std::io::Result<()> {
+    let redis_url = config::section::<String>("redis", "url", "redis://127.0.0.1:6379".to_string());
+
+    let mut conn = match redis::create_redis_pool(redis_url).await {
+        Ok(v) => v,
+        Err(err) => fail(format!("redis 连接失败: {}", err)),
+    };
+
+    let keys = redis::keys(&mut conn, "nako:*").await.unwrap_or_default();
+    match redis::delete(&mut conn, keys).await {
+        Ok(count) => println!("已清空缓存: {}", count),
+        Err(err) => fail(format!("清空缓存失败: {}", err)),
+    }
+
+    Ok(())
+}
+
+// 导入后清除相关文章缓存，redis 不可用时忽略
+async fn clear_related() {
+    let redis_url = config::section::<String>("redis", "url", "redis://127.0.0.1:6379".to_string());
+
+    if let Ok(mut conn) = redis::create_redis_pool(redis_url).await {
+        related::clear_all(&mut conn).await;
+    }
+}
+
+// 导出文章及页面
+async fn export(file: String) -> std::io::Result<()> {
+    let conn = connect().await;
+
+    match markdown::export(&conn).await {
+        Ok(data) => {
+            fs::write(file.as_str(), data)?;
+            println!("导出成功: {}", file);
+        }
+        Err(err) => fail(format!("导出失败: {}", err)),
+    }
+
+    Ok(())
+}
+
+// 导入 Markdown 目录或者压缩包
+async fn import(file: String, user_id: u32) -> std::io::Result<()> {
+    let path = Path::new(file.as_str());
+    let files = if path.is_dir() {
+        markdown::read_dir(path)
+    } else {
+        markdown::read_zip(&fs::read(path)?)
+    };
+
+    let files = match files {
+        Ok(v) => v,
+        Err(err) => fail(err.to_string()),
+    };
+
+    let conn = connect().await;
+
+    let report = markdown::import(&conn, files, user_id, "127.0.0.1").await;
+    clear_related().await;
+
+    println!("添加: {}", report.created);
+    println!("更新: {}", report.updated);
+    println!("跳过: {}", report.skipped);
+
+    for message in report.messages.iter() {
+        println!("{}", message);
+    }
+
+    Ok(())
+}
+
+// 导入 WordPress 数据
+async fn import_wxr(
+    file: String,
+    dry_run: bool,
+    download_media: bool,
+    user_id: u32,
+) -> std::io::Result<()> {
+    let data = fs::read_to_string(file.as_str())?;
+    let wxr_data = match wxr::parse(data.as_str()) {
+        Ok(v) => v,
+        Err(err) => fail(err.to_string()),
+    };
+
+    let conn = connect().await;
+
+    let report = wxr::import(
+        &conn,
+        wxr_data,
+        wxr::ImportOptions {
+            user_id,
+            dry_run,
+            download_media,
+            add_ip: "127.0.0.1".to_string(),
+        },
+    )
+    .await;
+    if !report.dry_run {
+        clear_related().await;
+    }
+
+    println!("模式: {}", if report.dry_run { "预览" } else { "导入" });
+    println!("分类: {}", report.cates);
+    println!("标签: {}", report.tags);
+    println!("文章: {}", report.arts);
+    println!("页面: {}", report.pages);
+    println!("评论: {}", report.comments);
+    println!("附件: {}", report.attachs);
+    println!("跳过: {}", report.skipped);
+
+    for message in report.messages.iter() {
+        println!("{}", message);
+    }
+    for link in report.links.iter() {
+        println!("{} => {}", link.link, link.uuid);
+    }
+
+    Ok(())
+}
+
+// 根据文章标签字段重建标签关联
+async fn reindex() -> std::io::Result<()> {
+    let conn = connect().await;
+
+    match art_tag::ArtTagModel::sync_all(&conn).await {
+        Ok(count) => println!("已处理文章: {}", count),
+        Err(err) => fail(format!("重建失败: {}", err)),
+    }
+
+    Ok(())
+}
+
+// 生成会话密钥，写入配置 [session] 的 secret
+fn gen_key() -> std::io::Result<()> {
+    let mut key = [0u8; 64];
+    rand::thread_rng().fill_bytes(&mut key);
+
+    println!("secret = \"{}\"", utils::base64_encode(&key));
+
+    Ok(())
+}
+
+// 发送测试邮件
+async fn mail_test(email: String) -> std::io::Result<()> {
+    let is_embed = config::section::<bool>("app", "is_embed", true);
+
+    let tpl = match view::build(is_embed) {
+        Ok(v) => v,
+        Err(err) => fail(format!("模板加载失败: {}", err)),
+    };
+
+    match mail::send_test(&tpl, email.as_str()).await {
+        Ok(_) => println!("发送成功: {}", email),
+        Err(err) => fail(format!("发送失败: {}", err)),
+    }
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ use actix_web::{
     body::BoxBody,
     dev::ServiceResponse,
     error::{InternalError, JsonPayloadError, PathError, QueryPayloadError, UrlencodedError},
-    http::Method,
+    http::{Method, StatusCode},
     middleware::ErrorHandlerResponse,
     web, Error, HttpRequest, HttpResponse, Responder, Result,
 };
@@ -10,7 +10,7 @@ use actix_web::{
 use crate::nako::{app, global::AppState, http as nako_http};
 
 pub(crate) async fn app_default(req: HttpRequest) -> impl Responder {
-    get_error_response(&req, "404 Not Found")
+    not_found_response(&req, "404 Not Found")
 }
 
 pub(crate) fn json_parser_error(err: JsonPayloadError, req: &HttpRequest) -> Error {
@@ -61,7 +61,7 @@ pub(crate) fn path_parser_error(err: PathError, req: &HttpRequest) -> Error {
 pub(crate) fn not_found<B>(res: ServiceResponse<B>) -> Result<ErrorHandlerResponse<BoxBody>> {
     let req = res.request();
 
-    let response = get_error_response(&req, "Page not found");
+    let response = not_found_response(&req, "Page not found");
 
     Ok(ErrorHandlerResponse::Response(ServiceResponse::new(
         res.into_parts().0,
@@ -69,10 +69,20 @@ pub(crate) fn not_found<B>(res: ServiceResponse<B>) -> Result<ErrorHandlerRespon
     )))
 }
 
+// 404 响应，页面保留 404 状态码以便记录
+fn not_found_response(req: &HttpRequest, error: &str) -> HttpResponse {
+    let mut response = get_error_response(req, error);
+    if req.method() != Method::POST {
+        *response.status_mut() = StatusCode::NOT_FOUND;
+    }
+
+    response
+}
+
 // 获取响应
 fn get_error_response(req: &HttpRequest, error: &str) -> HttpResponse {
     if let Some(state) = req.app_data::<web::Data<AppState>>() {
-        let mut view = state.view.clone();
+        let mut view = state.view.get();
 
         if req.method() == Method::POST {
             return nako_http::error_response_json(error);
@@ -1,2 +1,4 @@
 pub mod app;
-pub mod error;
\ No newline at end of file
+pub mod cli;
+pub mod error;
+pub mod reload;
\ No newline at end of file
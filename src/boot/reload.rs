@@ -0,0 +1,129 @@
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use notify::{Event, RecursiveMode, Watcher};
+
+use crate::nako::{config, i18n, theme, view};
+
+// 合并短时间内的多次文件变动
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// 监听配置文件、模板目录、语言包目录及主题目录，变动后重新加载
+/// 服务地址、数据库及 redis 等配置需重启后生效
+pub fn watch(views: view::Views, is_embed: bool) {
+    let (tx, rx) = mpsc::channel::<notify::Result<Event>>();
+
+    let mut watcher = match notify::recommended_watcher(tx) {
+        Ok(v) => v,
+        Err(err) => {
+            log::error!("hot reload err: {err}");
+            return;
+        }
+    };
+
+    // 编辑器保存时可能替换文件，所以监听所在目录
+    let conf_file = config::file().map(|f| absolute(Path::new(f.as_str())));
+    if let Some(file) = conf_file.as_ref() {
+        if let Some(dir) = file.parent() {
+            if let Err(err) = watcher.watch(dir, RecursiveMode::NonRecursive) {
+                log::error!("watch {} err: {err}", dir.display());
+            }
+        }
+    }
+
+    let tpl_dir = absolute(Path::new(view::TEMPLATE_DIR));
+    let locale_dir = absolute(Path::new(i18n::LOCALE_DIR));
+    if !is_embed {
+        for dir in [&tpl_dir, &locale_dir] {
+            if let Err(err) = watcher.watch(dir.as_path(), RecursiveMode::Recursive) {
+                log::error!("watch {} err: {err}", dir.display());
+            }
+        }
+    }
+
+    // 主题目录
+    let theme_dir = absolute(Path::new(theme::dir().as_str()));
+    let theme_watched = theme_dir.is_dir()
+        && match watcher.watch(theme_dir.as_path(), RecursiveMode::Recursive) {
+            Ok(_) => true,
+            Err(err) => {
+                log::error!("watch {} err: {err}", theme_dir.display());
+                false
+            }
+        };
+
+    thread::spawn(move || {
+        // 线程结束前保持监听
+        let _watcher = watcher;
+
+        while let Ok(event) = rx.recv() {
+            let mut paths: Vec<PathBuf> = Vec::new();
+            collect(&mut paths, event);
+
+            while let Ok(event) = rx.recv_timeout(DEBOUNCE) {
+                collect(&mut paths, event);
+            }
+
+            let conf_changed = paths.iter().any(|p| Some(p) == conf_file.as_ref());
+            let tpl_changed = (!is_embed && paths.iter().any(|p| p.starts_with(&tpl_dir)))
+                || (theme_watched && paths.iter().any(|p| p.starts_with(&theme_dir)));
+            let locale_changed = !is_embed && paths.iter().any(|p| p.starts_with(&locale_dir));
+
+            if conf_changed {
+                match config::reload() {
+                    Ok(_) => log::info!("config reloaded"),
+                    Err(err) => log::error!("config reload err: {err}"),
+                }
+            }
+
+            if tpl_changed {
+                match view::build(is_embed) {
+                    Ok(v) => {
+                        views.set(v);
+                        log::info!("templates reloaded");
+                    }
+                    Err(err) => log::error!("templates reload err: {err}"),
+                }
+            }
+
+            if locale_changed {
+                match i18n::reload(is_embed) {
+                    Ok(_) => log::info!("locales reloaded"),
+                    Err(err) => log::error!("locales reload err: {err}"),
+                }
+            }
+        }
+    });
+}
+
+// 记录变动的文件
+fn collect(paths: &mut Vec<PathBuf>, event: notify::Result<Event>) {
+    match event {
+        Ok(event) => {
+            if event.kind.is_access() {
+                return;
+            }
+
+            for path in event.paths {
+                let path = absolute(path.as_path());
+                if !paths.contains(&path) {
+                    paths.push(path);
+                }
+            }
+        }
+        Err(err) => log::error!("watch err: {err}"),
+    }
+}
+
+// 绝对路径
+fn absolute(path: &Path) -> PathBuf {
+    if path.is_absolute() {
+        return path.to_path_buf();
+    }
+
+    std::env::current_dir()
+        .map(|dir| dir.join(path))
+        .unwrap_or(path.to_path_buf())
+}
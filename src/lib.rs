@@ -1,4 +1,5 @@
 pub mod app;
 pub mod boot;
+pub mod migration;
 pub mod nako;
 pub mod route;
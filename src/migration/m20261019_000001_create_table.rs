@@ -0,0 +1,698 @@
+use sea_orm::DbBackend;
+use sea_orm_migration::prelude::*;
+
+use super::execute;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for (table, indexes) in tables(manager) {
+            create(manager, table, indexes).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for (table, _) in tables(manager).into_iter().rev() {
+            if let Some(TableRef::Table(name)) = table.get_table_name() {
+                manager
+                    .drop_table(Table::drop().table(name.clone()).if_exists().to_owned())
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// 创建表及索引，已存在的表跳过
+pub(super) async fn create(
+    manager: &SchemaManager<'_>,
+    table: TableCreateStatement,
+    indexes: Vec<IndexCreateStatement>,
+) -> Result<(), DbErr> {
+    let name = match table.get_table_name() {
+        Some(TableRef::Table(v)) => v.to_string(),
+        _ => return Ok(()),
+    };
+
+    // 已存在的旧表由后续迁移升级
+    if manager.has_table(name.as_str()).await? {
+        return Ok(());
+    }
+
+    let has_id = table
+        .get_columns()
+        .iter()
+        .any(|c| c.get_column_name() == "id");
+    let is_postgres = manager.get_database_backend() == DbBackend::Postgres;
+
+    if is_postgres && has_id {
+        execute(
+            manager,
+            format!(
+                "CREATE SEQUENCE IF NOT EXISTS \"{}\"",
+                sequence(Alias::new(&name))
+            )
+            .as_str(),
+        )
+        .await?;
+    }
+
+    manager.create_table(table).await?;
+
+    // 删除表时同时删除序列
+    if is_postgres && has_id {
+        execute(
+            manager,
+            format!(
+                "ALTER SEQUENCE \"{}\" OWNED BY \"{}\".\"id\"",
+                sequence(Alias::new(&name)),
+                name
+            )
+            .as_str(),
+        )
+        .await?;
+    }
+
+    for index in indexes {
+        manager.create_index(index).await?;
+    }
+
+    Ok(())
+}
+
+// 新建表
+pub(super) fn table<T: IntoIden + 'static>(
+    manager: &SchemaManager,
+    name: T,
+) -> TableCreateStatement {
+    let mut stmt = Table::create();
+    stmt.table(name);
+
+    if manager.get_database_backend() == DbBackend::MySql {
+        stmt.engine("InnoDB")
+            .character_set("utf8mb4")
+            .collate("utf8mb4_unicode_ci");
+    }
+
+    stmt
+}
+
+// 主键，PostgreSQL 使用 oid 类型对应无符号整数，自增由序列生成
+pub(super) fn id<T, C>(manager: &SchemaManager, table: T, name: C) -> ColumnDef
+where
+    T: IntoIden,
+    C: IntoIden,
+{
+    let mut col = ColumnDef::new(name);
+    if manager.get_database_backend() == DbBackend::Postgres {
+        col.custom(Alias::new("oid"))
+            .extra(format!("DEFAULT nextval('{}')::oid", sequence(table)));
+    } else {
+        col.unsigned().auto_increment();
+    }
+
+    col.not_null().primary_key().to_owned()
+}
+
+// 自增序列名称
+fn sequence<T: IntoIden>(table: T) -> String {
+    format!("{}_id_seq", table.into_iden().to_string())
+}
+
+// 无符号整数
+pub(super) fn unsigned<T: IntoIden>(manager: &SchemaManager, name: T) -> ColumnDef {
+    let mut col = ColumnDef::new(name);
+    if manager.get_database_backend() == DbBackend::Postgres {
+        col.custom(Alias::new("oid"));
+    } else {
+        col.unsigned();
+    }
+
+    col
+}
+
+// 状态等小整数，PostgreSQL 的 smallint 不能读取为 i32
+pub(super) fn tiny<T: IntoIden>(manager: &SchemaManager, name: T) -> ColumnDef {
+    let mut col = ColumnDef::new(name);
+    if manager.get_database_backend() == DbBackend::MySql {
+        col.tiny_integer();
+    } else {
+        col.integer();
+    }
+
+    col
+}
+
+// 长文本
+pub(super) fn long_text<T: IntoIden>(manager: &SchemaManager, name: T) -> ColumnDef {
+    let mut col = ColumnDef::new(name);
+    if manager.get_database_backend() == DbBackend::MySql {
+        col.custom(Alias::new("longtext"));
+    } else {
+        col.text();
+    }
+
+    col.not_null().to_owned()
+}
+
+// 添加时间及 IP
+pub(super) fn add_time<T: IntoIden>(name: T) -> ColumnDef {
+    ColumnDef::new(name)
+        .big_integer()
+        .not_null()
+        .default(0)
+        .to_owned()
+}
+
+pub(super) fn add_ip<T: IntoIden>(name: T) -> ColumnDef {
+    ColumnDef::new(name)
+        .string_len(50)
+        .not_null()
+        .default("")
+        .to_owned()
+}
+
+// 索引，名称为 idx-表名-字段名
+pub(super) fn index<T, C>(table: T, col: C, unique: bool) -> IndexCreateStatement
+where
+    T: IntoIden,
+    C: IntoIden,
+{
+    let (table, col) = (table.into_iden(), col.into_iden());
+    let name = format!("idx-{}-{}", table.to_string(), col.to_string());
+
+    let mut stmt = Index::create();
+    stmt.name(name.as_str()).table(table).col(col);
+    if unique {
+        stmt.unique();
+    }
+
+    stmt
+}
+
+// 全部表结构
+fn tables(manager: &SchemaManager) -> Vec<(TableCreateStatement, Vec<IndexCreateStatement>)> {
+    vec![
+        (
+            table(manager, Art::Table)
+                .col(&mut id(manager, Art::Table, Art::Id))
+                .col(
+                    ColumnDef::new(Art::Uuid)
+                        .char_len(36)
+                        .not_null()
+                        .default(""),
+                )
+                .col(unsigned(manager, Art::CateId).not_null().default(0))
+                .col(unsigned(manager, Art::UserId).not_null().default(0))
+                .col(
+                    ColumnDef::new(Art::Title)
+                        .string_len(150)
+                        .not_null()
+                        .default(""),
+                )
+                .col(ColumnDef::new(Art::Keywords).string_len(100).default(""))
+                .col(ColumnDef::new(Art::Description).string_len(200))
+                .col(ColumnDef::new(Art::Cover).string_len(150))
+                .col(&mut long_text(manager, Art::Content))
+                .col(ColumnDef::new(Art::Brief).string_len(150).default(""))
+                .col(ColumnDef::new(Art::Tags).string_len(250))
+                .col(ColumnDef::new(Art::From).string_len(200).default(""))
+                .col(ColumnDef::new(Art::Views).big_integer().default(0))
+                .col(tiny(manager, Art::IsTop).default(0))
+                .col(tiny(manager, Art::Status).default(1))
+                .col(&mut add_time(Art::AddTime))
+                .col(&mut add_ip(Art::AddIp))
+                .to_owned(),
+            vec![
+                index(Art::Table, Art::Uuid, true),
+                index(Art::Table, Art::CateId, false),
+            ],
+        ),
+        (
+            table(manager, ArtTag::Table)
+                .col(unsigned(manager, ArtTag::ArtId).not_null())
+                .col(unsigned(manager, ArtTag::TagId).not_null())
+                .primary_key(Index::create().col(ArtTag::ArtId).col(ArtTag::TagId))
+                .to_owned(),
+            vec![index(ArtTag::Table, ArtTag::TagId, false)],
+        ),
+        (
+            table(manager, Attach::Table)
+                .col(&mut id(manager, Attach::Table, Attach::Id))
+                .col(ColumnDef::new(Attach::Name).text().not_null())
+                .col(
+                    ColumnDef::new(Attach::Path)
+                        .string_len(250)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(Attach::Ext)
+                        .string_len(10)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(Attach::Size)
+                        .big_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(Attach::Md5)
+                        .char_len(32)
+                        .not_null()
+                        .default(""),
+                )
+                .col(tiny(manager, Attach::Type).default(0))
+                .col(tiny(manager, Attach::Status).not_null().default(1))
+                .col(&mut add_time(Attach::AddTime))
+                .col(&mut add_ip(Attach::AddIp))
+                .to_owned(),
+            vec![],
+        ),
+        (
+            table(manager, Cate::Table)
+                .col(&mut id(manager, Cate::Table, Cate::Id))
+                .col(unsigned(manager, Cate::Pid).not_null().default(0))
+                .col(
+                    ColumnDef::new(Cate::Name)
+                        .string_len(50)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(Cate::Slug)
+                        .string_len(50)
+                        .not_null()
+                        .default(""),
+                )
+                .col(ColumnDef::new(Cate::Desc).string_len(200))
+                .col(ColumnDef::new(Cate::Sort).integer().default(100))
+                .col(
+                    ColumnDef::new(Cate::ListTpl)
+                        .string_len(200)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(Cate::ViewTpl)
+                        .string_len(200)
+                        .not_null()
+                        .default(""),
+                )
+                .col(tiny(manager, Cate::Status).default(1))
+                .col(&mut add_time(Cate::AddTime))
+                .col(&mut add_ip(Cate::AddIp))
+                .to_owned(),
+            vec![
+                index(Cate::Table, Cate::Slug, true),
+                index(Cate::Table, Cate::Pid, false),
+            ],
+        ),
+        (
+            table(manager, Comment::Table)
+                .col(&mut id(manager, Comment::Table, Comment::Id))
+                .col(unsigned(manager, Comment::ArtId).not_null().default(0))
+                .col(&mut unsigned(manager, Comment::ReplyId))
+                .col(
+                    ColumnDef::new(Comment::Username)
+                        .string_len(50)
+                        .not_null()
+                        .default(""),
+                )
+                .col(ColumnDef::new(Comment::Email).string_len(150).default(""))
+                .col(ColumnDef::new(Comment::Content).text().not_null())
+                .col(tiny(manager, Comment::Status).default(1))
+                .col(&mut add_time(Comment::AddTime))
+                .col(&mut add_ip(Comment::AddIp))
+                .to_owned(),
+            vec![index(Comment::Table, Comment::ArtId, false)],
+        ),
+        (
+            table(manager, Friendlink::Table)
+                .col(&mut id(manager, Friendlink::Table, Friendlink::Id))
+                .col(
+                    ColumnDef::new(Friendlink::Title)
+                        .string_len(150)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(Friendlink::Url)
+                        .string_len(250)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(Friendlink::Target)
+                        .string_len(10)
+                        .default("_blank"),
+                )
+                .col(ColumnDef::new(Friendlink::Icon).string_len(250).default(""))
+                .col(ColumnDef::new(Friendlink::Sort).integer().default(100))
+                .col(tiny(manager, Friendlink::Status).default(1))
+                .col(&mut add_time(Friendlink::AddTime))
+                .col(&mut add_ip(Friendlink::AddIp))
+                .to_owned(),
+            vec![],
+        ),
+        (
+            table(manager, Guestbook::Table)
+                .col(&mut id(manager, Guestbook::Table, Guestbook::Id))
+                .col(
+                    ColumnDef::new(Guestbook::Name)
+                        .string_len(50)
+                        .not_null()
+                        .default(""),
+                )
+                .col(ColumnDef::new(Guestbook::Message).text().not_null())
+                .col(ColumnDef::new(Guestbook::Phone).string_len(15).default(""))
+                .col(ColumnDef::new(Guestbook::Email).string_len(50).default(""))
+                .col(ColumnDef::new(Guestbook::Qq).string_len(15).default(""))
+                .col(
+                    ColumnDef::new(Guestbook::Weixin)
+                        .string_len(100)
+                        .default(""),
+                )
+                .col(tiny(manager, Guestbook::Status).default(1))
+                .col(&mut add_time(Guestbook::AddTime))
+                .col(&mut add_ip(Guestbook::AddIp))
+                .to_owned(),
+            vec![],
+        ),
+        (
+            table(manager, Page::Table)
+                .col(&mut id(manager, Page::Table, Page::Id))
+                .col(unsigned(manager, Page::UserId).not_null().default(0))
+                .col(
+                    ColumnDef::new(Page::Slug)
+                        .string_len(150)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(Page::Title)
+                        .string_len(200)
+                        .not_null()
+                        .default(""),
+                )
+                .col(ColumnDef::new(Page::Keywords).string_len(100).default(""))
+                .col(ColumnDef::new(Page::Description).string_len(200))
+                .col(&mut long_text(manager, Page::Content))
+                .col(ColumnDef::new(Page::Tpl).string_len(200).default(""))
+                .col(tiny(manager, Page::Status).default(1))
+                .col(&mut add_time(Page::AddTime))
+                .col(&mut add_ip(Page::AddIp))
+                .to_owned(),
+            vec![index(Page::Table, Page::Slug, true)],
+        ),
+        (
+            table(manager, Setting::Table)
+                .col(&mut id(manager, Setting::Table, Setting::Id))
+                .col(
+                    ColumnDef::new(Setting::Key)
+                        .string_len(100)
+                        .not_null()
+                        .default(""),
+                )
+                .col(ColumnDef::new(Setting::Value).text())
+                .col(ColumnDef::new(Setting::Desc).string_len(200).default(""))
+                .to_owned(),
+            vec![index(Setting::Table, Setting::Key, true)],
+        ),
+        (
+            table(manager, Tag::Table)
+                .col(&mut id(manager, Tag::Table, Tag::Id))
+                .col(
+                    ColumnDef::new(Tag::Name)
+                        .string_len(50)
+                        .not_null()
+                        .default(""),
+                )
+                .col(ColumnDef::new(Tag::Desc).string_len(200))
+                .col(ColumnDef::new(Tag::Sort).integer().default(100))
+                .col(tiny(manager, Tag::Status).default(1))
+                .col(&mut add_time(Tag::AddTime))
+                .col(&mut add_ip(Tag::AddIp))
+                .to_owned(),
+            vec![index(Tag::Table, Tag::Name, true)],
+        ),
+        (
+            table(manager, User::Table)
+                .col(&mut id(manager, User::Table, User::Id))
+                .col(
+                    ColumnDef::new(User::Username)
+                        .string_len(50)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(User::Password)
+                        .char_len(62)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(User::Nickname)
+                        .string_len(100)
+                        .not_null()
+                        .default(""),
+                )
+                .col(ColumnDef::new(User::Avatar).string_len(150))
+                .col(ColumnDef::new(User::Sign).string_len(200))
+                .col(tiny(manager, User::Status).default(1))
+                .col(&mut add_time(User::AddTime))
+                .col(&mut add_ip(User::AddIp))
+                .to_owned(),
+            vec![index(User::Table, User::Username, true)],
+        ),
+        (
+            table(manager, UserToken::Table)
+                .col(&mut id(manager, UserToken::Table, UserToken::Id))
+                .col(unsigned(manager, UserToken::UserId).not_null().default(0))
+                .col(
+                    ColumnDef::new(UserToken::Name)
+                        .string_len(100)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(UserToken::Token)
+                        .char_len(64)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(UserToken::TokenPrefix)
+                        .string_len(20)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(UserToken::Scopes)
+                        .string_len(200)
+                        .not_null()
+                        .default(""),
+                )
+                .col(ColumnDef::new(UserToken::LastUsedTime).big_integer())
+                .col(ColumnDef::new(UserToken::ExpireTime).big_integer())
+                .col(tiny(manager, UserToken::Status).default(1))
+                .col(&mut add_time(UserToken::AddTime))
+                .col(&mut add_ip(UserToken::AddIp))
+                .to_owned(),
+            vec![
+                index(UserToken::Table, UserToken::Token, true),
+                index(UserToken::Table, UserToken::UserId, false),
+            ],
+        ),
+    ]
+}
+
+#[derive(Iden)]
+enum Art {
+    #[iden = "nako_art"]
+    Table,
+    Id,
+    Uuid,
+    CateId,
+    UserId,
+    Title,
+    Keywords,
+    Description,
+    Cover,
+    Content,
+    Brief,
+    Tags,
+    From,
+    Views,
+    IsTop,
+    Status,
+    AddTime,
+    AddIp,
+}
+
+#[derive(Iden)]
+enum ArtTag {
+    #[iden = "nako_art_tag"]
+    Table,
+    ArtId,
+    TagId,
+}
+
+#[derive(Iden)]
+enum Attach {
+    #[iden = "nako_attach"]
+    Table,
+    Id,
+    Name,
+    Path,
+    Ext,
+    Size,
+    Md5,
+    Type,
+    Status,
+    AddTime,
+    AddIp,
+}
+
+#[derive(Iden)]
+enum Cate {
+    #[iden = "nako_cate"]
+    Table,
+    Id,
+    Pid,
+    Name,
+    Slug,
+    Desc,
+    Sort,
+    ListTpl,
+    ViewTpl,
+    Status,
+    AddTime,
+    AddIp,
+}
+
+#[derive(Iden)]
+enum Comment {
+    #[iden = "nako_comment"]
+    Table,
+    Id,
+    ArtId,
+    ReplyId,
+    Username,
+    Email,
+    Content,
+    Status,
+    AddTime,
+    AddIp,
+}
+
+#[derive(Iden)]
+enum Friendlink {
+    #[iden = "nako_friendlink"]
+    Table,
+    Id,
+    Title,
+    Url,
+    Target,
+    Icon,
+    Sort,
+    Status,
+    AddTime,
+    AddIp,
+}
+
+#[derive(Iden)]
+enum Guestbook {
+    #[iden = "nako_guestbook"]
+    Table,
+    Id,
+    Name,
+    Message,
+    Phone,
+    Email,
+    Qq,
+    Weixin,
+    Status,
+    AddTime,
+    AddIp,
+}
+
+#[derive(Iden)]
+enum Page {
+    #[iden = "nako_page"]
+    Table,
+    Id,
+    UserId,
+    Slug,
+    Title,
+    Keywords,
+    Description,
+    Content,
+    Tpl,
+    Status,
+    AddTime,
+    AddIp,
+}
+
+#[derive(Iden)]
+enum Setting {
+    #[iden = "nako_setting"]
+    Table,
+    Id,
+    Key,
+    Value,
+    Desc,
+}
+
+#[derive(Iden)]
+enum Tag {
+    #[iden = "nako_tag"]
+    Table,
+    Id,
+    Name,
+    Desc,
+    Sort,
+    Status,
+    AddTime,
+    AddIp,
+}
+
+#[derive(Iden)]
+enum User {
+    #[iden = "nako_user"]
+    Table,
+    Id,
+    Username,
+    Password,
+    Nickname,
+    Avatar,
+    Sign,
+    Status,
+    AddTime,
+    AddIp,
+}
+
+#[derive(Iden)]
+enum UserToken {
+    #[iden = "nako_user_token"]
+    Table,
+    Id,
+    UserId,
+    Name,
+    Token,
+    TokenPrefix,
+    Scopes,
+    LastUsedTime,
+    ExpireTime,
+    Status,
+    AddTime,
+    AddIp,
+}
@@ -0,0 +1,136 @@
+use sea_orm::{ConnectionTrait, DbBackend, Statement};
+use sea_orm_migration::prelude::*;
+
+use super::execute;
+use super::m20261019_000001_create_table::index;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// 全部数据表
+const TABLES: [&str; 12] = [
+    "nako_art",
+    "nako_art_tag",
+    "nako_attach",
+    "nako_cate",
+    "nako_comment",
+    "nako_friendlink",
+    "nako_guestbook",
+    "nako_page",
+    "nako_setting",
+    "nako_tag",
+    "nako_user",
+    "nako_user_token",
+];
+
+// 索引，表名、字段名及是否唯一
+const INDEXES: [(&str, &str, bool); 9] = [
+    ("nako_art", "uuid", true),
+    ("nako_art", "cate_id", false),
+    ("nako_cate", "slug", true),
+    ("nako_cate", "pid", false),
+    ("nako_comment", "art_id", false),
+    ("nako_page", "slug", true),
+    ("nako_setting", "key", true),
+    ("nako_tag", "name", true),
+    ("nako_user", "username", true),
+];
+
+/// 升级由 docs/nako_blog.sql 导入的旧表，新建的表不受影响
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DbBackend::MySql {
+            return Ok(());
+        }
+
+        // 友情链接缺少主键
+        if !has_index(manager, "nako_friendlink", "PRIMARY", "id", false).await? {
+            execute(
+                manager,
+                "ALTER TABLE `nako_friendlink` DROP INDEX `id`, ADD PRIMARY KEY (`id`)",
+            )
+            .await?;
+        }
+
+        // 文章的 uuid 索引建在了 id 字段上
+        if has_index(manager, "nako_art", "uuid", "id", false).await? {
+            execute(manager, "ALTER TABLE `nako_art` DROP INDEX `uuid`").await?;
+        }
+
+        for table in TABLES {
+            if table_engine(manager, table).await?.as_deref() == Some("MyISAM") {
+                execute(
+                    manager,
+                    format!("ALTER TABLE `{}` ENGINE=InnoDB", table).as_str(),
+                )
+                .await?;
+            }
+        }
+
+        for (table, col, unique) in INDEXES {
+            if !has_index(manager, table, "", col, unique).await? {
+                manager
+                    .create_index(index(Alias::new(table), Alias::new(col), unique))
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
+
+// 表引擎
+async fn table_engine(manager: &SchemaManager<'_>, table: &str) -> Result<Option<String>, DbErr> {
+    let row = manager
+        .get_connection()
+        .query_one(Statement::from_sql_and_values(
+            DbBackend::MySql,
+            "SELECT `ENGINE` AS `engine` FROM information_schema.TABLES WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = ?",
+            [table.into()],
+        ))
+        .await?;
+
+    match row {
+        Some(row) => Ok(row.try_get::<Option<String>>("", "engine")?),
+        None => Ok(None),
+    }
+}
+
+// 字段上是否有索引，name 为空时不限制索引名称
+async fn has_index(
+    manager: &SchemaManager<'_>,
+    table: &str,
+    name: &str,
+    col: &str,
+    unique: bool,
+) -> Result<bool, DbErr> {
+    let mut sql = "SELECT COUNT(*) AS `num` FROM information_schema.STATISTICS WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = ? AND COLUMN_NAME = ? AND SEQ_IN_INDEX = 1".to_string();
+    let mut values: Vec<sea_orm::Value> = vec![table.into(), col.into()];
+
+    if !name.is_empty() {
+        sql.push_str(" AND INDEX_NAME = ?");
+        values.push(name.into());
+    }
+    if unique {
+        sql.push_str(" AND NON_UNIQUE = 0");
+    }
+
+    let row = manager
+        .get_connection()
+        .query_one(Statement::from_sql_and_values(
+            DbBackend::MySql,
+            sql.as_str(),
+            values,
+        ))
+        .await?;
+
+    match row {
+        Some(row) => Ok(row.try_get::<i64>("", "num")? > 0),
+        None => Ok(false),
+    }
+}
@@ -0,0 +1,113 @@
+use sea_orm::ConnectionTrait;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// 默认配置
+const SETTINGS: [(&str, &str, &str); 6] = [
+    ("website_name", "Nako Blog", "名称"),
+    (
+        "website_keywords",
+        "Nako Blog, actix-web, sea-orm, tera, 博客系统",
+        "关键字",
+    ),
+    (
+        "website_description",
+        "Nako Blog 是使用 actix-web, sea-orm 和 tera 开发的博客系统。",
+        "描述",
+    ),
+    ("website_copyright", "版权", "版权"),
+    ("website_status", "1", "状态"),
+    ("website_beian", "网站备案", "备案"),
+];
+
+// 默认管理员 admin / 123456
+const ADMIN_PASSWORD: &str = "$2b$12$/aXiCMVd11/L5Mt0WonuiOfNLr81HJtNsIzLucYVVm9dNlZbcH7q.";
+
+/// 空表时写入默认数据
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if is_empty(manager, "nako_setting").await? {
+            let mut stmt = Query::insert();
+            stmt.into_table(Alias::new("nako_setting")).columns([
+                Alias::new("key"),
+                Alias::new("value"),
+                Alias::new("desc"),
+            ]);
+            for (key, value, desc) in SETTINGS {
+                stmt.values_panic([key.into(), value.into(), desc.into()]);
+            }
+
+            manager.exec_stmt(stmt).await?;
+        }
+
+        if is_empty(manager, "nako_user").await? {
+            let stmt = Query::insert()
+                .into_table(Alias::new("nako_user"))
+                .columns([
+                    Alias::new("username"),
+                    Alias::new("password"),
+                    Alias::new("nickname"),
+                    Alias::new("status"),
+                ])
+                .values_panic([
+                    "admin".into(),
+                    ADMIN_PASSWORD.into(),
+                    "管理员".into(),
+                    1.into(),
+                ])
+                .to_owned();
+
+            manager.exec_stmt(stmt).await?;
+        }
+
+        if is_empty(manager, "nako_cate").await? {
+            let stmt = Query::insert()
+                .into_table(Alias::new("nako_cate"))
+                .columns([
+                    Alias::new("name"),
+                    Alias::new("slug"),
+                    Alias::new("list_tpl"),
+                    Alias::new("view_tpl"),
+                    Alias::new("status"),
+                ])
+                .values_panic([
+                    "默认分类".into(),
+                    "default".into(),
+                    "list.html".into(),
+                    "view.html".into(),
+                    1.into(),
+                ])
+                .to_owned();
+
+            manager.exec_stmt(stmt).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
+
+// 表是否为空
+async fn is_empty(manager: &SchemaManager<'_>, table: &str) -> Result<bool, DbErr> {
+    let stmt = Query::select()
+        .expr_as(Expr::cust("COUNT(*)"), Alias::new("num"))
+        .from(Alias::new(table))
+        .to_owned();
+
+    let backend = manager.get_database_backend();
+    let row = manager
+        .get_connection()
+        .query_one(backend.build(&stmt))
+        .await?;
+
+    match row {
+        Some(row) => Ok(row.try_get::<i64>("", "num")? == 0),
+        None => Ok(true),
+    }
+}
@@ -0,0 +1,56 @@
+use sea_orm::{ConnectionTrait, DbBackend, Statement};
+use sea_orm_migration::prelude::*;
+
+use super::execute;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// 阅读量及文件大小改为有符号整数，PostgreSQL 及 SQLite 不支持读取 u64
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DbBackend::MySql {
+            return Ok(());
+        }
+
+        if is_unsigned(manager, "nako_art", "views").await? {
+            execute(
+                manager,
+                "ALTER TABLE `nako_art` MODIFY `views` BIGINT DEFAULT 0",
+            )
+            .await?;
+        }
+
+        if is_unsigned(manager, "nako_attach", "size").await? {
+            execute(
+                manager,
+                "ALTER TABLE `nako_attach` MODIFY `size` BIGINT NOT NULL DEFAULT 0",
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
+
+// 字段是否为无符号类型
+async fn is_unsigned(manager: &SchemaManager<'_>, table: &str, col: &str) -> Result<bool, DbErr> {
+    let row = manager
+        .get_connection()
+        .query_one(Statement::from_sql_and_values(
+            DbBackend::MySql,
+            "SELECT `COLUMN_TYPE` AS `type` FROM information_schema.COLUMNS WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = ? AND COLUMN_NAME = ?",
+            [table.into(), col.into()],
+        ))
+        .await?;
+
+    match row {
+        Some(row) => Ok(row.try_get::<String>("", "type")?.contains("unsigned")),
+        None => Ok(false),
+    }
+}
@@ -0,0 +1,99 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20261019_000001_create_table::{
+    add_ip, add_time, create, id, index, long_text, table, unsigned,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// 后台编辑的模板及历史版本
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        create(
+            manager,
+            table(manager, Template::Table)
+                .col(&mut id(manager, Template::Table, Template::Id))
+                .col(
+                    ColumnDef::new(Template::Name)
+                        .string_len(150)
+                        .not_null()
+                        .default(""),
+                )
+                .col(&mut long_text(manager, Template::Content))
+                .col(unsigned(manager, Template::UserId).not_null().default(0))
+                .col(&mut add_time(Template::UpdateTime))
+                .col(&mut add_time(Template::AddTime))
+                .to_owned(),
+            vec![index(Template::Table, Template::Name, true)],
+        )
+        .await?;
+
+        create(
+            manager,
+            table(manager, TemplateVersion::Table)
+                .col(&mut id(
+                    manager,
+                    TemplateVersion::Table,
+                    TemplateVersion::Id,
+                ))
+                .col(
+                    ColumnDef::new(TemplateVersion::Name)
+                        .string_len(150)
+                        .not_null()
+                        .default(""),
+                )
+                .col(&mut long_text(manager, TemplateVersion::Content))
+                .col(
+                    unsigned(manager, TemplateVersion::UserId)
+                        .not_null()
+                        .default(0),
+                )
+                .col(&mut add_time(TemplateVersion::AddTime))
+                .col(&mut add_ip(TemplateVersion::AddIp))
+                .to_owned(),
+            vec![index(TemplateVersion::Table, TemplateVersion::Name, false)],
+        )
+        .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(TemplateVersion::Table)
+                    .if_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Template::Table).if_exists().to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Template {
+    #[iden = "nako_template"]
+    Table,
+    Id,
+    Name,
+    Content,
+    UserId,
+    UpdateTime,
+    AddTime,
+}
+
+#[derive(Iden)]
+enum TemplateVersion {
+    #[iden = "nako_template_version"]
+    Table,
+    Id,
+    Name,
+    Content,
+    UserId,
+    AddTime,
+    AddIp,
+}
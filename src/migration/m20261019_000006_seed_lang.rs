@@ -0,0 +1,52 @@
+use sea_orm::ConnectionTrait;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// 添加网站语言设置，为空时按浏览器语言
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if has_setting(manager, "website_lang").await? {
+            return Ok(());
+        }
+
+        let stmt = Query::insert()
+            .into_table(Alias::new("nako_setting"))
+            .columns([Alias::new("key"), Alias::new("value"), Alias::new("desc")])
+            .values_panic(["website_lang".into(), "".into(), "语言".into()])
+            .to_owned();
+
+        manager.exec_stmt(stmt).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let stmt = Query::delete()
+            .from_table(Alias::new("nako_setting"))
+            .and_where(Expr::col(Alias::new("key")).eq("website_lang"))
+            .to_owned();
+
+        manager.exec_stmt(stmt).await
+    }
+}
+
+// 设置是否存在
+pub(super) async fn has_setting(manager: &SchemaManager<'_>, key: &str) -> Result<bool, DbErr> {
+    let stmt = Query::select()
+        .expr_as(Expr::cust("COUNT(*)"), Alias::new("num"))
+        .from(Alias::new("nako_setting"))
+        .and_where(Expr::col(Alias::new("key")).eq(key))
+        .to_owned();
+
+    let backend = manager.get_database_backend();
+    let row = manager
+        .get_connection()
+        .query_one(backend.build(&stmt))
+        .await?;
+
+    match row {
+        Some(row) => Ok(row.try_get::<i64>("", "num")? > 0),
+        None => Ok(false),
+    }
+}
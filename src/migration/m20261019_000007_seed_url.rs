@@ -0,0 +1,33 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20261019_000006_seed_lang::has_setting;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// 添加网站地址设置，用于生成规范链接等绝对地址
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if has_setting(manager, "website_url").await? {
+            return Ok(());
+        }
+
+        let stmt = Query::insert()
+            .into_table(Alias::new("nako_setting"))
+            .columns([Alias::new("key"), Alias::new("value"), Alias::new("desc")])
+            .values_panic(["website_url".into(), "".into(), "网址".into()])
+            .to_owned();
+
+        manager.exec_stmt(stmt).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let stmt = Query::delete()
+            .from_table(Alias::new("nako_setting"))
+            .and_where(Expr::col(Alias::new("key")).eq("website_url"))
+            .to_owned();
+
+        manager.exec_stmt(stmt).await
+    }
+}
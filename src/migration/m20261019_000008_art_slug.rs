@@ -0,0 +1,102 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20261019_000001_create_table::{add_time, create, id, index, table, unsigned};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// 文章别名及旧链接跳转
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if !manager.has_column("nako_art", "slug").await? {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Art::Table)
+                        .add_column(ColumnDef::new(Art::Slug).string_len(150).null())
+                        .to_owned(),
+                )
+                .await?;
+
+            manager
+                .create_index(index(Art::Table, Art::Slug, true))
+                .await?;
+        }
+
+        create(
+            manager,
+            table(manager, Redirect::Table)
+                .col(&mut id(manager, Redirect::Table, Redirect::Id))
+                .col(
+                    ColumnDef::new(Redirect::Source)
+                        .string_len(255)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(Redirect::Target)
+                        .string_len(255)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(Redirect::Code)
+                        .integer()
+                        .not_null()
+                        .default(301),
+                )
+                .col(unsigned(manager, Redirect::ArtId).not_null().default(0))
+                .col(&mut add_time(Redirect::AddTime))
+                .to_owned(),
+            vec![
+                index(Redirect::Table, Redirect::Source, true),
+                index(Redirect::Table, Redirect::ArtId, false),
+            ],
+        )
+        .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Redirect::Table).if_exists().to_owned())
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-nako_art-slug")
+                    .table(Art::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Art::Table)
+                    .drop_column(Art::Slug)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Art {
+    #[iden = "nako_art"]
+    Table,
+    Slug,
+}
+
+#[derive(Iden)]
+enum Redirect {
+    #[iden = "nako_redirect"]
+    Table,
+    Id,
+    Source,
+    Target,
+    Code,
+    ArtId,
+    AddTime,
+}
@@ -0,0 +1,177 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20261019_000001_create_table::{add_time, create, id, index, table, tiny, unsigned};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// 跳转规则及 404 记录
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SQLite 每次只能添加一个字段
+        let columns = [
+            (
+                "match_type",
+                ColumnDef::new(Redirect::MatchType)
+                    .string_len(10)
+                    .not_null()
+                    .default("exact")
+                    .to_owned(),
+            ),
+            (
+                "hits",
+                ColumnDef::new(Redirect::Hits)
+                    .big_integer()
+                    .not_null()
+                    .default(0)
+                    .to_owned(),
+            ),
+            (
+                "status",
+                tiny(manager, Redirect::Status)
+                    .not_null()
+                    .default(1)
+                    .to_owned(),
+            ),
+        ];
+        for (name, mut col) in columns {
+            if !manager.has_column("nako_redirect", name).await? {
+                manager
+                    .alter_table(
+                        Table::alter()
+                            .table(Redirect::Table)
+                            .add_column(&mut col)
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+        }
+
+        create(
+            manager,
+            table(manager, NotFound::Table)
+                .col(&mut id(manager, NotFound::Table, NotFound::Id))
+                .col(
+                    ColumnDef::new(NotFound::Path)
+                        .string_len(255)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(NotFound::Hits)
+                        .big_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(NotFound::Referrer)
+                        .string_len(255)
+                        .not_null()
+                        .default(""),
+                )
+                .col(&mut add_time(NotFound::LastTime))
+                .col(&mut add_time(NotFound::AddTime))
+                .to_owned(),
+            vec![index(NotFound::Table, NotFound::Path, true)],
+        )
+        .await?;
+
+        create(
+            manager,
+            table(manager, NotFoundReferrer::Table)
+                .col(&mut id(
+                    manager,
+                    NotFoundReferrer::Table,
+                    NotFoundReferrer::Id,
+                ))
+                .col(
+                    unsigned(manager, NotFoundReferrer::NotFoundId)
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(NotFoundReferrer::Referrer)
+                        .string_len(255)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(NotFoundReferrer::Hits)
+                        .big_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(&mut add_time(NotFoundReferrer::LastTime))
+                .to_owned(),
+            vec![Index::create()
+                .name("idx-nako_not_found_referrer-referrer")
+                .table(NotFoundReferrer::Table)
+                .col(NotFoundReferrer::NotFoundId)
+                .col(NotFoundReferrer::Referrer)
+                .unique()
+                .to_owned()],
+        )
+        .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(NotFoundReferrer::Table)
+                    .if_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(NotFound::Table).if_exists().to_owned())
+            .await?;
+
+        for col in [Redirect::Status, Redirect::Hits, Redirect::MatchType] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Redirect::Table)
+                        .drop_column(col)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Redirect {
+    #[iden = "nako_redirect"]
+    Table,
+    MatchType,
+    Hits,
+    Status,
+}
+
+#[derive(Iden)]
+enum NotFound {
+    #[iden = "nako_not_found"]
+    Table,
+    Id,
+    Path,
+    Hits,
+    Referrer,
+    LastTime,
+    AddTime,
+}
+
+#[derive(Iden)]
+enum NotFoundReferrer {
+    #[iden = "nako_not_found_referrer"]
+    Table,
+    Id,
+    NotFoundId,
+    Referrer,
+    Hits,
+    LastTime,
+}
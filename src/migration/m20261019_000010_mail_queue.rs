@@ -0,0 +1,136 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20261019_000001_create_table::{add_time, create, id, long_text, table, tiny};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// 邮件队列，用户邮箱及评论回复通知
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if !manager.has_column("nako_user", "email").await? {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(User::Table)
+                        .add_column(
+                            ColumnDef::new(User::Email)
+                                .string_len(100)
+                                .not_null()
+                                .default(""),
+                        )
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        if !manager.has_column("nako_comment", "notify").await? {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Comment::Table)
+                        .add_column(tiny(manager, Comment::Notify).not_null().default(0))
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        create(
+            manager,
+            table(manager, Mail::Table)
+                .col(&mut id(manager, Mail::Table, Mail::Id))
+                .col(
+                    ColumnDef::new(Mail::Email)
+                        .string_len(100)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(Mail::Subject)
+                        .string_len(255)
+                        .not_null()
+                        .default(""),
+                )
+                .col(&mut long_text(manager, Mail::Body))
+                .col(tiny(manager, Mail::Status).not_null().default(0))
+                .col(
+                    ColumnDef::new(Mail::Attempts)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(Mail::Error)
+                        .string_len(255)
+                        .not_null()
+                        .default(""),
+                )
+                .col(&mut add_time(Mail::NextTime))
+                .col(&mut add_time(Mail::SendTime))
+                .col(&mut add_time(Mail::AddTime))
+                .to_owned(),
+            vec![Index::create()
+                .name("idx-nako_mail-status")
+                .table(Mail::Table)
+                .col(Mail::Status)
+                .col(Mail::NextTime)
+                .to_owned()],
+        )
+        .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Mail::Table).if_exists().to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Comment::Table)
+                    .drop_column(Comment::Notify)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    .drop_column(User::Email)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum User {
+    #[iden = "nako_user"]
+    Table,
+    Email,
+}
+
+#[derive(Iden)]
+enum Comment {
+    #[iden = "nako_comment"]
+    Table,
+    Notify,
+}
+
+#[derive(Iden)]
+enum Mail {
+    #[iden = "nako_mail"]
+    Table,
+    Id,
+    Email,
+    Subject,
+    Body,
+    Status,
+    Attempts,
+    Error,
+    NextTime,
+    SendTime,
+    AddTime,
+}
@@ -0,0 +1,145 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20261019_000001_create_table::{
+    add_time, create, id, index, long_text, table, tiny, unsigned,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Webhook 及投递记录
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        create(
+            manager,
+            table(manager, Webhook::Table)
+                .col(&mut id(manager, Webhook::Table, Webhook::Id))
+                .col(
+                    ColumnDef::new(Webhook::Name)
+                        .string_len(50)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(Webhook::Url)
+                        .string_len(255)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(Webhook::Secret)
+                        .string_len(100)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(Webhook::Events)
+                        .string_len(255)
+                        .not_null()
+                        .default(""),
+                )
+                .col(tiny(manager, Webhook::Status).not_null().default(1))
+                .col(&mut add_time(Webhook::AddTime))
+                .to_owned(),
+            vec![],
+        )
+        .await?;
+
+        create(
+            manager,
+            table(manager, WebhookLog::Table)
+                .col(&mut id(manager, WebhookLog::Table, WebhookLog::Id))
+                .col(
+                    unsigned(manager, WebhookLog::WebhookId)
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(WebhookLog::Event)
+                        .string_len(50)
+                        .not_null()
+                        .default(""),
+                )
+                .col(&mut long_text(manager, WebhookLog::Payload))
+                .col(tiny(manager, WebhookLog::Status).not_null().default(0))
+                .col(
+                    ColumnDef::new(WebhookLog::Attempts)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(WebhookLog::ResponseCode)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(WebhookLog::Response)
+                        .string_len(255)
+                        .not_null()
+                        .default(""),
+                )
+                .col(&mut add_time(WebhookLog::NextTime))
+                .col(&mut add_time(WebhookLog::SendTime))
+                .col(&mut add_time(WebhookLog::AddTime))
+                .to_owned(),
+            vec![
+                index(WebhookLog::Table, WebhookLog::WebhookId, false),
+                Index::create()
+                    .name("idx-nako_webhook_log-status")
+                    .table(WebhookLog::Table)
+                    .col(WebhookLog::Status)
+                    .col(WebhookLog::NextTime)
+                    .to_owned(),
+            ],
+        )
+        .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(WebhookLog::Table)
+                    .if_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Webhook::Table).if_exists().to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Webhook {
+    #[iden = "nako_webhook"]
+    Table,
+    Id,
+    Name,
+    Url,
+    Secret,
+    Events,
+    Status,
+    AddTime,
+}
+
+#[derive(Iden)]
+enum WebhookLog {
+    #[iden = "nako_webhook_log"]
+    Table,
+    Id,
+    WebhookId,
+    Event,
+    Payload,
+    Status,
+    Attempts,
+    ResponseCode,
+    Response,
+    NextTime,
+    SendTime,
+    AddTime,
+}
@@ -0,0 +1,125 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20261019_000001_create_table::{add_time, create, id, index, table, tiny};
+use super::m20261019_000006_seed_lang::has_setting;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// 评论设置
+const SETTINGS: [(&str, &str, &str); 2] = [
+    ("comment_auto_approve", "1", "自动通过有已通过评论的邮箱"),
+    ("comment_close_days", "0", "发布多少天后关闭评论"),
+];
+
+/// 评论审核规则，文章关闭评论
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if !manager.has_column("nako_art", "comment_closed").await? {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Art::Table)
+                        .add_column(tiny(manager, Art::CommentClosed).not_null().default(0))
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        create(
+            manager,
+            table(manager, CommentRule::Table)
+                .col(&mut id(manager, CommentRule::Table, CommentRule::Id))
+                .col(
+                    ColumnDef::new(CommentRule::RuleType)
+                        .string_len(10)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(CommentRule::Value)
+                        .string_len(255)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(CommentRule::Action)
+                        .string_len(10)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(CommentRule::Hits)
+                        .big_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(&mut add_time(CommentRule::AddTime))
+                .to_owned(),
+            vec![index(CommentRule::Table, CommentRule::RuleType, false)],
+        )
+        .await?;
+
+        for (key, value, desc) in SETTINGS {
+            if has_setting(manager, key).await? {
+                continue;
+            }
+
+            let stmt = Query::insert()
+                .into_table(Alias::new("nako_setting"))
+                .columns([Alias::new("key"), Alias::new("value"), Alias::new("desc")])
+                .values_panic([key.into(), value.into(), desc.into()])
+                .to_owned();
+
+            manager.exec_stmt(stmt).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let stmt = Query::delete()
+            .from_table(Alias::new("nako_setting"))
+            .and_where(Expr::col(Alias::new("key")).is_in(SETTINGS.map(|v| v.0)))
+            .to_owned();
+        manager.exec_stmt(stmt).await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(CommentRule::Table)
+                    .if_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Art::Table)
+                    .drop_column(Art::CommentClosed)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Art {
+    #[iden = "nako_art"]
+    Table,
+    CommentClosed,
+}
+
+#[derive(Iden)]
+enum CommentRule {
+    #[iden = "nako_comment_rule"]
+    Table,
+    Id,
+    RuleType,
+    Value,
+    Action,
+    Hits,
+    AddTime,
+}
@@ -0,0 +1,92 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20261019_000001_create_table::{add_time, tiny};
+use super::m20261019_000006_seed_lang::has_setting;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// 前台显示留言
+const SETTING: (&str, &str, &str) = ("guestbook_public", "0", "前台显示已审核的留言");
+
+/// 留言回复及前台显示审核，与原有的联系状态分开
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if !manager.has_column("nako_guestbook", "reply").await? {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Guestbook::Table)
+                        .add_column(ColumnDef::new(Guestbook::Reply).text().null())
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        if !manager.has_column("nako_guestbook", "reply_time").await? {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Guestbook::Table)
+                        .add_column(&mut add_time(Guestbook::ReplyTime))
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        if !manager.has_column("nako_guestbook", "approved").await? {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Guestbook::Table)
+                        .add_column(tiny(manager, Guestbook::Approved).not_null().default(0))
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        let (key, value, desc) = SETTING;
+        if !has_setting(manager, key).await? {
+            let stmt = Query::insert()
+                .into_table(Alias::new("nako_setting"))
+                .columns([Alias::new("key"), Alias::new("value"), Alias::new("desc")])
+                .values_panic([key.into(), value.into(), desc.into()])
+                .to_owned();
+
+            manager.exec_stmt(stmt).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let stmt = Query::delete()
+            .from_table(Alias::new("nako_setting"))
+            .and_where(Expr::col(Alias::new("key")).eq(SETTING.0))
+            .to_owned();
+        manager.exec_stmt(stmt).await?;
+
+        for col in [Guestbook::Approved, Guestbook::ReplyTime, Guestbook::Reply] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Guestbook::Table)
+                        .drop_column(col)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Guestbook {
+    #[iden = "nako_guestbook"]
+    Table,
+    Reply,
+    ReplyTime,
+    Approved,
+}
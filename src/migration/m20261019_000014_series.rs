@@ -0,0 +1,102 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20261019_000001_create_table::{add_time, create, id, index, table, tiny, unsigned};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// 专题及专题文章
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        create(
+            manager,
+            table(manager, Series::Table)
+                .col(&mut id(manager, Series::Table, Series::Id))
+                .col(
+                    ColumnDef::new(Series::Title)
+                        .string_len(100)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(Series::Slug)
+                        .string_len(100)
+                        .not_null()
+                        .default(""),
+                )
+                .col(ColumnDef::new(Series::Desc).text())
+                .col(
+                    ColumnDef::new(Series::Cover)
+                        .string_len(150)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(Series::Sort)
+                        .integer()
+                        .not_null()
+                        .default(100),
+                )
+                .col(tiny(manager, Series::Status).not_null().default(1))
+                .col(&mut add_time(Series::AddTime))
+                .to_owned(),
+            vec![index(Series::Table, Series::Slug, true)],
+        )
+        .await?;
+
+        create(
+            manager,
+            table(manager, SeriesArt::Table)
+                .col(unsigned(manager, SeriesArt::SeriesId).not_null())
+                .col(unsigned(manager, SeriesArt::ArtId).not_null())
+                .col(
+                    ColumnDef::new(SeriesArt::Sort)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .primary_key(
+                    Index::create()
+                        .col(SeriesArt::SeriesId)
+                        .col(SeriesArt::ArtId),
+                )
+                .to_owned(),
+            vec![index(SeriesArt::Table, SeriesArt::ArtId, false)],
+        )
+        .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SeriesArt::Table).if_exists().to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Series::Table).if_exists().to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Series {
+    #[iden = "nako_series"]
+    Table,
+    Id,
+    Title,
+    Slug,
+    Desc,
+    Cover,
+    Sort,
+    Status,
+    AddTime,
+}
+
+#[derive(Iden)]
+enum SeriesArt {
+    #[iden = "nako_series_art"]
+    Table,
+    SeriesId,
+    ArtId,
+    Sort,
+}
@@ -0,0 +1,88 @@
+use sea_orm::{ConnectionTrait, DbConn, DbErr, Statement};
+use sea_orm_migration::prelude::*;
+
+mod m20261019_000001_create_table;
+mod m20261019_000002_upgrade_legacy;
+mod m20261019_000003_seed_data;
+mod m20261019_000004_signed_counters;
+mod m20261019_000005_create_template;
+mod m20261019_000006_seed_lang;
+mod m20261019_000007_seed_url;
+mod m20261019_000008_art_slug;
+mod m20261019_000009_redirect_rule;
+mod m20261019_000010_mail_queue;
+mod m20261019_000011_webhook;
+mod m20261019_000012_comment_moderation;
+mod m20261019_000013_guestbook_reply;
+mod m20261019_000014_series;
+
+pub struct Migrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for Migrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(m20261019_000001_create_table::Migration),
+            Box::new(m20261019_000002_upgrade_legacy::Migration),
+            Box::new(m20261019_000003_seed_data::Migration),
+            Box::new(m20261019_000004_signed_counters::Migration),
+            Box::new(m20261019_000005_create_template::Migration),
+            Box::new(m20261019_000006_seed_lang::Migration),
+            Box::new(m20261019_000007_seed_url::Migration),
+            Box::new(m20261019_000008_art_slug::Migration),
+            Box::new(m20261019_000009_redirect_rule::Migration),
+            Box::new(m20261019_000010_mail_queue::Migration),
+            Box::new(m20261019_000011_webhook::Migration),
+            Box::new(m20261019_000012_comment_moderation::Migration),
+            Box::new(m20261019_000013_guestbook_reply::Migration),
+            Box::new(m20261019_000014_series::Migration),
+        ]
+    }
+}
+
+/// 执行全部未执行的迁移
+pub async fn up(db: &DbConn) -> Result<(), DbErr> {
+    Migrator::up(db, None).await
+}
+
+/// 回滚迁移
+pub async fn down(db: &DbConn, steps: u32) -> Result<(), DbErr> {
+    Migrator::down(db, Some(steps)).await
+}
+
+/// 迁移状态，返回迁移名称及是否已执行
+pub async fn status(db: &DbConn) -> Result<Vec<(String, bool)>, DbErr> {
+    Migrator::install(db).await?;
+
+    let backend = db.get_database_backend();
+    let stmt = Query::select()
+        .column(Alias::new("version"))
+        .from(Alias::new("seaql_migrations"))
+        .to_owned();
+
+    let applied = db
+        .query_all(backend.build(&stmt))
+        .await?
+        .into_iter()
+        .filter_map(|row| row.try_get::<String>("", "version").ok())
+        .collect::<Vec<String>>();
+
+    Ok(Migrator::migrations()
+        .iter()
+        .map(|m| {
+            let name = m.name().to_string();
+            let done = applied.contains(&name);
+            (name, done)
+        })
+        .collect())
+}
+
+/// 执行原始语句
+pub(crate) async fn execute(manager: &SchemaManager<'_>, sql: &str) -> Result<(), DbErr> {
+    let backend = manager.get_database_backend();
+    manager
+        .get_connection()
+        .execute(Statement::from_string(backend, sql.to_owned()))
+        .await
+        .map(|_| ())
+}
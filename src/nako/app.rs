@@ -1,9 +1,6 @@
-use std::fs;
-use std::path::Path;
+use actix_web::{http::StatusCode, HttpResponse};
 
-use actix_web::HttpResponse;
-
-use crate::nako::{config, embed, http, utils};
+use crate::nako::{config, http, theme};
 
 // 是否是调试模式
 pub fn is_debug() -> bool {
@@ -46,51 +43,9 @@ pub fn attach_path(name: String) -> String {
     format!("{}{}", path, name)
 }
 
-// 列出模板
+// 列出当前主题的模板，包含默认主题的模板
 pub fn list_tpls_by_prefix(file_prefix: String) -> Vec<String> {
-    let base_path = "./assert/templates";
-
-    let tpl_path = config::section::<String>("view", "blog_tpl_path", "blog".to_string());
-    let theme = config::section::<String>("view", "blog_theme", "nako".to_string());
-
-    // 打包静态文件
-    let is_embed = config::section::<bool>("app", "is_embed", true);
-    if is_embed {
-        let prefix = format!("{}/{}/{}", tpl_path, theme, file_prefix);
-
-        let mut tpl_list: Vec<String> = Vec::new();
-        for file in embed::Templates::iter() {
-            let filename = file.as_ref();
-            let file_name = filename.clone();
-
-            if file_name.starts_with(&prefix) {
-                tpl_list.push(utils::get_path_filename(filename.clone()));
-            }
-        }
-
-        return tpl_list;
-    }
-
-    let path = format!("{}/{}/{}/", base_path, tpl_path, theme);
-
-    let path_object = Path::new(&path);
-
-    let mut tpl_list: Vec<String> = Vec::new();
-    if path_object.exists() && path_object.is_dir() {
-        if let Ok(entrys) = fs::read_dir(path) {
-            for entry in entrys {
-                if let Ok(file_entry) = entry {
-                    let file_name = file_entry.file_name().into_string().unwrap_or_default();
-
-                    if file_name.starts_with(&file_prefix) {
-                        tpl_list.push(file_name);
-                    }
-                }
-            }
-        }
-    }
-
-    tpl_list
+    theme::templates_by_prefix(theme::active().as_str(), file_prefix.as_str())
 }
 
 // 列表模板
@@ -114,14 +69,9 @@ pub fn page_tpls() -> Vec<String> {
     list_tpls_by_prefix(file_prefix)
 }
 
-// 模板路径
+// 模板路径，当前主题缺少的模板使用默认主题的模板
 pub fn view_path(name: &str) -> String {
-    let tpl_path = config::section::<String>("view", "blog_tpl_path", "blog".to_string());
-    let theme = config::section::<String>("view", "blog_theme", "nako".to_string());
-
-    let path = format!("{}/{}/{}", tpl_path, theme, name);
-
-    path
+    theme::template(theme::active().as_str(), name)
 }
 
 // 返回失败页面
@@ -131,3 +81,11 @@ pub fn error_html(t: &mut tera::Tera, message: &str) -> HttpResponse {
 
     http::view(t, view_path("error.html").as_str(), &ctx)
 }
+
+// 返回指定状态码的失败页面，如 404 及 410
+pub fn error_html_status(t: &mut tera::Tera, message: &str, status: StatusCode) -> HttpResponse {
+    let mut res = error_html(t, message);
+    *res.status_mut() = status;
+
+    res
+}
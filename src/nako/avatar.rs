@@ -0,0 +1,141 @@
+use std::fs;
+use std::path::Path;
+
+use once_cell::sync::Lazy;
+
+use crate::nako::{config, random, utils};
+
+/// 头像链接前缀
+pub const URL_PREFIX: &str = "/avatar";
+
+// 未配置密钥时自动生成的密钥，保存在文件中，重启后保持不变
+static GENERATED_SECRET: Lazy<String> = Lazy::new(generated_secret);
+
+/// 隐私模式，不请求 Gravatar，头像链接不使用邮箱的 md5
+pub fn privacy() -> bool {
+    config::section::<bool>("avatar", "privacy", false)
+}
+
+/// 头像尺寸
+pub fn size() -> u32 {
+    config::section::<u32>("avatar", "size", 80).clamp(16, 512)
+}
+
+/// 缓存目录
+pub fn cache_path() -> String {
+    config::section::<String>("avatar", "cache_path", "./storage/avatar".to_string())
+}
+
+/// 邮箱哈希，隐私模式下使用带密钥的哈希，不能用来查询 Gravatar
+pub fn hash(email: &str) -> String {
+    let email = email.trim().to_lowercase();
+
+    if privacy() {
+        return private_hash(email.as_str());
+    }
+
+    utils::md5(email.as_str())
+}
+
+/// 带密钥的邮箱哈希，不能反查邮箱
+pub fn private_hash(email: &str) -> String {
+    let email = email.trim().to_lowercase();
+
+    let mut hash = utils::hmac_sha256(email.as_bytes(), secret().as_bytes());
+    hash.truncate(32);
+
+    hash
+}
+
+// 哈希密钥，依次使用头像密钥、会话密钥及自动生成的密钥，不能为空
+fn secret() -> String {
+    let secret = config::section::<String>("avatar", "secret", "".to_string());
+    if !secret.is_empty() {
+        return secret;
+    }
+
+    let secret = config::section::<String>("session", "secret", "".to_string());
+    if !secret.is_empty() {
+        return secret;
+    }
+
+    GENERATED_SECRET.clone()
+}
+
+// 读取或者生成密钥文件，保存失败时只在本次运行中使用
+fn generated_secret() -> String {
+    let file =
+        config::section::<String>("avatar", "secret_file", "./storage/avatar.key".to_string());
+
+    let secret = fs::read_to_string(file.as_str()).unwrap_or_default();
+    let secret = secret.trim();
+    if secret.len() >= 32 {
+        return secret.to_string();
+    }
+
+    let secret = random::random_b64(64);
+
+    let path = Path::new(file.as_str());
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).ok();
+    }
+    if let Err(err) = fs::write(path, secret.as_str()) {
+        log::error!("avatar secret save err: {}", err);
+    }
+
+    secret
+}
+
+/// 头像链接
+pub fn url(email: &str) -> String {
+    format!("{}/{}", URL_PREFIX, hash(email))
+}
+
+/// 使用带密钥哈希的头像链接，用于公开的留言等
+pub fn private_url(email: &str) -> String {
+    format!("{}/{}", URL_PREFIX, private_hash(email))
+}
+
+/// 是否为头像哈希
+pub fn is_hash(hash: &str) -> bool {
+    hash.len() == 32 && hash.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f'))
+}
+
+/// 根据哈希生成 identicon，5x5 左右对称的色块
+pub fn identicon(hash: &str, size: u32) -> String {
+    let nibbles = hash
+        .chars()
+        .map(|c| c.to_digit(16).unwrap_or_default())
+        .collect::<Vec<u32>>();
+    let nibble = |i: usize| nibbles.get(i).copied().unwrap_or_default();
+
+    // 颜色
+    let hue = (nibble(25) << 8 | nibble(26) << 4 | nibble(27)) * 360 / 4096;
+    let saturation = 45 + nibble(28) * 20 / 15;
+    let lightness = 50 + nibble(29) * 15 / 15;
+    let color = format!("hsl({}, {}%, {}%)", hue, saturation, lightness);
+
+    let mut rects = String::new();
+    for col in 0..3 {
+        for row in 0..5 {
+            if nibble(col * 5 + row) % 2 != 0 {
+                continue;
+            }
+
+            for x in [col, 4 - col] {
+                rects.push_str(&format!(
+                    r#"<rect x="{}" y="{}" width="1" height="1"/>"#,
+                    x, row
+                ));
+
+                if col == 2 {
+                    break;
+                }
+            }
+        }
+    }
+
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{size}" height="{size}" viewBox="-0.5 -0.5 6 6" shape-rendering="crispEdges"><rect x="-0.5" y="-0.5" width="6" height="6" fill="#f0f0f0"/><g fill="{color}">{rects}</g></svg>"##
+    )
+}
@@ -1,28 +1,123 @@
+use chrono_tz::Tz;
 use ini::Ini;
 use once_cell::sync::Lazy;
+use std::env;
 use std::path;
 use std::str::FromStr;
-use std::sync::Mutex;
+use std::sync::{Mutex, RwLock, RwLockReadGuard};
 
-use crate::nako::embed;
+use crate::nako::{db, embed, mail, permalink, utils};
+
+// 环境变量前缀，NAKO_DB__URL 对应 [db] 的 url
+const ENV_PREFIX: &str = "NAKO_";
 
 static GLOBAL_CONF_FILE: Lazy<Mutex<String>> = Lazy::new(|| Mutex::new(String::from("conf.ini")));
 
-static GLOBAL_CONF: Lazy<Ini> = Lazy::new(|| {
-    if let Ok(v) = GLOBAL_CONF_FILE.lock() {
-        if path::Path::new(v.clone().as_str()).exists() {
-            return Ini::load_from_file(v.clone().as_str()).unwrap_or_default();
-        }
+// 配置按 内置默认配置 -> 配置文件 -> 环境变量 依次覆盖
+static GLOBAL_CONF: Lazy<RwLock<Ini>> = Lazy::new(|| {
+    // 导入 .env 文件中的环境变量
+    dotenvy::dotenv().ok();
+
+    RwLock::new(layered(file().map(|f| load_from_file(f.as_str()))))
+});
+
+// 合并各层配置
+fn layered(file_conf: Option<Ini>) -> Ini {
+    let mut conf = embedded();
+
+    if let Some(file_conf) = file_conf {
+        merge(&mut conf, &file_conf);
     }
 
-    let conf = match embed::Config::get("conf.ini") {
-        Some(v) => v.data.into_owned(),
-        None => todo!(),
+    merge_env(&mut conf, env::vars());
+
+    conf
+}
+
+// 当前配置
+fn conf() -> RwLockReadGuard<'static, Ini> {
+    match GLOBAL_CONF.read() {
+        Ok(v) => v,
+        Err(e) => e.into_inner(),
+    }
+}
+
+// 替换配置，返回旧配置
+fn replace(conf: Ini) -> Ini {
+    let mut guard = match GLOBAL_CONF.write() {
+        Ok(v) => v,
+        Err(e) => e.into_inner(),
     };
-    let conf_str = std::str::from_utf8(conf.as_ref()).unwrap_or("");
 
-    Ini::load_from_str(conf_str).unwrap_or_default()
-});
+    std::mem::replace(&mut *guard, conf)
+}
+
+/// 重新读取配置文件，解析或者校验失败时保留原配置
+pub fn reload() -> Result<(), String> {
+    let file_conf = match file() {
+        Some(f) => Some(Ini::load_from_file(f.as_str()).map_err(|e| format!("{}: {}", f, e))?),
+        None => None,
+    };
+
+    let old = replace(layered(file_conf));
+    if let Err(errors) = AppConfig::load() {
+        replace(old);
+        return Err(errors.join("; "));
+    }
+
+    Ok(())
+}
+
+/// 设置配置文件，需在读取配置前调用
+pub fn set_file(file: &str) {
+    if let Ok(mut v) = GLOBAL_CONF_FILE.lock() {
+        *v = file.to_string();
+    }
+}
+
+/// 存在的配置文件
+pub fn file() -> Option<String> {
+    let file = GLOBAL_CONF_FILE.lock().ok()?.clone();
+
+    if path::Path::new(file.as_str()).exists() {
+        Some(file)
+    } else {
+        None
+    }
+}
+
+// 内置默认配置
+fn embedded() -> Ini {
+    match embed::Config::get("conf.ini") {
+        Some(v) => load_from_str(std::str::from_utf8(v.data.as_ref()).unwrap_or("")),
+        None => new(),
+    }
+}
+
+// 合并配置
+fn merge(conf: &mut Ini, other: &Ini) {
+    for (section, props) in other.iter() {
+        for (key, value) in props.iter() {
+            conf.with_section(section).set(key, value);
+        }
+    }
+}
+
+// 合并环境变量，格式为 NAKO_{SECTION}__{KEY}
+fn merge_env<I: Iterator<Item = (String, String)>>(conf: &mut Ini, vars: I) {
+    for (name, value) in vars {
+        let name = match name.strip_prefix(ENV_PREFIX) {
+            Some(v) => v.to_lowercase(),
+            None => continue,
+        };
+
+        if let Some((section, key)) = name.split_once("__") {
+            if !section.is_empty() && !key.is_empty() {
+                conf.with_section(Some(section)).set(key, value);
+            }
+        }
+    }
+}
 
 /// 初始化
 pub fn new() -> Ini {
@@ -44,9 +139,7 @@ pub fn section<B: FromStr>(section: &str, key: &str, def_val: B) -> B
 where
     <B as FromStr>::Err: std::fmt::Debug,
 {
-    let ini = &GLOBAL_CONF;
-
-    match ini.get_from(Some(section), key) {
+    match conf().get_from(Some(section), key) {
         Some(data) => match data.parse::<B>() {
             Ok(v) => v,
             Err(_) => def_val,
@@ -54,3 +147,170 @@ where
         _ => def_val,
     }
 }
+
+/// 服务配置
+#[derive(Clone, Debug)]
+pub struct ServerConfig {
+    pub host: String,
+    pub port: u16,
+}
+
+/// 数据库配置
+#[derive(Clone, Debug)]
+pub struct DbConfig {
+    pub url: String,
+    pub max_connections: u32,
+    pub min_connections: u32,
+    pub auto_migrate: bool,
+}
+
+/// 会话配置
+#[derive(Clone, Debug)]
+pub struct SessionConfig {
+    pub redis_url: String,
+    pub secret: Vec<u8>,
+}
+
+/// 启动需要的配置
+#[derive(Clone, Debug)]
+pub struct AppConfig {
+    pub server: ServerConfig,
+    pub db: DbConfig,
+    pub redis_url: String,
+    pub session: SessionConfig,
+    pub timezone: Tz,
+}
+
+impl AppConfig {
+    /// 读取并校验配置，返回全部错误信息
+    pub fn load() -> Result<AppConfig, Vec<String>> {
+        let mut errors: Vec<String> = Vec::new();
+
+        if let Some(file) = file() {
+            if let Err(err) = Ini::load_from_file(file.as_str()) {
+                errors.push(format!("配置文件 {} 解析失败: {}", file, err));
+            }
+        }
+
+        let server = ServerConfig {
+            host: parse(&mut errors, "server", "host", "127.0.0.1".to_string()),
+            port: parse(&mut errors, "server", "port", 8080),
+        };
+
+        let db = DbConfig {
+            url: parse(&mut errors, "db", "url", "".to_string()),
+            max_connections: parse(&mut errors, "db", "max_connections", 100),
+            min_connections: parse(&mut errors, "db", "min_connections", 5),
+            auto_migrate: parse(&mut errors, "db", "auto_migrate", false),
+        };
+
+        if db.url.is_empty() {
+            errors.push("[db] url 不能为空，可使用环境变量 NAKO_DB__URL 设置".to_string());
+        } else {
+            match db::backend(db.url.as_str()) {
+                Some(backend) if !db::enabled(backend) => errors.push(format!(
+                    "[db] url 的数据库驱动未启用，请使用 --features {} 编译",
+                    db::feature(backend)
+                )),
+                Some(_) => {}
+                None => errors.push(
+                    "[db] url 只支持 mysql://、postgres:// 及 sqlite:// 开头的地址".to_string(),
+                ),
+            }
+        }
+        if db.max_connections == 0 || db.min_connections > db.max_connections {
+            errors.push("[db] max_connections 需大于 0 且不小于 min_connections".to_string());
+        }
+
+        let redis_url = parse(
+            &mut errors,
+            "redis",
+            "url",
+            "redis://127.0.0.1:6379".to_string(),
+        );
+        check_redis_url(&mut errors, "redis", redis_url.as_str());
+
+        let session_redis_url = parse(
+            &mut errors,
+            "session",
+            "redis_url",
+            "redis://127.0.0.1:6379".to_string(),
+        );
+        check_redis_url(&mut errors, "session", session_redis_url.as_str());
+
+        let secret = parse(&mut errors, "session", "secret", "".to_string());
+        let session = SessionConfig {
+            redis_url: session_redis_url,
+            secret: utils::base64_decode(secret.clone()),
+        };
+        if !secret.is_empty() && session.secret.len() < 64 {
+            errors.push(
+                "[session] secret 需为至少 64 字节的 base64 字符串，可使用 nako-blog gen-key 生成"
+                    .to_string(),
+            );
+        }
+
+        let timezone = parse(&mut errors, "time", "timezone", chrono_tz::UTC);
+
+        let pattern = parse(
+            &mut errors,
+            "view",
+            "permalink",
+            permalink::DEFAULT_PATTERN.to_string(),
+        );
+        if let Err(err) = permalink::check(pattern.as_str()) {
+            errors.push(format!(
+                "[view] permalink 的值 \"{}\" 错误，{}",
+                pattern, err
+            ));
+        }
+
+        if parse(&mut errors, "mail", "enable", false) {
+            parse::<u16>(&mut errors, "mail", "port", 25);
+            if let Err(err) = mail::MailConfig::load().check() {
+                errors.push(format!("[mail] {}", err));
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(AppConfig {
+            server,
+            db,
+            redis_url,
+            session,
+            timezone,
+        })
+    }
+}
+
+// 解析配置项，格式错误时记录错误
+fn parse<B: FromStr>(errors: &mut Vec<String>, section: &str, key: &str, def_val: B) -> B {
+    match conf().get_from(Some(section), key) {
+        Some(data) => match data.parse::<B>() {
+            Ok(v) => v,
+            Err(_) => {
+                errors.push(format!("[{}] {} 的值 \"{}\" 格式错误", section, key, data));
+                def_val
+            }
+        },
+        None => def_val,
+    }
+}
+
+// 校验 redis 地址
+fn check_redis_url(errors: &mut Vec<String>, section: &str, redis_url: &str) {
+    let valid = match url::Url::parse(redis_url) {
+        Ok(v) => v.scheme().starts_with("redis"),
+        Err(_) => false,
+    };
+
+    if !valid {
+        errors.push(format!(
+            "[{}] redis 地址 \"{}\" 格式错误，应为 redis://host:port",
+            section, redis_url
+        ));
+    }
+}
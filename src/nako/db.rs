@@ -1,11 +1,57 @@
 use std::time::Duration;
 
-use sea_orm::{ConnectOptions, Database, DatabaseConnection, DbErr};
+use sea_orm::{ConnectOptions, Database, DatabaseConnection, DbBackend, DbErr};
 
 pub use sea_orm;
 
 use crate::nako::{app, config};
 
+#[cfg(not(any(feature = "mysql", feature = "postgres", feature = "sqlite")))]
+compile_error!("至少需要启用 mysql、postgres 及 sqlite 中的一个数据库特性");
+
+// 根据连接地址判断数据库类型
+pub fn backend(url: &str) -> Option<DbBackend> {
+    let scheme = url.split(':').next().unwrap_or_default();
+
+    match scheme {
+        "mysql" | "mariadb" => Some(DbBackend::MySql),
+        "postgres" | "postgresql" => Some(DbBackend::Postgres),
+        "sqlite" => Some(DbBackend::Sqlite),
+        _ => None,
+    }
+}
+
+// 数据库驱动是否已编译
+pub fn enabled(backend: DbBackend) -> bool {
+    match backend {
+        DbBackend::MySql => cfg!(feature = "mysql"),
+        DbBackend::Postgres => cfg!(feature = "postgres"),
+        DbBackend::Sqlite => cfg!(feature = "sqlite"),
+    }
+}
+
+// 数据库对应的编译特性
+pub fn feature(backend: DbBackend) -> &'static str {
+    match backend {
+        DbBackend::MySql => "mysql",
+        DbBackend::Postgres => "postgres",
+        DbBackend::Sqlite => "sqlite",
+    }
+}
+
+// SQLite 数据库文件不存在时自动创建
+fn sqlite_url(url: String) -> String {
+    if url.contains(":memory:") || url.contains("mode=") {
+        return url;
+    }
+
+    if url.contains('?') {
+        format!("{}&mode=rwc", url)
+    } else {
+        format!("{}?mode=rwc", url)
+    }
+}
+
 // 数据库连接
 pub async fn connect() -> Result<DatabaseConnection, DbErr> {
     let db_url = config::section::<String>("db", "url", "".to_string());
@@ -23,16 +69,38 @@ pub async fn connect() -> Result<DatabaseConnection, DbErr> {
     let db_logging_level = config::section::<String>("db", "logging_level", "info".into());
     let logging_level = app::get_log_level(db_logging_level);
 
-    let mut opt = ConnectOptions::new(db_url);
-    opt.max_connections(max_connections)
-        .min_connections(min_connections)
-        .connect_timeout(Duration::from_secs(connect_timeout))
+    let backend = match backend(db_url.as_str()) {
+        Some(v) => v,
+        None => return Err(DbErr::Custom(format!("不支持的数据库地址: {}", db_url))),
+    };
+    if !enabled(backend) {
+        return Err(DbErr::Custom(format!(
+            "数据库驱动未启用，请使用 --features {} 编译",
+            feature(backend)
+        )));
+    }
+
+    let db_url = match backend {
+        DbBackend::Sqlite => sqlite_url(db_url),
+        _ => db_url,
+    };
+
+    let mut opt = ConnectOptions::new(db_url.clone());
+    opt.connect_timeout(Duration::from_secs(connect_timeout))
         .acquire_timeout(Duration::from_secs(acquire_timeout))
-        .idle_timeout(Duration::from_secs(idle_timeout))
-        .max_lifetime(Duration::from_secs(max_lifetime))
         .sqlx_logging(logging)
         .sqlx_logging_level(logging_level);
 
+    // 内存数据库只在同一个连接内有效，连接不能被回收
+    if db_url.contains(":memory:") {
+        opt.max_connections(1).min_connections(1);
+    } else {
+        opt.max_connections(max_connections)
+            .min_connections(min_connections)
+            .idle_timeout(Duration::from_secs(idle_timeout))
+            .max_lifetime(Duration::from_secs(max_lifetime));
+    }
+
     let db = Database::connect(opt);
 
     db.await
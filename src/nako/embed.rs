@@ -12,6 +12,10 @@ pub struct Templates;
 #[folder = "assert/config/"]
 pub struct Config;
 
+#[derive(RustEmbed)]
+#[folder = "assert/locales/"]
+pub struct Locales;
+
 /// 获取模板数据
 pub fn get_tpl_data(name: &str) -> String {
     let data = match Templates::get(name) {
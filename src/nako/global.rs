@@ -1,5 +1,4 @@
 use sea_orm::DatabaseConnection;
-use tera::Tera;
 
 pub use actix_session::Session;
 use redis::aio::ConnectionManager;
@@ -7,9 +6,11 @@ pub use serde::{Deserialize, Serialize};
 
 pub use validator::{Validate, ValidationError};
 
+use crate::nako::view::Views;
+
 #[derive(Clone)]
 pub struct AppState {
-    pub view: Tera,
+    pub view: Views,
     pub db: DatabaseConnection,
     pub redis: ConnectionManager,
 }
@@ -38,6 +38,13 @@ pub fn html(body: String) -> HttpResponse {
         .body(body)
 }
 
+// 返回 xml
+pub fn xml(body: String) -> HttpResponse {
+    HttpResponse::build(StatusCode::OK)
+        .content_type(ContentType::xml())
+        .body(body)
+}
+
 // 返回 json
 pub fn json<T: Serialize>(res_body: T) -> HttpResponse {
     HttpResponse::build(StatusCode::OK).json(res_body)
@@ -50,6 +57,13 @@ pub fn redirect(url: String) -> HttpResponse {
         .finish()
 }
 
+// 永久跳转
+pub fn redirect_permanent(url: String) -> HttpResponse {
+    HttpResponse::MovedPermanently()
+        .append_header((header::LOCATION, url))
+        .finish()
+}
+
 // let mut ctx = view_data();
 // ctx.insert("name", "hello");
 pub fn view_data() -> tera::Context {
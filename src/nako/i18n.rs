@@ -0,0 +1,311 @@
+use std::collections::HashMap;
+use std::fs;
+use std::future::Future;
+use std::sync::RwLock;
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+use fluent_langneg::{accepted_languages, negotiate_languages, NegotiationStrategy};
+use once_cell::sync::Lazy;
+use serde_json::Value;
+use unic_langid::LanguageIdentifier;
+
+use crate::nako::{config, embed};
+
+/// 未打包时的语言包目录
+pub const LOCALE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/assert/locales");
+
+/// 内置默认语言
+pub const DEFAULT_LOCALE: &str = "zh-CN";
+
+/// 保存访客语言的 cookie 名称
+pub const COOKIE_NAME: &str = "nako_lang";
+
+/// 网站语言的设置名称，为空时按浏览器语言
+pub const SETTING_KEY: &str = "website_lang";
+
+type Bundle = FluentBundle<FluentResource>;
+
+// 全部语言包
+static BUNDLES: Lazy<RwLock<HashMap<String, Bundle>>> = Lazy::new(|| {
+    let is_embed = config::section::<bool>("app", "is_embed", true);
+
+    RwLock::new(build(is_embed).unwrap_or_else(|err| {
+        log::error!("locales load err: {err}");
+        HashMap::new()
+    }))
+});
+
+tokio::task_local! {
+    // 当前请求的语言
+    static LOCALE: String;
+}
+
+/// 读取语言包，目录结构为 {语言}/*.ftl
+pub fn build(is_embed: bool) -> Result<HashMap<String, Bundle>, String> {
+    let mut sources: HashMap<String, Vec<(String, String)>> = HashMap::new();
+
+    if is_embed {
+        for file in embed::Locales::iter() {
+            let name = file.as_ref();
+            if let Some((locale, _)) = name.split_once('/') {
+                let data = embed::Locales::get(name)
+                    .map(|v| String::from_utf8_lossy(v.data.as_ref()).to_string())
+                    .unwrap_or_default();
+
+                sources
+                    .entry(locale.to_string())
+                    .or_default()
+                    .push((name.to_string(), data));
+            }
+        }
+    } else {
+        let entries = fs::read_dir(LOCALE_DIR).map_err(|e| format!("{}: {}", LOCALE_DIR, e))?;
+        for entry in entries.flatten() {
+            let locale = entry.file_name().into_string().unwrap_or_default();
+            let files = match fs::read_dir(entry.path()) {
+                Ok(v) => v,
+                Err(_) => continue,
+            };
+
+            for file in files.flatten() {
+                let path = file.path();
+                if path.extension().and_then(|v| v.to_str()) != Some("ftl") {
+                    continue;
+                }
+
+                let data = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+                sources
+                    .entry(locale.clone())
+                    .or_default()
+                    .push((path.display().to_string(), data));
+            }
+        }
+    }
+
+    let mut bundles = HashMap::new();
+    for (locale, files) in sources {
+        let langid: LanguageIdentifier = match locale.parse() {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+
+        let mut bundle = Bundle::new_concurrent(vec![langid.clone()]);
+        // 不添加 Unicode 隔离字符，避免出现在 html 中
+        bundle.set_use_isolating(false);
+
+        for (name, data) in files {
+            let resource = FluentResource::try_new(data)
+                .map_err(|(_, errors)| format!("{}: {:?}", name, errors))?;
+            bundle
+                .add_resource(resource)
+                .map_err(|errors| format!("{}: {:?}", name, errors))?;
+        }
+
+        bundles.insert(langid.to_string(), bundle);
+    }
+
+    Ok(bundles)
+}
+
+/// 重新读取语言包，出错时保留原语言包
+pub fn reload(is_embed: bool) -> Result<(), String> {
+    let bundles = build(is_embed)?;
+
+    match BUNDLES.write() {
+        Ok(mut v) => *v = bundles,
+        Err(e) => *e.into_inner() = bundles,
+    }
+
+    Ok(())
+}
+
+/// 已有的语言
+pub fn locales() -> Vec<String> {
+    let mut data = match BUNDLES.read() {
+        Ok(v) => v.keys().cloned().collect::<Vec<String>>(),
+        Err(e) => e.into_inner().keys().cloned().collect::<Vec<String>>(),
+    };
+    data.sort();
+
+    data
+}
+
+/// 默认语言
+pub fn default_locale() -> String {
+    config::section::<String>("i18n", "default_locale", DEFAULT_LOCALE.to_string())
+}
+
+/// 匹配已有的语言，没有匹配时返回 None
+pub fn negotiate(requested: &[LanguageIdentifier]) -> Option<String> {
+    let available = locales()
+        .iter()
+        .filter_map(|v| v.parse::<LanguageIdentifier>().ok())
+        .collect::<Vec<LanguageIdentifier>>();
+
+    negotiate_languages(requested, &available, None, NegotiationStrategy::Filtering)
+        .first()
+        .map(|v| v.to_string())
+}
+
+/// 语言名称对应的已有语言
+pub fn find(name: &str) -> Option<String> {
+    let langid = name.trim().parse::<LanguageIdentifier>().ok()?;
+
+    negotiate(&[langid])
+}
+
+/// 按 cookie、网站设置、浏览器语言的顺序获取语言
+pub fn resolve(
+    cookie: Option<&str>,
+    setting: Option<&str>,
+    accept_language: Option<&str>,
+) -> String {
+    if let Some(v) = cookie.and_then(find) {
+        return v;
+    }
+
+    if let Some(v) = setting.and_then(find) {
+        return v;
+    }
+
+    if let Some(header) = accept_language {
+        if let Some(v) = negotiate(&accepted_languages::parse(header)) {
+            return v;
+        }
+    }
+
+    find(default_locale().as_str()).unwrap_or(DEFAULT_LOCALE.to_string())
+}
+
+/// 在指定语言下运行
+pub async fn scope<F: Future>(locale: String, f: F) -> F::Output {
+    LOCALE.scope(locale, f).await
+}
+
+/// 当前语言
+pub fn locale() -> String {
+    LOCALE
+        .try_with(|v| v.clone())
+        .unwrap_or_else(|_| default_locale())
+}
+
+/// 翻译
+pub fn t(key: &str) -> String {
+    t_args(key, None)
+}
+
+/// 带参数的翻译，当前语言缺少时使用默认语言，都缺少时返回 key
+pub fn t_args(key: &str, args: Option<&FluentArgs>) -> String {
+    let bundles = match BUNDLES.read() {
+        Ok(v) => v,
+        Err(e) => e.into_inner(),
+    };
+
+    for locale in [locale(), default_locale(), DEFAULT_LOCALE.to_string()] {
+        if let Some(bundle) = bundles.get(locale.as_str()) {
+            if let Some(v) = format(bundle, key, args) {
+                return v;
+            }
+        }
+    }
+
+    key.to_string()
+}
+
+/// 带参数的翻译，参数为名称及文本
+pub fn t_with(key: &str, args: &[(&str, String)]) -> String {
+    let mut fluent_args = FluentArgs::new();
+    for (name, value) in args {
+        fluent_args.set(name.to_string(), FluentValue::from(value.clone()));
+    }
+
+    t_args(key, Some(&fluent_args))
+}
+
+// 格式化消息
+fn format(bundle: &Bundle, key: &str, args: Option<&FluentArgs>) -> Option<String> {
+    let (id, attr) = match key.split_once('.') {
+        Some((id, attr)) => (id, Some(attr)),
+        None => (key, None),
+    };
+
+    let message = bundle.get_message(id)?;
+    let pattern = match attr {
+        Some(attr) => message.get_attribute(attr)?.value(),
+        None => message.value()?,
+    };
+
+    let mut errors = vec![];
+    let value = bundle.format_pattern(pattern, args, &mut errors);
+    if !errors.is_empty() {
+        log::warn!("i18n {} format err: {:?}", key, errors);
+    }
+
+    Some(value.to_string())
+}
+
+/// 模板参数转换为翻译参数
+pub fn args_from_json(data: &HashMap<String, Value>) -> FluentArgs<'static> {
+    let mut args = FluentArgs::new();
+
+    for (name, value) in data {
+        match value {
+            Value::Number(v) => {
+                args.set(name.clone(), FluentValue::from(v.as_f64().unwrap_or(0.0)))
+            }
+            Value::String(v) => args.set(name.clone(), FluentValue::from(v.clone())),
+            Value::Bool(v) => args.set(name.clone(), FluentValue::from(v.to_string())),
+            _ => {}
+        }
+    }
+
+    args
+}
+
+/// 校验错误信息，message 为翻译的 key
+pub fn validate_message(errors: &validator::ValidationErrors) -> String {
+    let mut fields = errors.field_errors().into_iter().collect::<Vec<_>>();
+    fields.sort_by_key(|(name, _)| *name);
+
+    fields
+        .into_iter()
+        .flat_map(|(_, errors)| errors.iter())
+        .map(|err| match err.message.as_ref() {
+            Some(key) => t(key),
+            None => t(err.code.as_ref()),
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_locale() {
+        // cookie 优先
+        assert_eq!(resolve(Some("en"), Some("zh-CN"), Some("zh-CN")), "en");
+
+        // 不存在的语言依次使用网站设置及浏览器语言
+        assert_eq!(resolve(Some("fr"), Some("en"), Some("zh-CN")), "en");
+        assert_eq!(
+            resolve(Some("xx-invalid-"), None, Some("fr;q=0.9, en-US;q=0.8")),
+            "en"
+        );
+
+        // 都没有匹配时使用默认语言
+        assert_eq!(resolve(None, None, Some("fr")), default_locale());
+        assert_eq!(resolve(None, Some(""), None), default_locale());
+    }
+
+    #[test]
+    fn translate_with_args() {
+        let text = t_with("admin-slug-used", &[("slug", "hello".to_string())]);
+        assert!(text.contains("hello"));
+
+        // 缺少的翻译返回 key
+        assert_eq!(t("missing-key"), "missing-key");
+    }
+}
@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use lettre::{
+    message::{header::ContentType, Mailbox},
+    transport::smtp::authentication::Credentials,
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+
+use crate::nako::config;
+
+/// 加密方式
+pub const ENCRYPTIONS: [&str; 3] = ["none", "starttls", "tls"];
+
+/// 邮件配置
+#[derive(Clone, Debug)]
+pub struct MailConfig {
+    pub enable: bool,
+    pub host: String,
+    pub port: u16,
+    pub encryption: String,
+    pub username: String,
+    pub password: String,
+    pub from: String,
+    pub timeout: u64,
+}
+
+impl MailConfig {
+    /// 读取 [mail] 配置
+    pub fn load() -> Self {
+        Self {
+            enable: config::section::<bool>("mail", "enable", false),
+            host: config::section::<String>("mail", "host", "127.0.0.1".to_string()),
+            port: config::section::<u16>("mail", "port", 25),
+            encryption: config::section::<String>("mail", "encryption", "none".to_string()),
+            username: config::section::<String>("mail", "username", "".to_string()),
+            password: config::section::<String>("mail", "password", "".to_string()),
+            from: config::section::<String>("mail", "from", "".to_string()),
+            timeout: config::section::<u64>("mail", "timeout", 10),
+        }
+    }
+
+    /// 校验配置
+    pub fn check(&self) -> Result<(), String> {
+        if self.host.is_empty() {
+            return Err("host 不能为空".to_string());
+        }
+        if !ENCRYPTIONS.contains(&self.encryption.as_str()) {
+            return Err(format!(
+                "encryption 的值 \"{}\" 错误，可用 {}",
+                self.encryption,
+                ENCRYPTIONS.join("、")
+            ));
+        }
+        if let Err(err) = self.from.parse::<Mailbox>() {
+            return Err(format!("from 的值 \"{}\" 错误，{}", self.from, err));
+        }
+
+        Ok(())
+    }
+}
+
+// smtp 连接
+fn transport(conf: &MailConfig) -> Result<AsyncSmtpTransport<Tokio1Executor>, String> {
+    let host = conf.host.as_str();
+
+    let mut builder = match conf.encryption.as_str() {
+        "tls" => AsyncSmtpTransport::<Tokio1Executor>::relay(host).map_err(|e| e.to_string())?,
+        "starttls" => {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host).map_err(|e| e.to_string())?
+        }
+        _ => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host),
+    };
+
+    builder = builder
+        .port(conf.port)
+        .timeout(Some(Duration::from_secs(conf.timeout)));
+
+    if !conf.username.is_empty() {
+        builder = builder.credentials(Credentials::new(
+            conf.username.clone(),
+            conf.password.clone(),
+        ));
+    }
+
+    Ok(builder.build())
+}
+
+/// 发送 html 邮件
+pub async fn send(conf: &MailConfig, to: &str, subject: &str, body: &str) -> Result<(), String> {
+    let from = conf.from.parse::<Mailbox>().map_err(|e| e.to_string())?;
+    let to = to.parse::<Mailbox>().map_err(|e| e.to_string())?;
+
+    let message = Message::builder()
+        .from(from)
+        .to(to)
+        .subject(subject)
+        .header(ContentType::TEXT_HTML)
+        .body(body.to_string())
+        .map_err(|e| e.to_string())?;
+
+    transport(conf)?
+        .send(message)
+        .await
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
@@ -10,8 +10,14 @@ pub mod env;
 pub mod utils;
 pub mod random;
 pub mod redis;
+pub mod theme;
+pub mod i18n;
+pub mod permalink;
+pub mod avatar;
 pub mod rsa;
 pub mod file;
 pub mod page;
 pub mod config;
 pub mod embed;
+pub mod xmlrpc;
+pub mod mail;
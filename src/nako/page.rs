@@ -8,6 +8,17 @@ pub struct Pagination {
     pub total: usize,
 }
 
+/// 分页信息
+#[derive(Serialize, Deserialize)]
+pub struct PaginationMeta {
+    pub page: usize,
+    pub per_page: usize,
+    pub total: usize,
+    pub total_pages: usize,
+    pub prev_page: Option<usize>,
+    pub next_page: Option<usize>,
+}
+
 /// 分页
 impl Pagination {
     pub fn new(total: usize, page: usize, per_page: usize) -> Self {
@@ -46,6 +57,18 @@ impl Pagination {
         }
     }
 
+    /// 分页信息
+    pub fn meta(&self) -> PaginationMeta {
+        PaginationMeta {
+            page: self.page,
+            per_page: self.per_page,
+            total: self.total,
+            total_pages: self.total_pages(),
+            prev_page: self.prev_page(),
+            next_page: self.next_page(),
+        }
+    }
+
     /// 生成链接
     pub fn page_links(&self, base_url_str: &str) -> String {
         let parsed_url = self.build_base_url(base_url_str);
@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+
+use crate::nako::{config, time};
+
+/// 默认文章链接
+pub const DEFAULT_PATTERN: &str = "/a/{uuid}";
+
+/// 链接中可用的变量
+pub const PLACEHOLDERS: [&str; 7] = ["year", "month", "day", "slug", "uuid", "id", "cate"];
+
+// 分类 ID 对应的分类路径
+static CATE_PATHS: Lazy<RwLock<HashMap<u32, String>>> = Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// 生成链接需要的文章字段
+#[derive(Clone, Default, Debug)]
+pub struct Fields {
+    pub id: u32,
+    pub uuid: String,
+    pub slug: Option<String>,
+    pub cate_id: u32,
+    pub add_time: Option<i64>,
+}
+
+/// 配置的文章链接格式，格式错误时使用默认格式
+pub fn pattern() -> String {
+    let pattern = config::section::<String>("view", "permalink", DEFAULT_PATTERN.to_string());
+
+    match check(pattern.as_str()) {
+        Ok(_) => pattern,
+        Err(err) => {
+            log::error!("permalink {} err: {}", pattern, err);
+            DEFAULT_PATTERN.to_string()
+        }
+    }
+}
+
+/// 是否为默认链接格式
+pub fn is_default() -> bool {
+    pattern() == DEFAULT_PATTERN
+}
+
+/// 校验链接格式
+pub fn check(pattern: &str) -> Result<(), String> {
+    if !pattern.starts_with('/') || pattern.ends_with('/') {
+        return Err("链接需以 / 开头且不能以 / 结尾".to_string());
+    }
+
+    let names = placeholders(pattern);
+    if let Some(name) = names.iter().find(|v| !PLACEHOLDERS.contains(&v.as_str())) {
+        return Err(format!("不支持的变量 {{{}}}", name));
+    }
+    if !names
+        .iter()
+        .any(|v| v == "slug" || v == "uuid" || v == "id")
+    {
+        return Err("链接需包含 {slug}、{uuid} 或 {id}".to_string());
+    }
+
+    // 只有一段时会和单页冲突
+    let segments = pattern
+        .trim_start_matches('/')
+        .split('/')
+        .collect::<Vec<&str>>();
+    if segments.len() == 1 && segments[0].starts_with('{') && segments[0].ends_with('}') {
+        return Err("链接不能只有一个变量，会和单页链接冲突".to_string());
+    }
+
+    Ok(())
+}
+
+// 链接中的变量名称
+fn placeholders(pattern: &str) -> Vec<String> {
+    let mut names = Vec::new();
+
+    let mut rest = pattern;
+    while let Some(start) = rest.find('{') {
+        let end = match rest[start..].find('}') {
+            Some(v) => start + v,
+            None => break,
+        };
+
+        names.push(rest[start + 1..end].to_string());
+        rest = &rest[end + 1..];
+    }
+
+    names
+}
+
+/// 路由格式
+pub fn route_pattern(pattern: &str) -> String {
+    pattern
+        .replace("{year}", "{year:\\d{4}}")
+        .replace("{month}", "{month:\\d{2}}")
+        .replace("{day}", "{day:\\d{2}}")
+        .replace("{id}", "{id:\\d+}")
+        .replace("{cate}", "{cate:.+}")
+}
+
+/// 文章链接，缺少别名或者分类时使用默认链接
+pub fn url(fields: &Fields) -> String {
+    url_with(pattern().as_str(), fields)
+}
+
+/// 指定格式的文章链接
+pub fn url_with(pattern: &str, fields: &Fields) -> String {
+    let default = DEFAULT_PATTERN.replace("{uuid}", fields.uuid.as_str());
+
+    let slug = fields.slug.clone().unwrap_or_default();
+    if pattern.contains("{slug}") && slug.is_empty() {
+        return default;
+    }
+
+    let cate = cate_path(fields.cate_id).unwrap_or_default();
+    if pattern.contains("{cate}") && cate.is_empty() {
+        return default;
+    }
+
+    let date = time::from_timestamp(fields.add_time.unwrap_or_default());
+
+    pattern
+        .replace("{year}", date.format("%Y").to_string().as_str())
+        .replace("{month}", date.format("%m").to_string().as_str())
+        .replace("{day}", date.format("%d").to_string().as_str())
+        .replace("{slug}", slug.as_str())
+        .replace("{uuid}", fields.uuid.as_str())
+        .replace("{id}", fields.id.to_string().as_str())
+        .replace("{cate}", cate.as_str())
+}
+
+/// 设置分类路径
+pub fn set_cate_paths(data: HashMap<u32, String>) {
+    match CATE_PATHS.write() {
+        Ok(mut v) => *v = data,
+        Err(e) => *e.into_inner() = data,
+    }
+}
+
+/// 分类路径
+pub fn cate_path(id: u32) -> Option<String> {
+    match CATE_PATHS.read() {
+        Ok(v) => v.get(&id).cloned(),
+        Err(e) => e.into_inner().get(&id).cloned(),
+    }
+}
+
+/// 根据标题生成别名，中文转换为拼音
+pub fn slugify(title: &str) -> String {
+    let mut slug = slug::slugify(title);
+
+    if slug.len() > 100 {
+        slug.truncate(100);
+        slug = slug.trim_end_matches('-').to_string();
+    }
+
+    slug
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2023-11-15 09:20:00 UTC
+    const ADD_TIME: i64 = 1700040000;
+
+    fn fields(slug: Option<&str>, cate_id: u32) -> Fields {
+        Fields {
+            id: 12,
+            uuid: "a1b2".to_string(),
+            slug: slug.map(|v| v.to_string()),
+            cate_id,
+            add_time: Some(ADD_TIME),
+        }
+    }
+
+    #[test]
+    fn check_pattern() {
+        assert!(check(DEFAULT_PATTERN).is_ok());
+        assert!(check("/{year}/{month}/{slug}.html").is_ok());
+        assert!(check("/{cate}/{id}").is_ok());
+
+        assert!(check("a/{slug}").is_err());
+        assert!(check("/a/{slug}/").is_err());
+        assert!(check("/a/{name}").is_err());
+        assert!(check("/a/{year}").is_err());
+
+        // 只有一个变量时和单页冲突
+        assert!(check("/{slug}").is_err());
+        assert!(check("/{slug}.html").is_ok());
+    }
+
+    #[test]
+    fn url_with_fields() {
+        let data = fields(Some("hello"), 0);
+
+        assert_eq!(url_with(DEFAULT_PATTERN, &data), "/a/a1b2");
+        assert_eq!(
+            url_with("/{year}/{month}/{day}/{slug}.html", &data),
+            "/2023/11/15/hello.html"
+        );
+        assert_eq!(url_with("/p/{id}", &data), "/p/12");
+
+        // 没有别名时使用默认链接
+        assert_eq!(url_with("/{slug}.html", &fields(None, 0)), "/a/a1b2");
+        assert_eq!(url_with("/{slug}.html", &fields(Some(""), 0)), "/a/a1b2");
+    }
+
+    #[test]
+    fn url_with_cate() {
+        set_cate_paths(HashMap::from([(3, "tech/rust".to_string())]));
+
+        assert_eq!(
+            url_with("/{cate}/{slug}", &fields(Some("hello"), 3)),
+            "/tech/rust/hello"
+        );
+
+        // 没有分类路径时使用默认链接
+        assert_eq!(
+            url_with("/{cate}/{slug}", &fields(Some("hello"), 4)),
+            "/a/a1b2"
+        );
+    }
+
+    #[test]
+    fn route_pattern_regex() {
+        assert_eq!(route_pattern("/{year}/{id}"), "/{year:\\d{4}}/{id:\\d+}");
+    }
+}
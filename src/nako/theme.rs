@@ -0,0 +1,486 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::{Cursor, Read};
+use std::path::{Component, Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tera::Tera;
+
+use crate::nako::{config, embed, view};
+
+/// 主题描述文件
+pub const MANIFEST: &str = "theme.json";
+
+/// 当前主题的设置名称
+pub const SETTING_KEY: &str = "blog_theme";
+
+/// 预览主题的 session 名称
+pub const PREVIEW_KEY: &str = "preview_theme";
+
+/// 主题信息，来自主题目录的 theme.json
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    /// 主题目录名称
+    pub name: String,
+    pub title: String,
+    pub version: String,
+    pub author: String,
+    pub description: String,
+    /// 截图，相对主题静态目录
+    pub screenshot: String,
+    /// 主题必需的模板
+    pub templates: Vec<String>,
+    /// 来源，embed 为内置主题，disk 为主题目录
+    #[serde(skip_deserializing)]
+    pub source: String,
+    /// 缺少的必需模板
+    #[serde(skip_deserializing)]
+    pub missing: Vec<String>,
+}
+
+impl Theme {
+    /// 是否可以启用
+    pub fn is_valid(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+// 前台模板目录
+fn tpl_path() -> String {
+    config::section::<String>("view", "blog_tpl_path", "blog".to_string())
+}
+
+/// 默认主题，缺少的模板及资源从默认主题读取
+pub fn default_name() -> String {
+    config::section::<String>("view", "blog_theme", "nako".to_string())
+}
+
+/// 主题安装目录
+pub fn dir() -> String {
+    config::section::<String>("view", "theme_dir", "./themes".to_string())
+}
+
+// 是否使用打包文件
+fn is_embed() -> bool {
+    config::section::<bool>("app", "is_embed", true)
+}
+
+/// 主题名称只能包含字母、数字、- 及 _
+pub fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+// 路径不能跳出所在目录
+fn is_safe_path(path: &str) -> bool {
+    !path.is_empty()
+        && Path::new(path)
+            .components()
+            .all(|c| matches!(c, Component::Normal(_)))
+}
+
+/// 当前主题，来自网站设置
+pub fn active() -> String {
+    let name = view::SETTINGS.with(|settings| {
+        settings
+            .borrow()
+            .as_ref()
+            .and_then(|v| v.get(SETTING_KEY).cloned())
+    });
+
+    match name {
+        Some(v) if exists(v.as_str()) => v,
+        _ => default_name(),
+    }
+}
+
+/// 根据网站设置获取当前主题
+pub fn active_from(settings: &HashMap<String, String>) -> String {
+    match settings.get(SETTING_KEY) {
+        Some(v) if exists(v.as_str()) => v.clone(),
+        _ => default_name(),
+    }
+}
+
+/// 主题是否已加载
+pub fn exists(name: &str) -> bool {
+    is_valid_name(name) && view::has_template_prefix(format!("{}/{}/", tpl_path(), name).as_str())
+}
+
+/// 主题模板路径，主题缺少时使用默认主题的模板
+pub fn template(theme: &str, name: &str) -> String {
+    let path = format!("{}/{}/{}", tpl_path(), theme, name);
+    if view::has_template(path.as_str()) {
+        return path;
+    }
+
+    format!("{}/{}/{}", tpl_path(), default_name(), name)
+}
+
+/// 主题中指定前缀的模板文件，包含默认主题的模板
+pub fn templates_by_prefix(theme: &str, prefix: &str) -> Vec<String> {
+    let mut list: Vec<String> = Vec::new();
+
+    for name in [theme.to_string(), default_name()] {
+        let theme_prefix = format!("{}/{}/", tpl_path(), name);
+
+        for tpl in view::template_names() {
+            if let Some(file) = tpl.strip_prefix(theme_prefix.as_str()) {
+                if file.starts_with(prefix)
+                    && !file.contains('/')
+                    && !list.contains(&file.to_string())
+                {
+                    list.push(file.to_string());
+                }
+            }
+        }
+    }
+
+    list.sort();
+
+    list
+}
+
+/// 全部主题，包括内置主题及主题目录中的主题
+pub fn list() -> Vec<Theme> {
+    let mut names: Vec<String> = Vec::new();
+
+    let prefix = format!("{}/", tpl_path());
+    for tpl in view::template_names() {
+        if let Some((name, _)) = tpl
+            .strip_prefix(prefix.as_str())
+            .and_then(|v| v.split_once('/'))
+        {
+            if !names.contains(&name.to_string()) {
+                names.push(name.to_string());
+            }
+        }
+    }
+
+    if let Ok(entries) = fs::read_dir(dir()) {
+        for entry in entries.flatten() {
+            let name = entry.file_name().into_string().unwrap_or_default();
+            if entry.path().join(MANIFEST).exists() && !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+
+    names.sort();
+
+    names
+        .into_iter()
+        .filter(|name| is_valid_name(name))
+        .map(|name| manifest(name.as_str()))
+        .collect()
+}
+
+/// 主题信息
+pub fn find(name: &str) -> Option<Theme> {
+    list().into_iter().find(|v| v.name == name)
+}
+
+// 读取主题描述
+fn manifest(name: &str) -> Theme {
+    let disk_file = Path::new(dir().as_str()).join(name).join(MANIFEST);
+
+    let (data, source) = if disk_file.exists() {
+        (fs::read_to_string(disk_file).unwrap_or_default(), "disk")
+    } else {
+        let file = format!("{}/{}/{}", tpl_path(), name, MANIFEST);
+        let data = if is_embed() {
+            embed::Templates::get(file.as_str())
+                .map(|v| String::from_utf8_lossy(v.data.as_ref()).to_string())
+                .unwrap_or_default()
+        } else {
+            fs::read_to_string(Path::new(view::TEMPLATE_DIR).join(file)).unwrap_or_default()
+        };
+
+        (data, "embed")
+    };
+
+    let mut theme: Theme = serde_json::from_str(data.as_str()).unwrap_or_default();
+    theme.name = name.to_string();
+    theme.source = source.to_string();
+    if theme.title.is_empty() {
+        theme.title = name.to_string();
+    }
+
+    theme.missing = theme
+        .templates
+        .iter()
+        .filter(|t| !view::has_template(format!("{}/{}/{}", tpl_path(), name, t).as_str()))
+        .cloned()
+        .collect();
+
+    theme
+}
+
+/// 是否为可在后台编辑的主题模板，格式为 blog/{主题}/{文件}.html
+pub fn is_template_name(name: &str) -> bool {
+    let file = match name.strip_prefix(format!("{}/", tpl_path()).as_str()) {
+        Some(v) => v,
+        None => return false,
+    };
+
+    match file.split_once('/') {
+        Some((theme, file)) => {
+            is_valid_name(theme) && is_safe_path(file) && file.ends_with(".html")
+        }
+        None => false,
+    }
+}
+
+/// 可在后台编辑的全部模板
+pub fn editable_templates() -> Vec<String> {
+    view::template_names()
+        .into_iter()
+        .filter(|name| is_template_name(name))
+        .collect()
+}
+
+/// 模板原始内容，来自主题目录或者内置模板
+pub fn source(name: &str) -> Option<String> {
+    let file = name.strip_prefix(format!("{}/", tpl_path()).as_str())?;
+    if !is_safe_path(file) {
+        return None;
+    }
+
+    if let Some((theme, file)) = file.split_once('/') {
+        let disk_file = Path::new(dir().as_str())
+            .join(theme)
+            .join("templates")
+            .join(file);
+        if disk_file.is_file() {
+            return fs::read_to_string(disk_file).ok();
+        }
+    }
+
+    if is_embed() {
+        return embed::Templates::get(name)
+            .map(|v| String::from_utf8_lossy(v.data.as_ref()).to_string());
+    }
+
+    fs::read_to_string(Path::new(view::TEMPLATE_DIR).join(name)).ok()
+}
+
+/// 加载主题目录中的模板，模板名称为 blog/{主题}/{文件}，出错的主题会被跳过
+pub fn load_templates(view: &mut Tera) {
+    let entries = match fs::read_dir(dir()) {
+        Ok(v) => v,
+        Err(_) => return,
+    };
+
+    for entry in entries.flatten() {
+        let name = entry.file_name().into_string().unwrap_or_default();
+        let tpl_dir = entry.path().join("templates");
+        if !is_valid_name(name.as_str()) || !tpl_dir.is_dir() {
+            continue;
+        }
+
+        let mut files: Vec<PathBuf> = Vec::new();
+        read_files(tpl_dir.as_path(), &mut files);
+
+        let files = files
+            .into_iter()
+            .filter_map(|path| {
+                let file = path.strip_prefix(tpl_dir.as_path()).ok()?;
+                let file = file.to_string_lossy().replace('\\', "/");
+
+                Some((path, Some(format!("{}/{}/{}", tpl_path(), name, file))))
+            })
+            .collect::<Vec<_>>();
+
+        let backup = view.clone();
+        if let Err(err) = view.add_template_files(files) {
+            log::error!("theme {} load err: {:?}", name, err);
+            *view = backup;
+        }
+    }
+}
+
+// 目录下的全部文件
+fn read_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                read_files(path.as_path(), files);
+            } else {
+                files.push(path);
+            }
+        }
+    }
+}
+
+/// 主题资源链接
+pub fn asset_url(theme: &str, path: &str) -> String {
+    format!("/themes/{}/{}", theme, path.trim_start_matches('/'))
+}
+
+/// 主题资源，主题缺少时使用默认主题的资源
+pub fn asset(theme: &str, path: &str) -> Option<Vec<u8>> {
+    if !is_valid_name(theme) || !is_safe_path(path) {
+        return None;
+    }
+
+    asset_of(theme, path).or_else(|| asset_of(default_name().as_str(), path))
+}
+
+// 读取主题资源
+fn asset_of(theme: &str, path: &str) -> Option<Vec<u8>> {
+    let disk_file = Path::new(dir().as_str())
+        .join(theme)
+        .join("static")
+        .join(path);
+    if disk_file.is_file() {
+        return fs::read(disk_file).ok();
+    }
+
+    let file = format!("{}/{}/{}", tpl_path(), theme, path);
+    if is_embed() {
+        return embed::Static::get(file.as_str()).map(|v| v.data.into_owned());
+    }
+
+    fs::read(Path::new("./assert/static").join(file)).ok()
+}
+
+/// 安装主题压缩包，theme.json 需在压缩包根目录或者唯一的子目录中
+pub fn install(data: &[u8]) -> Result<Theme, String> {
+    let mut zip = zip::ZipArchive::new(Cursor::new(data)).map_err(|e| e.to_string())?;
+
+    let mut prefix: Option<String> = None;
+    for name in zip.file_names() {
+        if name == MANIFEST {
+            prefix = Some("".to_string());
+            break;
+        }
+
+        if let Some((top, file)) = name.split_once('/') {
+            if file == MANIFEST {
+                prefix = Some(format!("{}/", top));
+            }
+        }
+    }
+
+    let prefix = prefix.ok_or("压缩包中没有 theme.json".to_string())?;
+
+    let mut data = String::new();
+    zip.by_name(format!("{}{}", prefix, MANIFEST).as_str())
+        .map_err(|e| e.to_string())?
+        .read_to_string(&mut data)
+        .map_err(|e| e.to_string())?;
+
+    let theme: Theme =
+        serde_json::from_str(data.as_str()).map_err(|e| format!("theme.json 格式错误: {}", e))?;
+    if !is_valid_name(theme.name.as_str()) {
+        return Err("主题名称只能包含字母、数字、- 及 _".to_string());
+    }
+    if theme.name == default_name() {
+        return Err("不能覆盖默认主题".to_string());
+    }
+
+    // 先解压到临时目录，检查通过后再替换已安装的主题
+    let theme_dir = Path::new(dir().as_str()).join(theme.name.as_str());
+    let tmp_dir = Path::new(dir().as_str()).join(format!(".{}.tmp", theme.name));
+    if tmp_dir.exists() {
+        fs::remove_dir_all(tmp_dir.as_path()).map_err(|e| e.to_string())?;
+    }
+
+    if let Err(err) = extract(&mut zip, prefix.as_str(), tmp_dir.as_path()) {
+        fs::remove_dir_all(tmp_dir.as_path()).ok();
+
+        return Err(err);
+    }
+
+    let missing = theme
+        .templates
+        .iter()
+        .filter(|t| !tmp_dir.join("templates").join(t).is_file())
+        .cloned()
+        .collect::<Vec<String>>();
+    if !missing.is_empty() {
+        fs::remove_dir_all(tmp_dir.as_path()).ok();
+
+        return Err(format!("缺少必需模板: {}", missing.join(", ")));
+    }
+
+    if let Err(err) = replace_dir(tmp_dir.as_path(), theme_dir.as_path()) {
+        fs::remove_dir_all(tmp_dir.as_path()).ok();
+
+        return Err(err.to_string());
+    }
+
+    Ok(theme)
+}
+
+// 解压主题文件到目录
+fn extract(
+    zip: &mut zip::ZipArchive<Cursor<&[u8]>>,
+    prefix: &str,
+    target_dir: &Path,
+) -> Result<(), String> {
+    fs::create_dir_all(target_dir).map_err(|e| e.to_string())?;
+
+    for i in 0..zip.len() {
+        let mut file = zip.by_index(i).map_err(|e| e.to_string())?;
+        if file.is_dir() {
+            continue;
+        }
+
+        let path = match file.enclosed_name() {
+            Some(v) => v.to_path_buf(),
+            None => continue,
+        };
+        let path = match path.strip_prefix(prefix) {
+            Ok(v) => v.to_path_buf(),
+            Err(_) => continue,
+        };
+
+        // 只保留描述文件、模板及静态资源
+        if path != Path::new(MANIFEST)
+            && !path.starts_with("templates")
+            && !path.starts_with("static")
+        {
+            continue;
+        }
+
+        let mut buf: Vec<u8> = Vec::new();
+        file.read_to_end(&mut buf).map_err(|e| e.to_string())?;
+
+        let target = target_dir.join(path);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        fs::write(target, buf).map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+// 用新目录替换旧目录，替换失败时恢复旧目录
+fn replace_dir(from: &Path, to: &Path) -> std::io::Result<()> {
+    if !to.exists() {
+        return fs::rename(from, to);
+    }
+
+    let name = to.file_name().unwrap_or_default().to_string_lossy();
+    let old = to.with_file_name(format!(".{}.old", name));
+    if old.exists() {
+        fs::remove_dir_all(old.as_path())?;
+    }
+
+    fs::rename(to, old.as_path())?;
+    if let Err(err) = fs::rename(from, to) {
+        fs::rename(old.as_path(), to).ok();
+
+        return Err(err);
+    }
+
+    fs::remove_dir_all(old).ok();
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-pub use chrono::{offset::LocalResult, DateTime, NaiveDateTime, TimeZone, Utc};
+pub use chrono::{offset::LocalResult, DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
 
 use chrono_tz::Tz;
 use chrono_tz::UTC;
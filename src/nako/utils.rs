@@ -6,7 +6,8 @@ use crypto::hmac::Hmac;
 use crypto::mac::Mac;
 use crypto::md5::Md5;
 use crypto::sha1::Sha1;
-use data_encoding::BASE64;
+use crypto::sha2::Sha256;
+use data_encoding::{BASE64, HEXLOWER};
 use humansize::{format_size, DECIMAL};
 use uuid::Uuid;
 
@@ -26,6 +27,13 @@ pub fn sha1(data: &str) -> String {
     h.result_str()
 }
 
+// sha256
+pub fn sha256(data: &str) -> String {
+    let mut h = Sha256::new();
+    h.input_str(data);
+    h.result_str()
+}
+
 // sha1
 pub fn hmac_sha1<'a>(data: &'a str, key: &'a str) -> String {
     let mut hmac = Hmac::new(Sha1::new(), &key.as_bytes());
@@ -40,6 +48,15 @@ pub fn hmac_sha1<'a>(data: &'a str, key: &'a str) -> String {
     s.to_string()
 }
 
+// hmac-sha256，返回十六进制
+pub fn hmac_sha256(data: &[u8], key: &[u8]) -> String {
+    let mut hmac = Hmac::new(Sha256::new(), key);
+
+    hmac.input(data);
+
+    HEXLOWER.encode(hmac.result().code())
+}
+
 // base64 编码
 pub fn base64_encode(data: &[u8]) -> String {
     BASE64.encode(data)
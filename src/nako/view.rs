@@ -1,12 +1,24 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+
+use once_cell::sync::Lazy;
 
 use serde_json::value::{from_value, Value};
 use tera::{Error, Result, Tera};
 
 use actix_web::{dev::ResourceMap, test::TestRequest, Result as WebResult};
 
-use crate::nako::{app, config, utils};
+use crate::nako::{app, avatar as nako_avatar, config, embed, i18n, permalink, theme, utils};
+
+/// 未打包时的模板目录
+pub const TEMPLATE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/assert/templates");
+
+// 已加载的模板名称
+static TEMPLATE_NAMES: Lazy<RwLock<HashSet<String>>> = Lazy::new(|| RwLock::new(HashSet::new()));
+
+// 后台编辑的模板，覆盖同名的主题模板
+static OVERRIDES: Lazy<RwLock<HashMap<String, String>>> = Lazy::new(|| RwLock::new(HashMap::new()));
 
 thread_local! {
     pub static ROUTES_KEY: RefCell<Option<ResourceMap>> = RefCell::new(None);
@@ -61,6 +73,16 @@ fn avatar(args: &HashMap<String, Value>) -> Result<Value> {
     }
 }
 
+// 评论者头像
+fn comment_avatar(args: &HashMap<String, Value>) -> Result<Value> {
+    let email = args
+        .get("email")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default();
+
+    Ok(Value::String(nako_avatar::url(email)))
+}
+
 // 链接
 pub fn url_for(args: &HashMap<String, Value>) -> WebResult<Value, Error> {
     let name = args["name"]
@@ -133,12 +155,233 @@ pub fn settings(args: &HashMap<String, Value>) -> WebResult<Value, Error> {
     })
 }
 
+// 主题资源
+fn theme_assert(args: &HashMap<String, Value>) -> Result<Value> {
+    let path = match args.get("path") {
+        Some(val) => from_value::<String>(val.clone()).unwrap_or_default(),
+        None => "".to_string(),
+    };
+
+    Ok(Value::String(theme::asset_url(&theme::active(), &path)))
+}
+
+// 翻译，其他参数作为翻译参数
+fn t(args: &HashMap<String, Value>) -> Result<Value> {
+    let key = args
+        .get("key")
+        .and_then(|v| v.as_str())
+        .ok_or(Error::msg("`key` should be a string"))?;
+
+    let mut data = args.clone();
+    data.remove("key");
+
+    let fluent_args = i18n::args_from_json(&data);
+
+    Ok(Value::String(i18n::t_args(key, Some(&fluent_args))))
+}
+
+// 文章链接
+fn art_url(args: &HashMap<String, Value>) -> Result<Value> {
+    let art = args
+        .get("art")
+        .and_then(|v| v.as_object())
+        .ok_or(Error::msg("`art` should be an object"))?;
+
+    let fields = permalink::Fields {
+        id: art.get("id").and_then(|v| v.as_u64()).unwrap_or_default() as u32,
+        uuid: art
+            .get("uuid")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        slug: art.get("slug").and_then(|v| v.as_str()).map(|v| v.to_string()),
+        cate_id: art.get("cate_id").and_then(|v| v.as_u64()).unwrap_or_default() as u32,
+        add_time: art.get("add_time").and_then(|v| v.as_i64()),
+    };
+
+    Ok(Value::String(permalink::url(&fields)))
+}
+
+// 当前语言
+fn locale(_: &HashMap<String, Value>) -> Result<Value> {
+    Ok(Value::String(i18n::locale()))
+}
+
 // 设置模板方法
 pub fn set_fns(view: &mut Tera) {
     view.register_function("assert", assert);
+    view.register_function("theme_assert", theme_assert);
     view.register_function("upload_url", upload_url);
     view.register_function("avatar", avatar);
+    view.register_function("comment_avatar", comment_avatar);
     view.register_function("url_for", url_for);
     view.register_function("format_size", format_size);
     view.register_function("settings", settings);
+    view.register_function("t", t);
+    view.register_function("locale", locale);
+    view.register_function("art_url", art_url);
+}
+
+/// 加载模板，打包时使用内置模板
+pub fn build(is_embed: bool) -> std::result::Result<Tera, String> {
+    let mut view: Tera;
+
+    if is_embed {
+        view = Tera::default();
+
+        for file in embed::Templates::iter() {
+            let filename = file.as_ref();
+            view.add_raw_template(filename, embed::get_tpl_data(filename).as_str())
+                .map_err(|e| format!("{}: {:?}", filename, e))?;
+        }
+    } else {
+        view =
+            Tera::new(format!("{}/**/*", TEMPLATE_DIR).as_str()).map_err(|e| format!("{:?}", e))?;
+    }
+
+    // 磁盘上的主题
+    theme::load_templates(&mut view);
+
+    // 后台编辑的模板
+    load_overrides(&mut view);
+
+    // 设置模板方法
+    set_fns(&mut view);
+
+    Ok(view)
+}
+
+// 加载后台编辑的模板，出错的模板会被跳过
+fn load_overrides(view: &mut Tera) {
+    for (name, content) in overrides() {
+        let backup = view.clone();
+        if let Err(err) = view.add_raw_template(name.as_str(), content.as_str()) {
+            log::error!("template {} load err: {}", name, error_message(&err));
+            *view = backup;
+        }
+    }
+}
+
+/// 设置全部后台编辑的模板
+pub fn set_overrides(data: HashMap<String, String>) {
+    match OVERRIDES.write() {
+        Ok(mut v) => *v = data,
+        Err(e) => *e.into_inner() = data,
+    }
+}
+
+/// 设置后台编辑的模板，内容为空时删除
+pub fn set_override(name: &str, content: Option<String>) {
+    let mut guard = match OVERRIDES.write() {
+        Ok(v) => v,
+        Err(e) => e.into_inner(),
+    };
+
+    match content {
+        Some(v) => guard.insert(name.to_string(), v),
+        None => guard.remove(name),
+    };
+}
+
+/// 后台编辑的模板，按名称排序
+pub fn overrides() -> Vec<(String, String)> {
+    let mut data = match OVERRIDES.read() {
+        Ok(v) => v.clone().into_iter().collect::<Vec<_>>(),
+        Err(e) => e.into_inner().clone().into_iter().collect::<Vec<_>>(),
+    };
+    data.sort();
+
+    data
+}
+
+/// 校验模板语法及继承的模板
+pub fn check(view: &Tera, name: &str, content: &str) -> std::result::Result<Tera, String> {
+    let mut view = view.clone();
+    view.add_raw_template(name, content)
+        .map_err(|e| error_message(&e))?;
+
+    Ok(view)
+}
+
+/// 模板错误信息，包含全部原因
+pub fn error_message(err: &Error) -> String {
+    let mut data = vec![err.to_string()];
+
+    let mut cause = std::error::Error::source(err);
+    while let Some(e) = cause {
+        data.push(e.to_string());
+        cause = e.source();
+    }
+
+    data.join("\n")
+}
+
+// 记录模板名称
+fn set_template_names(view: &Tera) {
+    let names = view
+        .get_template_names()
+        .map(|v| v.to_string())
+        .collect::<HashSet<String>>();
+
+    match TEMPLATE_NAMES.write() {
+        Ok(mut v) => *v = names,
+        Err(e) => *e.into_inner() = names,
+    }
+}
+
+/// 模板是否存在
+pub fn has_template(name: &str) -> bool {
+    match TEMPLATE_NAMES.read() {
+        Ok(v) => v.contains(name),
+        Err(e) => e.into_inner().contains(name),
+    }
+}
+
+/// 是否有指定前缀的模板
+pub fn has_template_prefix(prefix: &str) -> bool {
+    match TEMPLATE_NAMES.read() {
+        Ok(v) => v.iter().any(|n| n.starts_with(prefix)),
+        Err(e) => e.into_inner().iter().any(|n| n.starts_with(prefix)),
+    }
+}
+
+/// 全部模板名称
+pub fn template_names() -> Vec<String> {
+    let mut names = match TEMPLATE_NAMES.read() {
+        Ok(v) => v.iter().cloned().collect::<Vec<String>>(),
+        Err(e) => e.into_inner().iter().cloned().collect::<Vec<String>>(),
+    };
+    names.sort();
+
+    names
+}
+
+/// 可热更新的模板，各个 worker 共享
+#[derive(Clone)]
+pub struct Views(Arc<RwLock<Tera>>);
+
+impl Views {
+    pub fn new(view: Tera) -> Self {
+        set_template_names(&view);
+
+        Views(Arc::new(RwLock::new(view)))
+    }
+
+    /// 当前模板
+    pub fn get(&self) -> Tera {
+        match self.0.read() {
+            Ok(v) => v.clone(),
+            Err(e) => e.into_inner().clone(),
+        }
+    }
+
+    /// 替换模板
+    pub fn set(&self, view: Tera) {
+        set_template_names(&view);
+
+        match self.0.write() {
+            Ok(mut v) => *v = view,
+            Err(e) => *e.into_inner() = view,
+        }
+    }
 }
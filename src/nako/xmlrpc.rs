@@ -0,0 +1,315 @@
+use quick_xml::escape::escape;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use crate::nako::utils;
+
+/// XML-RPC 数据
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Bool(bool),
+    String(String),
+    Double(f64),
+    DateTime(String),
+    Base64(Vec<u8>),
+    Struct(Vec<(String, Value)>),
+    Array(Vec<Value>),
+    Nil,
+}
+
+impl Value {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(v) => Some(v.as_str()),
+            _ => None,
+        }
+    }
+
+    /// 数字或者数字字符串
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Value::Int(v) => Some(*v),
+            Value::String(v) => v.trim().parse::<i64>().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(v) => Some(*v),
+            Value::Int(v) => Some(*v != 0),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Value::Base64(v) => Some(v.as_slice()),
+            Value::String(v) => Some(v.as_bytes()),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&Vec<Value>> {
+        match self {
+            Value::Array(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// 结构体字段
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Struct(v) => v.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// 生成结构体
+    pub fn new_struct(members: Vec<(&str, Value)>) -> Value {
+        Value::Struct(
+            members
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v))
+                .collect(),
+        )
+    }
+
+    fn write(&self, out: &mut String) {
+        out.push_str("<value>");
+
+        match self {
+            Value::Int(v) => out.push_str(format!("<int>{}</int>", v).as_str()),
+            Value::Bool(v) => {
+                out.push_str(format!("<boolean>{}</boolean>", if *v { 1 } else { 0 }).as_str())
+            }
+            Value::String(v) => {
+                out.push_str(format!("<string>{}</string>", escape(v.as_str())).as_str())
+            }
+            Value::Double(v) => out.push_str(format!("<double>{}</double>", v).as_str()),
+            Value::DateTime(v) => out.push_str(
+                format!(
+                    "<dateTime.iso8601>{}</dateTime.iso8601>",
+                    escape(v.as_str())
+                )
+                .as_str(),
+            ),
+            Value::Base64(v) => out.push_str(
+                format!("<base64>{}</base64>", utils::base64_encode(v.as_slice())).as_str(),
+            ),
+            Value::Struct(v) => {
+                out.push_str("<struct>");
+                for (name, value) in v {
+                    out.push_str("<member>");
+                    out.push_str(format!("<name>{}</name>", escape(name.as_str())).as_str());
+                    value.write(out);
+                    out.push_str("</member>");
+                }
+                out.push_str("</struct>");
+            }
+            Value::Array(v) => {
+                out.push_str("<array><data>");
+                for value in v {
+                    value.write(out);
+                }
+                out.push_str("</data></array>");
+            }
+            Value::Nil => out.push_str("<nil/>"),
+        }
+
+        out.push_str("</value>");
+    }
+}
+
+/// 请求
+pub struct MethodCall {
+    pub method: String,
+    pub params: Vec<Value>,
+}
+
+impl MethodCall {
+    /// 参数
+    pub fn param(&self, index: usize) -> Value {
+        self.params.get(index).cloned().unwrap_or(Value::Nil)
+    }
+}
+
+// xml 节点
+struct Node {
+    name: String,
+    text: String,
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn child(&self, name: &str) -> Option<&Node> {
+        self.children.iter().find(|n| n.name == name)
+    }
+}
+
+// 解析成节点树
+fn parse_node(data: &str) -> Result<Node, String> {
+    let mut reader = Reader::from_str(data);
+
+    let mut stack: Vec<Node> = vec![Node {
+        name: "".to_string(),
+        text: "".to_string(),
+        children: Vec::new(),
+    }];
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => {
+                stack.push(Node {
+                    name: String::from_utf8_lossy(e.name().as_ref()).to_string(),
+                    text: "".to_string(),
+                    children: Vec::new(),
+                });
+            }
+            Ok(Event::Empty(e)) => {
+                let node = Node {
+                    name: String::from_utf8_lossy(e.name().as_ref()).to_string(),
+                    text: "".to_string(),
+                    children: Vec::new(),
+                };
+                if let Some(parent) = stack.last_mut() {
+                    parent.children.push(node);
+                }
+            }
+            Ok(Event::Text(e)) => {
+                let text = e.unescape().map_err(|e| e.to_string())?;
+                if let Some(node) = stack.last_mut() {
+                    node.text.push_str(text.as_ref());
+                }
+            }
+            Ok(Event::CData(e)) => {
+                let text = String::from_utf8_lossy(e.into_inner().as_ref()).to_string();
+                if let Some(node) = stack.last_mut() {
+                    node.text.push_str(text.as_str());
+                }
+            }
+            Ok(Event::End(_)) => {
+                if stack.len() < 2 {
+                    return Err("xml 格式错误".to_string());
+                }
+
+                if let Some(node) = stack.pop() {
+                    if let Some(parent) = stack.last_mut() {
+                        parent.children.push(node);
+                    }
+                }
+            }
+            Ok(Event::Eof) => break,
+            Ok(_) => {}
+            Err(e) => return Err(e.to_string()),
+        }
+    }
+
+    if stack.len() != 1 {
+        return Err("xml 格式错误".to_string());
+    }
+
+    stack
+        .pop()
+        .and_then(|mut root| root.children.pop())
+        .ok_or("xml 数据为空".to_string())
+}
+
+// 解析 value 节点
+fn parse_value(node: &Node) -> Value {
+    let typ = match node.children.first() {
+        Some(v) => v,
+        // 没有类型时为字符串
+        None => return Value::String(node.text.clone()),
+    };
+
+    let text = typ.text.trim();
+
+    match typ.name.as_str() {
+        "int" | "i4" | "i8" => Value::Int(text.parse::<i64>().unwrap_or_default()),
+        "boolean" => Value::Bool(text == "1" || text == "true"),
+        "string" => Value::String(typ.text.clone()),
+        "double" => Value::Double(text.parse::<f64>().unwrap_or_default()),
+        "dateTime.iso8601" => Value::DateTime(text.to_string()),
+        "base64" => {
+            let data: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+            Value::Base64(utils::base64_decode(data))
+        }
+        "struct" => Value::Struct(
+            typ.children
+                .iter()
+                .filter(|m| m.name == "member")
+                .filter_map(|m| {
+                    let name = m.child("name")?.text.trim().to_string();
+                    let value = m.child("value").map(parse_value).unwrap_or(Value::Nil);
+                    Some((name, value))
+                })
+                .collect(),
+        ),
+        "array" => Value::Array(
+            typ.child("data")
+                .map(|d| {
+                    d.children
+                        .iter()
+                        .filter(|v| v.name == "value")
+                        .map(parse_value)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        ),
+        _ => Value::Nil,
+    }
+}
+
+/// 解析请求
+pub fn parse_call(data: &str) -> Result<MethodCall, String> {
+    let root = parse_node(data)?;
+    if root.name != "methodCall" {
+        return Err("请求格式错误".to_string());
+    }
+
+    let method = root
+        .child("methodName")
+        .map(|n| n.text.trim().to_string())
+        .unwrap_or_default();
+    if method.is_empty() {
+        return Err("请求方法不能为空".to_string());
+    }
+
+    let params = root
+        .child("params")
+        .map(|p| {
+            p.children
+                .iter()
+                .filter(|n| n.name == "param")
+                .map(|n| n.child("value").map(parse_value).unwrap_or(Value::Nil))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(MethodCall { method, params })
+}
+
+/// 成功响应
+pub fn response(value: Value) -> String {
+    let mut out =
+        String::from(r#"<?xml version="1.0" encoding="UTF-8"?><methodResponse><params><param>"#);
+    value.write(&mut out);
+    out.push_str("</param></params></methodResponse>");
+
+    out
+}
+
+/// 错误响应
+pub fn fault(code: i64, message: &str) -> String {
+    let mut out = String::from(r#"<?xml version="1.0" encoding="UTF-8"?><methodResponse><fault>"#);
+    Value::new_struct(vec![
+        ("faultCode", Value::Int(code)),
+        ("faultString", Value::String(message.to_string())),
+    ])
+    .write(&mut out);
+    out.push_str("</fault></methodResponse>");
+
+    out
+}
@@ -4,10 +4,11 @@ use actix_web_lab::middleware::from_fn;
 use crate::nako::config;
 
 use crate::app::controller::admin::{
-    art, attach, auth, cate, comment, error, friendlink, guestbook, index, page, profile, setting,
-    tag, upload, user,
+    art, attach, auth, cate, comment, comment_rule, error, friendlink, guestbook, import, index,
+    not_found, page, profile, redirect, series, setting, tag, template, theme, upload, user,
+    webhook,
 };
-use crate::app::middleware::admin_auth;
+use crate::app::middleware::{admin_auth, blog_settings, locale};
 
 pub fn route(cfg: &mut web::ServiceConfig) {
     let admin_prefix = config::section::<String>("app", "admin_prefix", "admin".to_string());
@@ -68,6 +69,26 @@ pub fn route(cfg: &mut web::ServiceConfig) {
                             .route(web::get().to(profile::update_avatar))
                             .route(web::post().to(profile::update_avatar_save))
                             .name("admin.profile-avatar"),
+                    )
+                    .service(
+                        web::resource("/token")
+                            .route(web::get().to(profile::token))
+                            .name("admin.profile-token"),
+                    )
+                    .service(
+                        web::resource("/token/list")
+                            .route(web::get().to(profile::token_list))
+                            .name("admin.profile-token-list"),
+                    )
+                    .service(
+                        web::resource("/token/create")
+                            .route(web::post().to(profile::token_create))
+                            .name("admin.profile-token-create"),
+                    )
+                    .service(
+                        web::resource("/token/delete")
+                            .route(web::post().to(profile::token_delete))
+                            .name("admin.profile-token-delete"),
                     ),
             )
             .service(
@@ -203,6 +224,108 @@ pub fn route(cfg: &mut web::ServiceConfig) {
                         web::resource("/batch-delete")
                             .route(web::post().to(comment::batch_delete))
                             .name("admin.comment-batch-delete"),
+                    )
+                    .service(
+                        web::resource("/batch-status")
+                            .route(web::post().to(comment::batch_status))
+                            .name("admin.comment-batch-status"),
+                    )
+                    .service(
+                        web::resource("/clear")
+                            .route(web::post().to(comment::clear))
+                            .name("admin.comment-clear"),
+                    ),
+            )
+            .service(
+                // 评论规则
+                web::scope("/comment-rule")
+                    .service(
+                        web::resource("/index")
+                            .route(web::get().to(comment_rule::index))
+                            .name("admin.comment-rule-index"),
+                    )
+                    .service(
+                        web::resource("/list")
+                            .route(web::get().to(comment_rule::list))
+                            .name("admin.comment-rule-list"),
+                    )
+                    .service(
+                        web::resource("/create")
+                            .route(web::get().to(comment_rule::create))
+                            .route(web::post().to(comment_rule::create_save))
+                            .name("admin.comment-rule-create"),
+                    )
+                    .service(
+                        web::resource("/update")
+                            .route(web::get().to(comment_rule::update))
+                            .route(web::post().to(comment_rule::update_save))
+                            .name("admin.comment-rule-update"),
+                    )
+                    .service(
+                        web::resource("/delete")
+                            .route(web::post().to(comment_rule::delete))
+                            .name("admin.comment-rule-delete"),
+                    ),
+            )
+            .service(
+                // 专题
+                web::scope("/series")
+                    .service(
+                        web::resource("/index")
+                            .route(web::get().to(series::index))
+                            .name("admin.series-index"),
+                    )
+                    .service(
+                        web::resource("/list")
+                            .route(web::get().to(series::list))
+                            .name("admin.series-list"),
+                    )
+                    .service(
+                        web::resource("/create")
+                            .route(web::get().to(series::create))
+                            .route(web::post().to(series::create_save))
+                            .name("admin.series-create"),
+                    )
+                    .service(
+                        web::resource("/update")
+                            .route(web::get().to(series::update))
+                            .route(web::post().to(series::update_save))
+                            .name("admin.series-update"),
+                    )
+                    .service(
+                        web::resource("/status")
+                            .route(web::post().to(series::update_status))
+                            .name("admin.series-status"),
+                    )
+                    .service(
+                        web::resource("/delete")
+                            .route(web::post().to(series::delete))
+                            .name("admin.series-delete"),
+                    )
+                    .service(
+                        web::resource("/arts")
+                            .route(web::get().to(series::arts))
+                            .name("admin.series-arts"),
+                    )
+                    .service(
+                        web::resource("/arts-list")
+                            .route(web::get().to(series::arts_list))
+                            .name("admin.series-arts-list"),
+                    )
+                    .service(
+                        web::resource("/art-add")
+                            .route(web::post().to(series::art_add))
+                            .name("admin.series-art-add"),
+                    )
+                    .service(
+                        web::resource("/art-delete")
+                            .route(web::post().to(series::art_delete))
+                            .name("admin.series-art-delete"),
+                    )
+                    .service(
+                        web::resource("/art-sort")
+                            .route(web::post().to(series::art_sort))
+                            .name("admin.series-art-sort"),
                     ),
             )
             .service(
@@ -244,6 +367,11 @@ pub fn route(cfg: &mut web::ServiceConfig) {
                         web::resource("/delete")
                             .route(web::post().to(tag::delete))
                             .name("admin.tag-delete"),
+                    )
+                    .service(
+                        web::resource("/merge")
+                            .route(web::post().to(tag::merge))
+                            .name("admin.tag-merge"),
                     ),
             )
             .service(
@@ -305,11 +433,22 @@ pub fn route(cfg: &mut web::ServiceConfig) {
                             .route(web::get().to(guestbook::detail))
                             .name("admin.guestbook-detail"),
                     )
+                    .service(
+                        web::resource("/reply")
+                            .route(web::get().to(guestbook::reply))
+                            .route(web::post().to(guestbook::reply_save))
+                            .name("admin.guestbook-reply"),
+                    )
                     .service(
                         web::resource("/status")
                             .route(web::post().to(guestbook::update_status))
                             .name("admin.guestbook-status"),
                     )
+                    .service(
+                        web::resource("/approved")
+                            .route(web::post().to(guestbook::update_approved))
+                            .name("admin.guestbook-approved"),
+                    )
                     .service(
                         web::resource("/delete")
                             .route(web::post().to(guestbook::delete))
@@ -362,6 +501,137 @@ pub fn route(cfg: &mut web::ServiceConfig) {
                             .name("admin.friendlink-delete"),
                     ),
             )
+            .service(
+                // 链接跳转
+                web::scope("/redirect")
+                    .service(
+                        web::resource("/index")
+                            .route(web::get().to(redirect::index))
+                            .name("admin.redirect-index"),
+                    )
+                    .service(
+                        web::resource("/list")
+                            .route(web::get().to(redirect::list))
+                            .name("admin.redirect-list"),
+                    )
+                    .service(
+                        web::resource("/create")
+                            .route(web::get().to(redirect::create))
+                            .route(web::post().to(redirect::create_save))
+                            .name("admin.redirect-create"),
+                    )
+                    .service(
+                        web::resource("/update")
+                            .route(web::get().to(redirect::update))
+                            .route(web::post().to(redirect::update_save))
+                            .name("admin.redirect-update"),
+                    )
+                    .service(
+                        web::resource("/status")
+                            .route(web::post().to(redirect::update_status))
+                            .name("admin.redirect-status"),
+                    )
+                    .service(
+                        web::resource("/delete")
+                            .route(web::post().to(redirect::delete))
+                            .name("admin.redirect-delete"),
+                    ),
+            )
+            .service(
+                // 404 记录
+                web::scope("/not-found")
+                    .service(
+                        web::resource("/index")
+                            .route(web::get().to(not_found::index))
+                            .name("admin.not-found-index"),
+                    )
+                    .service(
+                        web::resource("/list")
+                            .route(web::get().to(not_found::list))
+                            .name("admin.not-found-list"),
+                    )
+                    .service(
+                        web::resource("/detail")
+                            .route(web::get().to(not_found::detail))
+                            .name("admin.not-found-detail"),
+                    )
+                    .service(
+                        web::resource("/delete")
+                            .route(web::post().to(not_found::delete))
+                            .name("admin.not-found-delete"),
+                    )
+                    .service(
+                        web::resource("/clear")
+                            .route(web::post().to(not_found::clear))
+                            .name("admin.not-found-clear"),
+                    ),
+            )
+            .service(
+                // Webhook
+                web::scope("/webhook")
+                    .service(
+                        web::resource("/index")
+                            .route(web::get().to(webhook::index))
+                            .name("admin.webhook-index"),
+                    )
+                    .service(
+                        web::resource("/list")
+                            .route(web::get().to(webhook::list))
+                            .name("admin.webhook-list"),
+                    )
+                    .service(
+                        web::resource("/create")
+                            .route(web::get().to(webhook::create))
+                            .route(web::post().to(webhook::create_save))
+                            .name("admin.webhook-create"),
+                    )
+                    .service(
+                        web::resource("/update")
+                            .route(web::get().to(webhook::update))
+                            .route(web::post().to(webhook::update_save))
+                            .name("admin.webhook-update"),
+                    )
+                    .service(
+                        web::resource("/status")
+                            .route(web::post().to(webhook::update_status))
+                            .name("admin.webhook-status"),
+                    )
+                    .service(
+                        web::resource("/delete")
+                            .route(web::post().to(webhook::delete))
+                            .name("admin.webhook-delete"),
+                    )
+                    .service(
+                        web::resource("/test")
+                            .route(web::post().to(webhook::test))
+                            .name("admin.webhook-test"),
+                    )
+                    .service(
+                        web::resource("/log")
+                            .route(web::get().to(webhook::log))
+                            .name("admin.webhook-log"),
+                    )
+                    .service(
+                        web::resource("/log-list")
+                            .route(web::get().to(webhook::log_list))
+                            .name("admin.webhook-log-list"),
+                    )
+                    .service(
+                        web::resource("/log-detail")
+                            .route(web::get().to(webhook::log_detail))
+                            .name("admin.webhook-log-detail"),
+                    )
+                    .service(
+                        web::resource("/log-retry")
+                            .route(web::post().to(webhook::log_retry))
+                            .name("admin.webhook-log-retry"),
+                    )
+                    .service(
+                        web::resource("/log-delete")
+                            .route(web::post().to(webhook::log_delete))
+                            .name("admin.webhook-log-delete"),
+                    ),
+            )
             .service(
                 // 用户
                 web::scope("/user")
@@ -443,6 +713,30 @@ pub fn route(cfg: &mut web::ServiceConfig) {
                             .name("admin.attach-preview"),
                     ),
             )
+            .service(
+                // 导入
+                web::scope("/import")
+                    .service(
+                        web::resource("/index")
+                            .route(web::get().to(import::index))
+                            .name("admin.import-index"),
+                    )
+                    .service(
+                        web::resource("/wxr")
+                            .route(web::post().to(import::wxr))
+                            .name("admin.import-wxr"),
+                    )
+                    .service(
+                        web::resource("/markdown")
+                            .route(web::post().to(import::markdown))
+                            .name("admin.import-markdown"),
+                    )
+                    .service(
+                        web::resource("/export")
+                            .route(web::get().to(import::export))
+                            .name("admin.import-export"),
+                    ),
+            )
             .service(
                 // 设置
                 web::scope("/setting").service(
@@ -452,7 +746,73 @@ pub fn route(cfg: &mut web::ServiceConfig) {
                         .name("admin.setting-index"),
                 ),
             )
+            .service(
+                // 主题
+                web::scope("/theme")
+                    .service(
+                        web::resource("/index")
+                            .route(web::get().to(theme::index))
+                            .name("admin.theme-index"),
+                    )
+                    .service(
+                        web::resource("/activate")
+                            .route(web::post().to(theme::activate))
+                            .name("admin.theme-activate"),
+                    )
+                    .service(
+                        web::resource("/preview")
+                            .route(web::get().to(theme::preview))
+                            .name("admin.theme-preview"),
+                    )
+                    .service(
+                        web::resource("/upload")
+                            .route(web::post().to(theme::upload))
+                            .name("admin.theme-upload"),
+                    ),
+            )
+            .service(
+                // 模板
+                web::scope("/template")
+                    .service(
+                        web::resource("/index")
+                            .route(web::get().to(template::index))
+                            .name("admin.template-index"),
+                    )
+                    .service(
+                        web::resource("/edit")
+                            .route(web::get().to(template::edit))
+                            .route(web::post().to(template::save))
+                            .name("admin.template-edit"),
+                    )
+                    .service(
+                        web::resource("/check")
+                            .route(web::post().to(template::check))
+                            .name("admin.template-check"),
+                    )
+                    .service(
+                        web::resource("/preview")
+                            .route(web::post().to(template::preview))
+                            .name("admin.template-preview"),
+                    )
+                    .service(
+                        web::resource("/version")
+                            .route(web::get().to(template::version))
+                            .name("admin.template-version"),
+                    )
+                    .service(
+                        web::resource("/revert")
+                            .route(web::post().to(template::revert))
+                            .name("admin.template-revert"),
+                    )
+                    .service(
+                        web::resource("/reset")
+                            .route(web::post().to(template::reset))
+                            .name("admin.template-reset"),
+                    ),
+            )
             .default_service(web::to(error::index))
-            .wrap(from_fn(admin_auth::auth)),
+            .wrap(from_fn(blog_settings::settings))
+            .wrap(from_fn(admin_auth::auth))
+            .wrap(from_fn(locale::locale)),
     );
 }
@@ -0,0 +1,134 @@
+use actix_web::web;
+use actix_web_lab::middleware::from_fn;
+
+use crate::app::controller::api::{
+    admin, art, cate, comment, error, friendlink, page, tag, xmlrpc,
+};
+use crate::app::middleware::{api_auth, locale};
+
+pub fn route(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/v1")
+            .service(
+                // 令牌授权接口
+                web::scope("/admin")
+                    .service(
+                        web::resource("/art")
+                            .route(web::post().to(admin::art::create))
+                            .name("api.admin-art-create"),
+                    )
+                    .service(
+                        web::resource("/art/{id}")
+                            .route(web::put().to(admin::art::update))
+                            .route(web::delete().to(admin::art::delete))
+                            .name("api.admin-art-update"),
+                    )
+                    .service(
+                        web::resource("/upload/image")
+                            .route(web::post().to(admin::upload::image))
+                            .name("api.admin-upload-image"),
+                    )
+                    .service(
+                        web::resource("/upload/file")
+                            .route(web::post().to(admin::upload::file))
+                            .name("api.admin-upload-file"),
+                    )
+                    .service(
+                        web::resource("/comment")
+                            .route(web::get().to(admin::comment::list))
+                            .name("api.admin-comment-list"),
+                    )
+                    .service(
+                        web::resource("/comment/{id}")
+                            .route(web::delete().to(admin::comment::delete))
+                            .name("api.admin-comment-delete"),
+                    )
+                    .service(
+                        web::resource("/comment/{id}/status")
+                            .route(web::put().to(admin::comment::update_status))
+                            .name("api.admin-comment-status"),
+                    )
+                    .default_service(web::to(error::index))
+                    .wrap(from_fn(api_auth::auth)),
+            )
+            .service(
+                // 文章
+                web::scope("/art")
+                    .service(
+                        web::resource("")
+                            .route(web::get().to(art::list))
+                            .name("api.art-list"),
+                    )
+                    .service(
+                        web::resource("/{uuid}")
+                            .route(web::get().to(art::detail))
+                            .name("api.art-detail"),
+                    )
+                    .service(
+                        web::resource("/{uuid}/comment")
+                            .route(web::get().to(comment::list))
+                            .name("api.art-comment"),
+                    ),
+            )
+            .service(
+                // 分类
+                web::scope("/cate")
+                    .service(
+                        web::resource("")
+                            .route(web::get().to(cate::list))
+                            .name("api.cate-list"),
+                    )
+                    .service(
+                        web::resource("/{slug}")
+                            .route(web::get().to(cate::detail))
+                            .name("api.cate-detail"),
+                    ),
+            )
+            .service(
+                // 标签
+                web::scope("/tag")
+                    .service(
+                        web::resource("")
+                            .route(web::get().to(tag::list))
+                            .name("api.tag-list"),
+                    )
+                    .service(
+                        web::resource("/{name}")
+                            .route(web::get().to(tag::detail))
+                            .name("api.tag-detail"),
+                    ),
+            )
+            .service(
+                // 页面
+                web::scope("/page")
+                    .service(
+                        web::resource("")
+                            .route(web::get().to(page::list))
+                            .name("api.page-list"),
+                    )
+                    .service(
+                        web::resource("/{slug}")
+                            .route(web::get().to(page::detail))
+                            .name("api.page-detail"),
+                    ),
+            )
+            .service(
+                // 友情链接
+                web::scope("/friendlink").service(
+                    web::resource("")
+                        .route(web::get().to(friendlink::list))
+                        .name("api.friendlink-list"),
+                ),
+            )
+            .default_service(web::to(error::index))
+            .wrap(from_fn(locale::locale)),
+    );
+
+    // MetaWeblog 接口
+    cfg.service(
+        web::resource("/xmlrpc")
+            .app_data(web::PayloadConfig::new(20 * 1024 * 1024))
+            .route(web::post().to(xmlrpc::index))
+            .name("api.xmlrpc"),
+    );
+}
@@ -1,9 +1,13 @@
 use actix_web::web;
 use actix_web_lab::middleware::from_fn;
 
-use crate::app::controller::blog::{cate, comment, error, guestbook, index, page, tag, view};
+use crate::nako::{avatar as nako_avatar, permalink};
 
-use crate::app::middleware::{blog_open, blog_settings};
+use crate::app::controller::blog::{
+    avatar, cate, comment, error, guestbook, index, page, series, tag, view,
+};
+
+use crate::app::middleware::{blog_open, blog_settings, locale};
 
 pub fn route(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -25,7 +29,7 @@ pub fn route(cfg: &mut web::ServiceConfig) {
                             .name("blog.cate-index"),
                     )
                     .service(
-                        web::resource("/{slug}")
+                        web::resource("/{slug:.+}")
                             .route(web::get().to(cate::name))
                             .name("blog.cate-name"),
                     ),
@@ -38,6 +42,14 @@ pub fn route(cfg: &mut web::ServiceConfig) {
                         .name("blog.view-index"),
                 ),
             )
+            .service(
+                // 评论者头像
+                web::scope(nako_avatar::URL_PREFIX).service(
+                    web::resource("/{hash}")
+                        .route(web::get().to(avatar::index))
+                        .name("blog.avatar"),
+                ),
+            )
             .service(
                 // 评论
                 web::scope("/comment").service(
@@ -55,13 +67,38 @@ pub fn route(cfg: &mut web::ServiceConfig) {
                 ),
             )
             .service(
-                // 评论
-                web::scope("/guestbook").service(
-                    web::resource("/create")
-                        .route(web::post().to(guestbook::create))
-                        .name("blog.guestbook-create"),
+                // 专题
+                web::scope("/s").service(
+                    web::resource("/{slug}")
+                        .route(web::get().to(series::index))
+                        .name("blog.series-index"),
                 ),
             )
+            .service(
+                // 评论
+                web::scope("/guestbook")
+                    .service(
+                        web::resource("")
+                            .route(web::get().to(guestbook::index))
+                            .name("blog.guestbook-index"),
+                    )
+                    .service(
+                        web::resource("/create")
+                            .route(web::post().to(guestbook::create))
+                            .name("blog.guestbook-create"),
+                    ),
+            )
+            .configure(|cfg: &mut web::ServiceConfig| {
+                // 自定义文章链接
+                let pattern = permalink::pattern();
+                if pattern != permalink::DEFAULT_PATTERN {
+                    cfg.service(
+                        web::resource(permalink::route_pattern(pattern.as_str()))
+                            .route(web::get().to(view::permalink))
+                            .name("blog.view-permalink"),
+                    );
+                }
+            })
             .service(
                 // 页面
                 web::scope("").service(
@@ -72,6 +109,7 @@ pub fn route(cfg: &mut web::ServiceConfig) {
             )
             .default_service(web::to(error::index))
             .wrap(from_fn(blog_settings::settings))
-            .wrap(from_fn(blog_open::check)),
+            .wrap(from_fn(blog_open::check))
+            .wrap(from_fn(locale::locale)),
     );
 }
@@ -0,0 +1,381 @@
+//! 使用 SQLite 内存数据库测试迁移及模型查询，不需要数据库服务
+//!
+//! cargo test --features sqlite
+#![cfg(feature = "sqlite")]
+
+use std::collections::HashMap;
+
+use sea_orm::{ConnectOptions, Database, DbConn, TryIntoModel};
+
+use nako_blog::app::entity::{
+    self, art as art_entity, attach as attach_entity, comment as comment_entity,
+    guestbook as guestbook_entity, user as user_entity,
+};
+use nako_blog::app::model::{art, art_tag, attach, comment, guestbook, not_found, redirect, user};
+use nako_blog::app::service::permalink;
+use nako_blog::migration;
+use nako_blog::nako::auth;
+
+// 内存数据库每个连接都是独立的，只能使用一个连接
+async fn connect() -> DbConn {
+    let mut opt = ConnectOptions::new("sqlite::memory:".to_string());
+    opt.max_connections(1).sqlx_logging(false);
+
+    let db = Database::connect(opt).await.unwrap();
+    migration::up(&db).await.unwrap();
+
+    db
+}
+
+// 添加文章
+async fn create_art(
+    db: &DbConn,
+    uuid: &str,
+    cate_id: u32,
+    tags: &str,
+    status: i32,
+    add_time: i64,
+) -> art_entity::Model {
+    let data = art_entity::Model {
+        uuid: uuid.to_string(),
+        slug: Some(uuid.to_string()),
+        cate_id,
+        title: format!("title {}", uuid),
+        tags: Some(tags.to_string()),
+        status: Some(status),
+        add_time: Some(add_time),
+        add_ip: Some("127.0.0.1".to_string()),
+        ..entity::default()
+    };
+
+    art::ArtModel::create(db, data)
+        .await
+        .unwrap()
+        .try_into_model()
+        .unwrap()
+}
+
+#[actix_web::test]
+async fn migrate() {
+    let db = connect().await;
+
+    let status = migration::status(&db).await.unwrap();
+    assert!(!status.is_empty());
+    assert!(status.iter().all(|(_, applied)| *applied));
+
+    // 已执行时再次执行不报错
+    migration::up(&db).await.unwrap();
+}
+
+#[actix_web::test]
+async fn art_views_and_find() {
+    let db = connect().await;
+
+    let data = create_art(&db, "a", 1, "", 1, 100).await;
+    assert_eq!(data.views, None);
+
+    let data = art::ArtModel::view_add(&db, data.id, 1).await.unwrap();
+    assert_eq!(data.views, Some(1));
+    let data = art::ArtModel::view_add(&db, data.id, 2).await.unwrap();
+    assert_eq!(data.views, Some(3));
+
+    let found = art::ArtModel::find_by_slug(&db, "a").await.unwrap();
+    assert_eq!(found.map(|v| v.id), Some(data.id));
+    assert!(art::ArtModel::find_by_slug(&db, "b")
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[actix_web::test]
+async fn art_prev_next() {
+    let db = connect().await;
+
+    let a = create_art(&db, "a", 1, "", 1, 100).await;
+    let b = create_art(&db, "b", 2, "", 1, 200).await;
+    let c = create_art(&db, "c", 1, "", 1, 200).await;
+    create_art(&db, "d", 1, "", 0, 300).await;
+
+    // 发布时间相同时按 ID
+    let next = art::ArtModel::find_next(&db, &b, None).await.unwrap();
+    assert_eq!(next.map(|v| v.id), Some(c.id));
+    let prev = art::ArtModel::find_prev(&db, &c, None).await.unwrap();
+    assert_eq!(prev.map(|v| v.id), Some(b.id));
+
+    // 限定分类，不包含未发布的文章
+    let prev = art::ArtModel::find_prev(&db, &c, Some(1)).await.unwrap();
+    assert_eq!(prev.map(|v| v.id), Some(a.id));
+    assert!(art::ArtModel::find_next(&db, &c, Some(1))
+        .await
+        .unwrap()
+        .is_none());
+
+    let titles = art::ArtModel::find_published_titles(&db).await.unwrap();
+    assert_eq!(titles.len(), 3);
+}
+
+#[actix_web::test]
+async fn art_permalink() {
+    let db = connect().await;
+
+    let a = create_art(&db, "a", 1, "", 1, 100).await;
+    let b = create_art(&db, "b", 1, "", 0, 100).await;
+
+    // 只能通过自定义链接访问已发布的文章
+    for (name, value) in [("slug", "a"), ("id", a.id.to_string().as_str())] {
+        let params = HashMap::from([(name.to_string(), value.to_string())]);
+        let found = permalink::find(&db, &params).await;
+        assert_eq!(found.map(|(v, _)| v.id), Some(a.id));
+    }
+    for (name, value) in [("slug", "b"), ("id", b.id.to_string().as_str())] {
+        let params = HashMap::from([(name.to_string(), value.to_string())]);
+        assert!(permalink::find(&db, &params).await.is_none());
+    }
+}
+
+#[actix_web::test]
+async fn art_tags() {
+    let db = connect().await;
+
+    let a = create_art(&db, "a", 1, "rust,go", 1, 100).await;
+    let b = create_art(&db, "b", 1, "rust", 1, 200).await;
+    create_art(&db, "c", 1, "rust", 0, 300).await;
+
+    let tags = art_tag::ArtTagModel::find_tags_by_art_id(&db, a.id)
+        .await
+        .unwrap();
+    assert_eq!(tags.len(), 2);
+
+    let rust = tags.iter().find(|v| v.name == "rust").unwrap();
+    let mut art_ids = art_tag::ArtTagModel::find_art_ids_by_tag_id(&db, rust.id)
+        .await
+        .unwrap();
+    art_ids.sort();
+    assert_eq!(art_ids.len(), 3);
+
+    // 只统计已发布的文章
+    let counts = art_tag::ArtTagModel::find_tag_counts(&db).await.unwrap();
+    assert_eq!(counts.get(&rust.id), Some(&2));
+
+    // 删除文章时删除标签关联
+    art::ArtModel::delete(&db, b.id).await.unwrap();
+    let counts = art_tag::ArtTagModel::find_tag_counts(&db).await.unwrap();
+    assert_eq!(counts.get(&rust.id), Some(&1));
+}
+
+#[actix_web::test]
+async fn attach_size() {
+    let db = connect().await;
+
+    // 超过 u32 的大小
+    let size = 5_000_000_000;
+    let data = attach::AttachModel::create(
+        &db,
+        attach_entity::Model {
+            name: "a.zip".to_string(),
+            path: "a.zip".to_string(),
+            ext: "zip".to_string(),
+            size,
+            md5: "md5".to_string(),
+            status: 1,
+            add_time: 100,
+            add_ip: "127.0.0.1".to_string(),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap()
+    .try_into_model()
+    .unwrap();
+
+    let found = attach::AttachModel::find_by_md5(&db, "md5").await.unwrap();
+    assert_eq!(found.map(|v| (v.id, v.size)), Some((data.id, size)));
+}
+
+#[actix_web::test]
+async fn art_redirect() {
+    let db = connect().await;
+
+    redirect::RedirectModel::save_art_redirect(&db, 1, "/a/foo", "/a/bar", 100)
+        .await
+        .unwrap();
+    // 再次改名时原跳转指向新链接，新链接的跳转被删除
+    redirect::RedirectModel::save_art_redirect(&db, 1, "/a/bar", "/a/baz", 200)
+        .await
+        .unwrap();
+
+    let foo = redirect::RedirectModel::find_by_source(&db, "/a/foo")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(foo.target, "/a/baz");
+
+    redirect::RedirectModel::hit(&db, foo.id).await.unwrap();
+    redirect::RedirectModel::hit(&db, foo.id).await.unwrap();
+    let foo = redirect::RedirectModel::find_by_id(&db, foo.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(foo.hits, 2);
+
+    redirect::RedirectModel::delete_by_source(&db, "/a/foo")
+        .await
+        .unwrap();
+    assert!(redirect::RedirectModel::find_by_source(&db, "/a/foo")
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        redirect::RedirectModel::find_open(&db).await.unwrap().len(),
+        1
+    );
+}
+
+#[actix_web::test]
+async fn not_found_log() {
+    let db = connect().await;
+
+    // 记录数及每个链接的来源数达到上限后只更新已有的记录
+    for (path, referrer) in [
+        ("/a", "r1"),
+        ("/a", "r2"),
+        ("/a", "r3"),
+        ("/a", "r1"),
+        ("/b", "r1"),
+    ] {
+        not_found::NotFoundModel::log(&db, path, referrer, 100, 1, 2)
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(not_found::NotFoundModel::find_count(&db).await.unwrap(), 1);
+
+    let data = not_found::NotFoundModel::search_in_page(
+        &db,
+        1,
+        10,
+        not_found::NotFoundWhere {
+            path: None,
+            referrer: None,
+        },
+    )
+    .await
+    .unwrap()
+    .0;
+    assert_eq!(data[0].hits, 4);
+
+    let referrers = not_found::NotFoundModel::find_referrers(&db, data[0].id, 10)
+        .await
+        .unwrap();
+    assert_eq!(
+        referrers
+            .iter()
+            .map(|v| (v.referrer.as_str(), v.hits))
+            .collect::<Vec<_>>(),
+        vec![("r1", 2), ("r2", 1)]
+    );
+}
+
+#[actix_web::test]
+async fn approved_emails() {
+    let db = connect().await;
+
+    for (email, status) in [
+        ("a@example.com", 1),
+        ("a@example.com", 1),
+        ("b@example.com", 2),
+    ] {
+        comment::CommentModel::create(
+            &db,
+            comment_entity::Model {
+                art_id: 1,
+                username: "a".to_string(),
+                email: Some(email.to_string()),
+                content: "content".to_string(),
+                status: Some(status),
+                add_time: Some(100),
+                add_ip: Some("127.0.0.1".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    let emails = comment::CommentModel::find_approved_emails(&db)
+        .await
+        .unwrap();
+    assert_eq!(emails, vec!["a@example.com".to_string()]);
+
+    guestbook::GuestbookModel::create(
+        &db,
+        guestbook_entity::Model {
+            name: "a".to_string(),
+            message: "message".to_string(),
+            email: Some("c@example.com".to_string()),
+            approved: 0,
+            status: Some(1),
+            add_time: Some(100),
+            add_ip: Some("127.0.0.1".to_string()),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    let emails = guestbook::GuestbookModel::find_approved_emails(&db)
+        .await
+        .unwrap();
+    assert!(emails.is_empty());
+}
+
+#[actix_web::test]
+async fn user_create() {
+    let db = connect().await;
+
+    // 创建时同时保存密码
+    user::UserModel::create_user(
+        &db,
+        user_entity::Model {
+            username: "root".to_string(),
+            password: Some(auth::password_hash("secret")),
+            nickname: "root".to_string(),
+            status: Some(1),
+            add_time: Some(100),
+            add_ip: Some("127.0.0.1".to_string()),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    // 未指定密码时为空
+    user::UserModel::create_user(
+        &db,
+        user_entity::Model {
+            username: "editor".to_string(),
+            nickname: "editor".to_string(),
+            status: Some(1),
+            add_time: Some(100),
+            add_ip: Some("127.0.0.1".to_string()),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    let root = user::UserModel::find_user_by_username(&db, "root")
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(auth::password_verify(
+        "secret",
+        root.password.unwrap_or_default().as_str()
+    ));
+
+    let editor = user::UserModel::find_user_by_username(&db, "editor")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(editor.password, Some("".to_string()));
+}